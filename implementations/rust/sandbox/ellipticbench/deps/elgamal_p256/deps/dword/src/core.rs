@@ -117,7 +117,7 @@ impl DWordPtr {
 impl DWord {
     /// Get a read reference to the word.
     #[inline(always)]
-    pub fn as_ref(&self) -> DWordRef {
+    pub fn as_ref(&self) -> DWordRef<'_> {
         DWordRef {
             scope: PhantomData,
             data: self.data,
@@ -78,7 +78,7 @@ impl<T: Static> Static for Vec<T> {
 
 impl<T: Zero> Zero for Vec<T> {
     fn zero((vec_len, elem_len): Self::Length) -> Self {
-        Self::from_fn(vec_len as usize, |_| T::zero(elem_len.clone()))
+        <Self as FromFn<T>>::from_fn(vec_len as usize, |_| T::zero(elem_len.clone()))
     }
 }
 
@@ -99,7 +99,7 @@ impl<T: Type> Sequence for &[T] {
     where
         T: Zero,
     {
-        Vec::<T>::from_fn(self.seq_length(), |i| {
+        <Vec<T> as FromFn<T>>::from_fn(self.seq_length(), |i| {
             if i < amt {
                 T::zero(n.clone())
             } else {
@@ -109,7 +109,7 @@ impl<T: Type> Sequence for &[T] {
     }
 
     fn seq_shift_right_signed(self, amt: usize) -> Self::Owned {
-        Vec::<T>::from_fn(self.seq_length(), |i| {
+        <Vec<T> as FromFn<T>>::from_fn(self.seq_length(), |i| {
             self.seq_index(if i < amt { 0 } else { i - amt })
         })
     }
@@ -120,7 +120,7 @@ impl<T: Type> Sequence for &[T] {
             return self.clone_arg();
         };
         let a = amt % n;
-        Vec::<T>::from_fn(n, |i| self.seq_index((n.clone() + i - a) % n))
+        <Vec<T> as FromFn<T>>::from_fn(n, |i| self.seq_index((n.clone() + i - a) % n))
     }
 
     fn seq_shift_left(self, n: T::Length, amt: usize) -> Self::Owned
@@ -128,7 +128,7 @@ impl<T: Type> Sequence for &[T] {
         T: Zero,
     {
         let vec_len = self.seq_length();
-        Vec::<T>::from_fn(vec_len, |i| {
+        <Vec<T> as FromFn<T>>::from_fn(vec_len, |i| {
             let j = amt + i;
             if j >= vec_len {
                 T::zero(n.clone())
@@ -140,7 +140,7 @@ impl<T: Type> Sequence for &[T] {
 
     fn seq_rotate_left(self, amt: usize) -> Self::Owned {
         let n = self.seq_length();
-        Vec::<T>::from_fn(n, |i| self.seq_index((amt + i) % n))
+        <Vec<T> as FromFn<T>>::from_fn(n, |i| self.seq_index((amt + i) % n))
     }
 }
 
@@ -251,7 +251,7 @@ impl<T: Ring> Ring for Vec<T> {
 
     fn from_integer(n: Self::Length, x: &num::BigInt) -> Self {
         let r = T::from_integer(n.1, x);
-        Self::from_fn(n.0, |_i| r.clone())
+        <Self as FromFn<T>>::from_fn(n.0, |_i| r.clone())
     }
 
     fn exp_usize(x: Self::Arg<'_>, y: usize) -> Self {
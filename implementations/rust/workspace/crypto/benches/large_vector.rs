@@ -7,7 +7,7 @@
  * @version 0.1
  */
 
-//! LargeVector benchmark
+//! `LargeVector` benchmark
 //!
 //! This benchmark measures the performance of [`LargeVector`][`crypto::utils::serialization::variable::LargeVector`]
 //! serialization under two implementations
@@ -27,7 +27,6 @@
 extern crate test;
 use test::Bencher;
 
-use bincode;
 use bincode::config;
 use bincode::serde::encode_to_vec;
 use crypto::context::Context;
@@ -64,7 +63,7 @@ struct SerdeVector(Vec<EG>);
 /// Serialize the vector using serde + bincode.
 fn lvserde(lv: &SerdeVector) {
     let config = config::standard();
-    let _bytes = encode_to_vec(&lv, config).unwrap();
+    let _bytes = encode_to_vec(lv, config).unwrap();
 }
 
 /// Serialize the [`LargeVector`] using our custom serialization.
@@ -79,6 +78,7 @@ where
 ///
 /// Includes printout of resulting byte vector size.
 #[bench]
+#[allow(clippy::print_stdout)]
 fn bench_large_vector(b: &mut Bencher) {
     let mut lv = LargeVector(vec![]);
     let count = 1000;
@@ -101,6 +101,7 @@ fn bench_large_vector(b: &mut Bencher) {
 ///
 /// Includes printout of resulting byte vector size.
 #[bench]
+#[allow(clippy::print_stdout)]
 fn bench_large_vector_serde_bincode(b: &mut Bencher) {
     let mut lv = SerdeVector(vec![]);
     let count = 1000;
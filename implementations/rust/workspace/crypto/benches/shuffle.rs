@@ -55,7 +55,7 @@ fn bench_shuffle_verify_ristretto(b: &mut Bencher) {
 /// `cargo bench shuffle -- --include-ignored`
 ///
 /// to run.
-#[ignore]
+#[ignore = "expensive, run with --include-ignored"]
 #[bench]
 fn bench_shuffle_prove_p256(b: &mut Bencher) {
     bench_shuffle_prove::<PCtx>(b);
@@ -68,7 +68,7 @@ fn bench_shuffle_prove_p256(b: &mut Bencher) {
 /// `cargo bench shuffle -- --include-ignored`
 ///
 /// to run.
-#[ignore]
+#[ignore = "expensive, run with --include-ignored"]
 #[bench]
 fn bench_shuffle_verify_p256(b: &mut Bencher) {
     bench_shuffle_verify::<PCtx>(b);
@@ -84,11 +84,11 @@ fn bench_shuffle_prove<C: Context>(b: &mut Bencher) {
         .collect();
     let ciphertexts: Vec<Ciphertext<C, W>> = messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-    let generators = C::G::ind_generators(count, &vec![]).unwrap();
+    let generators = C::G::ind_generators(count, &[]).unwrap();
     let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
 
     b.iter(|| {
-        let (_pciphertexts, _proof) = black_box(shuffler.shuffle(&ciphertexts, &vec![]).unwrap());
+        let (_pciphertexts, _proof) = black_box(shuffler.shuffle(&ciphertexts, &[]).unwrap());
     });
 }
 
@@ -102,15 +102,15 @@ fn bench_shuffle_verify<C: Context>(b: &mut Bencher) {
         .collect();
     let ciphertexts: Vec<Ciphertext<C, W>> = messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-    let generators = C::G::ind_generators(count, &vec![]).unwrap();
+    let generators = C::G::ind_generators(count, &[]).unwrap();
     let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
 
-    let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &vec![]).unwrap();
+    let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &[]).unwrap();
 
     b.iter(|| {
         let ok = black_box(
             shuffler
-                .verify(&ciphertexts, &pciphertexts, &proof, &vec![])
+                .verify(&ciphertexts, &pciphertexts, &proof, &[])
                 .unwrap(),
         );
         assert!(ok);
@@ -0,0 +1,553 @@
+/*
+ * Benaloh ballot check
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+//! Benaloh ballot check
+//!
+//! Implements the cryptographic steps of the ballot check subprotocol, where
+//! the voter challenges an encrypted ballot to verify that it was cast as intended.
+//! The Voting Application (VA) commits to a [Naor-Yung][`crate::cryptosystem::naoryung`]
+//! encryption of the ballot before knowing whether it will be cast or checked.
+//!
+//! - VA side: [`EncryptedBallot`][`crate::ballot_check::EncryptedBallot`] keeps
+//!   the encryption randomness of one ballot. The ballot is either
+//!   [cast][`crate::ballot_check::EncryptedBallot::cast`], or
+//!   [spoiled][`crate::ballot_check::EncryptedBallot::spoil`] to export its
+//!   [`Randomizers`][`crate::ballot_check::Randomizers`] to the Ballot Check
+//!   Application (BCA). A spoiled ballot can never be cast afterwards, and the
+//!   randomizers of a cast ballot can never be exported.
+//!
+//! - Ballot box side: the VA publishes the [`SpoilMarker`][`crate::ballot_check::SpoilMarker`]
+//!   of a spoiled ballot. The ballot box records published markers in
+//!   [`SpoiledBallots`][`crate::ballot_check::SpoiledBallots`] and
+//!   [checks][`crate::ballot_check::SpoiledBallots::check_cast`] every cast
+//!   ciphertext against them, so that a spoiled ciphertext is rejected however
+//!   it is submitted.
+//!
+//! - BCA side: [`recover_plaintext`][`crate::ballot_check::recover_plaintext`]
+//!   recomputes the ciphertext from the revealed randomizers and checks it
+//!   against the copy on the bulletin board.
+//!
+//! - Report: [`recover_selections`][`crate::ballot_check::recover_selections`] additionally
+//!   [decodes][`crate::cryptosystem::encoding`] the plaintext selections
+//!   for display to the voter.
+//!
+//! * NOTE: randomizers must be transmitted confidentially from the VA to the BCA,
+//!   encrypted under the BCA's public key. This module does not model the messages
+//!   of the subprotocol.
+//!
+//! See the ballot check subprotocol specification, Phase 3.
+
+use crate::context::Context;
+use crate::cryptosystem::encoding;
+use crate::cryptosystem::naoryung::{Ciphertext, PublicKey};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::traits::groups::ReplGroupOps;
+use crate::utils::error::Error;
+use crate::utils::hash;
+use crate::utils::serialization::{Bytes, VSerializable};
+use sha3::Digest;
+use std::collections::HashSet;
+use vser_derive::VSerializable as VSer;
+
+/**
+ * The randomness used to compute a Naor-Yung ballot ciphertext.
+ *
+ * Revealing these values for a spoiled ballot allows the BCA to decrypt
+ * and recompute the ciphertext, including its proof of well-formedness.
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct Randomizers<C: Context, const W: usize> {
+    /// the encryption randomness
+    pub r: [C::Scalar; W],
+    /// the randomness of the proof of well-formedness
+    pub proof_r: [C::Scalar; W],
+}
+
+impl<C: Context, const W: usize> Randomizers<C, W> {
+    /// Construct randomizers from the given values.
+    pub fn new(r: [C::Scalar; W], proof_r: [C::Scalar; W]) -> Self {
+        Self { r, proof_r }
+    }
+
+    /// Generate fresh randomizers.
+    #[must_use]
+    pub fn generate() -> Self {
+        let mut rng = C::get_rng();
+        let r = <[C::Scalar; W]>::random(&mut rng);
+        let proof_r = <[C::Scalar; W]>::random(&mut rng);
+
+        Self::new(r, proof_r)
+    }
+}
+
+/// The status of an [`EncryptedBallot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BallotStatus {
+    /// The ballot has been encrypted, but neither cast nor spoiled
+    Encrypted,
+    /// The ballot has been cast; its randomizers can no longer be revealed
+    Cast,
+    /// The ballot has been spoiled for checking; it can no longer be cast
+    Spoiled,
+}
+
+/**
+ * A publishable record that a ballot ciphertext has been spoiled.
+ *
+ * Holds the hash of the serialized ciphertext, so that the ballot box and the
+ * bulletin board can recognize the ciphertext without the marker revealing
+ * anything beyond it.
+ */
+#[derive(Debug, Clone, VSer, PartialEq, Eq)]
+pub struct SpoilMarker {
+    /// `H(ciphertext)`
+    pub hash: Bytes,
+}
+
+impl SpoilMarker {
+    /// Domain separation tag of the ciphertext hash
+    const DS_TAG: &'static [u8] = b"ballot_spoil_marker";
+
+    /// Construct the spoil marker of the given ciphertext.
+    pub fn new<C: Context, const W: usize>(ciphertext: &Ciphertext<C, W>) -> Self {
+        let mut hasher = C::get_hasher();
+        hash::update_hasher(&mut hasher, &[&ciphertext.ser()], &[Self::DS_TAG]);
+
+        Self {
+            hash: Bytes(hasher.finalize().to_vec()),
+        }
+    }
+}
+
+/**
+ * The spoil markers published so far, as recorded by the ballot box.
+ *
+ * The cast path must [check][`SpoiledBallots::check_cast`] every ciphertext
+ * against this registry before accepting it.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::ballot_check::{EncryptedBallot, SpoiledBallots};
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::context::Context;
+ * use crypto::cryptosystem::naoryung::KeyPair;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate(&[]).unwrap();
+ * let message = [RCtx::random_element()];
+ *
+ * let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+ * let copy = ballot.ciphertext().clone();
+ * let _randomizers = ballot.spoil().unwrap();
+ *
+ * let mut spoiled = SpoiledBallots::new();
+ * spoiled.insert(ballot.spoil_marker().unwrap());
+ * assert!(spoiled.check_cast(&copy).is_err());
+ * ```
+ */
+#[derive(Debug, Clone, Default)]
+pub struct SpoiledBallots {
+    /// the published markers
+    markers: HashSet<SpoilMarker>,
+}
+
+impl SpoiledBallots {
+    /// Construct an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a published spoil marker.
+    pub fn insert(&mut self, marker: SpoilMarker) {
+        self.markers.insert(marker);
+    }
+
+    /// Returns `true` if the given ciphertext has been spoiled.
+    pub fn is_spoiled<C: Context, const W: usize>(&self, ciphertext: &Ciphertext<C, W>) -> bool {
+        self.markers.contains(&SpoilMarker::new(ciphertext))
+    }
+
+    /// Check that the given ciphertext may be cast.
+    ///
+    /// # Errors
+    ///
+    /// - `BallotCheckError` if the ciphertext has been spoiled
+    pub fn check_cast<C: Context, const W: usize>(
+        &self,
+        ciphertext: &Ciphertext<C, W>,
+    ) -> Result<(), Error> {
+        if self.is_spoiled(ciphertext) {
+            return Err(Error::BallotCheckError(
+                "Cannot cast a spoiled ballot".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/**
+ * A ballot encrypted by the Voting Application, together with its randomizers.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::ballot_check::{self, EncryptedBallot};
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::encoding;
+ * use crypto::cryptosystem::naoryung::KeyPair;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate(&[]).unwrap();
+ * let selections = b"contest-1: candidate-2";
+ * let message = encoding::encode_bytes::<RCtx, 1>(selections).unwrap();
+ *
+ * // VA side
+ * let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+ * let bulletin = ballot.ciphertext().clone();
+ * let randomizers = ballot.spoil().unwrap();
+ * assert!(ballot.cast().is_err());
+ *
+ * // BCA side
+ * let recovered = ballot_check::recover_selections(&keypair.pkey, &bulletin, &randomizers, &[]).unwrap();
+ * assert_eq!(recovered, selections);
+ * ```
+ */
+#[derive(Debug)]
+pub struct EncryptedBallot<C: Context, const W: usize> {
+    /// the ballot ciphertext, posted to the bulletin board
+    ciphertext: Ciphertext<C, W>,
+    /// the randomness used to compute the ciphertext
+    randomizers: Randomizers<C, W>,
+    /// whether the ballot has been cast or spoiled
+    status: BallotStatus,
+}
+
+impl<C: Context, const W: usize> EncryptedBallot<C, W> {
+    /// Encrypt the given ballot, retaining its randomizers.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: the election public key
+    /// - `message`: the encoded ballot, of width `W`
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for the proof of well-formedness returns error
+    pub fn encrypt(
+        pk: &PublicKey<C>,
+        message: &[C::Element; W],
+        context: &[u8],
+    ) -> Result<Self, Error> {
+        let randomizers = Randomizers::generate();
+        let ciphertext =
+            pk.encrypt_with_randomness(message, &randomizers.r, &randomizers.proof_r, context)?;
+
+        Ok(Self {
+            ciphertext,
+            randomizers,
+            status: BallotStatus::Encrypted,
+        })
+    }
+
+    /// Returns the ballot ciphertext.
+    pub fn ciphertext(&self) -> &Ciphertext<C, W> {
+        &self.ciphertext
+    }
+
+    /// Returns the status of this ballot.
+    pub fn status(&self) -> BallotStatus {
+        self.status
+    }
+
+    /// Spoil this ballot, returning its randomizers for the ballot check.
+    ///
+    /// A spoiled ballot cannot be cast afterwards. Spoiling an already
+    /// spoiled ballot returns the same randomizers.
+    ///
+    /// # Errors
+    ///
+    /// - `BallotCheckError` if the ballot has already been cast
+    pub fn spoil(&mut self) -> Result<Randomizers<C, W>, Error> {
+        if self.status == BallotStatus::Cast {
+            return Err(Error::BallotCheckError(
+                "Cannot reveal randomizers of a cast ballot".to_string(),
+            ));
+        }
+        self.status = BallotStatus::Spoiled;
+
+        Ok(self.randomizers.clone())
+    }
+
+    /// Returns the marker to publish for this spoiled ballot.
+    ///
+    /// # Errors
+    ///
+    /// - `BallotCheckError` if the ballot has not been spoiled
+    pub fn spoil_marker(&self) -> Result<SpoilMarker, Error> {
+        if self.status != BallotStatus::Spoiled {
+            return Err(Error::BallotCheckError(
+                "Only spoiled ballots have a spoil marker".to_string(),
+            ));
+        }
+
+        Ok(SpoilMarker::new(&self.ciphertext))
+    }
+
+    /// Cast this ballot, returning its ciphertext.
+    ///
+    /// The randomizers of a cast ballot are erased and can no longer be revealed.
+    /// This only guards this instance; the ballot box must also check the
+    /// ciphertext against [`SpoiledBallots`].
+    ///
+    /// # Errors
+    ///
+    /// - `BallotCheckError` if the ballot has been spoiled
+    pub fn cast(&mut self) -> Result<&Ciphertext<C, W>, Error> {
+        if self.status == BallotStatus::Spoiled {
+            return Err(Error::BallotCheckError(
+                "Cannot cast a spoiled ballot".to_string(),
+            ));
+        }
+        self.status = BallotStatus::Cast;
+        let zero: [C::Scalar; W] = std::array::from_fn(|_| C::Scalar::zero());
+        self.randomizers = Randomizers::new(zero.clone(), zero);
+
+        Ok(&self.ciphertext)
+    }
+}
+
+/// Recover the plaintext of a spoiled ballot, checking it against the bulletin board copy.
+///
+/// Computes the plaintext `m = v_b / y^r`, then recomputes the Naor-Yung
+/// ciphertext of `m` with the revealed randomizers and checks that it equals
+/// the bulletin board copy, including its proof of well-formedness.
+///
+/// # Parameters
+///
+/// - `pk`: the election public key
+/// - `bulletin`: the ballot ciphertext as posted on the bulletin board
+/// - `randomizers`: the randomizers revealed by the VA
+/// - `context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `BallotCheckError` if the recomputed ciphertext does not match the bulletin board copy
+/// - `HashToElementError` if challenge generation for the proof of well-formedness returns error
+///
+/// Returns the plaintext ballot.
+pub fn recover_plaintext<C: Context, const W: usize>(
+    pk: &PublicKey<C>,
+    bulletin: &Ciphertext<C, W>,
+    randomizers: &Randomizers<C, W>,
+    context: &[u8],
+) -> Result<[C::Element; W], Error> {
+    let y_r = pk.pk_b.repl_exp(&randomizers.r);
+    let message = bulletin.v_b.mul(&y_r.inv());
+
+    let recomputed =
+        pk.encrypt_with_randomness(&message, &randomizers.r, &randomizers.proof_r, context)?;
+    if recomputed != *bulletin {
+        return Err(Error::BallotCheckError(
+            "Recomputed ciphertext does not match the bulletin board".to_string(),
+        ));
+    }
+
+    Ok(message)
+}
+
+/// Recover and decode the selections of a spoiled ballot.
+///
+/// See [`recover_plaintext`] and [`encoding::decode_bytes`].
+///
+/// # Errors
+///
+/// - `BallotCheckError` if the recomputed ciphertext does not match the bulletin board copy
+/// - `HashToElementError` if challenge generation for the proof of well-formedness returns error
+/// - `EncodingError` if the plaintext is not a valid encoding of a byte string
+///
+/// Returns the decoded selections.
+pub fn recover_selections<C: Context, const W: usize>(
+    pk: &PublicKey<C>,
+    bulletin: &Ciphertext<C, W>,
+    randomizers: &Randomizers<C, W>,
+    context: &[u8],
+) -> Result<Vec<u8>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    let message = recover_plaintext(pk, bulletin, randomizers, context)?;
+
+    encoding::decode_bytes::<C, W>(&message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::naoryung::KeyPair;
+    use crate::utils::serialization::{VDeserializable, VSerializable};
+
+    #[test]
+    fn test_ballot_check_ristretto() {
+        test_ballot_check::<RCtx>();
+    }
+
+    #[test]
+    fn test_ballot_check_p256() {
+        test_ballot_check::<PCtx>();
+    }
+
+    #[test]
+    fn test_ballot_check_invalid_ristretto() {
+        test_ballot_check_invalid::<RCtx>();
+    }
+
+    #[test]
+    fn test_ballot_check_invalid_p256() {
+        test_ballot_check_invalid::<PCtx>();
+    }
+
+    #[test]
+    fn test_ballot_status_ristretto() {
+        test_ballot_status::<RCtx>();
+    }
+
+    #[test]
+    fn test_ballot_status_p256() {
+        test_ballot_status::<PCtx>();
+    }
+
+    #[test]
+    fn test_spoiled_ballots_ristretto() {
+        test_spoiled_ballots::<RCtx>();
+    }
+
+    #[test]
+    fn test_spoiled_ballots_p256() {
+        test_spoiled_ballots::<PCtx>();
+    }
+
+    fn test_ballot_check<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Message = Ctx::Element>,
+        <Ctx::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let keypair = KeyPair::<Ctx>::generate(b"keypair").unwrap();
+        let selections = b"contest-1: candidate-2; contest-2: write-in 'Jane Doe'";
+        let message = encoding::encode_bytes::<Ctx, 3>(selections).unwrap();
+
+        let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, b"ballot").unwrap();
+        let bulletin = ballot.ciphertext().clone();
+        let randomizers = ballot.spoil().unwrap();
+
+        // randomizers survive transport
+        let randomizers = Randomizers::<Ctx, 3>::deser(&randomizers.ser()).unwrap();
+
+        let plaintext =
+            recover_plaintext(&keypair.pkey, &bulletin, &randomizers, b"ballot").unwrap();
+        assert_eq!(plaintext, message);
+        assert_eq!(keypair.decrypt(&bulletin, b"ballot").unwrap(), plaintext);
+
+        let recovered =
+            recover_selections(&keypair.pkey, &bulletin, &randomizers, b"ballot").unwrap();
+        assert_eq!(recovered, selections);
+    }
+
+    fn test_ballot_check_invalid<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate(b"keypair").unwrap();
+        let message = [Ctx::random_element(), Ctx::random_element()];
+
+        let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, b"ballot").unwrap();
+        let bulletin = ballot.ciphertext().clone();
+        let randomizers = ballot.spoil().unwrap();
+
+        // wrong context
+        let result = recover_plaintext(&keypair.pkey, &bulletin, &randomizers, b"other");
+        assert!(matches!(result, Err(Error::BallotCheckError(_))));
+
+        // wrong encryption randomness
+        let mut wrong = randomizers.clone();
+        wrong.r[1] = Ctx::random_scalar();
+        let result = recover_plaintext(&keypair.pkey, &bulletin, &wrong, b"ballot");
+        assert!(matches!(result, Err(Error::BallotCheckError(_))));
+
+        // wrong proof randomness
+        let mut wrong = randomizers.clone();
+        wrong.proof_r[0] = Ctx::random_scalar();
+        let result = recover_plaintext(&keypair.pkey, &bulletin, &wrong, b"ballot");
+        assert!(matches!(result, Err(Error::BallotCheckError(_))));
+
+        // substituted bulletin board ciphertext
+        let other = keypair.pkey.encrypt(&message, b"ballot").unwrap();
+        let result = recover_plaintext(&keypair.pkey, &other, &randomizers, b"ballot");
+        assert!(matches!(result, Err(Error::BallotCheckError(_))));
+    }
+
+    fn test_ballot_status<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate(b"keypair").unwrap();
+        let message = [Ctx::random_element()];
+
+        // spoiled ballots cannot be cast
+        let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+        assert_eq!(ballot.status(), BallotStatus::Encrypted);
+        let randomizers = ballot.spoil().unwrap();
+        assert_eq!(ballot.status(), BallotStatus::Spoiled);
+        assert!(matches!(ballot.cast(), Err(Error::BallotCheckError(_))));
+        assert_eq!(ballot.status(), BallotStatus::Spoiled);
+        assert_eq!(ballot.spoil().unwrap(), randomizers);
+
+        // cast ballots cannot be spoiled
+        let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+        let ciphertext = ballot.ciphertext().clone();
+        assert_eq!(*ballot.cast().unwrap(), ciphertext);
+        assert_eq!(ballot.status(), BallotStatus::Cast);
+        assert!(matches!(ballot.spoil(), Err(Error::BallotCheckError(_))));
+        assert_eq!(keypair.decrypt(&ciphertext, &[]).unwrap(), message);
+    }
+
+    fn test_spoiled_ballots<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate(b"keypair").unwrap();
+        let message = [Ctx::random_element()];
+        let mut spoiled = SpoiledBallots::new();
+
+        let mut ballot = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+        assert!(matches!(
+            ballot.spoil_marker(),
+            Err(Error::BallotCheckError(_))
+        ));
+        let ciphertext = ballot.ciphertext().clone();
+        assert!(spoiled.check_cast(&ciphertext).is_ok());
+
+        ballot.spoil().unwrap();
+        let marker = ballot.spoil_marker().unwrap();
+        assert_eq!(marker, SpoilMarker::new(&ciphertext));
+
+        // the published marker survives transport
+        let marker = SpoilMarker::deser(&marker.ser()).unwrap();
+        spoiled.insert(marker);
+
+        // a copy of the spoiled ciphertext, however obtained, cannot be cast
+        let copy = Ciphertext::<Ctx, 1>::deser(&ciphertext.ser()).unwrap();
+        assert!(spoiled.is_spoiled(&copy));
+        assert!(matches!(
+            spoiled.check_cast(&copy),
+            Err(Error::BallotCheckError(_))
+        ));
+
+        // other ballots are unaffected
+        let mut other = EncryptedBallot::encrypt(&keypair.pkey, &message, &[]).unwrap();
+        assert!(spoiled.check_cast(other.cast().unwrap()).is_ok());
+    }
+}
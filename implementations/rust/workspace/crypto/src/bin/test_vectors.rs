@@ -0,0 +1,77 @@
+/*
+ * Known-answer test vector generator and checker
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+//! Generates and checks known-answer test vectors, see [`crypto::test_vectors`].
+//!
+//! ```text
+//! test-vectors generate <context> <seed> > vectors.json
+//! test-vectors check vectors.json
+//! ```
+//!
+//! The seed is 32 bytes in hexadecimal.
+
+use crypto::test_vectors::{self, CONTEXTS, VectorFile};
+use std::io::Write;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["generate", context, seed] => generate(context, seed),
+        ["check", path] => check(path),
+        _ => Err(usage()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            // nothing else to report if stderr is closed
+            let _ = writeln!(std::io::stderr(), "{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes the vectors of the given context and seed to stdout.
+fn generate(context: &str, seed: &str) -> Result<(), String> {
+    let seed: [u8; 32] = hex::decode(seed)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("The seed must be 32 bytes in hexadecimal")?;
+
+    let file = test_vectors::generate(context, seed).map_err(|e| e.to_string())?;
+    let json = file.to_json().map_err(|e| e.to_string())?;
+
+    writeln!(std::io::stdout(), "{json}").map_err(|e| e.to_string())
+}
+
+/// Checks the vector file at the given path.
+fn check(path: &str) -> Result<(), String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    let file = VectorFile::from_json(&json).map_err(|e| format!("{path}: {e}"))?;
+    test_vectors::check(&file).map_err(|e| format!("{path}: {e}"))?;
+
+    writeln!(
+        std::io::stdout(),
+        "{path}: {} {} vectors ok",
+        file.vectors.len(),
+        file.context
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Returns the usage message.
+fn usage() -> String {
+    format!(
+        "Usage:\n  test-vectors generate <context> <seed>\n  test-vectors check <file>\n\nContexts: {}",
+        CONTEXTS.join(", ")
+    )
+}
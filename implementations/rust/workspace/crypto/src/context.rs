@@ -10,13 +10,20 @@
 
 use rand::rngs::OsRng;
 
+#[cfg(feature = "cryptol")]
+use crate::groups::CryptolP256Group;
+use crate::groups::EgGroup;
+use crate::groups::K256Group;
 use crate::groups::P256Group;
+use crate::groups::P384Group;
 use crate::groups::Ristretto255Group;
 use crate::traits::groups::CryptoGroup;
 use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::utils::hash::Hasher;
 use crate::utils::rng::Rng;
+#[cfg(any(test, feature = "test-vectors"))]
+use crate::utils::rng::SeededRng;
 use crate::utils::serialization::{FSer, VSer};
 use crate::utils::signatures::Ed25519;
 use crate::utils::signatures::SignatureScheme;
@@ -148,6 +155,78 @@ impl Context for P256Ctx {
 
     type G = P256Group;
 }
+
+/**
+ * Defines the Cryptol-derived P256 context.
+ *
+ * Sets
+ * - `p256`, with group operations backed by the Cryptol-derived `elgamal_p256` crate, as the underlying curve.
+ * - `Sha3-256` as the hashing function.
+ * - `OsRng` as the random number generator.
+ * - `Ed25519` as the digital signature scheme.
+ *
+ * Values are byte-compatible with those of [`P256Ctx`]. Group operations are
+ * considerably slower.
+ */
+#[cfg(feature = "cryptol")]
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct CryptolP256Ctx;
+
+#[cfg(feature = "cryptol")]
+impl Context for CryptolP256Ctx {
+    type Element = <Self::G as CryptoGroup>::Element;
+    type Scalar = <Self::G as CryptoGroup>::Scalar;
+    type Hasher = <Self::G as CryptoGroup>::Hasher;
+    type Rng = OsRng;
+    type SignatureScheme = Ed25519<Self::Rng>;
+
+    type G = CryptolP256Group;
+}
+
+/**
+ * Defines the P384 context.
+ *
+ * Sets
+ * - `p384` as the underlying curve.
+ * - `Sha3-512` as the hashing function.
+ * - `OsRng` as the random number generator.
+ * - `Ed25519` as the digital signature scheme.
+ */
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct P384Ctx;
+
+impl Context for P384Ctx {
+    type Element = <Self::G as CryptoGroup>::Element;
+    type Scalar = <Self::G as CryptoGroup>::Scalar;
+    type Hasher = <Self::G as CryptoGroup>::Hasher;
+    type Rng = OsRng;
+    type SignatureScheme = Ed25519<Self::Rng>;
+
+    type G = P384Group;
+}
+
+/**
+ * Defines the secp256k1 context.
+ *
+ * Sets
+ * - `secp256k1` as the underlying curve.
+ * - `Sha3-256` as the hashing function.
+ * - `OsRng` as the random number generator.
+ * - `Ed25519` as the digital signature scheme.
+ */
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct K256Ctx;
+
+impl Context for K256Ctx {
+    type Element = <Self::G as CryptoGroup>::Element;
+    type Scalar = <Self::G as CryptoGroup>::Scalar;
+    type Hasher = <Self::G as CryptoGroup>::Hasher;
+    type Rng = OsRng;
+    type SignatureScheme = Ed25519<Self::Rng>;
+
+    type G = K256Group;
+}
+
 /**
  * Defines the Ristretto context.
  *
@@ -170,6 +249,79 @@ impl Context for RistrettoCtx {
     type G = Ristretto255Group;
 }
 
+/**
+ * Defines the `ElectionGuard` context.
+ *
+ * Sets
+ * - The subgroup of order `q` of `Z_p^*` with the [`ElectionGuard` 2.x parameters][`crate::groups::electionguard::parameters`] as the underlying group.
+ * - The [`ElectionGuard` hash][`crate::groups::electionguard::hash`] `H(H_P; ·)`,
+ *   HMAC-SHA-256 keyed with the parameter base hash `H_P`, as the hashing function.
+ * - `OsRng` as the random number generator.
+ * - `Ed25519` as the digital signature scheme.
+ *
+ * Fiat-Shamir challenges are `H_q(H_P; ·)` values of the transcript, and
+ * elements and scalars serialize as `ElectionGuard` fixed width big-endian
+ * integers of 512 and 32 bytes. Group operations are considerably slower than
+ * those of the curve contexts.
+ */
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct EgCtx;
+
+impl Context for EgCtx {
+    type Element = <Self::G as CryptoGroup>::Element;
+    type Scalar = <Self::G as CryptoGroup>::Scalar;
+    type Hasher = <Self::G as CryptoGroup>::Hasher;
+    type Rng = OsRng;
+    type SignatureScheme = Ed25519<Self::Rng>;
+
+    type G = EgGroup;
+}
+
+/**
+ * Defines a deterministic variant of a context, for reproducible test vectors.
+ *
+ * Sets
+ * - The underlying group and hashing function of `C`.
+ * - [`SeededRng`][`crate::utils::rng::SeededRng`], a seeded `ChaCha20` stream, as the random number generator.
+ * - `Ed25519` as the digital signature scheme.
+ *
+ * Keys, ciphertexts and proofs are the same types as those of `C`, and every
+ * output is a function of the seed and the sequence of operations. Only
+ * available with the `test-vectors` feature. It must not be used to protect
+ * actual votes.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::{RistrettoCtx, SeededCtx};
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::utils::rng::SeededRng;
+ * use crypto::utils::serialization::VSerializable;
+ *
+ * SeededRng::seed([1u8; 32]);
+ * let first = KeyPair::<SeededCtx<RistrettoCtx>>::generate();
+ *
+ * SeededRng::seed([1u8; 32]);
+ * let second = KeyPair::<SeededCtx<RistrettoCtx>>::generate();
+ *
+ * assert_eq!(first.ser(), second.ser());
+ * ```
+ */
+#[cfg(any(test, feature = "test-vectors"))]
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct SeededCtx<C: Context>(pub(crate) std::marker::PhantomData<C>);
+
+#[cfg(any(test, feature = "test-vectors"))]
+impl<C: Context> Context for SeededCtx<C> {
+    type Element = C::Element;
+    type Scalar = C::Scalar;
+    type Hasher = C::Hasher;
+    type Rng = SeededRng;
+    type SignatureScheme = Ed25519<Self::Rng>;
+
+    type G = C::G;
+}
+
 /// Seals the [Context] trait to prevent external implementations.
 mod private {
     /// Sealed traits implement this.
@@ -179,3 +331,10 @@ mod private {
 
 impl private::Sealed for RistrettoCtx {}
 impl private::Sealed for P256Ctx {}
+impl private::Sealed for P384Ctx {}
+impl private::Sealed for K256Ctx {}
+impl private::Sealed for EgCtx {}
+#[cfg(feature = "cryptol")]
+impl private::Sealed for CryptolP256Ctx {}
+#[cfg(any(test, feature = "test-vectors"))]
+impl<C: Context> private::Sealed for SeededCtx<C> {}
@@ -8,10 +8,15 @@
  */
 
 use crate::context::Context;
+use crate::traits::groups::CryptoGroup;
 use crate::traits::groups::DistGroupOps;
+use crate::traits::groups::FixedBaseTable;
 use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::traits::groups::ReplGroupOps;
+use crate::utils::error::Error;
+use crate::zkp::dlogeq::DlogEqProof;
+use crate::zkp::transcript::Transcript;
 use vser_derive::VSerializable;
 
 /**
@@ -139,6 +144,62 @@ impl<C: Context> KeyPair<C> {
     pub fn decrypt<const W: usize>(&self, message: &Ciphertext<C, W>) -> [C::Element; W] {
         decrypt::<C, W>(message.u(), message.v(), &self.skey)
     }
+
+    /// Decrypt the given ciphertext with this key pair, and prove that the
+    /// plaintext is its decryption.
+    ///
+    /// The proof is a [`DlogEqProof`] that `v / plaintext = u^x` for the
+    /// secret key `x` of `y = g^x`, see [`verify_decryption`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crypto::cryptosystem::elgamal::{self, KeyPair};
+    /// use crypto::context::Context;
+    /// use crypto::context::RistrettoCtx as RCtx;
+    ///
+    /// let keypair: KeyPair<RCtx> = KeyPair::generate();
+    /// let message = [RCtx::random_element(); 2];
+    /// let ciphertext = keypair.encrypt(&message);
+    /// // Set to some relevant context value
+    /// let proof_context = &[];
+    /// let (decrypted, proof) = keypair.decrypt_with_proof(&ciphertext, proof_context).unwrap();
+    /// assert_eq!(message, decrypted);
+    ///
+    /// let ok = elgamal::verify_decryption(&keypair.pkey, &ciphertext, &decrypted, &proof, proof_context).unwrap();
+    /// assert!(ok);
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `ciphertext`: The ciphertext to decrypt.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns the pair (plaintext, proof).
+    pub fn decrypt_with_proof<const W: usize>(
+        &self,
+        ciphertext: &Ciphertext<C, W>,
+        context: &[u8],
+    ) -> Result<([C::Element; W], DlogEqProof<C, W>), Error> {
+        let plaintext = self.decrypt(ciphertext);
+        let u_x = ciphertext.u().dist_exp(&self.skey);
+
+        let mut transcript = Transcript::new(DECRYPTION_PROOF_PROTOCOL, context);
+        let proof = DlogEqProof::prove_with_transcript(
+            &mut transcript,
+            &self.skey,
+            &C::generator(),
+            &self.pkey.y,
+            ciphertext.u(),
+            &u_x,
+        )?;
+
+        Ok((plaintext, proof))
+    }
 }
 
 /**
@@ -228,9 +289,7 @@ impl<C: Context> PublicKey<C> {
         message: &[C::Element; W],
         r: &[C::Scalar; W],
     ) -> Ciphertext<C, W> {
-        let g = C::generator();
-
-        let u = g.repl_exp(r);
+        let u = r.each_ref().map(C::G::g_exp);
         let v = self.y.repl_exp(r);
         let v = message.mul(&v);
 
@@ -238,6 +297,79 @@ impl<C: Context> PublicKey<C> {
     }
 }
 
+/**
+ * An `ElGamal` public key with a precomputed [table][`FixedBaseTable`] of powers.
+ *
+ * Encryption with a [`PublicKey`] exponentiates the public key `y` once
+ * per ciphertext component. When encrypting many messages under the same
+ * key, for example the ballots of a voting client, computing a table of
+ * powers of `y` once makes each of these exponentiations considerably faster.
+ * Ciphertexts are identical to those computed with [`PublicKey::encrypt_with_r`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::cryptosystem::elgamal::{KeyPair, PrecomputedPublicKey};
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let precomputed = PrecomputedPublicKey::new(&keypair.pkey);
+ * let message = [RCtx::random_element(); 2];
+ * let ciphertext = precomputed.encrypt(&message);
+ *
+ * assert_eq!(message, keypair.decrypt(&ciphertext));
+ * ```
+ */
+pub struct PrecomputedPublicKey<C: Context> {
+    /// the public key
+    pkey: PublicKey<C>,
+    /// the precomputed powers of the public key
+    table: <C::G as CryptoGroup>::Table,
+}
+impl<C: Context> PrecomputedPublicKey<C> {
+    /// Construct a new precomputed public key, computing the table of powers of `pkey`.
+    #[must_use]
+    pub fn new(pkey: &PublicKey<C>) -> Self {
+        let table = <C::G as CryptoGroup>::Table::new(&pkey.y);
+
+        Self {
+            pkey: pkey.clone(),
+            table,
+        }
+    }
+
+    /// Returns the public key.
+    pub fn get_public_key(&self) -> &PublicKey<C> {
+        &self.pkey
+    }
+
+    /// Encrypt the given message with this public key.
+    ///
+    /// The message can have arbitrary width `W`.
+    pub fn encrypt<const W: usize>(&self, message: &[C::Element; W]) -> Ciphertext<C, W> {
+        let mut rng = C::get_rng();
+        let r = <[C::Scalar; W]>::random(&mut rng);
+
+        self.encrypt_with_r(message, &r)
+    }
+
+    /// Encrypt the given message with this public key and the given randomness.
+    ///
+    /// The message can have arbitrary width `W`.
+    pub fn encrypt_with_r<const W: usize>(
+        &self,
+        message: &[C::Element; W],
+        r: &[C::Scalar; W],
+    ) -> Ciphertext<C, W> {
+        let u = r.each_ref().map(C::G::g_exp);
+        let v = r.each_ref().map(|r| self.table.exp(r));
+        let v = message.mul(&v);
+
+        Ciphertext([u, v])
+    }
+}
+
 /**
  * Decrypt a ciphertext using the given secret key.
  *
@@ -256,6 +388,49 @@ pub fn decrypt<C: Context, const W: usize>(
     v.mul(&u_pow_neg_x)
 }
 
+/**
+ * Verify that `plaintext` is the decryption of `ciphertext` under the secret
+ * key of `pk`.
+ *
+ * Checks a proof computed with [`KeyPair::decrypt_with_proof`], that is a
+ * [`DlogEqProof`] that `v / plaintext = u^x` for `y = g^x`.
+ *
+ * # Parameters
+ *
+ * - `pk`: The public key the ciphertext was encrypted with
+ * - `ciphertext`: The decrypted ciphertext
+ * - `plaintext`: The claimed plaintext
+ * - `proof`: The proof of decryption
+ * - `context`: proof context label (ZKP CONTEXT)
+ *
+ * # Errors
+ *
+ * - `HashToScalarError` if challenge generation returns error
+ *
+ * Returns `true` if the proof is valid, `false` otherwise.
+ */
+pub fn verify_decryption<C: Context, const W: usize>(
+    pk: &PublicKey<C>,
+    ciphertext: &Ciphertext<C, W>,
+    plaintext: &[C::Element; W],
+    proof: &DlogEqProof<C, W>,
+    context: &[u8],
+) -> Result<bool, Error> {
+    let u_x = ciphertext.v().mul(&plaintext.inv());
+
+    let mut transcript = Transcript::new(DECRYPTION_PROOF_PROTOCOL, context);
+    proof.verify_with_transcript(
+        &mut transcript,
+        &C::generator(),
+        &pk.y,
+        ciphertext.u(),
+        &u_x,
+    )
+}
+
+/// Protocol label of the transcript of decryption proofs
+const DECRYPTION_PROOF_PROTOCOL: &[u8] = b"elgamal_decryption_proof";
+
 /**
  * An `ElGamal` ciphertext.
  *
@@ -323,6 +498,42 @@ impl<C: Context, const W: usize> Ciphertext<C, W> {
         Self(re_encrypted)
     }
 
+    /// Multiply this ciphertext component-wise with another ciphertext.
+    ///
+    /// The result encrypts the product of both plaintexts under the same public key.
+    /// For [exponential `ElGamal`][`crate::cryptosystem::exponential`] this is
+    /// homomorphic addition of the plaintext integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crypto::cryptosystem::elgamal::KeyPair;
+    /// use crypto::context::Context;
+    /// use crypto::context::RistrettoCtx as RCtx;
+    /// use crate::crypto::traits::groups::GroupElement;
+    ///
+    /// let keypair: KeyPair<RCtx> = KeyPair::generate();
+    /// let a = [RCtx::random_element(); 2];
+    /// let b = [RCtx::random_element(); 2];
+    /// let product = keypair.encrypt(&a).mul(&keypair.encrypt(&b));
+    /// let decrypted = keypair.decrypt(&product);
+    /// assert_eq!(a.mul(&b), decrypted);
+    /// ```
+    #[must_use]
+    pub fn mul(&self, other: &Self) -> Self {
+        Self(self.0.mul(&other.0))
+    }
+
+    /// Raise each component of this ciphertext to the given scalar.
+    ///
+    /// The result encrypts the plaintext raised to `k`. For
+    /// [exponential `ElGamal`][`crate::cryptosystem::exponential`] this is
+    /// homomorphic multiplication of the plaintext integers by `k`.
+    #[must_use]
+    pub fn exp(&self, k: &C::Scalar) -> Self {
+        Self(self.0.clone().map(|uv| uv.map(|e| e.exp(k))))
+    }
+
     /// Obtain a reference to the first element of the ciphertext, `u`.
     pub fn u(&self) -> &[C::Element; W] {
         &self.0[0]
@@ -382,15 +593,18 @@ impl<C: Context, const W: usize> Ciphertext<C, W> {
 #[cfg(test)]
 mod tests {
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
     use crate::cryptosystem::elgamal;
-    use crate::cryptosystem::elgamal::{Ciphertext, KeyPair};
+    use crate::cryptosystem::elgamal::{Ciphertext, KeyPair, PrecomputedPublicKey};
     use crate::traits::groups::CryptoGroup;
+    use crate::traits::groups::DistGroupOps;
     use crate::traits::groups::GroupElement;
     use crate::traits::groups::GroupScalar;
     use crate::traits::groups::ReplGroupOps;
     use crate::utils::serialization::{FDeserializable, FSerializable};
+    use crate::zkp::dlogeq::DlogEqProof;
 
     #[test]
     fn test_keypair_serialization_ristretto() {
@@ -412,6 +626,11 @@ mod tests {
         test_elgamal::<PCtx>();
     }
 
+    #[test]
+    fn test_elgamal_electionguard() {
+        test_elgamal::<ECtx>();
+    }
+
     #[test]
     fn test_elgamal_serialization_and_decryption_ristretto() {
         test_elgamal_serialization_and_decryption::<RCtx>();
@@ -422,6 +641,104 @@ mod tests {
         test_elgamal_serialization_and_decryption::<PCtx>();
     }
 
+    #[test]
+    fn test_elgamal_encoded_ristretto() {
+        test_elgamal_encoded::<RCtx>();
+    }
+
+    #[test]
+    fn test_elgamal_encoded_p256() {
+        test_elgamal_encoded::<PCtx>();
+    }
+
+    #[test]
+    fn test_elgamal_precomputed_ristretto() {
+        test_elgamal_precomputed::<RCtx>();
+    }
+
+    #[test]
+    fn test_elgamal_precomputed_p256() {
+        test_elgamal_precomputed::<PCtx>();
+    }
+
+    #[test]
+    fn test_elgamal_precomputed_electionguard() {
+        test_elgamal_precomputed::<ECtx>();
+    }
+
+    #[test]
+    fn test_decryption_proof_ristretto() {
+        test_decryption_proof::<RCtx, 1>();
+        test_decryption_proof::<RCtx, 3>();
+    }
+
+    #[test]
+    fn test_decryption_proof_p256() {
+        test_decryption_proof::<PCtx, 2>();
+    }
+
+    fn test_decryption_proof<Ctx: Context, const W: usize>() {
+        let keypair: KeyPair<Ctx> = KeyPair::generate();
+        let message: [Ctx::Element; W] = std::array::from_fn(|_| Ctx::random_element());
+        let ciphertext = keypair.encrypt(&message);
+
+        let (plaintext, proof) = keypair.decrypt_with_proof(&ciphertext, b"context").unwrap();
+        assert_eq!(message, plaintext);
+        assert!(
+            elgamal::verify_decryption(&keypair.pkey, &ciphertext, &plaintext, &proof, b"context")
+                .unwrap()
+        );
+        assert!(
+            !elgamal::verify_decryption(&keypair.pkey, &ciphertext, &plaintext, &proof, b"other")
+                .unwrap()
+        );
+
+        // a wrong plaintext, ciphertext or key
+        let wrong: [Ctx::Element; W] = std::array::from_fn(|_| Ctx::random_element());
+        assert!(
+            !elgamal::verify_decryption(&keypair.pkey, &ciphertext, &wrong, &proof, b"context")
+                .unwrap()
+        );
+        let other = keypair.encrypt(&message);
+        assert!(
+            !elgamal::verify_decryption(&keypair.pkey, &other, &plaintext, &proof, b"context")
+                .unwrap()
+        );
+        let other_keypair: KeyPair<Ctx> = KeyPair::generate();
+        assert!(
+            !elgamal::verify_decryption(
+                &other_keypair.pkey,
+                &ciphertext,
+                &plaintext,
+                &proof,
+                b"context"
+            )
+            .unwrap()
+        );
+
+        // a decryption factor proof for the same values does not verify as a decryption proof
+        let u_x = ciphertext.u().dist_exp(&keypair.skey);
+        let factor_proof = DlogEqProof::<Ctx, W>::prove(
+            &keypair.skey,
+            &Ctx::generator(),
+            &keypair.pkey.y,
+            ciphertext.u(),
+            &u_x,
+            b"context",
+        )
+        .unwrap();
+        assert!(
+            !elgamal::verify_decryption(
+                &keypair.pkey,
+                &ciphertext,
+                &plaintext,
+                &factor_proof,
+                b"context"
+            )
+            .unwrap()
+        );
+    }
+
     fn test_keypair_serialization<Ctx: Context>() {
         let keypair = KeyPair::<Ctx>::generate();
 
@@ -472,6 +789,44 @@ mod tests {
         assert_eq!(one, decrypted_message);
     }
 
+    fn test_elgamal_precomputed<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let precomputed = PrecomputedPublicKey::new(&keypair.pkey);
+        assert_eq!(precomputed.get_public_key(), &keypair.pkey);
+
+        let message = [Ctx::random_element(), Ctx::random_element()];
+        let r = [Ctx::random_scalar(), Ctx::random_scalar()];
+
+        let ciphertext: Ciphertext<Ctx, 2> = precomputed.encrypt_with_r(&message, &r);
+        assert_eq!(ciphertext, keypair.encrypt_with_r(&message, &r));
+
+        // g^r computed without the generator table
+        let g = Ctx::generator();
+        assert_eq!(ciphertext.u(), &g.repl_exp(&r));
+
+        let ciphertext: Ciphertext<Ctx, 2> = precomputed.encrypt(&message);
+        assert_eq!(message, keypair.decrypt(&ciphertext));
+    }
+
+    fn test_elgamal_encoded<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Plaintext = [u8; 30], Message = Ctx::Element>,
+    {
+        use rand::Rng;
+
+        let keypair = KeyPair::<Ctx>::generate();
+        let mut rng = Ctx::get_rng();
+        let mut plaintext = [0u8; 30];
+        rng.fill(&mut plaintext[..]);
+
+        let message = [Ctx::G::encode(&plaintext).unwrap()];
+        let ciphertext: Ciphertext<Ctx, 1> = keypair.encrypt(&message);
+        let [decrypted] = keypair.decrypt(&ciphertext);
+        let decoded = Ctx::G::decode(&decrypted).unwrap();
+
+        assert_eq!(plaintext, decoded);
+    }
+
     fn test_elgamal_serialization_and_decryption<Ctx: Context>() {
         let keypair = KeyPair::<Ctx>::generate();
         let message = [Ctx::random_element(), Ctx::random_element()];
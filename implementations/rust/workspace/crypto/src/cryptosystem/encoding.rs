@@ -0,0 +1,375 @@
+/*
+ * Variable length byte string encoding into multi-element messages
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::traits::groups::CryptoGroup;
+use crate::utils::error::Error;
+
+/// Number of bytes used for the big endian length prefix of framed byte strings
+pub const LENGTH_PREFIX_BYTES: usize = size_of::<u32>();
+
+/// Returns the number of bytes that fit into one group element of context `C`.
+#[must_use]
+pub fn element_capacity<C: Context>() -> usize
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    <C::G as CryptoGroup>::Plaintext::default().as_ref().len()
+}
+
+/// Returns the maximum payload length, in bytes, that can be encoded into `width` elements.
+///
+/// This is `width * capacity` minus the [length prefix][`LENGTH_PREFIX_BYTES`].
+#[must_use]
+pub fn max_payload<C: Context>(width: usize) -> usize
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    width
+        .saturating_mul(element_capacity::<C>())
+        .saturating_sub(LENGTH_PREFIX_BYTES)
+}
+
+/// Encode an arbitrary byte string into a message of width `W`.
+///
+/// The byte string is framed as
+///
+/// <`Length Prefix`><`Payload`><`Padding`>
+///
+/// where `Length Prefix` is the big endian `u32` length of `Payload` and
+/// `Padding` is the number of zero bytes needed to fill `W` elements. The
+/// framed bytes are split into `W` chunks, each of which is encoded with
+/// [`CryptoGroup::encode`].
+///
+/// # Examples
+///
+/// ```
+/// use crypto::context::RistrettoCtx as RCtx;
+/// use crypto::cryptosystem::elgamal::KeyPair;
+/// use crypto::cryptosystem::encoding::{decode_bytes, encode_bytes};
+///
+/// let keypair: KeyPair<RCtx> = KeyPair::generate();
+/// let payload = b"a write-in candidate whose name is longer than one element";
+///
+/// let message = encode_bytes::<RCtx, 3>(payload).unwrap();
+/// let ciphertext = keypair.encrypt(&message);
+/// let decrypted = keypair.decrypt(&ciphertext);
+///
+/// let decoded = decode_bytes::<RCtx, 3>(&decrypted).unwrap();
+/// assert_eq!(payload.as_slice(), decoded.as_slice());
+/// ```
+///
+/// # Errors
+///
+/// - `EncodingCapacityError` if the payload is longer than [`max_payload`] for width `W`
+/// - `EncodingError` if a point was not found for some chunk, with negligible probability
+pub fn encode_bytes<C: Context, const W: usize>(bytes: &[u8]) -> Result<[C::Element; W], Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    let ret = encode_framed::<C>(bytes, W)?;
+    let ret: [C::Element; W] = ret
+        .try_into()
+        .map_err(|_| Error::EncodingError("Unexpected encoded message width".to_string()))?;
+
+    Ok(ret)
+}
+
+/// Decode a byte string from a message of width `W`.
+///
+/// This is the inverse of [`encode_bytes`]. The framing is validated after
+/// decoding each element with [`CryptoGroup::decode`].
+///
+/// # Errors
+///
+/// - `EncodingError` if any element cannot be decoded, if the length prefix
+///   exceeds the available bytes, or if the padding is not all zeros
+pub fn decode_bytes<C: Context, const W: usize>(message: &[C::Element; W]) -> Result<Vec<u8>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    decode_framed::<C>(message)
+}
+
+/// Encode an arbitrary byte string into a runtime sized message.
+///
+/// Uses the same framing as [`encode_bytes`], with the smallest number of
+/// elements that can hold the payload (at least one).
+///
+/// # Errors
+///
+/// - `EncodingCapacityError` if the framed payload length overflows `usize` or `u32`
+/// - `EncodingError` if a point was not found for some chunk, with negligible probability
+pub fn encode_bytes_vec<C: Context>(bytes: &[u8]) -> Result<Vec<C::Element>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    let capacity = element_capacity::<C>();
+    let framed_len = bytes
+        .len()
+        .checked_add(LENGTH_PREFIX_BYTES)
+        .ok_or(Error::EncodingCapacityError(bytes.len(), usize::MAX))?;
+    let width = framed_len.div_ceil(capacity);
+
+    encode_framed::<C>(bytes, width)
+}
+
+/// Decode a byte string from a runtime sized message.
+///
+/// This is the inverse of [`encode_bytes_vec`].
+///
+/// # Errors
+///
+/// - `EncodingError` if any element cannot be decoded, if the length prefix
+///   exceeds the available bytes, or if the padding is not all zeros
+pub fn decode_bytes_vec<C: Context>(message: &[C::Element]) -> Result<Vec<u8>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    decode_framed::<C>(message)
+}
+
+/// Frame the payload and encode it into exactly `width` elements.
+///
+/// # Errors
+///
+/// - `EncodingCapacityError` if the payload does not fit into `width` elements
+/// - `EncodingError` if a point was not found for some chunk, with negligible probability
+fn encode_framed<C: Context>(bytes: &[u8], width: usize) -> Result<Vec<C::Element>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    let capacity = element_capacity::<C>();
+    let max = max_payload::<C>(width);
+    let too_large = Error::EncodingCapacityError(bytes.len(), max);
+    let total = width
+        .checked_mul(capacity)
+        .ok_or(Error::EncodingCapacityError(bytes.len(), usize::MAX))?;
+    if total < LENGTH_PREFIX_BYTES || bytes.len() > max {
+        return Err(too_large);
+    }
+    let length: u32 = bytes.len().try_into().map_err(|_| too_large)?;
+
+    let mut framed = vec![0u8; total];
+    let (prefix, rest) = framed.split_at_mut(LENGTH_PREFIX_BYTES);
+    prefix.copy_from_slice(&length.to_be_bytes());
+    rest[..bytes.len()].copy_from_slice(bytes);
+
+    framed
+        .chunks_exact(capacity)
+        .map(|chunk| {
+            let mut plaintext = <C::G as CryptoGroup>::Plaintext::default();
+            plaintext.as_mut().copy_from_slice(chunk);
+            C::G::encode(&plaintext)
+        })
+        .collect()
+}
+
+/// Decode each element and remove the framing.
+///
+/// # Errors
+///
+/// - `EncodingError` if any element cannot be decoded, if the length prefix
+///   exceeds the available bytes, or if the padding is not all zeros
+fn decode_framed<C: Context>(message: &[C::Element]) -> Result<Vec<u8>, Error>
+where
+    C::G: CryptoGroup<Message = C::Element>,
+    <C::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+{
+    let mut framed = vec![];
+    for element in message {
+        let plaintext = C::G::decode(element)?;
+        framed.extend_from_slice(plaintext.as_ref());
+    }
+
+    let Some((prefix, rest)) = framed.split_first_chunk::<LENGTH_PREFIX_BYTES>() else {
+        return Err(Error::EncodingError(
+            "Message too short to contain a length prefix".to_string(),
+        ));
+    };
+    let length: usize = u32::from_be_bytes(*prefix)
+        .try_into()
+        .map_err(|_| Error::EncodingError("Length prefix exceeds usize".to_string()))?;
+    if length > rest.len() {
+        return Err(Error::EncodingError(
+            "Length prefix exceeds message capacity".to_string(),
+        ));
+    }
+
+    let (payload, padding) = rest.split_at(length);
+    if padding.iter().any(|b| *b != 0) {
+        return Err(Error::EncodingError(
+            "Malformed padding in decoded message".to_string(),
+        ));
+    }
+
+    Ok(payload.to_vec())
+}
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::elgamal::KeyPair;
+
+    #[test]
+    fn test_encode_bytes_ristretto() {
+        test_encode_bytes::<RCtx>();
+    }
+
+    #[test]
+    fn test_encode_bytes_p256() {
+        test_encode_bytes::<PCtx>();
+    }
+
+    #[test]
+    fn test_encode_bytes_vec_ristretto() {
+        test_encode_bytes_vec::<RCtx>();
+    }
+
+    #[test]
+    fn test_encode_bytes_vec_p256() {
+        test_encode_bytes_vec::<PCtx>();
+    }
+
+    #[test]
+    fn test_encode_bytes_capacity_ristretto() {
+        test_encode_bytes_capacity::<RCtx>();
+    }
+
+    #[test]
+    fn test_encode_bytes_capacity_p256() {
+        test_encode_bytes_capacity::<PCtx>();
+    }
+
+    #[test]
+    fn test_decode_bytes_malformed_ristretto() {
+        test_decode_bytes_malformed::<RCtx>();
+    }
+
+    #[test]
+    fn test_decode_bytes_malformed_p256() {
+        test_decode_bytes_malformed::<PCtx>();
+    }
+
+    fn test_encode_bytes<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Message = Ctx::Element>,
+        <Ctx::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+    {
+        use rand::Rng;
+
+        let keypair = KeyPair::<Ctx>::generate();
+        let mut rng = Ctx::get_rng();
+
+        for length in [0, 1, 26, 27, 55, max_payload::<Ctx>(3)] {
+            let mut payload = vec![0u8; length];
+            rng.fill(&mut payload[..]);
+
+            let message = encode_bytes::<Ctx, 3>(&payload).unwrap();
+            let ciphertext = keypair.encrypt(&message);
+            let decrypted = keypair.decrypt(&ciphertext);
+            let decoded = decode_bytes::<Ctx, 3>(&decrypted).unwrap();
+
+            assert_eq!(payload, decoded);
+        }
+    }
+
+    fn test_encode_bytes_vec<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Message = Ctx::Element>,
+        <Ctx::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let capacity = element_capacity::<Ctx>();
+
+        let message = encode_bytes_vec::<Ctx>(&[]).unwrap();
+        assert_eq!(message.len(), 1);
+        assert!(decode_bytes_vec::<Ctx>(&message).unwrap().is_empty());
+
+        let payload = vec![0xffu8; capacity - LENGTH_PREFIX_BYTES];
+        let message = encode_bytes_vec::<Ctx>(&payload).unwrap();
+        assert_eq!(message.len(), 1);
+        assert_eq!(decode_bytes_vec::<Ctx>(&message).unwrap(), payload);
+
+        let payload = vec![0xffu8; capacity - LENGTH_PREFIX_BYTES + 1];
+        let message = encode_bytes_vec::<Ctx>(&payload).unwrap();
+        assert_eq!(message.len(), 2);
+        assert_eq!(decode_bytes_vec::<Ctx>(&message).unwrap(), payload);
+
+        let payload = vec![0x5au8; 1000];
+        let message = encode_bytes_vec::<Ctx>(&payload).unwrap();
+        assert_eq!(
+            message.len(),
+            (1000 + LENGTH_PREFIX_BYTES).div_ceil(capacity)
+        );
+        assert_eq!(decode_bytes_vec::<Ctx>(&message).unwrap(), payload);
+    }
+
+    fn test_encode_bytes_capacity<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Message = Ctx::Element>,
+        <Ctx::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let max = max_payload::<Ctx>(2);
+        assert_eq!(max, 2 * element_capacity::<Ctx>() - LENGTH_PREFIX_BYTES);
+
+        assert!(encode_bytes::<Ctx, 2>(&vec![1u8; max]).is_ok());
+
+        let result = encode_bytes::<Ctx, 2>(&vec![1u8; max + 1]);
+        assert!(matches!(
+            result,
+            Err(Error::EncodingCapacityError(len, cap)) if len == max + 1 && cap == max
+        ));
+
+        // width zero cannot hold the length prefix
+        let result = encode_bytes::<Ctx, 0>(&[]);
+        assert!(matches!(result, Err(Error::EncodingCapacityError(0, 0))));
+    }
+
+    fn test_decode_bytes_malformed<Ctx: Context>()
+    where
+        Ctx::G: CryptoGroup<Message = Ctx::Element>,
+        <Ctx::G as CryptoGroup>::Plaintext: Default + AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let capacity = element_capacity::<Ctx>();
+        let encode_raw = |bytes: &[u8]| {
+            let mut plaintext = <Ctx::G as CryptoGroup>::Plaintext::default();
+            plaintext.as_mut()[..bytes.len()].copy_from_slice(bytes);
+            Ctx::G::encode(&plaintext).unwrap()
+        };
+
+        // length prefix larger than the available bytes
+        let message = [encode_raw(&u32::MAX.to_be_bytes())];
+        assert!(decode_bytes::<Ctx, 1>(&message).is_err());
+
+        let too_long: u32 = (capacity - LENGTH_PREFIX_BYTES + 1).try_into().unwrap();
+        let message = [encode_raw(&too_long.to_be_bytes())];
+        assert!(decode_bytes::<Ctx, 1>(&message).is_err());
+
+        // non-zero padding after the payload
+        let message = [encode_raw(&[0, 0, 0, 1, 0xaa, 0, 0xbb])];
+        assert!(decode_bytes::<Ctx, 1>(&message).is_err());
+
+        // well-formed framing decodes
+        let message = [encode_raw(&[0, 0, 0, 2, 0xaa, 0xbb])];
+        assert_eq!(decode_bytes::<Ctx, 1>(&message).unwrap(), vec![0xaa, 0xbb]);
+
+        // an empty message has no length prefix
+        assert!(decode_bytes_vec::<Ctx>(&[]).is_err());
+    }
+}
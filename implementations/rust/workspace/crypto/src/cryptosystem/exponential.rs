@@ -0,0 +1,323 @@
+/*
+ * Exponential ElGamal cryptosystem
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use std::collections::HashMap;
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::{Ciphertext, KeyPair, PublicKey};
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use crate::utils::serialization::FSerializable;
+
+/**
+ * Lift the given integers into the group, as `g^m`.
+ *
+ * This is the plaintext encoding of exponential `ElGamal`.
+ */
+#[must_use]
+pub fn lift<C: Context, const W: usize>(m: &[u32; W]) -> [C::Element; W] {
+    let g = C::generator();
+    m.map(|m| g.exp(&C::Scalar::from(m)))
+}
+
+/**
+ * Encrypt the given integers with exponential `ElGamal`.
+ *
+ * The integers are [lifted][`lift`] into the group and then encrypted with
+ * plain `ElGamal` under the given public key. The resulting ciphertexts are
+ * additively homomorphic, see [`Ciphertext::mul`] and [`Ciphertext::exp`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::cryptosystem::exponential::{self, DiscreteLogTable};
+ * use crypto::context::RistrettoCtx as RCtx;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let a = exponential::encrypt(&keypair.pkey, &[3, 0]);
+ * let b = exponential::encrypt(&keypair.pkey, &[1, 1]);
+ *
+ * // homomorphic addition
+ * let sum = a.mul(&b);
+ *
+ * let table = DiscreteLogTable::<RCtx>::new(100);
+ * let tally = exponential::decrypt(&keypair, &sum, &table).unwrap();
+ * assert_eq!(tally, [4, 1]);
+ * ```
+ */
+pub fn encrypt<C: Context, const W: usize>(pk: &PublicKey<C>, m: &[u32; W]) -> Ciphertext<C, W> {
+    pk.encrypt(&lift::<C, W>(m))
+}
+
+/**
+ * Encrypt the given integers with exponential `ElGamal`, using the given randomness.
+ *
+ * See [`encrypt`].
+ */
+pub fn encrypt_with_r<C: Context, const W: usize>(
+    pk: &PublicKey<C>,
+    m: &[u32; W],
+    r: &[C::Scalar; W],
+) -> Ciphertext<C, W> {
+    pk.encrypt_with_r(&lift::<C, W>(m), r)
+}
+
+/**
+ * Decrypt the given exponential `ElGamal` ciphertext.
+ *
+ * Decrypts with plain `ElGamal` and then recovers the exponents with the
+ * given [`DiscreteLogTable`].
+ *
+ * # Errors
+ *
+ * - `DiscreteLogError` if some plaintext exponent exceeds the table's maximum
+ */
+pub fn decrypt<C: Context, const W: usize>(
+    keypair: &KeyPair<C>,
+    ciphertext: &Ciphertext<C, W>,
+    table: &DiscreteLogTable<C>,
+) -> Result<[u32; W], Error> {
+    table.solve_array(&keypair.decrypt(ciphertext))
+}
+
+/**
+ * Precomputed baby-step giant-step table for bounded discrete logarithms.
+ *
+ * Recovers `m` from `g^m` for `0 <= m <= max`, as required to decrypt
+ * exponential `ElGamal` tallies. The table holds `ceil(sqrt(max + 1))` baby
+ * steps, so that a lookup takes at most as many giant steps.
+ *
+ * The table applies equally to the output of a local decryption and of a
+ * threshold decryption with [`combine`][`crate::dkgd::recipient::combine`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::exponential::{self, DiscreteLogTable};
+ *
+ * let table = DiscreteLogTable::<RCtx>::new(10_000);
+ * let [h] = exponential::lift::<RCtx, 1>(&[4321]);
+ *
+ * assert_eq!(table.solve(&h).unwrap(), 4321);
+ * ```
+ */
+#[derive(Debug)]
+pub struct DiscreteLogTable<C: Context> {
+    /// serialized baby steps `g^j` mapped to `j`, for `0 <= j < step`
+    baby_steps: HashMap<Vec<u8>, u32>,
+    /// the giant step `g^-step`
+    giant_step: C::Element,
+    /// the number of baby steps
+    step: u32,
+    /// the maximum recoverable exponent
+    max: u32,
+}
+
+impl<C: Context> DiscreteLogTable<C> {
+    /// Precompute a table that recovers exponents in the range `0..=max`.
+    #[must_use]
+    pub fn new(max: u32) -> Self {
+        // ceil(sqrt(max + 1)), computed in u64 so that max = u32::MAX does not overflow
+        let range = u64::from(max).saturating_add(1);
+        let mut step = range.isqrt();
+        if step.saturating_mul(step) < range {
+            step = step.saturating_add(1);
+        }
+        // sqrt(2^32) = 2^16 always fits
+        let step = u32::try_from(step).unwrap_or(u32::MAX);
+
+        let g = C::generator();
+        let mut baby_steps = HashMap::with_capacity(step as usize);
+        let mut current = C::Element::one();
+        for j in 0..step {
+            baby_steps.insert(current.ser_f(), j);
+            current = current.mul(&g);
+        }
+        let giant_step = g.exp(&C::Scalar::from(step).neg());
+
+        Self {
+            baby_steps,
+            giant_step,
+            step,
+            max,
+        }
+    }
+
+    /// Returns the maximum exponent this table can recover.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Recover the exponent `m` from `h = g^m`.
+    ///
+    /// # Errors
+    ///
+    /// - `DiscreteLogError` if `m` is not in the range `0..=max`
+    pub fn solve(&self, h: &C::Element) -> Result<u32, Error> {
+        // step >= 1 by construction
+        let giant_steps = self.max.checked_div(self.step).unwrap_or(self.max);
+        let mut gamma = h.clone();
+
+        for i in 0..=giant_steps {
+            if let Some(j) = self.baby_steps.get(&gamma.ser_f()) {
+                // i * step + j < max + step, which fits in u64
+                let m = u64::from(i)
+                    .checked_mul(u64::from(self.step))
+                    .and_then(|m| m.checked_add(u64::from(*j)))
+                    .and_then(|m| u32::try_from(m).ok());
+                if let Some(m) = m.filter(|m| *m <= self.max) {
+                    return Ok(m);
+                }
+            }
+            gamma = gamma.mul(&self.giant_step);
+        }
+
+        Err(self.not_found())
+    }
+
+    /// Recover the exponents of each element of the given array.
+    ///
+    /// # Errors
+    ///
+    /// - `DiscreteLogError` if some exponent is not in the range `0..=max`
+    pub fn solve_array<const W: usize>(&self, h: &[C::Element; W]) -> Result<[u32; W], Error> {
+        let mut ret = [0u32; W];
+        for (m, h) in ret.iter_mut().zip(h.iter()) {
+            *m = self.solve(h)?;
+        }
+
+        Ok(ret)
+    }
+
+    /// Error returned when an exponent is out of range.
+    fn not_found(&self) -> Error {
+        Error::DiscreteLogError(format!("Discrete log not found in range 0..={}", self.max))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use crate::context::EgCtx as ECtx;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::elgamal::KeyPair;
+    use crate::dkgd::dealer::{Dealer, VerifiableShare};
+    use crate::dkgd::recipient::combine;
+    use crate::dkgd::recipient::{DkgCiphertext, DkgPublicKey, ParticipantPosition, Recipient};
+    use std::array;
+
+    #[test]
+    fn test_discrete_log_table_ristretto() {
+        test_discrete_log_table::<RCtx>();
+    }
+
+    #[test]
+    fn test_discrete_log_table_p256() {
+        test_discrete_log_table::<PCtx>();
+    }
+
+    #[test]
+    fn test_exponential_homomorphism_ristretto() {
+        test_exponential_homomorphism::<RCtx>();
+    }
+
+    #[test]
+    fn test_exponential_homomorphism_p256() {
+        test_exponential_homomorphism::<PCtx>();
+    }
+
+    #[test]
+    fn test_exponential_homomorphism_electionguard() {
+        test_exponential_homomorphism::<ECtx>();
+    }
+
+    #[test]
+    fn test_exponential_threshold_ristretto() {
+        test_exponential_threshold::<RCtx>();
+    }
+
+    #[test]
+    fn test_exponential_threshold_p256() {
+        test_exponential_threshold::<PCtx>();
+    }
+
+    fn test_discrete_log_table<Ctx: Context>() {
+        for max in [0, 1, 2, 15, 16, 17, 1000] {
+            let table = DiscreteLogTable::<Ctx>::new(max);
+            for m in 0..=max {
+                let [h] = lift::<Ctx, 1>(&[m]);
+                assert_eq!(table.solve(&h).unwrap(), m);
+            }
+            let [h] = lift::<Ctx, 1>(&[max + 1]);
+            assert!(matches!(table.solve(&h), Err(Error::DiscreteLogError(_))));
+        }
+
+        let table = DiscreteLogTable::<Ctx>::new(100);
+        assert!(table.solve(&Ctx::random_element()).is_err());
+    }
+
+    fn test_exponential_homomorphism<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let table = DiscreteLogTable::<Ctx>::new(1000);
+
+        let votes = [[1, 0, 0], [0, 1, 0], [1, 0, 0], [0, 0, 1], [1, 0, 0]];
+        let mut sum = encrypt::<Ctx, 3>(&keypair.pkey, &[0, 0, 0]);
+        for vote in &votes {
+            sum = sum.mul(&encrypt(&keypair.pkey, vote));
+        }
+        assert_eq!(decrypt(&keypair, &sum, &table).unwrap(), [3, 1, 1]);
+
+        let scaled = sum.exp(&Ctx::Scalar::from(7));
+        assert_eq!(decrypt(&keypair, &scaled, &table).unwrap(), [21, 7, 7]);
+
+        let r = [Ctx::random_scalar()];
+        let c = encrypt_with_r::<Ctx, 1>(&keypair.pkey, &[1000], &r);
+        assert_eq!(decrypt(&keypair, &c, &table).unwrap(), [1000]);
+        let c = c.mul(&encrypt(&keypair.pkey, &[1]));
+        assert!(decrypt(&keypair, &c, &table).is_err());
+    }
+
+    fn test_exponential_threshold<Ctx: Context>() {
+        const W: usize = 2;
+        const T: usize = 2;
+        const P: usize = 3;
+
+        let dealers: [Dealer<Ctx, T, P>; P] = array::from_fn(|_| Dealer::generate());
+        let recipients: [(Recipient<Ctx, T, P>, DkgPublicKey<Ctx, T>); P] = array::from_fn(|i| {
+            let position = ParticipantPosition::from_usize(i + 1);
+            let verifiable_shares: [VerifiableShare<Ctx, T>; P] = dealers
+                .clone()
+                .map(|d| d.get_verifiable_shares().for_recipient(&position));
+
+            Recipient::from_shares(position, &verifiable_shares).unwrap()
+        });
+        let pk = &recipients[0].1;
+
+        let votes = [[1, 0], [0, 1], [1, 0]];
+        let mut sum = encrypt::<Ctx, W>(&pk.inner, &[0, 0]);
+        for vote in &votes {
+            sum = sum.mul(&encrypt(&pk.inner, vote));
+        }
+        let ciphertexts = vec![DkgCiphertext::<Ctx, W, T>(sum)];
+
+        let present = [&recipients[0].0, &recipients[2].0];
+        let dfactors = present.map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap());
+        let verification_keys = present.map(|r| r.get_verification_key().clone());
+
+        let plaintexts = combine(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+        let table = DiscreteLogTable::<Ctx>::new(100);
+        assert_eq!(table.solve_array(&plaintexts[0]).unwrap(), [2, 1]);
+    }
+}
@@ -31,6 +31,31 @@
 //!
 //! See `EVS`: Definition 11.31
 //!
+//! # [`Exponential ElGamal`][`crate::cryptosystem::exponential`]
+//!
+//! The additive variant of `ElGamal`, where small integers `m` are encrypted
+//! as `g^m`. Ciphertexts are additively homomorphic, and plaintexts are recovered
+//! with a bounded discrete log computation, as needed for homomorphic tallying.
+//!
+//! # [`Hybrid encryption`][`crate::cryptosystem::hybrid`]
+//!
+//! Hybrid public key encryption of arbitrary byte payloads to a party's group
+//! public key: a Diffie-Hellman KEM over the context's group, HKDF-SHA256 and
+//! `ChaCha20Poly1305`. This is used to transmit secrets such as pairwise DKG
+//! shares or ballot check randomizers confidentially.
+//!
+//! The key schedule follows the structure of RFC 9180 `mode_base`, but this is
+//! not RFC 9180 HPKE and does not interoperate with it: the KEM is not a registered
+//! DHKEM, it is identified by the serialization of the group generator, and the
+//! encapsulated key is the crate's serialization of a group element.
+//!
+//! # [`Encoding`][`crate::cryptosystem::encoding`]
+//!
+//! Encoding of variable length byte strings into multi-element messages.
+//! Byte strings are framed with a length prefix and zero padding, and then split
+//! into chunks that are each [encoded][`crate::traits::groups::CryptoGroup::encode`]
+//! as a group element.
+//!
 //! # Examples
 //!
 //! ```
@@ -67,3 +92,12 @@ pub mod elgamal;
 
 /// Naor-Yung cryptosystem.
 pub mod naoryung;
+
+/// Exponential `ElGamal` cryptosystem.
+pub mod exponential;
+
+/// Hybrid public key encryption, not compatible with RFC 9180.
+pub mod hybrid;
+
+/// Variable length byte string encoding.
+pub mod encoding;
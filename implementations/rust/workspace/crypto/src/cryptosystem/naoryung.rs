@@ -15,7 +15,9 @@ use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::traits::groups::ReplGroupOps;
 use crate::utils::error::Error;
+use crate::zkp::dlogeq::DlogEqProof;
 use crate::zkp::pleq::PlEqProof;
+use crate::zkp::transcript::ChallengeVersion;
 use vser_derive::VSerializable;
 
 /**
@@ -257,6 +259,51 @@ impl<C: Context> KeyPair<C> {
         self.pkey.strip(c, context)
     }
 
+    /// Strip this Naor-Yung ciphertext, with a proof of well-formedness computed
+    /// with the given challenge version.
+    ///
+    /// See [`PublicKey::strip_with_version`].
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with.
+    /// - `c`: The ciphertext to strip.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] verification returns error
+    /// - `NaorYungStripError` if the proof of well-formedness fails.
+    pub fn strip_with_version<const W: usize>(
+        &self,
+        version: ChallengeVersion,
+        c: Ciphertext<C, W>,
+        context: &[u8],
+    ) -> Result<elgamal::Ciphertext<C, W>, Error> {
+        self.pkey.strip_with_version(version, c, context)
+    }
+
+    /// Strip a batch of Naor-Yung ciphertexts, returning the underlying plain `ElGamal` ciphertexts.
+    ///
+    /// See [`PublicKey::strip_batch`].
+    ///
+    /// # Parameters
+    ///
+    /// - `cs`: The ciphertexts to strip.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] verification returns error
+    /// - `NaorYungStripError` if any proof of well-formedness fails.
+    pub fn strip_batch<const W: usize>(
+        &self,
+        cs: Vec<Ciphertext<C, W>>,
+        context: &[u8],
+    ) -> Result<Vec<elgamal::Ciphertext<C, W>>, Error> {
+        self.pkey.strip_batch(cs, context)
+    }
+
     /// Decrypt the given ciphertext with this key pair.
     ///
     /// This function verifies the proof of well-formedness before decrypting.
@@ -299,7 +346,32 @@ impl<C: Context> KeyPair<C> {
         c: &Ciphertext<C, W>,
         context: &[u8],
     ) -> Result<[C::Element; W], Error> {
-        let proof_ok = c.proof.verify(
+        self.decrypt_with_version(ChallengeVersion::CURRENT, c, context)
+    }
+
+    /// Decrypt the given ciphertext with this key pair, with a proof of
+    /// well-formedness computed with the given challenge version.
+    ///
+    /// See [`KeyPair::decrypt`].
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with.
+    /// - `c`: The ciphertext to decrypt.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] verification returns error
+    /// - `NaorYungStripError` if the proof of well-formedness fails.
+    pub fn decrypt_with_version<const W: usize>(
+        &self,
+        version: ChallengeVersion,
+        c: &Ciphertext<C, W>,
+        context: &[u8],
+    ) -> Result<[C::Element; W], Error> {
+        let proof_ok = c.proof.verify_with_version(
+            version,
             &self.pkey.pk_b,
             &self.pkey.pk_a,
             &c.u_b,
@@ -318,6 +390,57 @@ impl<C: Context> KeyPair<C> {
             ))
         }
     }
+
+    /// Decrypt the given ciphertext with this key pair, and prove that the
+    /// plaintext is its decryption.
+    ///
+    /// This function verifies the proof of well-formedness before decrypting.
+    /// The proof of decryption is an [`ElGamal` decryption proof][`elgamal::KeyPair::decrypt_with_proof`]
+    /// of the stripped ciphertext, see [`verify_decryption`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crypto::cryptosystem::naoryung::{self, KeyPair};
+    /// use crypto::context::Context;
+    /// use crypto::context::RistrettoCtx as RCtx;
+    ///
+    /// // Set to some relevant context value
+    /// let keypair_context = &[];
+    /// let keypair: KeyPair<RCtx> = KeyPair::generate(keypair_context).unwrap();
+    /// let message = [RCtx::random_element(); 2];
+    /// // Set to some relevant context value
+    /// let context = &[];
+    /// let ciphertext = keypair.encrypt(&message, context).unwrap();
+    /// let (decrypted, proof) = keypair.decrypt_with_proof(&ciphertext, context).unwrap();
+    /// assert_eq!(message, decrypted);
+    ///
+    /// let ok = naoryung::verify_decryption(&keypair.pkey, &ciphertext, &decrypted, &proof, context).unwrap();
+    /// assert!(ok);
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `c`: The ciphertext to decrypt.
+    /// - `context`: proof context label (ZKP CONTEXT), for both the proof of
+    ///   well-formedness and the proof of decryption
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    /// - `NaorYungStripError` if the proof of well-formedness fails.
+    ///
+    /// Returns the pair (plaintext, proof).
+    pub fn decrypt_with_proof<const W: usize>(
+        &self,
+        c: &Ciphertext<C, W>,
+        context: &[u8],
+    ) -> Result<([C::Element; W], DlogEqProof<C, W>), Error> {
+        let stripped = self.strip(c.clone(), context)?;
+        let elgamal_keypair = EGKeyPair::new(self.sk_b.clone(), self.pkey.pk_b.clone());
+
+        elgamal_keypair.decrypt_with_proof(&stripped, context)
+    }
 }
 
 /**
@@ -466,6 +589,38 @@ impl<C: Context> PublicKey<C> {
         message: &[C::Element; W],
         r: &[C::Scalar; W],
         context: &[u8],
+    ) -> Result<Ciphertext<C, W>, Error> {
+        let mut rng = C::get_rng();
+        let proof_r = <[C::Scalar; W]>::random(&mut rng);
+
+        self.encrypt_with_randomness(message, r, &proof_r, context)
+    }
+
+    /// Encrypt the given message with this public key, using the given encryption
+    /// and proof randomness.
+    ///
+    /// The result is fully determined by the inputs, including the proof of
+    /// well-formedness. This allows recomputing a ciphertext once its randomness
+    /// is revealed, see [`ballot_check`][`crate::ballot_check`].
+    ///
+    /// See [`PlEqProof::prove_with_randomness`][`crate::zkp::pleq::PlEqProof::prove_with_randomness`].
+    ///
+    ///  # Parameters
+    ///
+    /// - `message`: The message to encrypt, of width `W`
+    /// - `r`: The random values for the encryption, of width `W`
+    /// - `proof_r`: The random values for the proof of well-formedness, of width `W`
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] computation returns error
+    pub fn encrypt_with_randomness<const W: usize>(
+        &self,
+        message: &[C::Element; W],
+        r: &[C::Scalar; W],
+        proof_r: &[C::Scalar; W],
+        context: &[u8],
     ) -> Result<Ciphertext<C, W>, Error> {
         let g = C::generator();
 
@@ -474,7 +629,9 @@ impl<C: Context> PublicKey<C> {
         let v_b = message.mul(&v_b);
         let u_a = self.pk_a.repl_exp(r);
 
-        let proof = PlEqProof::<C, W>::prove(&self.pk_b, &self.pk_a, &u_b, &v_b, &u_a, r, context)?;
+        let proof = PlEqProof::<C, W>::prove_with_randomness(
+            &self.pk_b, &self.pk_a, &u_b, &v_b, &u_a, r, proof_r, context,
+        )?;
 
         let ret = Ciphertext::new(u_b, v_b, u_a, proof);
 
@@ -526,9 +683,33 @@ impl<C: Context> PublicKey<C> {
         c: Ciphertext<C, W>,
         context: &[u8],
     ) -> Result<elgamal::Ciphertext<C, W>, Error> {
-        let proof_ok = c
-            .proof
-            .verify(&self.pk_b, &self.pk_a, &c.u_b, &c.v_b, &c.u_a, context)?;
+        self.strip_with_version(ChallengeVersion::CURRENT, c, context)
+    }
+
+    /// Strip this Naor-Yung ciphertext, with a proof of well-formedness computed
+    /// with the given challenge version.
+    ///
+    /// See [`PublicKey::strip`].
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with.
+    /// - `c`: The ciphertext to strip.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] verification returns error
+    /// - `NaorYungStripError` if the proof of well-formedness fails.
+    pub fn strip_with_version<const W: usize>(
+        &self,
+        version: ChallengeVersion,
+        c: Ciphertext<C, W>,
+        context: &[u8],
+    ) -> Result<elgamal::Ciphertext<C, W>, Error> {
+        let proof_ok = c.proof.verify_with_version(
+            version, &self.pk_b, &self.pk_a, &c.u_b, &c.v_b, &c.u_a, context,
+        )?;
 
         if proof_ok {
             Ok(elgamal::Ciphertext::<C, W>::new(c.u_b, c.v_b))
@@ -538,6 +719,56 @@ impl<C: Context> PublicKey<C> {
             ))
         }
     }
+
+    /// Strip a batch of Naor-Yung ciphertexts, returning the underlying plain `ElGamal` ciphertexts.
+    ///
+    /// Equivalent to calling [`PublicKey::strip`] on every ciphertext, but the
+    /// proofs of well-formedness are [verified in a batch][`PlEqProof::verify_batch`].
+    /// If the batch fails, the proofs are verified individually to locate the
+    /// first invalid ciphertext.
+    ///
+    /// # Parameters
+    ///
+    /// - `cs`: The ciphertexts to strip.
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation for [`PlEqProof`] verification returns error
+    /// - `NaorYungStripError` if any proof of well-formedness fails, naming the
+    ///   index of the first invalid ciphertext.
+    pub fn strip_batch<const W: usize>(
+        &self,
+        cs: Vec<Ciphertext<C, W>>,
+        context: &[u8],
+    ) -> Result<Vec<elgamal::Ciphertext<C, W>>, Error> {
+        let batch: Vec<_> = cs
+            .iter()
+            .map(|c| (&c.proof, &c.u_b, &c.v_b, &c.u_a))
+            .collect();
+
+        if !PlEqProof::verify_batch(&self.pk_b, &self.pk_a, &batch, context)? {
+            for (i, c) in cs.iter().enumerate() {
+                let proof_ok = c
+                    .proof
+                    .verify(&self.pk_b, &self.pk_a, &c.u_b, &c.v_b, &c.u_a, context)?;
+                if !proof_ok {
+                    return Err(Error::NaorYungStripError(format!(
+                        "Proof failed to validate for Naor-Yung ciphertext {i}"
+                    )));
+                }
+            }
+            // not reached: the batch holds whenever all proofs are valid
+            return Err(Error::NaorYungStripError(
+                "Batch proof failed to validate for Naor-Yung ciphertexts".into(),
+            ));
+        }
+
+        Ok(cs
+            .into_iter()
+            .map(|c| elgamal::Ciphertext::<C, W>::new(c.u_b, c.v_b))
+            .collect())
+    }
 }
 
 /**
@@ -603,10 +834,53 @@ impl<C: Context, const W: usize> Ciphertext<C, W> {
     }
 }
 
+/**
+ * Verify that `plaintext` is the decryption of the Naor-Yung ciphertext `c`
+ * under the secret key of `pk`.
+ *
+ * Checks the proof of well-formedness of `c` and a proof computed with
+ * [`KeyPair::decrypt_with_proof`], see [`elgamal::verify_decryption`].
+ *
+ * # Parameters
+ *
+ * - `pk`: The public key the ciphertext was encrypted with
+ * - `c`: The decrypted ciphertext
+ * - `plaintext`: The claimed plaintext
+ * - `proof`: The proof of decryption
+ * - `context`: proof context label (ZKP CONTEXT), for both the proof of
+ *   well-formedness and the proof of decryption
+ *
+ * # Errors
+ *
+ * - `HashToScalarError` if challenge generation returns error
+ *
+ * Returns `true` if both proofs are valid, `false` otherwise.
+ */
+pub fn verify_decryption<C: Context, const W: usize>(
+    pk: &PublicKey<C>,
+    c: &Ciphertext<C, W>,
+    plaintext: &[C::Element; W],
+    proof: &DlogEqProof<C, W>,
+    context: &[u8],
+) -> Result<bool, Error> {
+    let well_formed = c
+        .proof
+        .verify(&pk.pk_b, &pk.pk_a, &c.u_b, &c.v_b, &c.u_a, context)?;
+    if !well_formed {
+        return Ok(false);
+    }
+
+    let elgamal_pk = elgamal::PublicKey::new(pk.pk_b.clone());
+    let stripped = elgamal::Ciphertext::<C, W>::new(c.u_b.clone(), c.v_b.clone());
+
+    elgamal::verify_decryption(&elgamal_pk, &stripped, plaintext, proof, context)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
     use crate::cryptosystem::elgamal;
@@ -645,16 +919,42 @@ mod tests {
         test_encryption::<PCtx>();
     }
 
+    #[test]
+    fn test_encryption_electionguard() {
+        test_encryption::<ECtx>();
+    }
+
     #[test]
     fn test_serialization_and_decryption_ristretto() {
         test_serialization_and_decryption::<RCtx>();
     }
 
+    #[test]
+    fn test_strip_batch_ristretto() {
+        test_strip_batch::<RCtx>();
+    }
+
+    #[test]
+    fn test_strip_batch_p256() {
+        test_strip_batch::<PCtx>();
+    }
+
     #[test]
     fn test_serialization_and_decryption_p256() {
         test_serialization_and_decryption::<PCtx>();
     }
 
+    #[test]
+    fn test_decryption_proof_ristretto() {
+        test_decryption_proof::<RCtx, 1>();
+        test_decryption_proof::<RCtx, 3>();
+    }
+
+    #[test]
+    fn test_decryption_proof_p256() {
+        test_decryption_proof::<PCtx, 2>();
+    }
+
     fn test_naoryung_from_elgamal<Ctx: Context>() {
         let keypair_context = &[];
 
@@ -685,7 +985,7 @@ mod tests {
     }
 
     fn test_keypair_serialization<Ctx: Context>() {
-        let keypair = KeyPair::<Ctx>::generate(&vec![]).unwrap();
+        let keypair = KeyPair::<Ctx>::generate(&[]).unwrap();
 
         let serialized = keypair.ser_f();
         assert_eq!(serialized.len(), KeyPair::<Ctx>::size_bytes());
@@ -695,19 +995,19 @@ mod tests {
     }
 
     fn test_encryption<Ctx: Context>() {
-        let keypair = KeyPair::<Ctx>::generate(&vec![]).unwrap();
+        let keypair = KeyPair::<Ctx>::generate(&[]).unwrap();
         let message = [Ctx::random_element(), Ctx::random_element()];
 
-        let ciphertext: Ciphertext<Ctx, 2> = keypair.encrypt(&message, &vec![]).unwrap();
-        let decrypted_message = keypair.decrypt(&ciphertext, &vec![]).unwrap();
+        let ciphertext: Ciphertext<Ctx, 2> = keypair.encrypt(&message, &[]).unwrap();
+        let decrypted_message = keypair.decrypt(&ciphertext, &[]).unwrap();
         assert_eq!(message, decrypted_message);
     }
 
     fn test_serialization_and_decryption<Ctx: Context>() {
-        let keypair = KeyPair::<Ctx>::generate(&vec![]).unwrap();
+        let keypair = KeyPair::<Ctx>::generate(&[]).unwrap();
         let message = [Ctx::random_element(), Ctx::random_element()];
 
-        let ciphertext: Ciphertext<Ctx, 2> = keypair.encrypt(&message, &vec![]).unwrap();
+        let ciphertext: Ciphertext<Ctx, 2> = keypair.encrypt(&message, &[]).unwrap();
         let serialized_ct = ciphertext.ser_f();
         assert_eq!(serialized_ct.len(), Ciphertext::<Ctx, 2>::size_bytes());
 
@@ -715,7 +1015,66 @@ mod tests {
 
         assert_eq!(ciphertext, deserialized_ct);
 
-        let decrypted_message = keypair.decrypt(&deserialized_ct, &vec![]).unwrap();
+        let decrypted_message = keypair.decrypt(&deserialized_ct, &[]).unwrap();
         assert_eq!(message, decrypted_message);
     }
+
+    fn test_decryption_proof<Ctx: Context, const W: usize>() {
+        let keypair = KeyPair::<Ctx>::generate(&[]).unwrap();
+        let message: [Ctx::Element; W] = std::array::from_fn(|_| Ctx::random_element());
+        let ciphertext: Ciphertext<Ctx, W> = keypair.encrypt(&message, b"context").unwrap();
+
+        let (plaintext, proof) = keypair.decrypt_with_proof(&ciphertext, b"context").unwrap();
+        assert_eq!(message, plaintext);
+        assert!(
+            verify_decryption(&keypair.pkey, &ciphertext, &plaintext, &proof, b"context").unwrap()
+        );
+        assert!(
+            !verify_decryption(&keypair.pkey, &ciphertext, &plaintext, &proof, b"other").unwrap()
+        );
+
+        let wrong: [Ctx::Element; W] = std::array::from_fn(|_| Ctx::random_element());
+        assert!(
+            !verify_decryption(&keypair.pkey, &ciphertext, &wrong, &proof, b"context").unwrap()
+        );
+
+        // a ciphertext that is not well-formed
+        let mut malformed = ciphertext.clone();
+        malformed.u_a = std::array::from_fn(|_| Ctx::random_element());
+        assert!(
+            !verify_decryption(&keypair.pkey, &malformed, &plaintext, &proof, b"context").unwrap()
+        );
+        assert!(matches!(
+            keypair.decrypt_with_proof(&malformed, b"context"),
+            Err(Error::NaorYungStripError(_))
+        ));
+    }
+
+    fn test_strip_batch<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate(&[]).unwrap();
+        let messages: Vec<[Ctx::Element; 2]> = (0..4)
+            .map(|_| [Ctx::random_element(), Ctx::random_element()])
+            .collect();
+        let ciphertexts: Vec<Ciphertext<Ctx, 2>> = messages
+            .iter()
+            .map(|m| keypair.encrypt(m, b"context").unwrap())
+            .collect();
+
+        let stripped = keypair
+            .strip_batch(ciphertexts.clone(), b"context")
+            .unwrap();
+        for ((s, c), m) in stripped.iter().zip(ciphertexts.iter()).zip(messages.iter()) {
+            let single = keypair.strip(c.clone(), b"context").unwrap();
+            assert_eq!(s, &single);
+            assert_eq!(&elgamal::decrypt::<Ctx, 2>(s.u(), s.v(), &keypair.sk_b), m);
+        }
+
+        let mut tampered = ciphertexts;
+        tampered[2].v_b[0] = tampered[2].v_b[0].mul(&Ctx::generator());
+        let result = keypair.strip_batch(tampered, b"context");
+        let Err(Error::NaorYungStripError(message)) = result else {
+            panic!("Expected a strip error");
+        };
+        assert!(message.ends_with(" 2"));
+    }
 }
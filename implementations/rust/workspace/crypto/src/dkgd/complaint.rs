@@ -0,0 +1,406 @@
+/*
+ * Joint-Feldman distributed key generation: complaints
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::dkgd::dealer::VerifiableShare;
+use crate::dkgd::recipient::{ParticipantPosition, Recipient};
+use crate::dkgd::transport::{PairwiseShareMessage, PublicCheckValueMessage, VerifyingKey};
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error;
+use crate::utils::serialization::{Bytes, VSerializable};
+use crate::zkp::dlogeq::DlogEqProof;
+use vser_derive::VSerializable;
+
+/// Domain separation label for the complaint proof context
+const COMPLAINT_LABEL: &[u8] = b"dkg_complaint";
+
+/**
+ * A publicly verifiable accusation against a dealer in the DKG protocol.
+ *
+ * A recipient that receives a [pairwise share][`PairwiseShareMessage`] that is
+ * addressed to a different key, cannot be decrypted, or does not verify against
+ * the dealer's checking values, publishes a complaint. The complaint reveals the Diffie-Hellman value
+ * `dh = enc^x` of the share's encryption, where `x` is the recipient's secret
+ * encryption key, together with a [proof][`DlogEqProof`] that `dh` was computed
+ * with `x`. This allows anyone to decrypt the accused share, and only that share,
+ * and [rule][`Self::rule`] on the complaint.
+ *
+ * The proof can only be computed with the recipient's secret key, so complaints
+ * need not be signed.
+ *
+ * See also [`QualifiedSet`]
+ *
+ * # Examples
+ *
+ * ```
+ * use std::array;
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::dkgd::complaint::Complaint;
+ * use crypto::dkgd::dealer::Dealer;
+ * use crypto::dkgd::recipient::ParticipantPosition;
+ * use crypto::dkgd::transport::{PairwiseShareMessage, PublicCheckValueMessage};
+ * use crypto::utils::signatures::SignatureScheme;
+ *
+ * const P: usize = 3;
+ * const T: usize = 2;
+ *
+ * let election_hash = b"election";
+ * let signer = RCtx::gen_signing_key();
+ * let trustee_key = <RCtx as Context>::SignatureScheme::verifying_key(&signer);
+ * let recipient = KeyPair::<RCtx>::generate();
+ * let complainant = ParticipantPosition::from_usize(1);
+ * let accused = ParticipantPosition::from_usize(2);
+ *
+ * // the dealer signs checking values that do not match the share it sends
+ * let dealer: Dealer<RCtx, T, P> = Dealer::generate();
+ * let other: Dealer<RCtx, T, P> = Dealer::generate();
+ * let cv = PublicCheckValueMessage::sign(&dealer.get_verifiable_shares(), election_hash, &signer).unwrap();
+ * let share = PairwiseShareMessage::seal(
+ *     &other.get_verifiable_shares(), &complainant, &recipient.pkey.y, election_hash, &signer
+ * ).unwrap();
+ *
+ * let complaint = Complaint::new(complainant, accused, &share, &recipient.skey, election_hash).unwrap();
+ *
+ * // anyone can rule on the complaint
+ * let upheld = complaint.rule(election_hash, &trustee_key, &recipient.pkey.y, &cv, &share).unwrap();
+ * assert!(upheld);
+ * ```
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct Complaint<C: Context, const P: usize> {
+    /// the position of the recipient making the complaint
+    pub complainant: ParticipantPosition<P>,
+    /// the position of the accused dealer
+    pub dealer: ParticipantPosition<P>,
+    /// the revealed Diffie-Hellman value of the accused share's encryption
+    pub dh: C::Element,
+    /// proof that `dh` was computed with the complainant's secret encryption key
+    pub proof: DlogEqProof<C, 1>,
+}
+
+impl<C: Context, const P: usize> Complaint<C, P> {
+    /// Construct a complaint against the dealer of the given pairwise share.
+    ///
+    /// # Parameters
+    ///
+    /// - `complainant`: the position of the recipient making the complaint
+    /// - `dealer`: the position of the accused dealer
+    /// - `share`: the accused pairwise share message
+    /// - `secret_key`: the complainant's secret encryption key
+    /// - `election_hash`: the hash of the election configuration
+    ///
+    /// The share need not be addressed to `secret_key`; a share that the dealer
+    /// sealed to a different key is itself grounds for the complaint.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    pub fn new(
+        complainant: ParticipantPosition<P>,
+        dealer: ParticipantPosition<P>,
+        share: &PairwiseShareMessage<C>,
+        secret_key: &C::Scalar,
+        election_hash: &[u8],
+    ) -> Result<Self, Error> {
+        let g = C::generator();
+        let public_key = g.exp(secret_key);
+
+        let enc = &share.pairwise_share.enc;
+        let dh = enc.exp(secret_key);
+        let proof_context = Self::proof_context(&complainant, &dealer, election_hash);
+        let proof = DlogEqProof::prove(
+            secret_key,
+            &g,
+            &public_key,
+            std::array::from_ref(enc),
+            std::array::from_ref(&dh),
+            &proof_context,
+        )?;
+
+        Ok(Self {
+            complainant,
+            dealer,
+            dh,
+            proof,
+        })
+    }
+
+    /// Rule on this complaint.
+    ///
+    /// The complaint is upheld if its proof is valid and the accused share is
+    /// addressed to a key other than `complainant_key`, cannot be decrypted, or
+    /// does not verify against the accused dealer's checking values. A complaint
+    /// with an invalid proof is rejected.
+    ///
+    /// # Parameters
+    ///
+    /// - `election_hash`: the hash of the election configuration
+    /// - `trustee_key`: the accused dealer's public signing key
+    /// - `complainant_key`: the complainant's public encryption key
+    /// - `check_values`: the accused dealer's public check value message
+    /// - `share`: the accused dealer's pairwise share message for the complainant
+    ///
+    /// # Errors
+    ///
+    /// - `ShareTransportError` if the accused dealer's messages do not verify
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the complaint is upheld, `false` otherwise.
+    pub fn rule<const T: usize>(
+        &self,
+        election_hash: &[u8],
+        trustee_key: &VerifyingKey<C>,
+        complainant_key: &C::Element,
+        check_values: &PublicCheckValueMessage<C, T>,
+        share: &PairwiseShareMessage<C>,
+    ) -> Result<bool, Error> {
+        check_values.verify(election_hash, trustee_key)?;
+        share.verify(election_hash, trustee_key)?;
+
+        let proof_context = Self::proof_context(&self.complainant, &self.dealer, election_hash);
+        let valid = self.proof.verify(
+            &C::generator(),
+            complainant_key,
+            std::array::from_ref(&share.pairwise_share.enc),
+            std::array::from_ref(&self.dh),
+            &proof_context,
+        )?;
+        if !valid {
+            return Ok(false);
+        }
+
+        if !complainant_key.equals(&share.recipient_public_key) {
+            // the dealer signed a share addressed to a different recipient
+            return Ok(true);
+        }
+
+        let Ok(value) = share.open_with_dh(election_hash, &self.dh) else {
+            // the dealer signed a share that cannot be decrypted
+            return Ok(true);
+        };
+        let verifiable_share = VerifiableShare::new(value, check_values.check_values.clone());
+        let verified = Recipient::<C, T, P>::verify_share(&verifiable_share, &self.complainant);
+
+        Ok(verified.is_err())
+    }
+
+    /// Returns the proof context, binding the proof to the election and the parties.
+    fn proof_context(
+        complainant: &ParticipantPosition<P>,
+        dealer: &ParticipantPosition<P>,
+        election_hash: &[u8],
+    ) -> Vec<u8> {
+        let label = Bytes(COMPLAINT_LABEL.to_vec());
+        let election_hash = Bytes(election_hash.to_vec());
+
+        (&label, &election_hash, complainant, dealer).ser()
+    }
+}
+
+/// Verify and decrypt the messages addressed to one recipient, returning a complaint
+/// for each dealer whose share is addressed to a different key, cannot be decrypted,
+/// or does not verify.
+///
+/// If no complaints are returned, the shares can be obtained with
+/// [`receive_shares`][`crate::dkgd::transport::receive_shares`].
+///
+/// # Parameters
+///
+/// - `position`: the position of the recipient
+/// - `election_hash`: the hash of the election configuration
+/// - `secret_key`: the recipient's secret encryption key
+/// - `trustee_keys`: the public signing keys of all `P` dealers, in position order
+/// - `check_values`: the public check value messages of all `P` dealers, in position order
+/// - `pairwise_shares`: the pairwise share messages addressed to this recipient
+///   from all `P` dealers, in position order
+///
+/// # Errors
+///
+/// - `ShareTransportError` if some message does not verify; such messages
+///   should not have been accepted by the trustee board
+/// - `HashToElementError` if challenge generation returns error
+pub fn check_shares<C: Context, const T: usize, const P: usize>(
+    position: &ParticipantPosition<P>,
+    election_hash: &[u8],
+    secret_key: &C::Scalar,
+    trustee_keys: &[VerifyingKey<C>; P],
+    check_values: &[&PublicCheckValueMessage<C, T>; P],
+    pairwise_shares: &[&PairwiseShareMessage<C>; P],
+) -> Result<Vec<Complaint<C, P>>, Error> {
+    let public_key = C::generator().exp(secret_key);
+    let mut complaints = vec![];
+
+    for (i, ((trustee_key, cv), share)) in trustee_keys
+        .iter()
+        .zip(check_values.iter())
+        .zip(pairwise_shares.iter())
+        .enumerate()
+    {
+        cv.verify(election_hash, trustee_key)?;
+        share.verify(election_hash, trustee_key)?;
+
+        let dh = share.pairwise_share.enc.exp(secret_key);
+        let addressed = public_key.equals(&share.recipient_public_key);
+        let valid = addressed
+            && share.open_with_dh(election_hash, &dh).is_ok_and(|value| {
+                let verifiable_share = VerifiableShare::new(value, cv.check_values.clone());
+                Recipient::<C, T, P>::verify_share(&verifiable_share, position).is_ok()
+            });
+
+        if !valid {
+            // i + 1 cannot overflow, i < P < 100
+            #[allow(clippy::arithmetic_side_effects)]
+            let dealer = ParticipantPosition::from_usize(i + 1);
+            let complaint =
+                Complaint::new(position.clone(), dealer, share, secret_key, election_hash)?;
+            complaints.push(complaint);
+        }
+    }
+
+    Ok(complaints)
+}
+
+/// Rule on the given complaints, returning the set of qualified dealers.
+///
+/// Every dealer against which some complaint is upheld is excluded from the
+/// qualified set. Rejected complaints have no effect; this includes complaints
+/// with positions outside `1..=P`, and complaints that cannot be ruled on, see
+/// [`Complaint::rule`].
+///
+/// # Parameters
+///
+/// - `complaints`: the complaints posted by all recipients
+/// - `election_hash`: the hash of the election configuration
+/// - `trustee_keys`: the public signing keys of all `P` dealers, in position order
+/// - `recipient_keys`: the public encryption keys of all `P` recipients, in position order
+/// - `check_values`: the public check value messages of all `P` dealers, in position order
+/// - `pairwise_shares`: the pairwise share messages of all `P` dealers, in position
+///   order, each in recipient position order
+#[must_use]
+pub fn qualified_set<C: Context, const T: usize, const P: usize>(
+    complaints: &[Complaint<C, P>],
+    election_hash: &[u8],
+    trustee_keys: &[VerifyingKey<C>; P],
+    recipient_keys: &[C::Element; P],
+    check_values: &[&PublicCheckValueMessage<C, T>; P],
+    pairwise_shares: &[&[PairwiseShareMessage<C>; P]; P],
+) -> QualifiedSet<P> {
+    let mut qualified = QualifiedSet::new();
+
+    for complaint in complaints {
+        let upheld = checked_index(&complaint.dealer).and_then(|dealer| {
+            let complainant = checked_index(&complaint.complainant)?;
+            let upheld = complaint.rule(
+                election_hash,
+                &trustee_keys[dealer],
+                &recipient_keys[complainant],
+                check_values[dealer],
+                &pairwise_shares[dealer][complainant],
+            )?;
+
+            Ok(upheld.then_some(dealer))
+        });
+        // a complaint that cannot be ruled on is rejected, it does not abort the others
+        if let Ok(Some(dealer)) = upheld {
+            qualified.0[dealer] = false;
+        }
+    }
+
+    qualified
+}
+
+/// Returns the 0-based index of `position`, checking that it is in the range `1..=P`.
+///
+/// Positions in complaints are untrusted input, and must be checked before indexing.
+///
+/// # Errors
+///
+/// - `ComplaintError` if the position is not in the range `1..=P`
+fn checked_index<const P: usize>(position: &ParticipantPosition<P>) -> Result<usize, Error> {
+    let index = position.index();
+    if position.0 == 0 || index >= P {
+        return Err(Error::ComplaintError(format!(
+            "Position {} is out of range 1..={P}",
+            position.0
+        )));
+    }
+
+    Ok(index)
+}
+
+/**
+ * The set of qualified dealers (QUAL) in the DKG protocol.
+ *
+ * Initially all `P` dealers are qualified. Dealers against which some
+ * [complaint][`Complaint`] is upheld are excluded. The joint public key and
+ * the recipients' secret shares are computed from the qualified dealers only,
+ * see [`Recipient::from_qualified_shares`].
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualifiedSet<const P: usize>(pub(crate) [bool; P]);
+
+impl<const P: usize> QualifiedSet<P> {
+    /// Construct a qualified set containing all `P` dealers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self([true; P])
+    }
+
+    /// Exclude the dealer at `position` from this set.
+    ///
+    /// # Errors
+    ///
+    /// - `ComplaintError` if the position is not in the range `1..=P`
+    pub fn disqualify(&mut self, position: &ParticipantPosition<P>) -> Result<(), Error> {
+        let index = checked_index(position)?;
+        self.0[index] = false;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the dealer at `position` is qualified.
+    ///
+    /// Positions outside the range `1..=P` are not qualified.
+    #[must_use]
+    pub fn contains(&self, position: &ParticipantPosition<P>) -> bool {
+        checked_index(position).is_ok_and(|index| self.0[index])
+    }
+
+    /// Returns the number of qualified dealers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.iter().filter(|q| **q).count()
+    }
+
+    /// Returns `true` if no dealer is qualified.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the positions of the qualified dealers, in order.
+    #[must_use]
+    pub fn positions(&self) -> Vec<ParticipantPosition<P>> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, q)| **q)
+            // i + 1 cannot overflow, i < P < 100
+            .map(|(i, _)| ParticipantPosition::from_usize(i.saturating_add(1)))
+            .collect()
+    }
+}
+
+impl<const P: usize> Default for QualifiedSet<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
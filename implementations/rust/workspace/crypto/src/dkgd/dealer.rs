@@ -19,8 +19,8 @@ use vser_derive::VSerializable;
  * A dealer in the Joint-Feldman distributed key generation (DKG) protocol.
  *
  * * NOTE: this API does not represent private shares as encrypted values.
- *   In the messaging layer, private shares should be encrypted with the recipient's
- *   public key.
+ *   Use the [transport][`crate::dkgd::transport`] module to encrypt private shares
+ *   with the recipient's public key.
  *
  * In the Joint-Feldman DKG, the dealer generates a random polynomial `f` of degree
  * `T - 1` and distributes `P` shares of its secret, `f(0)`, to all participants,
@@ -126,6 +126,14 @@ impl<C: Context, const T: usize, const P: usize> Dealer<C, T, P> {
         Self { polynomial }
     }
 
+    /// Construct a new [`Dealer`] with the given polynomial.
+    pub(crate) fn from_polynomial(polynomial: Polynomial<C, T>) -> Self {
+        #[allow(path_statements)]
+        Self::CHECK;
+
+        Self { polynomial }
+    }
+
     /// Compute the `P` shares distributed by this dealer, and its `T` checking values.
     ///
     /// Returns a [`DealerShares`] instance containing the shares and checking values.
@@ -0,0 +1,667 @@
+/*
+ * Distributed key generation and decryption with runtime parameters
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::{Ciphertext, PublicKey};
+use crate::dkgd::dealer::{DealerShares, VerifiableShare};
+use crate::dkgd::recipient::{DecryptionFactor, ParticipantPosition};
+use crate::traits::groups::DistGroupOps;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use crate::zkp::dlogeq::DlogEqProof;
+use vser_derive::VSerializable;
+
+/**
+ * The threshold and number of participants of a DKG protocol execution.
+ *
+ * The types in this module are equivalent to the
+ * [dealer][`crate::dkgd::dealer`] and [recipient][`crate::dkgd::recipient`]
+ * types, but take the threshold `T` and the number of participants `P` from
+ * a `DkgParameters` value, for example one read from an election
+ * configuration file, instead of const generics. Invalid parameters are
+ * reported as errors when constructing the `DkgParameters`, instead of
+ * failing at compile time.
+ *
+ * Shares, checking values, decryption factors and proofs are computed and
+ * serialized exactly as their statically sized counterparts.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::dkgd::dynamic::{
+ *     combine, DkgParameters, DynDealer, DynParticipantPosition, DynRecipient,
+ *     DynVerifiableShare,
+ * };
+ *
+ * // e.g. read from the election configuration
+ * let (threshold, participants) = (2, 3);
+ * let params = DkgParameters::new(threshold, participants).unwrap();
+ *
+ * let dealers: Vec<DynDealer<RCtx>> =
+ *     (0..participants).map(|_| DynDealer::generate(&params)).collect();
+ *
+ * let recipients: Vec<DynRecipient<RCtx>> = params
+ *     .positions()
+ *     .map(|position| {
+ *         let shares: Vec<DynVerifiableShare<RCtx>> = dealers
+ *             .iter()
+ *             .map(|d| d.get_verifiable_shares().for_recipient(&position).unwrap())
+ *             .collect();
+ *         DynRecipient::from_shares(&params, position, &shares).unwrap().0
+ *     })
+ *     .collect();
+ *
+ * let all_checking_values: Vec<_> =
+ *     dealers.iter().map(|d| d.get_verifiable_shares().checking_values).collect();
+ * let pk = DynRecipient::<RCtx>::joint_public_key(&params, &all_checking_values).unwrap();
+ *
+ * let message = [RCtx::random_element()];
+ * let ciphertexts = vec![pk.encrypt(&message)];
+ *
+ * // any `threshold` recipients can decrypt
+ * let present = &recipients[1..];
+ * let dfactors: Vec<_> = present
+ *     .iter()
+ *     .map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap())
+ *     .collect();
+ * let verification_keys: Vec<_> =
+ *     present.iter().map(|r| r.get_verification_key().clone()).collect();
+ *
+ * let decrypted = combine(&params, &ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+ * assert_eq!(message, decrypted[0]);
+ * ```
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DkgParameters {
+    /// the number of participants needed to decrypt, `T`
+    threshold: usize,
+    /// the total number of participants, `P`
+    participants: usize,
+}
+
+impl DkgParameters {
+    /// Construct new [`DkgParameters`] for a threshold of `threshold` out of `participants`.
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if `threshold` is zero or greater than `participants`,
+    ///   or if `participants` does not fit a [position][`DynParticipantPosition`]
+    pub fn new(threshold: usize, participants: usize) -> Result<Self, Error> {
+        if threshold == 0 {
+            return Err(Error::DkgParameterError(
+                "Threshold must be positive".to_string(),
+            ));
+        }
+        if threshold > participants {
+            return Err(Error::DkgParameterError(format!(
+                "Threshold {threshold} exceeds the number of participants {participants}"
+            )));
+        }
+        if u32::try_from(participants).is_err() {
+            return Err(Error::DkgParameterError(format!(
+                "Too many participants: {participants}"
+            )));
+        }
+
+        Ok(Self {
+            threshold,
+            participants,
+        })
+    }
+
+    /// Returns the threshold, `T`.
+    #[must_use]
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of participants, `P`.
+    #[must_use]
+    pub fn participants(&self) -> usize {
+        self.participants
+    }
+
+    /// Returns the positions of all participants, in order.
+    pub fn positions(&self) -> impl Iterator<Item = DynParticipantPosition> + use<> {
+        // participants <= u32::MAX, checked in the constructor
+        let participants = u32::try_from(self.participants).unwrap_or(u32::MAX);
+        (1..=participants).map(DynParticipantPosition)
+    }
+
+    /// Check that `len` matches `expected`, returning a `DkgParameterError` naming `what` otherwise.
+    fn check_len(what: &str, len: usize, expected: usize) -> Result<(), Error> {
+        if len == expected {
+            Ok(())
+        } else {
+            Err(Error::DkgParameterError(format!(
+                "Expected {expected} {what}, got {len}"
+            )))
+        }
+    }
+}
+
+/**
+ * The position of a participant in a DKG protocol execution with
+ * [runtime parameters][`DkgParameters`].
+ *
+ * Positions are 1-based, and serialize like [`ParticipantPosition`].
+ */
+#[derive(Clone, Debug, VSerializable, PartialEq, Eq)]
+pub struct DynParticipantPosition(pub u32);
+
+impl DynParticipantPosition {
+    /// Construct a new [`DynParticipantPosition`].
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if `position` is not in `1..=participants`
+    pub fn new(position: usize, params: &DkgParameters) -> Result<Self, Error> {
+        if position == 0 || position > params.participants {
+            return Err(Error::DkgParameterError(format!(
+                "Position {position} out of range 1..={}",
+                params.participants
+            )));
+        }
+        let position = u32::try_from(position)
+            .map_err(|_| Error::DkgParameterError(format!("Position {position} too large")))?;
+
+        Ok(Self(position))
+    }
+
+    /// Returns the 0-based index of this position.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        (self.0 as usize).saturating_sub(1)
+    }
+
+    /// Check that this position is valid for the given parameters.
+    fn check(&self, params: &DkgParameters) -> Result<(), Error> {
+        Self::new(self.0 as usize, params).map(|_| ())
+    }
+}
+
+impl<const P: usize> From<ParticipantPosition<P>> for DynParticipantPosition {
+    fn from(position: ParticipantPosition<P>) -> Self {
+        Self(position.0)
+    }
+}
+
+/**
+ * A dealer in the Joint-Feldman DKG protocol, with
+ * [runtime parameters][`DkgParameters`].
+ *
+ * See [`Dealer`][`crate::dkgd::dealer::Dealer`].
+ */
+#[derive(Clone)]
+pub struct DynDealer<C: Context> {
+    /// the parameters of this protocol execution
+    params: DkgParameters,
+    /// the `T` coefficients of this dealer's polynomial
+    pub(crate) coefficients: Vec<C::Scalar>,
+}
+
+impl<C: Context> DynDealer<C> {
+    /// Construct a new [`DynDealer`] with a random polynomial of degree `T - 1`.
+    #[must_use]
+    pub fn generate(params: &DkgParameters) -> Self {
+        let coefficients = (0..params.threshold).map(|_| C::random_scalar()).collect();
+
+        Self {
+            params: *params,
+            coefficients,
+        }
+    }
+
+    /// Compute the `P` shares distributed by this dealer, and its `T` checking values.
+    #[must_use]
+    pub fn get_verifiable_shares(&self) -> DynDealerShares<C> {
+        let shares = self
+            .params
+            .positions()
+            .map(|p| self.eval(&p.0.into()))
+            .collect();
+        let g = C::generator();
+        let checking_values = self.coefficients.iter().map(|c| g.exp(c)).collect();
+
+        DynDealerShares {
+            shares,
+            checking_values,
+        }
+    }
+
+    /// Evaluate this dealer's polynomial at `x`.
+    fn eval(&self, x: &C::Scalar) -> C::Scalar {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(C::Scalar::zero(), |acc, c| acc.mul(x).add(c))
+    }
+}
+
+/**
+ * The shares and checking values distributed by a [`DynDealer`].
+ *
+ * Serializes like [`DealerShares`].
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct DynDealerShares<C: Context> {
+    /// the `P` shares, in position order
+    pub shares: Vec<C::Scalar>,
+    /// the `T` checking values
+    pub checking_values: Vec<C::Element>,
+}
+
+impl<C: Context> DynDealerShares<C> {
+    /// Returns the [`DynVerifiableShare`] for the given recipient.
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if there is no share for `recipient`
+    pub fn for_recipient(
+        &self,
+        recipient: &DynParticipantPosition,
+    ) -> Result<DynVerifiableShare<C>, Error> {
+        let value = recipient
+            .0
+            .checked_sub(1)
+            .and_then(|i| self.shares.get(i as usize))
+            .ok_or_else(|| {
+                Error::DkgParameterError(format!("No share for position {}", recipient.0))
+            })?;
+
+        Ok(DynVerifiableShare {
+            value: value.clone(),
+            checking_values: self.checking_values.clone(),
+        })
+    }
+}
+
+impl<C: Context, const T: usize, const P: usize> From<DealerShares<C, T, P>>
+    for DynDealerShares<C>
+{
+    fn from(shares: DealerShares<C, T, P>) -> Self {
+        Self {
+            shares: shares.shares.to_vec(),
+            checking_values: shares.checking_values.to_vec(),
+        }
+    }
+}
+
+/**
+ * A share for one recipient together with its dealer's checking values.
+ *
+ * Serializes like [`VerifiableShare`].
+ */
+#[derive(Debug, Clone, VSerializable)]
+pub struct DynVerifiableShare<C: Context> {
+    /// the share value
+    pub value: C::Scalar,
+    /// the `T` checking values of the dealer
+    pub checking_values: Vec<C::Element>,
+}
+
+impl<C: Context, const T: usize> From<VerifiableShare<C, T>> for DynVerifiableShare<C> {
+    fn from(share: VerifiableShare<C, T>) -> Self {
+        Self {
+            value: share.value,
+            checking_values: share.checking_values.to_vec(),
+        }
+    }
+}
+
+/**
+ * A recipient in the Joint-Feldman DKG protocol, with
+ * [runtime parameters][`DkgParameters`].
+ *
+ * See [`Recipient`][`crate::dkgd::recipient::Recipient`].
+ */
+pub struct DynRecipient<C: Context> {
+    /// the parameters of this protocol execution
+    params: DkgParameters,
+    /// the position of this recipient
+    position: DynParticipantPosition,
+    /// the verification key of this recipient, `g^sk`
+    verification_key: C::Element,
+    /// the secret share of this recipient
+    sk: C::Scalar,
+}
+
+impl<C: Context> DynRecipient<C> {
+    /// Returns the verification key of this recipient.
+    pub fn get_verification_key(&self) -> &C::Element {
+        &self.verification_key
+    }
+
+    /// Returns the position of this recipient.
+    pub fn get_position(&self) -> &DynParticipantPosition {
+        &self.position
+    }
+
+    /// Returns the parameters of this recipient's protocol execution.
+    pub fn get_parameters(&self) -> &DkgParameters {
+        &self.params
+    }
+
+    /// Construct a new `DynRecipient` from the shares dealt by all `P` dealers.
+    ///
+    /// Returns a tuple with the constructed `DynRecipient` and the joint public key.
+    ///
+    /// # Parameters
+    ///
+    /// - `params`: the parameters of this protocol execution
+    /// - `position`: the position of this recipient
+    /// - `shares`: the shares for this recipient from every dealer
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if the input sizes do not match `params`
+    /// - `ShareVerificationFailed` if some share does not verify
+    pub fn from_shares(
+        params: &DkgParameters,
+        position: DynParticipantPosition,
+        shares: &[DynVerifiableShare<C>],
+    ) -> Result<(Self, PublicKey<C>), Error> {
+        position.check(params)?;
+        DkgParameters::check_len("shares", shares.len(), params.participants)?;
+
+        let mut verification_key = C::Element::one();
+        let mut joint_pk = C::Element::one();
+        let mut sk = C::Scalar::zero();
+
+        for share in shares {
+            DkgParameters::check_len(
+                "checking values",
+                share.checking_values.len(),
+                params.threshold,
+            )?;
+            let vk_factor = vk_factor::<C>(&share.checking_values, &position);
+            if C::generator().exp(&share.value) != vk_factor {
+                return Err(Error::ShareVerificationFailed(
+                    "Failed to verify share".into(),
+                ));
+            }
+            joint_pk = joint_pk.mul(&share.checking_values[0]);
+            verification_key = verification_key.mul(&vk_factor);
+            sk = sk.add(&share.value);
+        }
+
+        let recipient = Self {
+            params: *params,
+            position,
+            verification_key,
+            sk,
+        };
+
+        Ok((recipient, PublicKey::new(joint_pk)))
+    }
+
+    /// Compute the verification key of the recipient at `position` from public data.
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if the input sizes do not match `params`
+    pub fn verification_key(
+        params: &DkgParameters,
+        position: &DynParticipantPosition,
+        all_checking_values: &[Vec<C::Element>],
+    ) -> Result<C::Element, Error> {
+        position.check(params)?;
+        Self::check_checking_values(params, all_checking_values)?;
+
+        Ok(all_checking_values
+            .iter()
+            .fold(C::Element::one(), |acc, cv| {
+                acc.mul(&vk_factor::<C>(cv, position))
+            }))
+    }
+
+    /// Compute the joint public key from public data.
+    ///
+    /// # Errors
+    ///
+    /// - `DkgParameterError` if the input sizes do not match `params`
+    pub fn joint_public_key(
+        params: &DkgParameters,
+        all_checking_values: &[Vec<C::Element>],
+    ) -> Result<PublicKey<C>, Error> {
+        Self::check_checking_values(params, all_checking_values)?;
+
+        let joint_public_key = all_checking_values
+            .iter()
+            .fold(C::Element::one(), |acc, cv| acc.mul(&cv[0]));
+
+        Ok(PublicKey::new(joint_public_key))
+    }
+
+    /// Compute partial decryptions of the given ciphertexts, with proofs of correctness.
+    ///
+    /// See [`Recipient::decryption_factor`][`crate::dkgd::recipient::Recipient::decryption_factor`].
+    ///
+    /// # Errors
+    ///
+    /// - `Error` if proof generation fails
+    pub fn decryption_factor<const W: usize>(
+        &self,
+        ciphertexts: &[Ciphertext<C, W>],
+        proof_context: &[u8],
+    ) -> Result<Vec<DynDecryptionFactor<C, W>>, Error> {
+        ciphertexts
+            .iter()
+            .map(|c| {
+                let value = c.u().dist_exp(&self.sk);
+                let proof = DlogEqProof::<C, W>::prove(
+                    &self.sk,
+                    &C::generator(),
+                    &self.verification_key,
+                    c.u(),
+                    &value,
+                    proof_context,
+                )?;
+
+                Ok(DynDecryptionFactor {
+                    value,
+                    proof,
+                    source: self.position.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Check that there are `P` sets of `T` checking values.
+    fn check_checking_values(
+        params: &DkgParameters,
+        all_checking_values: &[Vec<C::Element>],
+    ) -> Result<(), Error> {
+        DkgParameters::check_len(
+            "sets of checking values",
+            all_checking_values.len(),
+            params.participants,
+        )?;
+        for cv in all_checking_values {
+            DkgParameters::check_len("checking values", cv.len(), params.threshold)?;
+        }
+
+        Ok(())
+    }
+}
+
+/**
+ * A partial decryption of a ciphertext, with a proof of correctness.
+ *
+ * Serializes like [`DecryptionFactor`].
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct DynDecryptionFactor<C: Context, const W: usize> {
+    /// the decryption factor, `u^sk`
+    pub(crate) value: [C::Element; W],
+    /// the proof of correctness of the decryption factor
+    pub(crate) proof: DlogEqProof<C, W>,
+    /// the position of the recipient that computed the decryption factor
+    pub(crate) source: DynParticipantPosition,
+}
+
+impl<C: Context, const W: usize> DynDecryptionFactor<C, W> {
+    /// Returns the position of the recipient that computed this decryption factor.
+    pub fn get_source(&self) -> &DynParticipantPosition {
+        &self.source
+    }
+}
+
+impl<C: Context, const P: usize, const W: usize> From<DecryptionFactor<C, P, W>>
+    for DynDecryptionFactor<C, W>
+{
+    fn from(dfactor: DecryptionFactor<C, P, W>) -> Self {
+        Self {
+            value: dfactor.value,
+            proof: dfactor.proof,
+            source: dfactor.source.into(),
+        }
+    }
+}
+
+/// Combine the decryption factors of `T` recipients to decrypt the given ciphertexts.
+///
+/// See [`combine`][`crate::dkgd::recipient::combine`].
+///
+/// # Parameters
+///
+/// - `params`: the parameters of the protocol execution
+/// - `ciphertexts`: the ciphertexts to decrypt
+/// - `dfactors`: the decryption factors of `T` distinct recipients, one per ciphertext
+/// - `verification_keys`: the verification keys of the recipients, in the same order
+/// - `proof_context`: the context used to compute the decryption proofs
+///
+/// # Errors
+///
+/// - `DkgParameterError` if the input sizes do not match `params`, or positions are
+///   out of range, mixed or repeated
+/// - `DecryptProofFailed` if some decryption proof does not verify
+pub fn combine<C: Context, const W: usize>(
+    params: &DkgParameters,
+    ciphertexts: &[Ciphertext<C, W>],
+    dfactors: &[Vec<DynDecryptionFactor<C, W>>],
+    verification_keys: &[C::Element],
+    proof_context: &[u8],
+) -> Result<Vec<[C::Element; W]>, Error> {
+    DkgParameters::check_len(
+        "sets of decryption factors",
+        dfactors.len(),
+        params.threshold,
+    )?;
+    DkgParameters::check_len(
+        "verification keys",
+        verification_keys.len(),
+        params.threshold,
+    )?;
+
+    let mut present: Vec<DynParticipantPosition> = Vec::with_capacity(dfactors.len());
+    for dfactor in dfactors {
+        DkgParameters::check_len("decryption factors", dfactor.len(), ciphertexts.len())?;
+        let Some(source) = dfactor.first().map(|df| &df.source) else {
+            return Err(Error::DkgParameterError("No ciphertexts".to_string()));
+        };
+        source.check(params)?;
+        if dfactor.iter().any(|df| df.source != *source) {
+            return Err(Error::DkgParameterError(
+                "Mixed decryption factor sources".to_string(),
+            ));
+        }
+        if present.contains(source) {
+            return Err(Error::DkgParameterError(
+                "Repeated decryption factor source".to_string(),
+            ));
+        }
+        present.push(source.clone());
+    }
+
+    let g = C::generator();
+    let batch: Vec<_> = dfactors
+        .iter()
+        .zip(verification_keys)
+        .flat_map(|(dfactor, vk)| {
+            dfactor
+                .iter()
+                .zip(ciphertexts)
+                .map(move |(df, c)| (&df.proof, vk, c.u(), &df.value))
+        })
+        .collect();
+    if !DlogEqProof::verify_batch(&g, &batch, proof_context)? {
+        // locate the invalid proof
+        for (proof, vk, u, value) in batch {
+            if !proof.verify(&g, vk, u, value, proof_context)? {
+                return Err(Error::DecryptProofFailed(
+                    "Failed to verify decryption proof".into(),
+                ));
+            }
+        }
+    }
+
+    let mut divisors: Vec<[C::Element; W]> = vec![<[C::Element; W]>::one(); ciphertexts.len()];
+
+    for (dfactor, position) in dfactors.iter().zip(&present) {
+        let lambda = lagrange::<C>(position, &present);
+
+        for (df, divisor) in dfactor.iter().zip(divisors.iter_mut()) {
+            *divisor = divisor.mul(&df.value.dist_exp(&lambda));
+        }
+    }
+
+    Ok(divisors
+        .iter()
+        .zip(ciphertexts)
+        .map(|(d, c)| c.v().mul(&d.inv()))
+        .collect())
+}
+
+/// Compute the factor of a recipient's verification key contributed by one dealer.
+///
+/// Returns `prod_j A_j^(position^j)`, computing powers of `position` in the
+/// scalar field.
+fn vk_factor<C: Context>(
+    checking_values: &[C::Element],
+    position: &DynParticipantPosition,
+) -> C::Element {
+    let x: C::Scalar = position.0.into();
+    let mut power = C::Scalar::one();
+    let mut ret = C::Element::one();
+
+    for cv in checking_values {
+        ret = ret.mul(&cv.exp(&power));
+        power = power.mul(&x);
+    }
+
+    ret
+}
+
+/// Compute the Lagrange coefficient at zero of `position` with respect to `present`.
+///
+/// The positions in `present` must be distinct.
+fn lagrange<C: Context>(
+    position: &DynParticipantPosition,
+    present: &[DynParticipantPosition],
+) -> C::Scalar {
+    let mut numerator = C::Scalar::one();
+    let mut denominator = C::Scalar::one();
+    let position_exp: C::Scalar = position.0.into();
+
+    for p in present.iter().filter(|p| *p != position) {
+        let present_exp: C::Scalar = p.0.into();
+        numerator = numerator.mul(&present_exp);
+        denominator = denominator.mul(&present_exp.sub(&position_exp));
+    }
+
+    numerator.mul(
+        &denominator
+            .inv()
+            .expect("denominator != 0: the denominator is a product of non-zero elements"),
+    )
+}
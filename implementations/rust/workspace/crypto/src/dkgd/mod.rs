@@ -9,9 +9,9 @@
 
 //! Distributed key generation and decryption
 //!
-//! * NOTE: this API does not represent private shares as encrypted values.
-//!   In the messaging layer, private shares should be encrypted with the recipient's
-//!   public key.
+//! * NOTE: the dealer and recipient APIs do not represent private shares as
+//!   encrypted values. The [`transport`][`crate::dkgd::transport`] module provides
+//!   the signed messages that carry shares encrypted to the recipient's public key.
 //!
 //! This module models distributed key generation and decryption with
 //! two main abstractions:
@@ -43,6 +43,43 @@
 //! and recipients receive shares, verify them, and construct the joint public
 //! key and their secrete shares.
 //!
+//! - [`transport`][`crate::dkgd::transport`]
+//!
+//!   The signed [`PublicCheckValueMessage`][`crate::dkgd::transport::PublicCheckValueMessage`]
+//!   and [`PairwiseShareMessage`][`crate::dkgd::transport::PairwiseShareMessage`]
+//!   that dealers post to the trustee board. Pairwise shares are
+//!   [encrypted][`crate::cryptosystem::hybrid`] to their recipient. Recipients verify
+//!   and decrypt their messages to obtain the shares passed to
+//!   [`Recipient::from_shares`][`crate::dkgd::recipient::Recipient::from_shares`].
+//!
+//! - [`complaint`][`crate::dkgd::complaint`]
+//!
+//!   Recipients that receive an invalid share publish a publicly verifiable
+//!   [`Complaint`][`crate::dkgd::complaint::Complaint`] against its dealer. Every
+//!   party rules on the complaints to compute the
+//!   [qualified set][`crate::dkgd::complaint::QualifiedSet`] of dealers, from which
+//!   the joint public key and secret shares are then computed.
+//!
+//! - [`pedersen`][`crate::dkgd::pedersen`]
+//!
+//!   An alternative, secure, DKG mode, in which dealers first share their secrets
+//!   with Pedersen commitments, and publish Feldman checking values only once the
+//!   qualified set is fixed. This prevents a rushing adversary from biasing the
+//!   joint public key. Its output is compatible with distributed decryption.
+//!
+//! - [`reshare`][`crate::dkgd::reshare`]
+//!
+//!   At least `T` recipients can reshare the secret key to a new set of
+//!   recipients, possibly with a different threshold, keeping the same joint
+//!   public key. Resharing to the same set refreshes the shares.
+//!
+//! - [`dynamic`][`crate::dkgd::dynamic`]
+//!
+//!   Equivalent dealer and recipient types whose threshold and number of
+//!   participants are [runtime parameters][`crate::dkgd::dynamic::DkgParameters`],
+//!   for example read from an election configuration, instead of const
+//!   generics. They use the same proof and serialization formats.
+//!
 //! # Distributed decryption
 //!
 //! Comprises the steps where recipients compute partial decryptions of
@@ -53,6 +90,18 @@
 //!
 //! 1) The ciphertexts to be decrypted
 //! 2) The choice of participants to compute partial decryptions
+//!
+//! [`combine_robust`][`crate::dkgd::recipient::combine_robust`] removes the
+//! need for the second, by accepting partial decryptions from any participants,
+//! excluding faulty ones.
+//!
+//! # Plaintext equivalence test
+//!
+//! Recipients can decide whether two ciphertexts hold the same plaintext
+//! without decrypting them, see
+//! [`plaintext_equivalence`][`crate::dkgd::pet::plaintext_equivalence`]. Each
+//! participating recipient blinds the quotient of the ciphertexts with a secret
+//! exponent, and `T` recipients then jointly decrypt the blinded quotient.
 
 /// Distributed key generation functionality.
 pub mod dealer;
@@ -60,6 +109,24 @@ pub mod dealer;
 /// Distributed key generation and decryption functionality.
 pub mod recipient;
 
+/// Encrypted and signed share transport.
+pub mod transport;
+
+/// Complaints against dealers and the qualified set.
+pub mod complaint;
+
+/// Pedersen-VSS based distributed key generation.
+pub mod pedersen;
+
+/// Share refresh and resharing to a new set of recipients.
+pub mod reshare;
+
+/// Distributed key generation and decryption with runtime parameters.
+pub mod dynamic;
+
+/// Threshold plaintext equivalence test.
+pub mod pet;
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[crate::warning("Need more threshold parameter combinations")]
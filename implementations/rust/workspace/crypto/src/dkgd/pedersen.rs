@@ -0,0 +1,594 @@
+/*
+ * Pedersen-VSS distributed key generation
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use std::array;
+use std::iter;
+
+use crate::context::Context;
+use crate::dkgd::complaint::QualifiedSet;
+use crate::dkgd::dealer::{Dealer, Polynomial, VerifiableShare};
+use crate::dkgd::recipient::{ParticipantPosition, Recipient};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use vser_derive::VSerializable;
+
+/// Label used to derive the second, independent, Pedersen generator
+const PEDERSEN_GENERATOR_LABEL: &[u8] = b"dkg_pedersen_generator";
+
+/// Returns the second generator `h` used in Pedersen commitments.
+///
+/// The generator is derived with [`CryptoGroup::ind_generators`], so that its
+/// discrete logarithm with respect to the group generator is unknown.
+///
+/// # Errors
+///
+/// - `HashToElementError` if generator derivation returns error
+pub fn pedersen_generator<C: Context>() -> Result<C::Element, Error> {
+    let mut generators = C::G::ind_generators(1, PEDERSEN_GENERATOR_LABEL)?;
+
+    generators
+        .pop()
+        .ok_or_else(|| Error::HashToElementError("No generator returned".to_string()))
+}
+
+/**
+ * A dealer in the Pedersen-VSS based distributed key generation (DKG) protocol.
+ *
+ * This is the secure DKG of Gennaro, Jarecki, Krawczyk and Rabin. Unlike in the
+ * [Joint-Feldman][`Dealer`] DKG, the dealer first publishes Pedersen commitments
+ * `C_k = g^a_k h^b_k` to the coefficients of its secret polynomial `f`, blinded
+ * with a second random polynomial `f'`. Pedersen commitments reveal nothing about
+ * `f`, so a rushing adversary cannot choose its contribution to the joint public
+ * key as a function of the honest dealers' contributions.
+ *
+ * The protocol proceeds in two phases:
+ *
+ * 1. Sharing: every dealer distributes [`PedersenShare`]s, which recipients
+ *    [verify][`PedersenShare::verify`] against the commitments. A recipient whose
+ *    share does not verify publishes a [`PedersenComplaint`], which the dealer
+ *    answers by publishing the share. Dealers that do not answer, or whose answer
+ *    does not verify, are excluded from the [qualified set][`QualifiedSet`] (QUAL),
+ *    see [`qualified_set`].
+ *
+ * 2. Extraction: once QUAL is fixed, every qualified dealer publishes its Feldman
+ *    [checking values][`Self::checking_values`] `A_k = g^a_k`. Recipients verify
+ *    them against the shares received in phase 1. A recipient whose share does
+ *    not verify publishes an [`ExtractionComplaint`] revealing its Pedersen-verified
+ *    share. If a complaint is upheld, recipients reveal their shares and the
+ *    correct checking values are [reconstructed][`extracted_checking_values`].
+ *
+ * The output of the extraction phase are [`VerifiableShare`]s, from which
+ * [`Recipient::from_qualified_shares`] computes the joint public key and the
+ * recipient's secret share, for use in
+ * [`decryption_factor`][`Recipient::decryption_factor`] and
+ * [`combine`][`crate::dkgd::recipient::combine`].
+ *
+ * # Examples
+ *
+ * ```
+ * use std::array;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::dkgd::complaint::QualifiedSet;
+ * use crypto::dkgd::dealer::VerifiableShare;
+ * use crypto::dkgd::pedersen::{self, PedersenDealer};
+ * use crypto::dkgd::recipient::{ParticipantPosition, Recipient};
+ *
+ * const P: usize = 3;
+ * const T: usize = 2;
+ *
+ * let h = pedersen::pedersen_generator::<RCtx>().unwrap();
+ * let dealers: [PedersenDealer<RCtx, T, P>; P] = array::from_fn(|_| PedersenDealer::generate());
+ *
+ * // phase 1: sharing with Pedersen commitments
+ * let shares = dealers.each_ref().map(|d| d.get_pedersen_shares(&h));
+ * let commitments = shares.each_ref().map(|s| s.commitments.clone());
+ * let position = ParticipantPosition::from_usize(1);
+ * let received = shares.each_ref().map(|s| s.for_recipient(&position));
+ * for share in &received {
+ *     share.verify(&position, &h).unwrap();
+ * }
+ * // no recipient complained
+ * let qualified: QualifiedSet<P> =
+ *     pedersen::qualified_set::<RCtx, T, P>(&[], &[], &commitments, &h);
+ *
+ * // phase 2: extraction of Feldman checking values
+ * let published = dealers.each_ref().map(|d| d.checking_values());
+ * let checking_values: [_; P] = array::from_fn(|i| {
+ *     let dealer = ParticipantPosition::<P>::from_usize(i + 1);
+ *     pedersen::extracted_checking_values::<RCtx, T, P>(
+ *         &dealer, &commitments[i], &published[i], &[], &[], &h,
+ *     ).unwrap()
+ * });
+ * let verifiable_shares: [VerifiableShare<RCtx, T>; P] =
+ *     array::from_fn(|i| received[i].extract(checking_values[i].clone()));
+ *
+ * let (recipient, pk) =
+ *     Recipient::from_qualified_shares(position, &qualified, &verifiable_shares).unwrap();
+ * ```
+ */
+#[derive(Clone)]
+pub struct PedersenDealer<C: Context, const T: usize, const P: usize> {
+    /// the Joint-Feldman dealer holding the secret polynomial `f`
+    dealer: Dealer<C, T, P>,
+    /// the blinding polynomial `f'`
+    blinding: Polynomial<C, T>,
+}
+
+impl<C: Context, const T: usize, const P: usize> PedersenDealer<C, T, P> {
+    /// Construct a new [`PedersenDealer`] by randomly generating two `T - 1` degree polynomials.
+    #[must_use]
+    pub fn generate() -> Self {
+        let dealer = Dealer::generate();
+        let blinding = Polynomial::generate();
+
+        Self { dealer, blinding }
+    }
+
+    /// Compute the `P` Pedersen shares distributed by this dealer in the sharing
+    /// phase, and its `T` Pedersen commitments.
+    ///
+    /// # Parameters
+    ///
+    /// - `h`: the [Pedersen generator][`pedersen_generator`]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_pedersen_shares(&self, h: &C::Element) -> PedersenShares<C, T, P> {
+        let shares = self.dealer.get_shares();
+        let blinding = array::from_fn(|p| {
+            // p + 1 cannot overflow, P < 100 is compile-time checked
+            #[allow(clippy::arithmetic_side_effects)]
+            let recipient: u32 = (p + 1).try_into().expect("P < 100 < u32::MAX");
+            self.blinding.eval(&recipient.into())
+        });
+        let g = C::generator();
+        let commitments: [C::Element; T] = array::from_fn(|k| {
+            let a_k = &self.dealer.polynomial.0[k];
+            let b_k = &self.blinding.0[k];
+            g.exp(a_k).mul(&h.exp(b_k))
+        });
+
+        PedersenShares {
+            shares,
+            blinding,
+            commitments,
+        }
+    }
+
+    /// Compute the `T` Feldman checking values published in the extraction phase.
+    ///
+    /// Each checking value is computed as `g^a_k`, where `a_k` are the
+    /// coefficients of the secret polynomial.
+    pub fn checking_values(&self) -> [C::Element; T] {
+        self.dealer.get_checking_values()
+    }
+}
+
+/**
+ * The set of Pedersen shares produced by one dealer in the sharing phase.
+ *
+ * Contains `P` shares and blinding shares, one for each of the `P`
+ * participants, together with the dealer's `T` Pedersen commitments.
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct PedersenShares<C: Context, const T: usize, const P: usize> {
+    /// The shares `f(i)` distributed to each participant, offset by -1.
+    pub shares: [C::Scalar; P],
+    /// The blinding shares `f'(i)` distributed to each participant, offset by -1.
+    pub blinding: [C::Scalar; P],
+    /// The Pedersen commitments to the dealer's polynomials.
+    pub commitments: [C::Element; T],
+}
+
+impl<C: Context, const T: usize, const P: usize> PedersenShares<C, T, P> {
+    /// Return the share for the recipient at the given [`ParticipantPosition`].
+    pub fn for_recipient(&self, recipient: &ParticipantPosition<P>) -> PedersenShare<C, T> {
+        let index = recipient.index();
+
+        PedersenShare {
+            value: self.shares[index].clone(),
+            blinding: self.blinding[index].clone(),
+            commitments: self.commitments.clone(),
+        }
+    }
+}
+
+/**
+ * One Pedersen share distributed by one dealer to one recipient, in the sharing phase.
+ *
+ * Like a [`VerifiableShare`], but verifiable against Pedersen commitments
+ * `C_k = g^a_k h^b_k` instead of Feldman checking values `A_k = g^a_k`.
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct PedersenShare<C: Context, const T: usize> {
+    /// the secret share `f(i)`
+    pub value: C::Scalar,
+    /// the blinding share `f'(i)`
+    pub blinding: C::Scalar,
+    /// the dealer's Pedersen commitments
+    pub commitments: [C::Element; T],
+}
+
+impl<C: Context, const T: usize> PedersenShare<C, T> {
+    /// Verify this share for a recipient at `position`, against the dealer's commitments.
+    ///
+    /// Checks that `g^f(i) h^f'(i) = prod_k C_k^(i^k)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: the position of the recipient
+    /// - `h`: the [Pedersen generator][`pedersen_generator`]
+    ///
+    /// # Errors
+    ///
+    /// - `ShareVerificationFailed` if the share does not verify
+    pub fn verify<const P: usize>(
+        &self,
+        position: &ParticipantPosition<P>,
+        h: &C::Element,
+    ) -> Result<(), Error> {
+        let lhs = C::generator().exp(&self.value).mul(&h.exp(&self.blinding));
+        let rhs = Recipient::<C, T, P>::vk_factor(&self.commitments, position);
+
+        if !lhs.equals(&rhs) {
+            return Err(Error::ShareVerificationFailed(
+                "Failed to verify Pedersen share".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Verify this share for a recipient at `position`, against the dealer's
+    /// Feldman checking values published in the extraction phase.
+    ///
+    /// # Errors
+    ///
+    /// - `ShareVerificationFailed` if the share does not verify
+    pub fn verify_extracted<const P: usize>(
+        &self,
+        position: &ParticipantPosition<P>,
+        checking_values: &[C::Element; T],
+    ) -> Result<(), Error> {
+        let share = VerifiableShare::new(self.value.clone(), checking_values.clone());
+        Recipient::<C, T, P>::verify_share(&share, position)?;
+
+        Ok(())
+    }
+
+    /// Combine this share with the dealer's Feldman checking values.
+    ///
+    /// The result can be passed to [`Recipient::from_qualified_shares`], which
+    /// verifies it.
+    #[must_use]
+    pub fn extract(&self, checking_values: [C::Element; T]) -> VerifiableShare<C, T> {
+        VerifiableShare::new(self.value.clone(), checking_values)
+    }
+}
+
+/**
+ * A public accusation against a dealer in the sharing phase of the Pedersen-VSS DKG.
+ *
+ * A recipient whose [`PedersenShare`] does not [verify][`PedersenShare::verify`]
+ * against the dealer's commitments publishes a complaint. The dealer
+ * [answers][`PedersenShares::answer`] it by publishing the accused share, which
+ * anyone can check against the commitments. If the answer verifies, the
+ * complainant uses the published share instead of the one it received.
+ *
+ * See [`qualified_set`].
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct PedersenComplaint<const P: usize> {
+    /// the position of the recipient making the complaint
+    pub complainant: ParticipantPosition<P>,
+    /// the position of the accused dealer
+    pub dealer: ParticipantPosition<P>,
+}
+
+impl<const P: usize> PedersenComplaint<P> {
+    /// Construct a complaint by the recipient at `complainant` against the dealer at `dealer`.
+    #[must_use]
+    pub fn new(complainant: ParticipantPosition<P>, dealer: ParticipantPosition<P>) -> Self {
+        Self {
+            complainant,
+            dealer,
+        }
+    }
+
+    /// Rule on this complaint.
+    ///
+    /// The complaint is upheld if the dealer did not answer it, or if the
+    /// published share does not verify against the dealer's commitments for
+    /// the complainant's position, that is, `g^f(i) h^f'(i) != prod_k C_k^(i^k)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `commitments`: the accused dealer's Pedersen commitments
+    /// - `answer`: the dealer's answer to this complaint, if any
+    /// - `h`: the [Pedersen generator][`pedersen_generator`]
+    ///
+    /// Returns `true` if the complaint is upheld, `false` otherwise.
+    pub fn rule<C: Context, const T: usize>(
+        &self,
+        commitments: &[C::Element; T],
+        answer: Option<&PedersenAnswer<C, T, P>>,
+        h: &C::Element,
+    ) -> bool {
+        let Some(answer) = answer else {
+            return true;
+        };
+
+        answer.share.commitments != *commitments
+            || answer.share.verify(&self.complainant, h).is_err()
+    }
+}
+
+/**
+ * A dealer's public answer to a [`PedersenComplaint`].
+ *
+ * Reveals the accused share, with its blinding share.
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct PedersenAnswer<C: Context, const T: usize, const P: usize> {
+    /// the complaint being answered
+    pub complaint: PedersenComplaint<P>,
+    /// the accused share
+    pub share: PedersenShare<C, T>,
+}
+
+impl<C: Context, const T: usize, const P: usize> PedersenShares<C, T, P> {
+    /// Answer the given complaint by publishing the complainant's share.
+    ///
+    /// # Errors
+    ///
+    /// - `ComplaintError` if the complainant's position is not in the range `1..=P`
+    pub fn answer(
+        &self,
+        complaint: &PedersenComplaint<P>,
+    ) -> Result<PedersenAnswer<C, T, P>, Error> {
+        let position = &complaint.complainant;
+        if position.0 == 0 || position.index() >= P {
+            return Err(Error::ComplaintError(format!(
+                "Position {} is out of range 1..={P}",
+                position.0
+            )));
+        }
+
+        Ok(PedersenAnswer {
+            complaint: complaint.clone(),
+            share: self.for_recipient(position),
+        })
+    }
+}
+
+/// Rule on the given sharing phase complaints, returning the set of qualified dealers.
+///
+/// Every dealer against which some complaint is [upheld][`PedersenComplaint::rule`]
+/// is excluded from the qualified set. Complaints with a dealer position outside
+/// `1..=P` are rejected.
+///
+/// # Parameters
+///
+/// - `complaints`: the complaints posted by all recipients
+/// - `answers`: the answers posted by the accused dealers
+/// - `commitments`: the Pedersen commitments of all `P` dealers, in position order
+/// - `h`: the [Pedersen generator][`pedersen_generator`]
+#[must_use]
+pub fn qualified_set<C: Context, const T: usize, const P: usize>(
+    complaints: &[PedersenComplaint<P>],
+    answers: &[PedersenAnswer<C, T, P>],
+    commitments: &[[C::Element; T]; P],
+    h: &C::Element,
+) -> QualifiedSet<P> {
+    let mut qualified = QualifiedSet::new();
+
+    for complaint in complaints {
+        let dealer = complaint.dealer.index();
+        if complaint.dealer.0 == 0 || dealer >= P {
+            continue;
+        }
+        let answer = answers.iter().find(|a| a.complaint == *complaint);
+        if complaint.rule(&commitments[dealer], answer, h) {
+            qualified.0[dealer] = false;
+        }
+    }
+
+    qualified
+}
+
+/**
+ * A public accusation against a qualified dealer in the extraction phase of the Pedersen-VSS DKG.
+ *
+ * A recipient whose share verifies against the dealer's Pedersen commitments, but
+ * not against the dealer's published Feldman checking values, publishes a complaint
+ * revealing the share. The complaint is [upheld][`Self::rule`] if anyone can
+ * confirm both checks, in which case the dealer's checking values are
+ * [reconstructed][`extracted_checking_values`] from shares revealed by the recipients.
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct ExtractionComplaint<C: Context, const T: usize, const P: usize> {
+    /// the position of the recipient making the complaint
+    pub complainant: ParticipantPosition<P>,
+    /// the position of the accused dealer
+    pub dealer: ParticipantPosition<P>,
+    /// the complainant's share from the accused dealer
+    pub share: PedersenShare<C, T>,
+}
+
+impl<C: Context, const T: usize, const P: usize> ExtractionComplaint<C, T, P> {
+    /// Construct a complaint by the recipient at `complainant` against the dealer at
+    /// `dealer`, revealing the share it received from that dealer.
+    #[must_use]
+    pub fn new(
+        complainant: ParticipantPosition<P>,
+        dealer: ParticipantPosition<P>,
+        share: PedersenShare<C, T>,
+    ) -> Self {
+        Self {
+            complainant,
+            dealer,
+            share,
+        }
+    }
+
+    /// Rule on this complaint.
+    ///
+    /// The complaint is upheld if the revealed share verifies against the dealer's
+    /// Pedersen commitments, and does not verify against its checking values.
+    ///
+    /// # Parameters
+    ///
+    /// - `commitments`: the accused dealer's Pedersen commitments
+    /// - `checking_values`: the accused dealer's published Feldman checking values
+    /// - `h`: the [Pedersen generator][`pedersen_generator`]
+    ///
+    /// Returns `true` if the complaint is upheld, `false` otherwise.
+    pub fn rule(
+        &self,
+        commitments: &[C::Element; T],
+        checking_values: &[C::Element; T],
+        h: &C::Element,
+    ) -> bool {
+        self.share.commitments == *commitments
+            && self.share.verify(&self.complainant, h).is_ok()
+            && self
+                .share
+                .verify_extracted(&self.complainant, checking_values)
+                .is_err()
+    }
+}
+
+/// Returns the Feldman checking values of a qualified dealer, after ruling on
+/// the extraction phase complaints against it.
+///
+/// If no complaint against `dealer` is upheld, these are its published checking
+/// values. Otherwise they are [reconstructed][`reconstruct_checking_values`]
+/// from the shares revealed by the recipients.
+///
+/// # Parameters
+///
+/// - `dealer`: the position of the dealer
+/// - `commitments`: the dealer's Pedersen commitments
+/// - `checking_values`: the dealer's published Feldman checking values
+/// - `complaints`: the extraction phase complaints posted by all recipients
+/// - `revealed`: the shares from `dealer` revealed by recipients, with their positions
+/// - `h`: the [Pedersen generator][`pedersen_generator`]
+///
+/// # Errors
+///
+/// - `ShareVerificationFailed` if some complaint is upheld, and fewer than `T`
+///   distinct positions revealed valid shares
+pub fn extracted_checking_values<C: Context, const T: usize, const P: usize>(
+    dealer: &ParticipantPosition<P>,
+    commitments: &[C::Element; T],
+    checking_values: &[C::Element; T],
+    complaints: &[ExtractionComplaint<C, T, P>],
+    revealed: &[(ParticipantPosition<P>, PedersenShare<C, T>)],
+    h: &C::Element,
+) -> Result<[C::Element; T], Error> {
+    let upheld = complaints
+        .iter()
+        .any(|c| c.dealer == *dealer && c.rule(commitments, checking_values, h));
+    if !upheld {
+        return Ok(checking_values.clone());
+    }
+
+    reconstruct_checking_values(commitments, revealed, h)
+}
+
+/// Reconstruct a dealer's Feldman checking values from shares revealed by the recipients.
+///
+/// Used in the extraction phase when a qualified dealer publishes checking values
+/// that do not verify. Each revealed share is verified against the dealer's Pedersen
+/// commitments, and the dealer's secret polynomial is interpolated from the first
+/// `T` valid shares at distinct positions. Later shares at the position of an earlier
+/// valid share, and shares at positions outside the range [1, P], are ignored.
+///
+/// # Parameters
+///
+/// - `commitments`: the dealer's Pedersen commitments from the sharing phase
+/// - `revealed`: the shares revealed by recipients, with their positions
+/// - `h`: the [Pedersen generator][`pedersen_generator`]
+///
+/// # Errors
+///
+/// - `ShareVerificationFailed` if fewer than `T` distinct positions revealed valid shares
+pub fn reconstruct_checking_values<C: Context, const T: usize, const P: usize>(
+    commitments: &[C::Element; T],
+    revealed: &[(ParticipantPosition<P>, PedersenShare<C, T>)],
+    h: &C::Element,
+) -> Result<[C::Element; T], Error> {
+    let mut seen = [false; P];
+    let points: Vec<(C::Scalar, C::Scalar)> = revealed
+        .iter()
+        .filter(|(position, share)| {
+            let index = position.index();
+            if position.0 == 0 || index >= P || seen[index] {
+                return false;
+            }
+            let valid = share.commitments == *commitments && share.verify(position, h).is_ok();
+            seen[index] = valid;
+
+            valid
+        })
+        .take(T)
+        .map(|(position, share)| (position.0.into(), share.value.clone()))
+        .collect();
+    if points.len() < T {
+        return Err(Error::ShareVerificationFailed(
+            "Not enough valid shares to reconstruct checking values".into(),
+        ));
+    }
+
+    let coefficients = interpolate::<C, T>(&points)?;
+    let g = C::generator();
+
+    Ok(coefficients.map(|a_k| g.exp(&a_k)))
+}
+
+/// Interpolate the coefficients of the degree `T - 1` polynomial through the given `T` points.
+///
+/// # Errors
+///
+/// - `ShareVerificationFailed` if the points are not at distinct positions
+fn interpolate<C: Context, const T: usize>(
+    points: &[(C::Scalar, C::Scalar)],
+) -> Result<[C::Scalar; T], Error> {
+    let mut coefficients: [C::Scalar; T] = array::from_fn(|_| C::Scalar::zero());
+
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        // the Lagrange basis polynomial prod_{j != i} (X - x_j) / (x_i - x_j)
+        let mut basis = vec![C::Scalar::one()];
+        let mut denominator = C::Scalar::one();
+
+        for (j, (x_j, _)) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            // multiply by (X - x_j): shift up one degree and subtract x_j times the basis
+            let shifted = iter::once(C::Scalar::zero()).chain(basis.iter().cloned());
+            let scaled = basis
+                .iter()
+                .map(|b| b.mul(x_j))
+                .chain(iter::once(C::Scalar::zero()));
+            basis = shifted.zip(scaled).map(|(s, m)| s.sub(&m)).collect();
+            denominator = denominator.mul(&x_i.sub(x_j));
+        }
+
+        let denominator = denominator.inv().ok_or_else(|| {
+            Error::ShareVerificationFailed("Revealed shares at duplicate positions".into())
+        })?;
+        let factor = y_i.mul(&denominator);
+        for (c, b) in coefficients.iter_mut().zip(basis.iter()) {
+            *c = c.add(&b.mul(&factor));
+        }
+    }
+
+    Ok(coefficients)
+}
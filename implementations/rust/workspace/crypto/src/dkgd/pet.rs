@@ -0,0 +1,466 @@
+/*
+ * Threshold plaintext equivalence test
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::Ciphertext;
+use crate::dkgd::recipient::{
+    DecryptionFactor, DkgCiphertext, ParticipantPosition, Recipient, combine,
+};
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error;
+use crate::zkp::sigma::{Homomorphism, Relation, SigmaProof};
+use crate::zkp::transcript::Transcript;
+use vser_derive::VSerializable;
+
+/**
+ * A blinded quotient ciphertext, computed by one recipient in the
+ * [plaintext equivalence test][`plaintext_equivalence`].
+ *
+ * Contains the [quotient] raised to the recipient's secret blinding exponent,
+ * together with a proof of knowledge of the exponent. The blinding factors
+ * of all participating recipients are combined with [`blind`].
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct BlindingFactor<C: Context, const P: usize, const W: usize> {
+    /// The quotient raised to the blinding exponent
+    pub(crate) value: Ciphertext<C, W>,
+    /// The proof of knowledge of the blinding exponent
+    pub(crate) proof: SigmaProof<C>,
+    /// The position of the participant who computed this blinding factor
+    pub(crate) source: ParticipantPosition<P>,
+}
+
+impl<C: Context, const P: usize, const W: usize> BlindingFactor<C, P, W> {
+    /// Constructs a new [`BlindingFactor`] from the given values.
+    ///
+    /// The standard way to compute blinding factors is through the
+    /// [`Recipient::blinding_factor`] method.
+    pub(crate) fn new(
+        value: Ciphertext<C, W>,
+        proof: SigmaProof<C>,
+        source: ParticipantPosition<P>,
+    ) -> Self {
+        Self {
+            value,
+            proof,
+            source,
+        }
+    }
+
+    /// Verify the proof of this blinding factor for the given quotient.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    fn verify(&self, quotient: &Ciphertext<C, W>, proof_context: &[u8]) -> Result<bool, Error> {
+        let relation = blinding_relation(quotient, &self.value)?;
+        let mut transcript = blinding_transcript(&self.source, proof_context);
+
+        self.proof
+            .verify_with_transcript(&mut transcript, &relation)
+    }
+}
+
+impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
+    /// Blind the given quotient ciphertexts with fresh secret exponents, one
+    /// per quotient, for the [plaintext equivalence test][`plaintext_equivalence`].
+    ///
+    /// # Parameters
+    ///
+    /// - `quotients`: the [quotients][quotient] of the pairs of ciphertexts to compare
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a vector of [`BlindingFactor`]'s corresponding to the input quotients;
+    /// this struct contains the blinded quotient together with a proof of knowledge
+    /// of the exponent.
+    pub fn blinding_factor<const W: usize>(
+        &self,
+        quotients: &[DkgCiphertext<C, W, T>],
+        proof_context: &[u8],
+    ) -> Result<Vec<BlindingFactor<C, P, W>>, Error> {
+        let exponents: Vec<C::Scalar> = quotients.iter().map(|_| C::random_scalar()).collect();
+
+        self.blinding_factor_with(quotients, &exponents, proof_context)
+    }
+
+    /// Blind the given quotient ciphertexts with the given exponents, one per quotient.
+    ///
+    /// See [`Recipient::blinding_factor`].
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    pub(crate) fn blinding_factor_with<const W: usize>(
+        &self,
+        quotients: &[DkgCiphertext<C, W, T>],
+        exponents: &[C::Scalar],
+        proof_context: &[u8],
+    ) -> Result<Vec<BlindingFactor<C, P, W>>, Error> {
+        quotients
+            .iter()
+            .zip(exponents.iter())
+            .map(|(q, z)| {
+                let value = q.0.exp(z);
+
+                let relation = blinding_relation(&q.0, &value)?;
+                let mut transcript = blinding_transcript(self.get_position(), proof_context);
+                let proof = SigmaProof::prove_with_transcript(
+                    &mut transcript,
+                    &relation,
+                    std::slice::from_ref(z),
+                )?;
+
+                Ok(BlindingFactor::new(
+                    value,
+                    proof,
+                    self.get_position().clone(),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Returns the quotient `a / b` of two ciphertexts, which encrypts the
+/// quotient of their plaintexts.
+///
+/// The plaintexts of `a` and `b` are equal if and only if the quotient
+/// encrypts the identity.
+#[must_use]
+pub fn quotient<C: Context, const W: usize, const T: usize>(
+    a: &DkgCiphertext<C, W, T>,
+    b: &DkgCiphertext<C, W, T>,
+) -> DkgCiphertext<C, W, T> {
+    DkgCiphertext(Ciphertext(a.0.0.mul(&b.0.0.inv())))
+}
+
+/// Verify the blinding factors of the participating recipients, and combine
+/// them into the blinded quotients.
+///
+/// The blinded quotient of `q` is `q^z`, where `z` is the sum of the blinding
+/// exponents of all participating recipients. The blinded plaintexts are only
+/// random if at least one blinder is honest, so the `T` recipients that will
+/// decrypt the blinded quotients must be among the blinders; any other recipient
+/// may blind as well. A blinding exponent of zero, or exponents that cancel out,
+/// would turn an unequal pair into an equal one, so a blinding factor or a
+/// blinded quotient whose `u` component is the identity when that of the
+/// quotient is not is rejected.
+///
+/// The test reveals nothing beyond the equality of the plaintexts unless all
+/// blinders collude. As the blinders include the decrypting recipients, this
+/// requires `T` colluding recipients, who could decrypt the quotients directly.
+///
+/// # Parameters
+///
+/// - `quotients`: the [quotients][quotient] of the pairs of ciphertexts to compare
+/// - `blinding_factors`: the blinding factors of each participating recipient
+/// - `decrypting`: the positions of the `T` recipients that will decrypt the blinded quotients
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `HashToScalarError` if any challenge generation returns error
+/// - `PetError` if there are no blinding factors, if a recipient supplied
+///   a wrong number of blinding factors, mixed sources, or more than one set of
+///   blinding factors, if any blinding proof fails to verify, if a blinding
+///   factor or blinded quotient is the identity, or if some decrypting recipient
+///   did not blind.
+///
+/// Returns the blinded quotients, to be jointly decrypted with
+/// [`Recipient::decryption_factor`].
+pub fn blind<C: Context, const T: usize, const P: usize, const W: usize>(
+    quotients: &[DkgCiphertext<C, W, T>],
+    blinding_factors: &[Vec<BlindingFactor<C, P, W>>],
+    decrypting: &[ParticipantPosition<P>; T],
+    proof_context: &[u8],
+) -> Result<Vec<DkgCiphertext<C, W, T>>, Error> {
+    if blinding_factors.is_empty() {
+        return Err(Error::PetError("No blinding factors".into()));
+    }
+
+    let mut seen = [false; P];
+    let mut blinded: Vec<[[C::Element; W]; 2]> =
+        vec![<[[C::Element; W]; 2]>::one(); quotients.len()];
+
+    for factors in blinding_factors {
+        let Some(source) = factors.first().map(|bf| &bf.source) else {
+            return Err(Error::PetError("Empty set of blinding factors".into()));
+        };
+        let index = source.index();
+        if source.0 == 0 || index >= P || seen[index] {
+            return Err(Error::PetError(format!(
+                "Invalid or repeated blinding factors of participant {}",
+                source.0
+            )));
+        }
+        seen[index] = true;
+
+        if factors.len() != quotients.len() || factors.iter().any(|bf| bf.source != *source) {
+            return Err(Error::PetError(format!(
+                "Malformed blinding factors of participant {}",
+                source.0
+            )));
+        }
+
+        for (i, ((bf, q), acc)) in factors
+            .iter()
+            .zip(quotients.iter())
+            .zip(blinded.iter_mut())
+            .enumerate()
+        {
+            if !bf.verify(&q.0, proof_context)? {
+                return Err(Error::PetError(format!(
+                    "Failed to verify blinding proof of participant {} for quotient {i}",
+                    source.0
+                )));
+            }
+            if vanishes::<C, W>(q.0.u(), bf.value.u()) {
+                return Err(Error::PetError(format!(
+                    "Identity blinding factor of participant {} for quotient {i}",
+                    source.0
+                )));
+            }
+            *acc = acc.mul(&bf.value.0);
+        }
+    }
+
+    for position in decrypting {
+        let index = position.index();
+        if position.0 == 0 || index >= P || !seen[index] {
+            return Err(Error::PetError(format!(
+                "Missing blinding factors of decrypting participant {}",
+                position.0
+            )));
+        }
+    }
+
+    let blinded: Vec<DkgCiphertext<C, W, T>> = blinded
+        .into_iter()
+        .map(|b| DkgCiphertext(Ciphertext(b)))
+        .collect();
+    for (i, (b, q)) in blinded.iter().zip(quotients.iter()).enumerate() {
+        if vanishes::<C, W>(q.0.u(), b.0.u()) {
+            return Err(Error::PetError(format!(
+                "Blinding exponents cancel out for quotient {i}"
+            )));
+        }
+    }
+
+    Ok(blinded)
+}
+
+/// Returns `true` if some component of the blinded `u` is the identity while
+/// that of the quotient is not, that is, if the blinding exponent is zero.
+fn vanishes<C: Context, const W: usize>(
+    quotient: &[C::Element; W],
+    blinded: &[C::Element; W],
+) -> bool {
+    let one = C::Element::one();
+
+    quotient
+        .iter()
+        .zip(blinded.iter())
+        .any(|(q, b)| !q.equals(&one) && b.equals(&one))
+}
+
+/// Combine the decryption factors of the blinded quotients, and decide
+/// whether the compared plaintexts are equal.
+///
+/// # Parameters
+///
+/// - `blinded`: the blinded quotients, see [`blind`]
+/// - `dfactors`: the decryption factors of the blinded quotients for the `T` participants
+/// - `verification_keys`: the verification keys for the `T` participants
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// This function includes verification of partial decryptions correctness,
+/// see [`combine`].
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for decryption proof verification returns error
+/// - `DecryptProofFailed` if any of the decryption proofs fail to verify.
+///
+/// Returns, for each blinded quotient, `true` if the compared plaintexts are equal.
+pub fn equal<C: Context, const T: usize, const P: usize, const W: usize>(
+    blinded: &[DkgCiphertext<C, W, T>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>; T],
+    verification_keys: &[C::Element; T],
+    proof_context: &[u8],
+) -> Result<Vec<bool>, Error> {
+    let plaintexts = combine(blinded, dfactors, verification_keys, proof_context)?;
+    let one = <[C::Element; W]>::one();
+
+    Ok(plaintexts.iter().map(|p| p.equals(&one)).collect())
+}
+
+/// Decide whether pairs of ciphertexts encrypted with the joint public key
+/// hold the same plaintexts, verifying every step of the threshold plaintext
+/// equivalence test (PET).
+///
+/// Given ciphertexts `a` and `b`:
+///
+/// 1) Anyone computes the [`quotient`] `q = a / b`, which encrypts `m_a / m_b`.
+/// 2) Each participating recipient `i`, including the `T` recipients that
+///    will decrypt, publishes a [blinding factor][`Recipient::blinding_factor`]
+///    `q^z_i` for a secret random exponent `z_i`, with a proof of knowledge of `z_i`.
+/// 3) Anyone verifies the blinding factors and computes the [blinded][`blind`]
+///    quotient `q^z`, for `z = sum z_i`, which encrypts `(m_a / m_b)^z`.
+/// 4) `T` recipients [jointly decrypt][`Recipient::decryption_factor`] the
+///    blinded quotient, with proofs of correctness.
+/// 5) Anyone [combines][`equal`] the decryption factors: the plaintexts are
+///    equal if and only if the blinded plaintext is the identity. Otherwise
+///    the blinded plaintext is random, and reveals nothing else about `m_a / m_b`.
+///
+/// This function computes steps 1, 3 and 5 from the published blinding and
+/// decryption factors, so that anyone can verify the answer. The decrypting
+/// recipients are the sources of the decryption factors. See [`blind`] for
+/// the collusion bound.
+///
+/// Jakobsson, Juels: Mix and Match: Secure Function Evaluation via Ciphertexts.
+///
+/// # Examples
+///
+/// ```
+/// use std::array;
+/// use crypto::context::Context;
+/// use crypto::context::RistrettoCtx as RCtx;
+/// use crypto::groups::ristretto255::RistrettoElement;
+/// use crypto::dkgd::dealer::{VerifiableShare, Dealer};
+/// use crypto::dkgd::pet::{self, BlindingFactor};
+/// use crypto::dkgd::recipient::{Recipient, DkgPublicKey, ParticipantPosition, DecryptionFactor};
+///
+/// const P: usize = 3;
+/// const T: usize = 2;
+///
+/// let dealers: [Dealer<RCtx, T, P>; P] = array::from_fn(|_| Dealer::generate());
+/// let recipients: [(Recipient<RCtx, T, P>, DkgPublicKey<RCtx, T>); P] = array::from_fn(|i| {
+///     let position = ParticipantPosition::from_usize(i + 1);
+///     let shares = dealers.clone().map(|d| d.get_verifiable_shares().for_recipient(&position));
+///     Recipient::from_shares(position, &shares).unwrap()
+/// });
+/// let pk = &recipients[0].1;
+///
+/// let message = [RCtx::random_element()];
+/// let a = vec![pk.encrypt(&message), pk.encrypt(&message)];
+/// let b = vec![pk.encrypt(&message), pk.encrypt(&[RCtx::random_element()])];
+///
+/// // every recipient blinds the quotients
+/// let quotients: Vec<_> = a.iter().zip(b.iter()).map(|(a, b)| pet::quotient(a, b)).collect();
+/// let blinding_factors: Vec<Vec<BlindingFactor<RCtx, P, 1>>> = recipients
+///     .iter()
+///     .map(|r| r.0.blinding_factor(&quotients, &[]).unwrap())
+///     .collect();
+///
+/// // T recipients decrypt the blinded quotients
+/// let decrypting: [ParticipantPosition<P>; T] = array::from_fn(|i| ParticipantPosition::from_usize(i + 1));
+/// let blinded = pet::blind(&quotients, &blinding_factors, &decrypting, &[]).unwrap();
+/// let dfactors: [Vec<DecryptionFactor<RCtx, P, 1>>; T] =
+///     array::from_fn(|i| recipients[i].0.decryption_factor(&blinded, &[]).unwrap());
+/// let verification_keys: [RistrettoElement; T] =
+///     array::from_fn(|i| recipients[i].0.get_verification_key().clone());
+///
+/// let equal = pet::plaintext_equivalence(&a, &b, &blinding_factors, &dfactors, &verification_keys, &[]).unwrap();
+/// assert_eq!(equal, vec![true, false]);
+/// ```
+///
+/// # Parameters
+///
+/// - `a`: the first ciphertext of each pair
+/// - `b`: the second ciphertext of each pair
+/// - `blinding_factors`: the blinding factors of each participating recipient
+/// - `dfactors`: the decryption factors of the blinded quotients for the `T` participants
+/// - `verification_keys`: the verification keys for the `T` participants
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `PetError` if `a` and `b` have different lengths, if some decrypting recipient
+///   supplied no decryption factors, or the blinding factors are invalid, see [`blind`]
+/// - `DecryptProofFailed` if any of the decryption proofs fail to verify.
+/// - `HashToScalarError` or `HashToElementError` if any challenge generation returns error
+///
+/// Returns, for each pair, `true` if the plaintexts are equal.
+pub fn plaintext_equivalence<C: Context, const T: usize, const P: usize, const W: usize>(
+    a: &[DkgCiphertext<C, W, T>],
+    b: &[DkgCiphertext<C, W, T>],
+    blinding_factors: &[Vec<BlindingFactor<C, P, W>>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>; T],
+    verification_keys: &[C::Element; T],
+    proof_context: &[u8],
+) -> Result<Vec<bool>, Error> {
+    if a.len() != b.len() {
+        return Err(Error::PetError(format!(
+            "Expected pairs of ciphertexts, got {} and {}",
+            a.len(),
+            b.len()
+        )));
+    }
+
+    let mut decrypting: [ParticipantPosition<P>; T] =
+        std::array::from_fn(|_| ParticipantPosition(0));
+    for (position, dfactor) in decrypting.iter_mut().zip(dfactors.iter()) {
+        let Some(df) = dfactor.first() else {
+            return Err(Error::PetError("Missing decryption factors".into()));
+        };
+        *position = df.source.clone();
+    }
+
+    let quotients: Vec<DkgCiphertext<C, W, T>> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| quotient(a, b))
+        .collect();
+    let blinded = blind(&quotients, blinding_factors, &decrypting, proof_context)?;
+
+    equal(&blinded, dfactors, verification_keys, proof_context)
+}
+
+/// Returns the [`Relation`] `value = quotient^z` of a blinding factor, for a
+/// single exponent `z` across all `2 * W` components of the ciphertext.
+///
+/// # Errors
+///
+/// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+fn blinding_relation<C: Context, const W: usize>(
+    quotient: &Ciphertext<C, W>,
+    value: &Ciphertext<C, W>,
+) -> Result<Relation<C>, Error> {
+    let rows = quotient
+        .0
+        .iter()
+        .flatten()
+        .map(|base| vec![(0, base.clone())])
+        .collect();
+    let map = Homomorphism::new(1, rows)?;
+    let image = value.0.iter().flatten().cloned().collect();
+
+    Relation::new(map, image)
+}
+
+/// Returns the transcript of a blinding proof, bound to the position of the
+/// blinding recipient, so that blinding factors cannot be replayed by another
+/// recipient.
+fn blinding_transcript<C: Context, const P: usize>(
+    source: &ParticipantPosition<P>,
+    proof_context: &[u8],
+) -> Transcript<C> {
+    let mut transcript = Transcript::new(BLINDING_PROTOCOL, proof_context);
+    transcript.absorb(b"source", &source.0);
+
+    transcript
+}
+
+/// Protocol label of the transcript of blinding proofs
+const BLINDING_PROTOCOL: &[u8] = b"pet_blinding_proof";
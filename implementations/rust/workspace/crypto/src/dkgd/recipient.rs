@@ -13,20 +13,22 @@ use std::array;
 use crate::context::Context;
 use crate::cryptosystem::elgamal::PublicKey;
 use crate::cryptosystem::elgamal::{Ciphertext, KeyPair};
+use crate::dkgd::complaint::QualifiedSet;
 use crate::dkgd::dealer::VerifiableShare;
 use crate::traits::groups::DistGroupOps;
 use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::utils::error::Error;
 use crate::zkp::dlogeq::DlogEqProof;
+use crate::zkp::transcript::ChallengeVersion;
 use vser_derive::VSerializable;
 
 /**
  * A recipient in the Joint-Feldman distributed key generation (DKG) protocol.
  *
  * * NOTE: this API does not represent private shares as encrypted values.
- *   In the messaging layer, private shares should be encrypted with the recipient's
- *   public key.
+ *   Use the [transport][`crate::dkgd::transport`] module to encrypt private shares
+ *   with the recipient's public key.
  *
  * In the Joint-Feldman DKG, a recipient receives a secret share from each of the
  * `P` participants, including itself. The recipient must verify that these shares
@@ -152,6 +154,16 @@ impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
         &self.verification_key
     }
 
+    /// Returns a reference to this recipient's position.
+    pub fn get_position(&self) -> &ParticipantPosition<P> {
+        &self.position
+    }
+
+    /// Returns a reference to this recipient's share of the secret key.
+    pub(crate) fn get_secret(&self) -> &C::Scalar {
+        &self.sk
+    }
+
     /// Construct a `Recipient` from its shares.
     ///
     /// The supplied shares will be verified by this function, using
@@ -286,6 +298,101 @@ impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
         DkgPublicKey::from_public_key(&inner)
     }
 
+    /// Construct a `Recipient` from the shares of the qualified dealers.
+    ///
+    /// Like [`from_shares`][`Self::from_shares`], but ignores the shares of dealers
+    /// excluded from the [qualified set][`QualifiedSet`] after the
+    /// [complaint][`crate::dkgd::complaint`] phase. Excluded shares are not verified.
+    ///
+    /// Returns a tuple with the constructed `Recipient` and the protocol's joint public key
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: the position of the recipient
+    /// - `qualified`: the set of qualified dealers
+    /// - `shares`: the set of shares assigned to this participant from all `P` dealers,
+    ///   in dealer position order
+    ///
+    /// # Errors
+    ///
+    /// - `ShareVerificationFailed` if the shares of some qualified dealer do not verify
+    /// - `ComplaintError` if the qualified set is empty
+    pub fn from_qualified_shares(
+        position: ParticipantPosition<P>,
+        qualified: &QualifiedSet<P>,
+        shares: &[VerifiableShare<C, T>; P],
+    ) -> Result<(Self, DkgPublicKey<C, T>), Error> {
+        if qualified.is_empty() {
+            return Err(Error::ComplaintError("Empty qualified set".to_string()));
+        }
+        let mut verification_key = C::Element::one();
+        let mut joint_pk = C::Element::one();
+        let mut sk = C::Scalar::zero();
+
+        for (is_qualified, verifiable_share) in qualified.0.iter().zip(shares.iter()) {
+            if !is_qualified {
+                continue;
+            }
+            let (pk_factor, vk_factor, sk_summand) =
+                Self::verify_share(verifiable_share, &position)?;
+            joint_pk = joint_pk.mul(&pk_factor);
+            verification_key = verification_key.mul(&vk_factor);
+            sk = sk.add(&sk_summand);
+        }
+
+        let inner = PublicKey::new(joint_pk);
+        let joint_pk = DkgPublicKey::from_public_key(&inner);
+        let recipient = Self::new(position, verification_key, sk);
+
+        Ok((recipient, joint_pk))
+    }
+
+    /// Compute the verification key for a `Recipient` at `position`, from the qualified dealers.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: the position of the recipient
+    /// - `qualified`: the set of qualified dealers
+    /// - `all_checking_values`: an array of checking values provided by each of `P` dealers,
+    ///   in dealer position order
+    pub fn qualified_verification_key(
+        position: &ParticipantPosition<P>,
+        qualified: &QualifiedSet<P>,
+        all_checking_values: &[[C::Element; T]; P],
+    ) -> C::Element {
+        let mut verification_key = C::Element::one();
+
+        for (is_qualified, cv) in qualified.0.iter().zip(all_checking_values.iter()) {
+            if *is_qualified {
+                verification_key = verification_key.mul(&Self::vk_factor(cv, position));
+            }
+        }
+
+        verification_key
+    }
+
+    /// Compute the joint public key from the qualified dealers.
+    ///
+    /// # Parameters
+    ///
+    /// - `qualified`: the set of qualified dealers
+    /// - `all_checking_values`: an array of checking values provided by each of `P` dealers,
+    ///   in dealer position order
+    pub fn qualified_joint_public_key(
+        qualified: &QualifiedSet<P>,
+        all_checking_values: &[[C::Element; T]; P],
+    ) -> DkgPublicKey<C, T> {
+        let mut joint_public_key = C::Element::one();
+
+        for (is_qualified, cv) in qualified.0.iter().zip(all_checking_values.iter()) {
+            if *is_qualified {
+                joint_public_key = joint_public_key.mul(&cv[0]);
+            }
+        }
+        let inner = PublicKey::new(joint_public_key);
+        DkgPublicKey::from_public_key(&inner)
+    }
+
     /// Compute this recipient's partial decryptions for the given ciphertexts.
     ///
     /// At least `T` partial decryptions are needed to decrypt ciphertexts encrypted with the
@@ -385,7 +492,7 @@ impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
     /// - `position`: the position of the recipient
     ///
     /// This function is used during share [verification][`Self::verify_share`].
-    fn vk_factor(
+    pub(crate) fn vk_factor(
         checking_values: &[C::Element; T],
         position: &ParticipantPosition<P>,
     ) -> C::Element {
@@ -414,7 +521,7 @@ impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
     /// # Errors
     ///
     /// - `ShareVerificationFailed` if the shares do not verify.
-    fn verify_share(
+    pub(crate) fn verify_share(
         verifiable_share: &VerifiableShare<C, T>,
         position: &ParticipantPosition<P>,
     ) -> Result<(C::Element, C::Element, C::Scalar), Error> {
@@ -586,6 +693,13 @@ impl<const P: usize> ParticipantPosition<P> {
         #[allow(clippy::cast_possible_truncation)]
         Self::new(position as u32)
     }
+
+    /// Returns the 0-based index of this position, for indexing arrays of length `P`.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        // positions are in the range [1, P], so this never saturates
+        (self.0 as usize).saturating_sub(1)
+    }
 }
 
 /// Combine the decryption factors and apply them to the ciphertext
@@ -599,64 +713,330 @@ impl<const P: usize> ParticipantPosition<P> {
 /// - `context`: proof context label (ZKP CONTEXT)
 ///
 /// This function includes verification of partial decryptions correctness.
+/// The decryption proofs are [verified in a batch][`DlogEqProof::verify_batch`].
 ///
 /// # Errors
 ///
 /// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
-/// - `DecryptProofFailed` if any of the decryption proofs fail to verify.
+/// - `DecryptProofFailed` if any of the decryption proofs fail to verify, naming
+///   the first participant with an invalid proof.
 pub fn combine<C: Context, const T: usize, const P: usize, const W: usize>(
     ciphertexts: &[DkgCiphertext<C, W, T>],
     dfactors: &[Vec<DecryptionFactor<C, P, W>>; T],
     verification_keys: &[C::Element; T],
     proof_context: &[u8],
+) -> Result<Vec<[C::Element; W]>, Error> {
+    combine_with_version(
+        ChallengeVersion::CURRENT,
+        ciphertexts,
+        dfactors,
+        verification_keys,
+        proof_context,
+    )
+}
+
+/// Combine the decryption factors and apply them to the ciphertext
+/// to yield the plaintext, verifying decryption proofs computed with the
+/// given challenge version.
+///
+/// See [`combine`].
+///
+/// # Parameters
+///
+/// - `version`: the challenge version the decryption proofs were computed with
+/// - `ciphertexts`: the ciphertexts to decrypt, marked with matching `T` parameters
+/// - `dfactors`: the decryption factors (partial decryptions) for the `T` participants
+/// - `verification_keys`: the verification keys for the `T` participants
+/// - `context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
+/// - `DecryptProofFailed` if any of the decryption proofs fail to verify, naming
+///   the first participant with an invalid proof.
+pub fn combine_with_version<C: Context, const T: usize, const P: usize, const W: usize>(
+    version: ChallengeVersion,
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>; T],
+    verification_keys: &[C::Element; T],
+    proof_context: &[u8],
 ) -> Result<Vec<[C::Element; W]>, Error> {
     // get the participants
+    let participants: Vec<(&Vec<DecryptionFactor<C, P, W>>, &C::Element)> =
+        dfactors.iter().zip(verification_keys.iter()).collect();
+    verify_decryption_factors(version, ciphertexts, &participants, proof_context)?;
+
+    Ok(interpolate(ciphertexts, dfactors))
+}
+
+/// Combine the decryption factors of `T` participants, without verifying their
+/// decryption proofs, and apply them to the ciphertexts to yield the plaintexts.
+///
+/// See [`combine`].
+pub(crate) fn interpolate<C: Context, const T: usize, const P: usize, const W: usize>(
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>; T],
+) -> Vec<[C::Element; W]> {
     let present: [ParticipantPosition<P>; T] = array::from_fn(|i| dfactors[i][0].source.clone());
     let mut divisors_acc: Vec<[C::Element; W]> = vec![<[C::Element; W]>::one(); ciphertexts.len()];
 
-    for (i, dfactor) in dfactors.iter().enumerate() {
-        let iter = dfactor.iter().zip(ciphertexts.iter());
+    for dfactor in dfactors {
         let lagrange = lagrange::<C, T, P>(&dfactor[0].source, &present);
 
-        let c_lambda = iter.map(|(df, c)| {
-            let g = C::generator();
-            let proof_ok =
-                df.proof
-                    .verify(&g, &verification_keys[i], c.u(), &df.value, proof_context)?;
-
-            if proof_ok {
-                Ok(df.value.dist_exp(&lagrange))
-            } else {
-                Err(Error::DecryptProofFailed(
-                    "Failed to verify decryption proof".into(),
-                ))
+        divisors_acc = divisors_acc
+            .iter()
+            .zip(dfactor.iter())
+            .map(|(divisor, df)| df.value.dist_exp(&lagrange).mul(divisor))
+            .collect();
+    }
+
+    divisors_acc
+        .iter()
+        .zip(ciphertexts.iter())
+        .map(|(d, c)| c.v().mul(&d.inv()))
+        .collect()
+}
+
+/// Combine the decryption factors of any number of participants, excluding
+/// those whose decryption factors are invalid, to yield the plaintexts.
+///
+/// Unlike [`combine`], which requires exactly `T` valid inputs, this function
+/// accepts decryption factors from any subset of the `P` participants, keyed by
+/// their [source][`ParticipantPosition`]. Sources are not authenticated, so more
+/// than one set of decryption factors may claim the same participant; every set
+/// is checked, and the participant is accepted if any of its sets has the right
+/// number of decryption factors, a single source and valid decryption proofs.
+/// Participants none of whose sets are valid are reported as faulty. Decryption
+/// factors whose source is not in the range [1, P] cannot be attributed and are
+/// ignored. The `T` valid participants at the lowest positions are then used for
+/// Lagrange interpolation.
+///
+/// Returns a tuple with the plaintexts and the positions of the faulty
+/// participants, in ascending order.
+///
+/// # Parameters
+///
+/// - `ciphertexts`: the ciphertexts to decrypt, marked with matching `T` parameters
+/// - `dfactors`: the decryption factors (partial decryptions) of each responding participant
+/// - `verification_keys`: the verification keys of all `P` participants, in position order
+/// - `context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
+/// - `DecryptProofFailed` if fewer than `T` participants supplied valid decryption factors
+pub fn combine_robust<C: Context, const T: usize, const P: usize, const W: usize>(
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>],
+    verification_keys: &[C::Element; P],
+    proof_context: &[u8],
+) -> Result<(Vec<[C::Element; W]>, Vec<ParticipantPosition<P>>), Error> {
+    combine_robust_with_version(
+        ChallengeVersion::CURRENT,
+        ciphertexts,
+        dfactors,
+        verification_keys,
+        proof_context,
+    )
+}
+
+/// Combine the decryption factors of any number of participants, excluding
+/// those whose decryption factors are invalid, verifying decryption proofs
+/// computed with the given challenge version.
+///
+/// See [`combine_robust`].
+///
+/// # Parameters
+///
+/// - `version`: the challenge version the decryption proofs were computed with
+/// - `ciphertexts`: the ciphertexts to decrypt, marked with matching `T` parameters
+/// - `dfactors`: the decryption factors (partial decryptions) of each responding participant
+/// - `verification_keys`: the verification keys of all `P` participants, in position order
+/// - `context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
+/// - `DecryptProofFailed` if fewer than `T` participants supplied valid decryption factors
+pub fn combine_robust_with_version<C: Context, const T: usize, const P: usize, const W: usize>(
+    version: ChallengeVersion,
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    dfactors: &[Vec<DecryptionFactor<C, P, W>>],
+    verification_keys: &[C::Element; P],
+    proof_context: &[u8],
+) -> Result<(Vec<[C::Element; W]>, Vec<ParticipantPosition<P>>), Error> {
+    if ciphertexts.is_empty() {
+        return Ok((vec![], vec![]));
+    }
+
+    // the sources of decryption factors are not authenticated, so every set
+    // claiming a position is a candidate until its proofs are checked
+    let mut responded = [false; P];
+    let mut candidates: [Vec<&Vec<DecryptionFactor<C, P, W>>>; P] = array::from_fn(|_| vec![]);
+
+    for dfactor in dfactors {
+        // decryption factors without a source cannot be attributed
+        let Some(source) = dfactor.first().map(|df| &df.source) else {
+            continue;
+        };
+        let index = source.index();
+        if source.0 == 0 || index >= P {
+            continue;
+        }
+        responded[index] = true;
+
+        let ok =
+            dfactor.len() == ciphertexts.len() && dfactor.iter().all(|df| df.source == *source);
+        if ok {
+            candidates[index].push(dfactor);
+        }
+    }
+
+    // verify the first candidate of each participant in a batch, falling back to
+    // verifying each candidate until one is valid
+    let mut valid: [Option<&Vec<DecryptionFactor<C, P, W>>>; P] =
+        candidates.each_ref().map(|c| c.first().copied());
+    let participants: Vec<(&Vec<DecryptionFactor<C, P, W>>, &C::Element)> = valid
+        .iter()
+        .zip(verification_keys.iter())
+        .filter_map(|(dfactor, vk)| dfactor.map(|df| (df, vk)))
+        .collect();
+    if !batch_verify_decryption_factors(version, ciphertexts, &participants, proof_context)? {
+        for ((valid, candidates), vk) in valid
+            .iter_mut()
+            .zip(candidates.iter())
+            .zip(verification_keys.iter())
+        {
+            *valid = None;
+            for dfactor in candidates {
+                let participant = [(*dfactor, vk)];
+                if batch_verify_decryption_factors(
+                    version,
+                    ciphertexts,
+                    &participant,
+                    proof_context,
+                )? {
+                    // decryption factors are deterministic, so valid candidates are equal
+                    *valid = Some(dfactor);
+                    break;
+                }
             }
-        });
+        }
+    }
 
-        let next: Result<Vec<[C::Element; W]>, Error> = divisors_acc
-            .iter()
-            .zip(c_lambda)
-            .map(|(divisor, c_lambda)| {
-                let c_lambda = c_lambda?;
+    let faulty: [bool; P] = array::from_fn(|i| responded[i] && valid[i].is_none());
 
-                Ok(c_lambda.mul(divisor))
-            })
-            .collect();
+    let faulty: Vec<ParticipantPosition<P>> = (1..=P)
+        .zip(faulty.iter())
+        .filter(|(_, f)| **f)
+        .map(|(p, _)| ParticipantPosition::from_usize(p))
+        .collect();
+
+    let selected: Vec<&Vec<DecryptionFactor<C, P, W>>> = valid.iter().flatten().copied().collect();
+    let Ok(selected): Result<[&Vec<DecryptionFactor<C, P, W>>; T], _> =
+        selected.get(0..T).unwrap_or_default().try_into()
+    else {
+        return Err(Error::DecryptProofFailed(format!(
+            "Fewer than {T} valid decryption factors, faulty participants: {:?}",
+            faulty.iter().map(|p| p.0).collect::<Vec<u32>>()
+        )));
+    };
+
+    let present: [ParticipantPosition<P>; T] = selected.map(|df| df[0].source.clone());
+    let mut divisors: Vec<[C::Element; W]> = vec![<[C::Element; W]>::one(); ciphertexts.len()];
 
-        divisors_acc = next?;
+    for (dfactor, position) in selected.iter().zip(present.iter()) {
+        let lambda = lagrange::<C, T, P>(position, &present);
+        for (divisor, df) in divisors.iter_mut().zip(dfactor.iter()) {
+            *divisor = divisor.mul(&df.value.dist_exp(&lambda));
+        }
     }
 
-    let ret: Result<Vec<[C::Element; W]>, Error> = divisors_acc
+    let plaintexts = divisors
         .iter()
         .zip(ciphertexts.iter())
-        .map(|(d, c)| {
-            let ret = c.v().mul(&d.inv());
+        .map(|(d, c)| c.v().mul(&d.inv()))
+        .collect();
+
+    Ok((plaintexts, faulty))
+}
+
+/// Verify the decryption proofs of the given participants.
+///
+/// The proofs are first [verified in a batch][`batch_verify_decryption_factors`].
+/// If the batch fails, they are verified individually to locate the first
+/// invalid proof.
+///
+/// # Parameters
+///
+/// - `version`: the challenge version the decryption proofs were computed with
+/// - `ciphertexts`: the ciphertexts to decrypt
+/// - `participants`: pairs of the decryption factors of a participant and its verification key
+/// - `context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
+/// - `DecryptProofFailed` if any of the decryption proofs fail to verify.
+#[allow(clippy::type_complexity)]
+fn verify_decryption_factors<C: Context, const T: usize, const P: usize, const W: usize>(
+    version: ChallengeVersion,
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    participants: &[(&Vec<DecryptionFactor<C, P, W>>, &C::Element)],
+    proof_context: &[u8],
+) -> Result<(), Error> {
+    if batch_verify_decryption_factors(version, ciphertexts, participants, proof_context)? {
+        return Ok(());
+    }
+
+    let g = C::generator();
+    for (dfactor, vk) in participants {
+        for (i, (df, c)) in dfactor.iter().zip(ciphertexts.iter()).enumerate() {
+            let ok =
+                df.proof
+                    .verify_with_version(version, &g, vk, c.u(), &df.value, proof_context)?;
+            if !ok {
+                return Err(Error::DecryptProofFailed(format!(
+                    "Failed to verify decryption proof of participant {} for ciphertext {i}",
+                    df.source.0
+                )));
+            }
+        }
+    }
 
-            Ok(ret)
+    // not reached: the batch holds whenever all proofs are valid
+    Err(Error::DecryptProofFailed(
+        "Failed to verify decryption proofs".into(),
+    ))
+}
+
+/// Verify the decryption proofs of the given participants in a batch.
+///
+/// See [`DlogEqProof::verify_batch_with_version`].
+///
+/// # Errors
+///
+/// - `HashToElementError` if any challenge generation for [`DlogEqProof`] verification returns error
+///
+/// Returns `true` if all proofs are valid, `false` otherwise.
+#[allow(clippy::type_complexity)]
+fn batch_verify_decryption_factors<C: Context, const T: usize, const P: usize, const W: usize>(
+    version: ChallengeVersion,
+    ciphertexts: &[DkgCiphertext<C, W, T>],
+    participants: &[(&Vec<DecryptionFactor<C, P, W>>, &C::Element)],
+    proof_context: &[u8],
+) -> Result<bool, Error> {
+    let batch: Vec<_> = participants
+        .iter()
+        .flat_map(|(dfactor, vk)| {
+            dfactor
+                .iter()
+                .zip(ciphertexts.iter())
+                .map(move |(df, c)| (&df.proof, *vk, c.u(), &df.value))
         })
         .collect();
 
-    ret
+    DlogEqProof::verify_batch_with_version(version, &C::generator(), &batch, proof_context)
 }
 
 #[crate::warning("Rustdoc needs a reference to lagrange coeff. calculation")]
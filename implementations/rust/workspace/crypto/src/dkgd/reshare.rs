@@ -0,0 +1,269 @@
+/*
+ * Share refresh and resharing
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use std::array;
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::PublicKey;
+use crate::dkgd::dealer::{Dealer, DealerShares, Polynomial, VerifiableShare};
+use crate::dkgd::recipient::{DkgPublicKey, ParticipantPosition, Recipient, lagrange};
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+
+/**
+ * A current recipient dealing sub-shares of its secret share to a new set of
+ * recipients, in the resharing protocol.
+ *
+ * Resharing transfers the secret key of a DKG protocol execution with threshold
+ * `T` of `P` to a new set of `P2` recipients with threshold `T2`, keeping the
+ * same [joint public key][`DkgPublicKey`]. At least `T` current recipients,
+ * `present`, take part. Each of them acts as a [`Dealer`] whose polynomial of
+ * degree `T2 - 1` has its own secret share `x_i` as constant term, so that its
+ * first checking value equals its current verification key `g^x_i`. This makes
+ * the resharing publicly verifiable, see [`verify_checking_values`].
+ *
+ * A new recipient `j` receives one sub-share `s_ij` from each present recipient
+ * `i`, and computes its new secret share as `sum_i lambda_i s_ij`, where
+ * `lambda_i` are the Lagrange coefficients of `present`. See
+ * [`Recipient::from_reshares`].
+ *
+ * Proactive share refresh is the special case in which the new set of
+ * recipients is the current one; it invalidates shares leaked before the
+ * refresh.
+ *
+ * # Examples
+ *
+ * ```
+ * use std::array;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::dkgd::dealer::{Dealer, VerifiableShare};
+ * use crypto::dkgd::recipient::{DkgPublicKey, ParticipantPosition, Recipient};
+ * use crypto::dkgd::reshare::Resharer;
+ *
+ * // the current key, 2 of 3
+ * let dealers: [Dealer<RCtx, 2, 3>; 3] = array::from_fn(|_| Dealer::generate());
+ * let recipients: [(Recipient<RCtx, 2, 3>, DkgPublicKey<RCtx, 2>); 3] = array::from_fn(|i| {
+ *     let position = ParticipantPosition::from_usize(i + 1);
+ *     let shares = dealers.clone().map(|d| d.get_verifiable_shares().for_recipient(&position));
+ *     Recipient::from_shares(position, &shares).unwrap()
+ * });
+ *
+ * // recipients 1 and 3 reshare to a new set, 3 of 4
+ * let present = [&recipients[0].0, &recipients[2].0];
+ * let resharers = present.map(Resharer::<RCtx, 3, 3, 4>::new);
+ * let positions = present.map(|r| r.get_position().clone());
+ * let verification_keys = present.map(|r| r.get_verification_key().clone());
+ *
+ * let new_position = ParticipantPosition::from_usize(4);
+ * let shares: [VerifiableShare<RCtx, 3>; 2] =
+ *     resharers.each_ref().map(|r| r.get_verifiable_shares().for_recipient(&new_position));
+ * let current = &recipients[0].1;
+ * let (new_recipient, pk) = Recipient::from_reshares(
+ *     new_position, current, &positions, &verification_keys, &shares
+ * ).unwrap();
+ *
+ * assert_eq!(pk.inner.y, current.inner.y);
+ * ```
+ */
+pub struct Resharer<C: Context, const P: usize, const T2: usize, const P2: usize> {
+    /// the position of this recipient in the current set
+    position: ParticipantPosition<P>,
+    /// the dealer of sub-shares, whose polynomial's constant term is the secret share
+    dealer: Dealer<C, T2, P2>,
+}
+
+impl<C: Context, const P: usize, const T2: usize, const P2: usize> Resharer<C, P, T2, P2> {
+    /// Construct a new [`Resharer`] for the given current recipient.
+    ///
+    /// Generates a random polynomial of degree `T2 - 1` whose constant term is
+    /// the recipient's secret share.
+    #[must_use]
+    pub fn new<const T: usize>(recipient: &Recipient<C, T, P>) -> Self {
+        let mut polynomial = Polynomial::<C, T2>::generate();
+        polynomial.0[0] = recipient.get_secret().clone();
+
+        Self {
+            position: recipient.get_position().clone(),
+            dealer: Dealer::from_polynomial(polynomial),
+        }
+    }
+
+    /// Returns the position of this recipient in the current set.
+    pub fn get_position(&self) -> &ParticipantPosition<P> {
+        &self.position
+    }
+
+    /// Compute the `P2` sub-shares distributed by this resharer, and its `T2` checking values.
+    ///
+    /// The first checking value equals this recipient's current verification key.
+    pub fn get_verifiable_shares(&self) -> DealerShares<C, T2, P2> {
+        self.dealer.get_verifiable_shares()
+    }
+}
+
+/// Verify that the resharers' checking values are consistent with the current key.
+///
+/// Checks that the first checking value of each resharer equals its current
+/// verification key, and that the resharers are at distinct positions. Anyone can
+/// run this check from public data.
+///
+/// # Parameters
+///
+/// - `present`: the positions of the `T` resharers in the current set
+/// - `verification_keys`: the current verification keys of the resharers, in the same order
+/// - `all_checking_values`: the checking values of the resharers, in the same order
+///
+/// # Errors
+///
+/// - `ReshareError` if some checking value does not match, or positions are repeated
+pub fn verify_checking_values<C: Context, const T: usize, const P: usize, const T2: usize>(
+    present: &[ParticipantPosition<P>; T],
+    verification_keys: &[C::Element; T],
+    all_checking_values: &[[C::Element; T2]; T],
+) -> Result<(), Error> {
+    for (i, position) in present.iter().enumerate() {
+        if present
+            .iter()
+            .skip(i.saturating_add(1))
+            .any(|p| p == position)
+        {
+            return Err(Error::ReshareError(
+                "Repeated resharer position".to_string(),
+            ));
+        }
+    }
+    for (vk, cv) in verification_keys.iter().zip(all_checking_values.iter()) {
+        if !vk.equals(&cv[0]) {
+            return Err(Error::ReshareError(
+                "Checking values do not match verification key".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+impl<C: Context, const T: usize, const P: usize> Recipient<C, T, P> {
+    /// Construct a new `Recipient` from the sub-shares dealt by `T0` [resharers][`Resharer`].
+    ///
+    /// The supplied sub-shares are verified against the resharers' checking
+    /// values, which are in turn [verified][`verify_checking_values`] against the
+    /// resharers' current verification keys. The joint public key interpolated
+    /// from the checking values must equal the `current` key, so that the new
+    /// shares are shares of the same secret.
+    ///
+    /// Returns a tuple with the constructed `Recipient` and the joint public key,
+    /// which is unchanged by resharing.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: the position of the new recipient in the new set
+    /// - `current`: the joint public key being reshared, with threshold `T1`
+    /// - `present`: the positions of the `T0` resharers in the current set
+    /// - `verification_keys`: the current verification keys of the resharers, in the same order
+    /// - `shares`: the sub-shares for this recipient from the resharers, in the same order
+    ///
+    /// # Errors
+    ///
+    /// - `ReshareError` if there are fewer than `T1` resharers, if the checking values do not
+    ///   match the verification keys, or if they do not interpolate to the `current` key
+    /// - `ShareVerificationFailed` if some sub-share does not verify
+    pub fn from_reshares<const T0: usize, const P0: usize, const T1: usize>(
+        position: ParticipantPosition<P>,
+        current: &DkgPublicKey<C, T1>,
+        present: &[ParticipantPosition<P0>; T0],
+        verification_keys: &[C::Element; T0],
+        shares: &[VerifiableShare<C, T>; T0],
+    ) -> Result<(Self, DkgPublicKey<C, T>), Error> {
+        if T0 < T1 {
+            return Err(Error::ReshareError(format!(
+                "Resharing requires at least {T1} resharers, got {T0}"
+            )));
+        }
+        let all_checking_values = shares.each_ref().map(|s| s.checking_values.clone());
+        verify_checking_values::<C, T0, P0, T>(present, verification_keys, &all_checking_values)?;
+
+        let mut verification_key = C::Element::one();
+        let mut joint_pk = C::Element::one();
+        let mut sk = C::Scalar::zero();
+
+        for (resharer, share) in present.iter().zip(shares.iter()) {
+            let lambda = lagrange::<C, T0, P0>(resharer, present);
+            let (pk_factor, vk_factor, sk_summand) = Self::verify_share(share, &position)?;
+            joint_pk = joint_pk.mul(&pk_factor.exp(&lambda));
+            verification_key = verification_key.mul(&vk_factor.exp(&lambda));
+            sk = sk.add(&sk_summand.mul(&lambda));
+        }
+
+        if !joint_pk.equals(&current.inner.y) {
+            return Err(Error::ReshareError(
+                "Reshared joint public key does not match the current key".to_string(),
+            ));
+        }
+
+        let inner = PublicKey::new(joint_pk);
+        let joint_pk = DkgPublicKey::from_public_key(&inner);
+        let recipient = Self::new(position, verification_key, sk);
+
+        Ok((recipient, joint_pk))
+    }
+
+    /// Compute the verification key of a new `Recipient` at `position` after resharing.
+    ///
+    /// Allows computing new verification keys from public data. The positions in
+    /// `present` must be distinct, see [`verify_checking_values`].
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: the position of the new recipient in the new set
+    /// - `present`: the positions of the `T0` resharers in the current set
+    /// - `all_checking_values`: the checking values of the resharers, in the same order
+    pub fn reshared_verification_key<const T0: usize, const P0: usize>(
+        position: &ParticipantPosition<P>,
+        present: &[ParticipantPosition<P0>; T0],
+        all_checking_values: &[[C::Element; T]; T0],
+    ) -> C::Element {
+        let lambdas: [C::Scalar; T0] =
+            array::from_fn(|i| lagrange::<C, T0, P0>(&present[i], present));
+
+        lambdas
+            .iter()
+            .zip(all_checking_values.iter())
+            .fold(C::Element::one(), |acc, (lambda, cv)| {
+                acc.mul(&Self::vk_factor(cv, position).exp(lambda))
+            })
+    }
+
+    /// Compute the joint public key after resharing.
+    ///
+    /// The result equals the joint public key before resharing if the
+    /// checking values [verify][`verify_checking_values`]. The positions in
+    /// `present` must be distinct.
+    ///
+    /// # Parameters
+    ///
+    /// - `present`: the positions of the `T0` resharers in the current set
+    /// - `all_checking_values`: the checking values of the resharers, in the same order
+    pub fn reshared_joint_public_key<const T0: usize, const P0: usize>(
+        present: &[ParticipantPosition<P0>; T0],
+        all_checking_values: &[[C::Element; T]; T0],
+    ) -> DkgPublicKey<C, T> {
+        let joint_public_key = present.iter().zip(all_checking_values.iter()).fold(
+            C::Element::one(),
+            |acc, (resharer, cv)| {
+                let lambda = lagrange::<C, T0, P0>(resharer, present);
+                acc.mul(&cv[0].exp(&lambda))
+            },
+        );
+        let inner = PublicKey::new(joint_public_key);
+
+        DkgPublicKey::from_public_key(&inner)
+    }
+}
@@ -7,18 +7,37 @@
  * @version 0.1
  */
 
-use crate::cryptosystem::elgamal::Ciphertext;
+use crate::cryptosystem::elgamal::{Ciphertext, KeyPair, PublicKey};
+use crate::dkgd::complaint::{self, Complaint, QualifiedSet};
 use crate::dkgd::dealer::{Dealer, VerifiableShare};
-use crate::dkgd::recipient::combine;
-use crate::dkgd::recipient::{DecryptionFactor, DkgPublicKey, ParticipantPosition, Recipient};
+use crate::dkgd::dynamic::{
+    self, DkgParameters, DynDealer, DynDealerShares, DynDecryptionFactor, DynParticipantPosition,
+    DynRecipient, DynVerifiableShare,
+};
+use crate::dkgd::pedersen::{
+    self, ExtractionComplaint, PedersenComplaint, PedersenDealer, PedersenShare, PedersenShares,
+};
+use crate::dkgd::pet::{self, BlindingFactor};
+use crate::dkgd::recipient::{
+    DecryptionFactor, DkgCiphertext, DkgPublicKey, ParticipantPosition, Recipient,
+};
+use crate::dkgd::recipient::{combine, combine_robust};
+use crate::dkgd::reshare::{self, Resharer};
+use crate::dkgd::transport::{self, PairwiseShareMessage, PublicCheckValueMessage, VerifyingKey};
 use crate::traits::groups::DistGroupOps;
 use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use crate::utils::rng::SeededRng;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use crate::utils::signatures::SignatureScheme;
 use std::array;
 
 use crate::context::Context;
+use crate::context::EgCtx as ECtx;
 use crate::context::RistrettoCtx as RCtx;
 use crate::context::RistrettoCtx as PCtx;
+use crate::context::SeededCtx;
 use rand::seq::SliceRandom;
 
 #[test]
@@ -51,6 +70,11 @@ fn test_dkgd_p256() {
     test_dkgd_non_t::<PCtx, 1, 1, 2>();
 }
 
+#[test]
+fn test_dkgd_electionguard() {
+    test_dkgd::<ECtx, 2, 3, 2>();
+}
+
 #[test]
 fn test_dkgd_non_t_ristretto() {
     test_dkgd_non_t::<RCtx, 2, 2, 2>();
@@ -69,6 +93,148 @@ fn test_dkgd_non_t_p256() {
     test_dkgd_non_t::<PCtx, 1, 1, 2>();
 }
 
+#[test]
+fn test_transport_ristretto() {
+    test_transport::<RCtx, 2, 3>();
+    test_transport::<RCtx, 3, 4>();
+}
+
+#[test]
+fn test_transport_p256() {
+    test_transport::<PCtx, 2, 3>();
+    test_transport::<PCtx, 3, 4>();
+}
+
+#[test]
+fn test_transport_invalid_ristretto() {
+    test_transport_invalid::<RCtx, 2, 3>();
+}
+
+#[test]
+fn test_transport_invalid_p256() {
+    test_transport_invalid::<PCtx, 2, 3>();
+}
+
+#[test]
+fn test_complaints_ristretto() {
+    test_complaints::<RCtx, 2, 4>();
+    test_complaints::<RCtx, 3, 4>();
+}
+
+#[test]
+fn test_complaints_p256() {
+    test_complaints::<PCtx, 2, 4>();
+    test_complaints::<PCtx, 3, 4>();
+}
+
+#[test]
+fn test_complaints_invalid_ristretto() {
+    test_complaints_invalid::<RCtx, 2, 3>();
+}
+
+#[test]
+fn test_complaints_invalid_p256() {
+    test_complaints_invalid::<PCtx, 2, 3>();
+}
+
+#[test]
+fn test_pedersen_dkg_ristretto() {
+    test_pedersen_dkg::<RCtx, 2, 3>();
+    test_pedersen_dkg::<RCtx, 3, 5>();
+}
+
+#[test]
+fn test_pedersen_dkg_p256() {
+    test_pedersen_dkg::<PCtx, 2, 3>();
+    test_pedersen_dkg::<PCtx, 3, 5>();
+}
+
+#[test]
+fn test_pedersen_complaints_ristretto() {
+    test_pedersen_complaints::<RCtx, 2, 4>();
+}
+
+#[test]
+fn test_pedersen_complaints_p256() {
+    test_pedersen_complaints::<PCtx, 2, 4>();
+}
+
+#[test]
+fn test_reshare_ristretto() {
+    test_reshare::<RCtx, 3, 5, 4, 7>();
+    test_reshare::<RCtx, 2, 3, 2, 3>();
+    test_reshare::<RCtx, 3, 4, 2, 2>();
+}
+
+#[test]
+fn test_reshare_p256() {
+    test_reshare::<PCtx, 3, 5, 4, 7>();
+    test_reshare::<PCtx, 2, 3, 2, 3>();
+    test_reshare::<PCtx, 3, 4, 2, 2>();
+}
+
+#[test]
+fn test_combine_robust_ristretto() {
+    test_combine_robust::<RCtx, 3, 6, 2>();
+    test_combine_robust::<RCtx, 2, 5, 1>();
+}
+
+#[test]
+fn test_combine_robust_p256() {
+    test_combine_robust::<PCtx, 3, 6, 2>();
+    test_combine_robust::<PCtx, 2, 5, 1>();
+}
+
+#[test]
+fn test_dynamic_ristretto() {
+    test_dynamic::<RCtx>(2, 3);
+    test_dynamic::<RCtx>(3, 5);
+    test_dynamic::<RCtx>(1, 1);
+    test_dynamic::<RCtx>(4, 7);
+}
+
+#[test]
+fn test_dynamic_p256() {
+    test_dynamic::<PCtx>(2, 3);
+    test_dynamic::<PCtx>(3, 5);
+    test_dynamic::<PCtx>(1, 1);
+}
+
+#[test]
+fn test_dynamic_compatible_ristretto() {
+    test_dynamic_compatible::<RCtx, 2, 3, 2>();
+    test_dynamic_compatible::<RCtx, 3, 5, 1>();
+}
+
+#[test]
+fn test_dynamic_compatible_p256() {
+    test_dynamic_compatible::<PCtx, 2, 3, 2>();
+    test_dynamic_compatible::<PCtx, 3, 5, 1>();
+}
+
+#[test]
+fn test_dynamic_invalid_ristretto() {
+    test_dynamic_invalid::<RCtx>();
+}
+
+#[test]
+fn test_dynamic_invalid_p256() {
+    test_dynamic_invalid::<PCtx>();
+}
+
+#[test]
+fn test_pet_ristretto() {
+    test_pet::<RCtx, 2, 3, 2>();
+    test_pet::<RCtx, 3, 4, 1>();
+}
+
+#[test]
+fn test_pet_p256() {
+    test_pet::<PCtx, 2, 3, 2>();
+    test_pet::<PCtx, 3, 4, 1>();
+}
+
+#[allow(clippy::arithmetic_side_effects)]
 fn test_dkgd<C: Context, const T: usize, const P: usize, const W: usize>() {
     assert!(T <= P);
 
@@ -109,15 +275,38 @@ fn test_dkgd<C: Context, const T: usize, const P: usize, const W: usize>() {
         array::from_fn(|i| recipients[i].0.get_verification_key().clone());
 
     let dfactors: [Vec<DecryptionFactor<C, P, W>>; P] =
-        recipients.map(|r| r.0.decryption_factor(&encrypted, &vec![]).unwrap());
+        recipients.map(|r| r.0.decryption_factor(&encrypted, &[]).unwrap());
 
     let threshold: &[Vec<DecryptionFactor<C, P, W>>; T] = dfactors[0..T]
         .try_into()
         .expect("slice matches array: T == T");
-    let decrypted = combine(&encrypted, &threshold, &verification_keys, &vec![]);
-    assert!(message == decrypted.unwrap()[0]);
+    let decrypted = combine(&encrypted, threshold, &verification_keys, &[]);
+    assert_eq!(message, decrypted.unwrap()[0]);
+}
+
+#[test]
+fn test_dkgd_seeded_ristretto() {
+    test_dkgd_seeded::<RCtx, 2, 3>();
+}
+
+/// Returns the serialized shares of `P` dealers, generated with a seeded context.
+fn seeded_dealer_shares<C: Context, const T: usize, const P: usize>(seed: [u8; 32]) -> Vec<u8> {
+    SeededRng::seed(seed);
+    let dealers: [Dealer<SeededCtx<C>, T, P>; P] = array::from_fn(|_| Dealer::generate());
+
+    dealers.map(|d| d.get_verifiable_shares()).ser()
 }
 
+fn test_dkgd_seeded<C: Context, const T: usize, const P: usize>() {
+    let lhs = seeded_dealer_shares::<C, T, P>([1u8; 32]);
+    let rhs = seeded_dealer_shares::<C, T, P>([1u8; 32]);
+    assert_eq!(lhs, rhs);
+
+    let other = seeded_dealer_shares::<C, T, P>([2u8; 32]);
+    assert_ne!(lhs, other);
+}
+
+#[allow(clippy::arithmetic_side_effects)]
 fn test_dkgd_non_t<C: Context, const T: usize, const P: usize, const W: usize>() {
     assert!(T <= P);
 
@@ -139,7 +328,7 @@ fn test_dkgd_non_t<C: Context, const T: usize, const P: usize, const W: usize>()
     let encrypted = vec![pk.encrypt(&message)];
 
     let mut dfactors: [Vec<DecryptionFactor<C, P, W>>; P] =
-        recipients.map(|r| r.0.decryption_factor(&encrypted, &vec![]).unwrap());
+        recipients.map(|r| r.0.decryption_factor(&encrypted, &[]).unwrap());
     let mut rng = C::get_rng();
     dfactors.shuffle(&mut rng);
 
@@ -148,9 +337,10 @@ fn test_dkgd_non_t<C: Context, const T: usize, const P: usize, const W: usize>()
 
     let encrypted: Vec<Ciphertext<C, W>> = encrypted.iter().map(|e| e.0.clone()).collect();
     let decrypted = untyped_combine(&encrypted, &dfactors);
-    assert!(message == decrypted[0]);
+    assert_eq!(message, decrypted[0]);
 }
 
+#[allow(clippy::arithmetic_side_effects)]
 fn test_joint_pkey<C: Context, const T: usize, const P: usize, const W: usize>() {
     assert!(T <= P);
 
@@ -176,6 +366,1312 @@ fn test_joint_pkey<C: Context, const T: usize, const P: usize, const W: usize>()
     assert_eq!(lhs, rhs);
 }
 
+#[allow(clippy::arithmetic_side_effects)]
+fn test_transport<C: Context, const T: usize, const P: usize>() {
+    let election_hash = b"election hash";
+    let signing_keys: [_; P] = array::from_fn(|_| C::gen_signing_key());
+    let trustee_keys: [VerifyingKey<C>; P] =
+        array::from_fn(|i| C::SignatureScheme::verifying_key(&signing_keys[i]));
+    let encryption_keys: [KeyPair<C>; P] = array::from_fn(|_| KeyPair::generate());
+    let recipient_keys: [C::Element; P] = array::from_fn(|i| encryption_keys[i].pkey.y.clone());
+
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let messages: [(PublicCheckValueMessage<C, T>, [PairwiseShareMessage<C>; P]); P] =
+        array::from_fn(|i| {
+            transport::post_shares(
+                &dealers[i],
+                &recipient_keys,
+                election_hash,
+                &signing_keys[i],
+            )
+            .unwrap()
+        });
+
+    // messages are transmitted in serialized form
+    let messages: [(PublicCheckValueMessage<C, T>, [PairwiseShareMessage<C>; P]); P] = messages
+        .map(|(cv, shares)| {
+            let cv = PublicCheckValueMessage::deser(&cv.ser()).unwrap();
+            let shares = shares.map(|s| PairwiseShareMessage::deser(&s.ser()).unwrap());
+            (cv, shares)
+        });
+    let check_values = messages.each_ref().map(|(cv, _)| cv);
+
+    let recipients: [(Recipient<C, T, P>, DkgPublicKey<C, T>); P] = array::from_fn(|i| {
+        let pairwise_shares = messages.each_ref().map(|(_, shares)| &shares[i]);
+        let shares = transport::receive_shares(
+            election_hash,
+            &encryption_keys[i].skey,
+            &trustee_keys,
+            &check_values,
+            &pairwise_shares,
+        )
+        .unwrap();
+
+        Recipient::from_shares(ParticipantPosition::from_usize(i + 1), &shares).unwrap()
+    });
+
+    let all_checking_values = check_values.map(|cv| cv.check_values.clone());
+    let joint_pk = Recipient::<C, T, P>::joint_public_key(&all_checking_values);
+    for (_, pk) in &recipients {
+        assert_eq!(pk.inner.y, joint_pk.inner.y);
+    }
+}
+
+fn test_transport_invalid<C: Context, const T: usize, const P: usize>() {
+    let election_hash = b"election hash";
+    let signer = C::gen_signing_key();
+    let trustee_key = C::SignatureScheme::verifying_key(&signer);
+    let recipient = KeyPair::<C>::generate();
+    let position = ParticipantPosition::<P>::from_usize(1);
+
+    let dealer: Dealer<C, T, P> = Dealer::generate();
+    let shares = dealer.get_verifiable_shares();
+    let cv = PublicCheckValueMessage::sign(&shares, election_hash, &signer).unwrap();
+    let share = PairwiseShareMessage::seal(
+        &shares,
+        &position,
+        &recipient.pkey.y,
+        election_hash,
+        &signer,
+    )
+    .unwrap();
+
+    assert!(cv.verify(election_hash, &trustee_key).is_ok());
+    let value = share
+        .open(election_hash, &recipient.skey, &trustee_key)
+        .unwrap();
+    assert_eq!(value, shares.for_recipient(&position).value);
+
+    // wrong election
+    let result = cv.verify(b"other", &trustee_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+    let result = share.open(b"other", &recipient.skey, &trustee_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+
+    // wrong trustee
+    let other_key = C::SignatureScheme::verifying_key(&C::gen_signing_key());
+    let result = cv.verify(election_hash, &other_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+    let result = share.open(election_hash, &recipient.skey, &other_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+
+    // wrong recipient
+    let other = KeyPair::<C>::generate();
+    let result = share.open(election_hash, &other.skey, &trustee_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+
+    // tampered check values
+    let mut tampered = PublicCheckValueMessage::<C, T>::deser(&cv.ser()).unwrap();
+    tampered.check_values[0] = C::random_element();
+    let result = tampered.verify(election_hash, &trustee_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+
+    // tampered share
+    let mut tampered = PairwiseShareMessage::<C>::deser(&share.ser()).unwrap();
+    tampered.pairwise_share.ct.0[0] ^= 1;
+    let result = tampered.open(election_hash, &recipient.skey, &trustee_key);
+    assert!(matches!(result, Err(Error::ShareTransportError(_))));
+
+    // a validly signed share that does not match the check values
+    let other_dealer: Dealer<C, T, P> = Dealer::generate();
+    let other_shares = other_dealer.get_verifiable_shares();
+    let share = PairwiseShareMessage::seal(
+        &other_shares,
+        &position,
+        &recipient.pkey.y,
+        election_hash,
+        &signer,
+    )
+    .unwrap();
+    let value = share
+        .open(election_hash, &recipient.skey, &trustee_key)
+        .unwrap();
+    let verifiable_shares: [VerifiableShare<C, T>; P] =
+        array::from_fn(|_| VerifiableShare::new(value.clone(), cv.check_values.clone()));
+    let result = Recipient::<C, T, P>::verify_shares(&position, &verifiable_shares);
+    assert!(matches!(result, Err(Error::ShareVerificationFailed(_))));
+}
+
+#[allow(clippy::arithmetic_side_effects, clippy::too_many_lines)]
+fn test_complaints<C: Context, const T: usize, const P: usize>() {
+    let election_hash = b"election hash";
+    let signing_keys: [_; P] = array::from_fn(|_| C::gen_signing_key());
+    let trustee_keys: [VerifyingKey<C>; P] =
+        array::from_fn(|i| C::SignatureScheme::verifying_key(&signing_keys[i]));
+    let encryption_keys: [KeyPair<C>; P] = array::from_fn(|_| KeyPair::generate());
+    let recipient_keys: [C::Element; P] = array::from_fn(|i| encryption_keys[i].pkey.y.clone());
+
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let mut messages: [(PublicCheckValueMessage<C, T>, [PairwiseShareMessage<C>; P]); P] =
+        array::from_fn(|i| {
+            transport::post_shares(
+                &dealers[i],
+                &recipient_keys,
+                election_hash,
+                &signing_keys[i],
+            )
+            .unwrap()
+        });
+
+    // the dealer at position 2 sends recipient 1 a validly signed share that
+    // does not match its checking values
+    let cheater = ParticipantPosition::<P>::from_usize(2);
+    let victim = ParticipantPosition::<P>::from_usize(1);
+    let bad_shares = Dealer::<C, T, P>::generate().get_verifiable_shares();
+    messages[cheater.index()].1[victim.index()] = PairwiseShareMessage::seal(
+        &bad_shares,
+        &victim,
+        &recipient_keys[victim.index()],
+        election_hash,
+        &signing_keys[cheater.index()],
+    )
+    .unwrap();
+
+    let check_values = messages.each_ref().map(|(cv, _)| cv);
+    let pairwise_shares = messages.each_ref().map(|(_, shares)| shares);
+
+    // each recipient checks its shares
+    let mut complaints = vec![];
+    for (i, keypair) in encryption_keys.iter().enumerate() {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        let received = pairwise_shares.map(|shares| &shares[i]);
+        let mut posted = complaint::check_shares(
+            &position,
+            election_hash,
+            &keypair.skey,
+            &trustee_keys,
+            &check_values,
+            &received,
+        )
+        .unwrap();
+        if position == victim {
+            assert_eq!(posted.len(), 1);
+            assert_eq!(posted[0].dealer, cheater);
+        } else {
+            assert!(posted.is_empty());
+        }
+        complaints.append(&mut posted);
+    }
+
+    // complaints are transmitted in serialized form
+    let complaints: Vec<Complaint<C, P>> = complaints
+        .iter()
+        .map(|c| Complaint::deser(&c.ser()).unwrap())
+        .collect();
+
+    // a false complaint against an honest dealer is rejected
+    let honest = ParticipantPosition::<P>::from_usize(3);
+    let false_complaint = Complaint::new(
+        victim.clone(),
+        honest.clone(),
+        &pairwise_shares[honest.index()][victim.index()],
+        &encryption_keys[victim.index()].skey,
+        election_hash,
+    )
+    .unwrap();
+    let upheld = false_complaint
+        .rule(
+            election_hash,
+            &trustee_keys[honest.index()],
+            &recipient_keys[victim.index()],
+            check_values[honest.index()],
+            &pairwise_shares[honest.index()][victim.index()],
+        )
+        .unwrap();
+    assert!(!upheld);
+
+    // a complaint with a forged Diffie-Hellman value is rejected
+    let mut forged = complaints[0].clone();
+    forged.dh = C::random_element();
+    let upheld = forged
+        .rule(
+            election_hash,
+            &trustee_keys[cheater.index()],
+            &recipient_keys[victim.index()],
+            check_values[cheater.index()],
+            &pairwise_shares[cheater.index()][victim.index()],
+        )
+        .unwrap();
+    assert!(!upheld);
+
+    // a complaint for a different election is rejected
+    let upheld = complaints[0]
+        .rule(
+            b"other",
+            &trustee_keys[cheater.index()],
+            &recipient_keys[victim.index()],
+            check_values[cheater.index()],
+            &pairwise_shares[cheater.index()][victim.index()],
+        )
+        .is_ok_and(|upheld| upheld);
+    assert!(!upheld);
+
+    // only someone holding the share's secret key can complain
+    let impostor = Complaint::<C, P>::new(
+        honest.clone(),
+        cheater.clone(),
+        &pairwise_shares[cheater.index()][victim.index()],
+        &encryption_keys[honest.index()].skey,
+        election_hash,
+    )
+    .unwrap();
+    let upheld = impostor
+        .rule(
+            election_hash,
+            &trustee_keys[cheater.index()],
+            &recipient_keys[honest.index()],
+            check_values[cheater.index()],
+            &pairwise_shares[cheater.index()][honest.index()],
+        )
+        .unwrap();
+    assert!(!upheld);
+
+    let mut all_complaints = complaints.clone();
+    all_complaints.push(false_complaint);
+    all_complaints.push(forged);
+    all_complaints.push(impostor);
+    let qualified = complaint::qualified_set(
+        &all_complaints,
+        election_hash,
+        &trustee_keys,
+        &recipient_keys,
+        &check_values,
+        &pairwise_shares,
+    );
+    let mut expected = QualifiedSet::<P>::new();
+    expected.disqualify(&cheater).unwrap();
+    assert_eq!(qualified, expected);
+    assert_eq!(qualified.len(), P - 1);
+    assert!(!qualified.contains(&cheater));
+    assert!(!qualified.positions().contains(&cheater));
+
+    // recipients, including the victim, compute their shares from the qualified dealers
+    let all_checking_values = check_values.map(|cv| cv.check_values.clone());
+    let joint_pk =
+        Recipient::<C, T, P>::qualified_joint_public_key(&qualified, &all_checking_values);
+    let recipients: [Recipient<C, T, P>; P] = array::from_fn(|i| {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        let shares: [VerifiableShare<C, T>; P] = array::from_fn(|d| {
+            let share = &pairwise_shares[d][i];
+            let value = if qualified.contains(&ParticipantPosition::from_usize(d + 1)) {
+                share
+                    .open(election_hash, &encryption_keys[i].skey, &trustee_keys[d])
+                    .unwrap()
+            } else {
+                C::random_scalar()
+            };
+            VerifiableShare::new(value, all_checking_values[d].clone())
+        });
+
+        // the unqualified dealer's share does not verify for the victim
+        if position == victim {
+            assert!(Recipient::from_shares(position.clone(), &shares).is_err());
+        }
+
+        let (recipient, pk) =
+            Recipient::from_qualified_shares(position.clone(), &qualified, &shares).unwrap();
+        assert_eq!(pk.inner.y, joint_pk.inner.y);
+        assert_eq!(
+            recipient.get_verification_key(),
+            &Recipient::<C, T, P>::qualified_verification_key(
+                &position,
+                &qualified,
+                &all_checking_values
+            )
+        );
+
+        recipient
+    });
+
+    // threshold decryption with the qualified joint public key
+    let message: [C::Element; 2] = array::from_fn(|_| C::random_element());
+    let ciphertexts = vec![joint_pk.encrypt(&message)];
+    let present: [&Recipient<C, T, P>; T] = array::from_fn(|i| &recipients[P - 1 - i]);
+    let dfactors = present.map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap());
+    let verification_keys = present.map(|r| r.get_verification_key().clone());
+    let decrypted = combine(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(decrypted[0], message);
+
+    let empty = QualifiedSet::<P>([false; P]);
+    assert!(empty.is_empty());
+    let shares: [VerifiableShare<C, T>; P] = array::from_fn(|d| {
+        VerifiableShare::new(C::random_scalar(), all_checking_values[d].clone())
+    });
+    let result = Recipient::from_qualified_shares(victim, &empty, &shares);
+    assert!(matches!(result, Err(Error::ComplaintError(_))));
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_complaints_invalid<C: Context, const T: usize, const P: usize>() {
+    let election_hash = b"election hash";
+    let signing_keys: [_; P] = array::from_fn(|_| C::gen_signing_key());
+    let trustee_keys: [VerifyingKey<C>; P] =
+        array::from_fn(|i| C::SignatureScheme::verifying_key(&signing_keys[i]));
+    let encryption_keys: [KeyPair<C>; P] = array::from_fn(|_| KeyPair::generate());
+    let recipient_keys: [C::Element; P] = array::from_fn(|i| encryption_keys[i].pkey.y.clone());
+
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let mut messages: [(PublicCheckValueMessage<C, T>, [PairwiseShareMessage<C>; P]); P] =
+        array::from_fn(|i| {
+            transport::post_shares(
+                &dealers[i],
+                &recipient_keys,
+                election_hash,
+                &signing_keys[i],
+            )
+            .unwrap()
+        });
+
+    // the dealer at position 2 seals the share of recipient 1 to recipient 2's key
+    let cheater = ParticipantPosition::<P>::from_usize(2);
+    let victim = ParticipantPosition::<P>::from_usize(1);
+    messages[cheater.index()].1[victim.index()] = PairwiseShareMessage::seal(
+        &dealers[cheater.index()].get_verifiable_shares(),
+        &victim,
+        &recipient_keys[cheater.index()],
+        election_hash,
+        &signing_keys[cheater.index()],
+    )
+    .unwrap();
+
+    let check_values = messages.each_ref().map(|(cv, _)| cv);
+    let pairwise_shares = messages.each_ref().map(|(_, shares)| shares);
+
+    // the victim complains instead of failing
+    let received = pairwise_shares.map(|shares| &shares[victim.index()]);
+    let complaints = complaint::check_shares(
+        &victim,
+        election_hash,
+        &encryption_keys[victim.index()].skey,
+        &trustee_keys,
+        &check_values,
+        &received,
+    )
+    .unwrap();
+    assert_eq!(complaints.len(), 1);
+    assert_eq!(complaints[0].dealer, cheater);
+    let upheld = complaints[0]
+        .rule(
+            election_hash,
+            &trustee_keys[cheater.index()],
+            &recipient_keys[victim.index()],
+            check_values[cheater.index()],
+            &pairwise_shares[cheater.index()][victim.index()],
+        )
+        .unwrap();
+    assert!(upheld);
+
+    // complaints with out of range positions are rejected without aborting the others
+    let too_large = ParticipantPosition(u32::try_from(P + 1).unwrap());
+    let mut all_complaints: Vec<Complaint<C, P>> = [ParticipantPosition(0), too_large.clone()]
+        .into_iter()
+        .flat_map(|position| {
+            let mut bad_dealer = complaints[0].clone();
+            bad_dealer.dealer = position.clone();
+            let mut bad_complainant = complaints[0].clone();
+            bad_complainant.complainant = position;
+            [bad_dealer, bad_complainant]
+        })
+        .collect();
+    let qualified = complaint::qualified_set(
+        &all_complaints,
+        election_hash,
+        &trustee_keys,
+        &recipient_keys,
+        &check_values,
+        &pairwise_shares,
+    );
+    assert_eq!(qualified, QualifiedSet::<P>::new());
+
+    all_complaints.extend(complaints);
+    let qualified = complaint::qualified_set(
+        &all_complaints,
+        election_hash,
+        &trustee_keys,
+        &recipient_keys,
+        &check_values,
+        &pairwise_shares,
+    );
+    let mut expected = QualifiedSet::<P>::new();
+    expected.disqualify(&cheater).unwrap();
+    assert_eq!(qualified, expected);
+
+    let mut qualified = QualifiedSet::<P>::new();
+    assert!(!qualified.contains(&ParticipantPosition(0)));
+    assert!(!qualified.contains(&too_large));
+    let result = qualified.disqualify(&ParticipantPosition(0));
+    assert!(matches!(result, Err(Error::ComplaintError(_))));
+    let result = qualified.disqualify(&too_large);
+    assert!(matches!(result, Err(Error::ComplaintError(_))));
+    assert_eq!(qualified.len(), P);
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_pedersen_dkg<C: Context, const T: usize, const P: usize>() {
+    let h = pedersen::pedersen_generator::<C>().unwrap();
+    let dealers: [PedersenDealer<C, T, P>; P] = array::from_fn(|_| PedersenDealer::generate());
+    let mut shares: [PedersenShares<C, T, P>; P] =
+        dealers.each_ref().map(|d| d.get_pedersen_shares(&h));
+
+    // phase 1: the dealer at position 1 sends an invalid share to recipient 2
+    let cheater = ParticipantPosition::<P>::from_usize(1);
+    let victim = ParticipantPosition::<P>::from_usize(2);
+    shares[cheater.index()].shares[victim.index()] = C::random_scalar();
+
+    let received: [[PedersenShare<C, T>; P]; P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        shares.each_ref().map(|s| s.for_recipient(&position))
+    });
+
+    let mut qualified = QualifiedSet::<P>::new();
+    for (i, shares) in received.iter().enumerate() {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        for (d, share) in shares.iter().enumerate() {
+            let dealer = ParticipantPosition::<P>::from_usize(d + 1);
+            let valid = share.verify(&position, &h).is_ok();
+            assert_eq!(valid, !(dealer == cheater && position == victim));
+            if !valid {
+                qualified.disqualify(&dealer).unwrap();
+            }
+        }
+    }
+    assert_eq!(qualified.len(), P - 1);
+
+    // phase 2: the dealer at position 2 publishes invalid checking values
+    let liar = ParticipantPosition::<P>::from_usize(2);
+    let mut checking_values = dealers.each_ref().map(PedersenDealer::checking_values);
+    checking_values[liar.index()][T - 1] = C::random_element();
+
+    for (i, shares) in received.iter().enumerate() {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        for (d, share) in shares.iter().enumerate() {
+            if !qualified.contains(&ParticipantPosition::from_usize(d + 1)) {
+                continue;
+            }
+            let valid = share
+                .verify_extracted(&position, &checking_values[d])
+                .is_ok();
+            assert_eq!(valid, d != liar.index());
+        }
+    }
+
+    // recipients reveal their shares from the liar to reconstruct its checking values
+    let revealed: Vec<(ParticipantPosition<P>, PedersenShare<C, T>)> = received
+        .iter()
+        .enumerate()
+        .map(|(i, shares)| {
+            (
+                ParticipantPosition::from_usize(i + 1),
+                shares[liar.index()].clone(),
+            )
+        })
+        .collect();
+    let commitments = &shares[liar.index()].commitments;
+    let reconstructed =
+        pedersen::reconstruct_checking_values(commitments, &revealed[P - T..], &h).unwrap();
+    assert_eq!(reconstructed, dealers[liar.index()].checking_values());
+    let reconstructed = pedersen::reconstruct_checking_values(commitments, &revealed, &h).unwrap();
+    assert_eq!(reconstructed, dealers[liar.index()].checking_values());
+    assert!(pedersen::reconstruct_checking_values(commitments, &revealed[..T - 1], &h).is_err());
+
+    // a repeated share counts once
+    let mut repeated = vec![revealed[0].clone()];
+    repeated.extend_from_slice(&revealed[..T]);
+    let reconstructed = pedersen::reconstruct_checking_values(commitments, &repeated, &h).unwrap();
+    assert_eq!(reconstructed, dealers[liar.index()].checking_values());
+    let repeated = vec![revealed[0].clone(); T];
+    assert!(pedersen::reconstruct_checking_values(commitments, &repeated, &h).is_err());
+    checking_values[liar.index()] = reconstructed;
+
+    // the joint public key is computed from the qualified dealers only
+    let joint_pk = Recipient::<C, T, P>::qualified_joint_public_key(&qualified, &checking_values);
+    let recipients: [Recipient<C, T, P>; P] = array::from_fn(|i| {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        let verifiable_shares: [VerifiableShare<C, T>; P] =
+            array::from_fn(|d| received[i][d].extract(checking_values[d].clone()));
+        let (recipient, pk) =
+            Recipient::from_qualified_shares(position, &qualified, &verifiable_shares).unwrap();
+        assert_eq!(pk.inner.y, joint_pk.inner.y);
+
+        recipient
+    });
+
+    let message: [C::Element; 2] = array::from_fn(|_| C::random_element());
+    let ciphertexts = vec![joint_pk.encrypt(&message)];
+    let present: [&Recipient<C, T, P>; T] = array::from_fn(|i| &recipients[P - 1 - i]);
+    let dfactors = present.map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap());
+    let verification_keys = present.map(|r| r.get_verification_key().clone());
+    let decrypted = combine(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(decrypted[0], message);
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_pedersen_complaints<C: Context, const T: usize, const P: usize>() {
+    assert!(T + 2 <= P);
+
+    let h = pedersen::pedersen_generator::<C>().unwrap();
+    let dealers: [PedersenDealer<C, T, P>; P] = array::from_fn(|_| PedersenDealer::generate());
+    let mut shares: [PedersenShares<C, T, P>; P] =
+        dealers.each_ref().map(|d| d.get_pedersen_shares(&h));
+    let commitments: [[C::Element; T]; P] = shares.each_ref().map(|s| s.commitments.clone());
+
+    // phase 1: the dealer at position 1 sends an invalid share to recipient 2 and
+    // stands by it, the dealer at position 3 does not answer, and the dealer at
+    // position 4 is accused falsely
+    let victim = ParticipantPosition::<P>::from_usize(2);
+    let cheater = ParticipantPosition::<P>::from_usize(1);
+    let silent = ParticipantPosition::<P>::from_usize(3);
+    let accused = ParticipantPosition::<P>::from_usize(4);
+    shares[cheater.index()].shares[victim.index()] = C::random_scalar();
+    assert!(
+        shares[cheater.index()]
+            .for_recipient(&victim)
+            .verify(&victim, &h)
+            .is_err()
+    );
+
+    let complaints = [&cheater, &silent, &accused]
+        .map(|dealer| PedersenComplaint::new(victim.clone(), dealer.clone()));
+    let answers = vec![
+        shares[cheater.index()].answer(&complaints[0]).unwrap(),
+        shares[accused.index()].answer(&complaints[2]).unwrap(),
+    ];
+    assert!(complaints[0].rule(&commitments[cheater.index()], Some(&answers[0]), &h));
+    assert!(complaints[1].rule::<C, T>(&commitments[silent.index()], None, &h));
+    assert!(!complaints[2].rule(&commitments[accused.index()], Some(&answers[1]), &h));
+
+    // an answer is checked against the dealer's published commitments
+    let mut forged = answers[1].clone();
+    forged.share = dealers[0].get_pedersen_shares(&h).for_recipient(&victim);
+    assert!(complaints[2].rule(&commitments[accused.index()], Some(&forged), &h));
+
+    // complaints against positions out of range are ignored
+    let mut all = complaints.to_vec();
+    let too_large = ParticipantPosition(u32::try_from(P + 1).unwrap());
+    for position in [ParticipantPosition(0), too_large] {
+        all.push(PedersenComplaint::new(victim.clone(), position));
+    }
+    let qualified = pedersen::qualified_set(&all, &answers, &commitments, &h);
+    assert_eq!(qualified.len(), P - 2);
+    assert!(!qualified.contains(&cheater));
+    assert!(!qualified.contains(&silent));
+    assert!(qualified.contains(&accused));
+    assert!(matches!(
+        shares[0].answer(&PedersenComplaint::new(
+            ParticipantPosition(0),
+            cheater.clone()
+        )),
+        Err(Error::ComplaintError(_))
+    ));
+
+    test_pedersen_extraction(&dealers, &shares, &qualified, &h);
+}
+
+/// Checks the extraction phase complaints of a Pedersen DKG, where the dealer at
+/// position 2 publishes the checking values of the dealer at position 4.
+#[allow(clippy::arithmetic_side_effects)]
+fn test_pedersen_extraction<C: Context, const T: usize, const P: usize>(
+    dealers: &[PedersenDealer<C, T, P>; P],
+    shares: &[PedersenShares<C, T, P>; P],
+    qualified: &QualifiedSet<P>,
+    h: &C::Element,
+) {
+    let commitments: [[C::Element; T]; P] = shares.each_ref().map(|s| s.commitments.clone());
+    let accused = ParticipantPosition::<P>::from_usize(4);
+
+    let received: [[PedersenShare<C, T>; P]; P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        shares.each_ref().map(|s| s.for_recipient(&position))
+    });
+    let liar = ParticipantPosition::<P>::from_usize(2);
+    let mut published = dealers.each_ref().map(PedersenDealer::checking_values);
+    published[liar.index()] = dealers[accused.index()].checking_values();
+
+    let complaint = ExtractionComplaint::new(
+        ParticipantPosition::from_usize(1),
+        liar.clone(),
+        received[0][liar.index()].clone(),
+    );
+    assert!(complaint.rule(&commitments[liar.index()], &published[liar.index()], h));
+
+    // complaints against valid checking values, or revealing invalid shares, are rejected
+    let honest = ExtractionComplaint::new(
+        ParticipantPosition::from_usize(1),
+        accused.clone(),
+        received[0][accused.index()].clone(),
+    );
+    assert!(!honest.rule(
+        &commitments[accused.index()],
+        &published[accused.index()],
+        h
+    ));
+    let mut invalid = complaint.clone();
+    invalid.share.value = C::random_scalar();
+    assert!(!invalid.rule(&commitments[liar.index()], &published[liar.index()], h));
+
+    let complaints = vec![complaint, honest, invalid];
+    let checking_values: [[C::Element; T]; P] = array::from_fn(|d| {
+        let dealer = ParticipantPosition::from_usize(d + 1);
+        let revealed: Vec<(ParticipantPosition<P>, PedersenShare<C, T>)> = received
+            .iter()
+            .enumerate()
+            .map(|(i, shares)| (ParticipantPosition::from_usize(i + 1), shares[d].clone()))
+            .collect();
+        pedersen::extracted_checking_values(
+            &dealer,
+            &commitments[d],
+            &published[d],
+            &complaints,
+            &revealed,
+            h,
+        )
+        .unwrap()
+    });
+    assert_eq!(
+        checking_values[liar.index()],
+        dealers[liar.index()].checking_values()
+    );
+    assert_eq!(checking_values[accused.index()], published[accused.index()]);
+
+    // the joint public key is that of the qualified dealers
+    let expected = Recipient::<C, T, P>::qualified_joint_public_key(
+        qualified,
+        &dealers.each_ref().map(PedersenDealer::checking_values),
+    );
+    for (i, received) in received.iter().enumerate() {
+        let position = ParticipantPosition::<P>::from_usize(i + 1);
+        let verifiable_shares: [VerifiableShare<C, T>; P] =
+            array::from_fn(|d| received[d].extract(checking_values[d].clone()));
+        let (_, pk) =
+            Recipient::from_qualified_shares(position, qualified, &verifiable_shares).unwrap();
+        assert_eq!(pk.inner.y, expected.inner.y);
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_reshare<C: Context, const T: usize, const P: usize, const T2: usize, const P2: usize>() {
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let recipients: [(Recipient<C, T, P>, DkgPublicKey<C, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let verifiable_shares: [VerifiableShare<C, T>; P] = dealers
+            .clone()
+            .map(|d| d.get_verifiable_shares().for_recipient(&position));
+
+        Recipient::from_shares(position, &verifiable_shares).unwrap()
+    });
+    let pk = &recipients[0].1;
+
+    // a message encrypted before resharing
+    let message: [C::Element; 2] = array::from_fn(|_| C::random_element());
+    let ciphertexts = vec![pk.encrypt(&message)];
+
+    // the last T current recipients reshare
+    let present: [&Recipient<C, T, P>; T] = array::from_fn(|i| &recipients[P - T + i].0);
+    let resharers = present.map(Resharer::<C, P, T2, P2>::new);
+    let positions = present.map(|r| r.get_position().clone());
+    let verification_keys = present.map(|r| r.get_verification_key().clone());
+    let dealt = resharers.each_ref().map(Resharer::get_verifiable_shares);
+    let all_checking_values = dealt.each_ref().map(|d| d.checking_values.clone());
+
+    reshare::verify_checking_values::<C, T, P, T2>(
+        &positions,
+        &verification_keys,
+        &all_checking_values,
+    )
+    .unwrap();
+    let reshared_pk =
+        Recipient::<C, T2, P2>::reshared_joint_public_key(&positions, &all_checking_values);
+    assert_eq!(reshared_pk.inner.y, pk.inner.y);
+
+    let new_recipients: [Recipient<C, T2, P2>; P2] = array::from_fn(|j| {
+        let position = ParticipantPosition::<P2>::from_usize(j + 1);
+        let shares = dealt.each_ref().map(|d| d.for_recipient(&position));
+        let (recipient, new_pk) = Recipient::from_reshares(
+            position.clone(),
+            pk,
+            &positions,
+            &verification_keys,
+            &shares,
+        )
+        .unwrap();
+        assert_eq!(new_pk.inner.y, pk.inner.y);
+        assert_eq!(
+            recipient.get_verification_key(),
+            &Recipient::<C, T2, P2>::reshared_verification_key(
+                &position,
+                &positions,
+                &all_checking_values
+            )
+        );
+
+        recipient
+    });
+
+    // any T2 of the new recipients decrypt
+    let present: [&Recipient<C, T2, P2>; T2] = array::from_fn(|i| &new_recipients[P2 - 1 - i]);
+    let ciphertexts: Vec<DkgCiphertext<C, 2, T2>> = ciphertexts
+        .into_iter()
+        .map(|c| DkgCiphertext(c.0))
+        .collect();
+    let dfactors = present.map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap());
+    let new_vks = present.map(|r| r.get_verification_key().clone());
+    let decrypted = combine(&ciphertexts, &dfactors, &new_vks, &[]).unwrap();
+    assert_eq!(decrypted[0], message);
+
+    // a resharer that does not share its actual secret is detected
+    let mut forged = all_checking_values.clone();
+    forged[0][0] = C::random_element();
+    let result =
+        reshare::verify_checking_values::<C, T, P, T2>(&positions, &verification_keys, &forged);
+    assert!(matches!(result, Err(Error::ReshareError(_))));
+
+    let position = ParticipantPosition::<P2>::from_usize(1);
+    let mut shares = dealt.each_ref().map(|d| d.for_recipient(&position));
+    shares[0].value = C::random_scalar();
+    let result = Recipient::from_reshares(
+        position.clone(),
+        pk,
+        &positions,
+        &verification_keys,
+        &shares,
+    );
+    assert!(matches!(result, Err(Error::ShareVerificationFailed(_))));
+
+    let repeated: [ParticipantPosition<P>; T] = array::from_fn(|_| positions[0].clone());
+    let shares = dealt.each_ref().map(|d| d.for_recipient(&position));
+    let result =
+        Recipient::from_reshares(position.clone(), pk, &repeated, &verification_keys, &shares);
+    assert!(matches!(result, Err(Error::ReshareError(_))));
+
+    // fewer resharers than the current threshold
+    let result = Recipient::from_reshares(
+        position.clone(),
+        pk,
+        &[positions[0].clone()],
+        &[verification_keys[0].clone()],
+        &[dealt[0].for_recipient(&position)],
+    );
+    assert!(matches!(result, Err(Error::ReshareError(_))));
+
+    // the resharers' checking values do not interpolate to the current key
+    let other = DkgPublicKey::<C, T>::from_public_key(&PublicKey::new(C::random_element()));
+    let result =
+        Recipient::from_reshares(position, &other, &positions, &verification_keys, &shares);
+    assert!(matches!(result, Err(Error::ReshareError(_))));
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_combine_robust<C: Context, const T: usize, const P: usize, const W: usize>() {
+    assert!(T + 3 <= P);
+
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let recipients: [(Recipient<C, T, P>, DkgPublicKey<C, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares = dealers
+            .clone()
+            .map(|d| d.get_verifiable_shares().for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+    let all_checking_values = dealers.map(|d| d.get_checking_values());
+    let verification_keys: [C::Element; P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        Recipient::<C, T, P>::verification_key(&position, &all_checking_values)
+    });
+
+    let pk = &recipients[0].1;
+    let messages: Vec<[C::Element; W]> = (0..2)
+        .map(|_| array::from_fn(|_| C::random_element()))
+        .collect();
+    let ciphertexts: Vec<DkgCiphertext<C, W, T>> = messages.iter().map(|m| pk.encrypt(m)).collect();
+
+    let mut dfactors: Vec<Vec<DecryptionFactor<C, P, W>>> = recipients
+        .iter()
+        .map(|r| r.0.decryption_factor(&ciphertexts, &[]).unwrap())
+        .collect();
+
+    // all participants respond correctly
+    let (decrypted, faulty) =
+        combine_robust(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(messages, decrypted);
+    assert!(faulty.is_empty());
+
+    // the first participant sends an invalid decryption factor, the last one is missing
+    dfactors[0][1].value = array::from_fn(|_| C::random_element());
+    let first: &[Vec<DecryptionFactor<C, P, W>>; T] = dfactors[0..T].try_into().unwrap();
+    let first_keys: [C::Element; T] = array::from_fn(|i| verification_keys[i].clone());
+    let result = combine(&ciphertexts, first, &first_keys, &[]);
+    assert!(matches!(
+        result,
+        Err(Error::DecryptProofFailed(m)) if m.contains("participant 1 for ciphertext 1")
+    ));
+    dfactors.pop();
+    // the second participant responds twice
+    dfactors.push(dfactors[1].clone());
+    let mut rng = C::get_rng();
+    dfactors.shuffle(&mut rng);
+
+    let (decrypted, faulty) =
+        combine_robust(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(messages, decrypted);
+    assert_eq!(faulty, vec![ParticipantPosition::from_usize(1)]);
+
+    // sets falsely claiming the positions of honest participants, received first,
+    // neither hide their valid sets nor frame them as faulty
+    let honest = |position: usize| {
+        dfactors
+            .iter()
+            .find(|df| df[0].source == ParticipantPosition::from_usize(position))
+            .unwrap()
+            .clone()
+    };
+    let mut forged = honest(2);
+    for df in &mut forged {
+        df.value = array::from_fn(|_| C::random_element());
+    }
+    let mut malformed = honest(3);
+    malformed.pop();
+    let mut framed = vec![forged, malformed];
+    framed.extend(dfactors.iter().cloned());
+    let (decrypted, framed_faulty) =
+        combine_robust(&ciphertexts, &framed, &verification_keys, &[]).unwrap();
+    assert_eq!(messages, decrypted);
+    assert_eq!(framed_faulty, faulty);
+
+    // fewer than T valid participants
+    let too_few: Vec<Vec<DecryptionFactor<C, P, W>>> = recipients[0..T]
+        .iter()
+        .map(|r| {
+            r.0.decryption_factor(&ciphertexts, b"other context")
+                .unwrap()
+        })
+        .collect();
+    let result = combine_robust(&ciphertexts, &too_few, &verification_keys, &[]);
+    assert!(matches!(result, Err(Error::DecryptProofFailed(_))));
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_pet<C: Context, const T: usize, const P: usize, const W: usize>() {
+    assert!(T < P);
+
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let recipients: [(Recipient<C, T, P>, DkgPublicKey<C, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares = dealers
+            .clone()
+            .map(|d| d.get_verifiable_shares().for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+    let verification_keys: [C::Element; T] =
+        array::from_fn(|i| recipients[i].0.get_verification_key().clone());
+
+    let pk = &recipients[0].1;
+    let message: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let other: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let a: Vec<DkgCiphertext<C, W, T>> = vec![pk.encrypt(&message), pk.encrypt(&message)];
+    let b: Vec<DkgCiphertext<C, W, T>> = vec![pk.encrypt(&message), pk.encrypt(&other)];
+    // equal plaintexts encrypted with different randomness
+    assert_ne!(a[0], b[0]);
+
+    let quotients: Vec<DkgCiphertext<C, W, T>> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| pet::quotient(a, b))
+        .collect();
+    let mut blinding_factors: Vec<Vec<BlindingFactor<C, P, W>>> = recipients
+        .iter()
+        .map(|r| r.0.blinding_factor(&quotients, &[]).unwrap())
+        .collect();
+
+    // the last T participants decrypt
+    let decryptors = &recipients[P - T..];
+    let decrypting: [ParticipantPosition<P>; T] =
+        array::from_fn(|i| decryptors[i].0.get_position().clone());
+    let blinded = pet::blind(&quotients, &blinding_factors, &decrypting, &[]).unwrap();
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] =
+        array::from_fn(|i| decryptors[i].0.decryption_factor(&blinded, &[]).unwrap());
+    let decryptor_keys: [C::Element; T] =
+        array::from_fn(|i| decryptors[i].0.get_verification_key().clone());
+
+    let equal =
+        pet::plaintext_equivalence(&a, &b, &blinding_factors, &dfactors, &decryptor_keys, &[])
+            .unwrap();
+    assert_eq!(equal, vec![true, false]);
+
+    // blinding by the decrypting participants only
+    let first: [ParticipantPosition<P>; T] =
+        array::from_fn(|i| ParticipantPosition::from_usize(i + 1));
+    let only = &blinding_factors[0..T];
+    let blinded = pet::blind(&quotients, only, &first, &[]).unwrap();
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] =
+        array::from_fn(|i| recipients[i].0.decryption_factor(&blinded, &[]).unwrap());
+    let equal = pet::equal(&blinded, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(equal, vec![true, false]);
+    let equal =
+        pet::plaintext_equivalence(&a, &b, only, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(equal, vec![true, false]);
+
+    // a decrypting participant that did not blind
+    let result = pet::plaintext_equivalence(
+        &a,
+        &b,
+        &blinding_factors[1..],
+        &dfactors,
+        &verification_keys,
+        &[],
+    );
+    assert!(matches!(result, Err(Error::PetError(_))));
+    // decryption factors of different blinded quotients
+    let result = pet::plaintext_equivalence(
+        &a,
+        &b,
+        &blinding_factors,
+        &dfactors,
+        &verification_keys,
+        &[],
+    );
+    assert!(matches!(result, Err(Error::DecryptProofFailed(_))));
+
+    // blinding factors with a different context
+    let result = pet::blind(&quotients, &blinding_factors, &first, b"other context");
+    assert!(matches!(result, Err(Error::PetError(_))));
+
+    // mismatched number of ciphertexts
+    let result = pet::plaintext_equivalence(&a, &b[0..1], only, &dfactors, &verification_keys, &[]);
+    assert!(matches!(result, Err(Error::PetError(_))));
+
+    // no blinding factors
+    let result = pet::blind::<C, T, P, W>(&quotients, &[], &first, &[]);
+    assert!(matches!(result, Err(Error::PetError(_))));
+
+    // repeated blinding factors
+    let repeated = vec![blinding_factors[0].clone(), blinding_factors[0].clone()];
+    let result = pet::blind(&quotients, &repeated, &first, &[]);
+    assert!(matches!(result, Err(Error::PetError(_))));
+
+    // blinding factors replayed under another participant's position
+    let mut replayed = blinding_factors[0].clone();
+    for bf in &mut replayed {
+        bf.source = ParticipantPosition::from_usize(2);
+    }
+    let result = pet::blind(&quotients, &[replayed], &first, &[]);
+    assert!(matches!(result, Err(Error::PetError(_))));
+
+    test_pet_blinders(&recipients, &quotients, &blinding_factors, &first);
+
+    // a participant blinds to the identity, turning an unequal pair into an equal one
+    let identity = Ciphertext::<C, W>::new(
+        array::from_fn(|_| C::Element::one()),
+        array::from_fn(|_| C::Element::one()),
+    );
+    blinding_factors[1][1].value = identity;
+    let result = pet::blind(&quotients, &blinding_factors, &first, &[]);
+    assert!(matches!(
+        result,
+        Err(Error::PetError(m)) if m.contains("participant 2 for quotient 1")
+    ));
+}
+
+/// Checks that the decrypting participants must blind, and that blinding
+/// exponents of zero, or exponents that cancel out, are rejected.
+#[allow(clippy::arithmetic_side_effects, clippy::type_complexity)]
+fn test_pet_blinders<C: Context, const T: usize, const P: usize, const W: usize>(
+    recipients: &[(Recipient<C, T, P>, DkgPublicKey<C, T>); P],
+    quotients: &[DkgCiphertext<C, W, T>],
+    blinding_factors: &[Vec<BlindingFactor<C, P, W>>],
+    decrypting: &[ParticipantPosition<P>; T],
+) {
+    // the first decrypting participant did not blind
+    let result = pet::blind(quotients, &blinding_factors[1..], decrypting, &[]);
+    assert!(matches!(
+        result,
+        Err(Error::PetError(m)) if m.contains("decrypting participant 1")
+    ));
+
+    let random = |_| C::random_scalar();
+    let blinders = &recipients[0..T];
+
+    // a zero exponent, with a valid proof of knowledge
+    let mut exponents: [Vec<C::Scalar>; T] =
+        array::from_fn(|_| quotients.iter().map(random).collect());
+    exponents[1][1] = C::Scalar::zero();
+    let blinding_factors: Vec<Vec<BlindingFactor<C, P, W>>> = blinders
+        .iter()
+        .zip(exponents.iter())
+        .map(|(r, z)| r.0.blinding_factor_with(quotients, z, &[]).unwrap())
+        .collect();
+    let result = pet::blind(quotients, &blinding_factors, decrypting, &[]);
+    assert!(matches!(
+        result,
+        Err(Error::PetError(m)) if m.contains("participant 2 for quotient 1")
+    ));
+
+    // the last blinder cancels the exponents of the others
+    let mut exponents: [Vec<C::Scalar>; T] =
+        array::from_fn(|_| quotients.iter().map(random).collect());
+    let sum = exponents[..T - 1]
+        .iter()
+        .fold(C::Scalar::zero(), |acc, z| acc.add(&z[0]));
+    exponents[T - 1][0] = sum.neg();
+    let blinding_factors: Vec<Vec<BlindingFactor<C, P, W>>> = blinders
+        .iter()
+        .zip(exponents.iter())
+        .map(|(r, z)| r.0.blinding_factor_with(quotients, z, &[]).unwrap())
+        .collect();
+    let result = pet::blind(quotients, &blinding_factors, decrypting, &[]);
+    assert!(matches!(
+        result,
+        Err(Error::PetError(m)) if m.contains("cancel out for quotient 0")
+    ));
+}
+
+fn dynamic_setup<C: Context>(
+    params: &DkgParameters,
+) -> (Vec<DynDealerShares<C>>, Vec<DynRecipient<C>>) {
+    let dealer_shares: Vec<DynDealerShares<C>> = (0..params.participants())
+        .map(|_| DynDealer::<C>::generate(params).get_verifiable_shares())
+        .collect();
+
+    let recipients = params
+        .positions()
+        .map(|position| {
+            let shares: Vec<DynVerifiableShare<C>> = dealer_shares
+                .iter()
+                .map(|d| d.for_recipient(&position).unwrap())
+                .collect();
+            DynRecipient::from_shares(params, position, &shares)
+                .unwrap()
+                .0
+        })
+        .collect();
+
+    (dealer_shares, recipients)
+}
+
+fn test_dynamic<C: Context>(threshold: usize, participants: usize) {
+    let params = DkgParameters::new(threshold, participants).unwrap();
+    let (dealer_shares, mut recipients) = dynamic_setup::<C>(&params);
+
+    let all_checking_values: Vec<Vec<C::Element>> = dealer_shares
+        .iter()
+        .map(|d| d.checking_values.clone())
+        .collect();
+    for r in &recipients {
+        let vk =
+            DynRecipient::<C>::verification_key(&params, r.get_position(), &all_checking_values)
+                .unwrap();
+        assert_eq!(&vk, r.get_verification_key());
+    }
+    let pk = DynRecipient::<C>::joint_public_key(&params, &all_checking_values).unwrap();
+
+    let messages: Vec<[C::Element; 2]> = (0..3)
+        .map(|_| array::from_fn(|_| C::random_element()))
+        .collect();
+    let ciphertexts: Vec<Ciphertext<C, 2>> = messages.iter().map(|m| pk.encrypt(m)).collect();
+
+    let mut rng = C::get_rng();
+    recipients.shuffle(&mut rng);
+    let present = &recipients[0..threshold];
+
+    let dfactors: Vec<Vec<DynDecryptionFactor<C, 2>>> = present
+        .iter()
+        .map(|r| r.decryption_factor(&ciphertexts, b"context").unwrap())
+        .collect();
+    let verification_keys: Vec<C::Element> = present
+        .iter()
+        .map(|r| r.get_verification_key().clone())
+        .collect();
+
+    let decrypted = dynamic::combine(
+        &params,
+        &ciphertexts,
+        &dfactors,
+        &verification_keys,
+        b"context",
+    )
+    .unwrap();
+    assert_eq!(messages, decrypted);
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+fn test_dynamic_compatible<C: Context, const T: usize, const P: usize, const W: usize>() {
+    let params = DkgParameters::new(T, P).unwrap();
+
+    // a statically sized dealer's shares serialize like the runtime sized ones
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let dynamic_dealers: Vec<DynDealer<C>> = dealers
+        .iter()
+        .map(|d| {
+            let mut dealer = DynDealer::<C>::generate(&params);
+            dealer.coefficients = d.polynomial.0.to_vec();
+            dealer
+        })
+        .collect();
+
+    for (d, dd) in dealers.iter().zip(dynamic_dealers.iter()) {
+        let shares = d.get_verifiable_shares();
+        let dynamic_shares = dd.get_verifiable_shares();
+        assert_eq!(shares.ser(), dynamic_shares.ser());
+        assert_eq!(DynDealerShares::from(shares.clone()), dynamic_shares);
+
+        let position = ParticipantPosition::<P>::from_usize(P);
+        let share = shares.for_recipient(&position);
+        let dynamic_share = dynamic_shares.for_recipient(&position.into()).unwrap();
+        assert_eq!(share.ser(), dynamic_share.ser());
+        let deserialized = VerifiableShare::<C, T>::deser(&dynamic_share.ser()).unwrap();
+        assert_eq!(deserialized.ser(), share.ser());
+    }
+
+    let static_recipients: [(Recipient<C, T, P>, DkgPublicKey<C, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares = dealers
+            .clone()
+            .map(|d| d.get_verifiable_shares().for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+    let dynamic_recipients: Vec<(DynRecipient<C>, _)> = params
+        .positions()
+        .map(|position| {
+            let shares: Vec<DynVerifiableShare<C>> = dynamic_dealers
+                .iter()
+                .map(|d| d.get_verifiable_shares().for_recipient(&position).unwrap())
+                .collect();
+            DynRecipient::from_shares(&params, position, &shares).unwrap()
+        })
+        .collect();
+
+    for ((r, pk), (dr, dpk)) in static_recipients.iter().zip(dynamic_recipients.iter()) {
+        assert_eq!(pk.inner, *dpk);
+        assert_eq!(r.get_verification_key(), dr.get_verification_key());
+        assert_eq!(r.get_position().0, dr.get_position().0);
+    }
+
+    // static and runtime sized decryption factors can be combined by either combine
+    let message: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let ciphertexts = vec![static_recipients[0].1.encrypt(&message)];
+    let plain: Vec<Ciphertext<C, W>> = ciphertexts.iter().map(|c| c.0.clone()).collect();
+
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] = array::from_fn(|i| {
+        static_recipients[i]
+            .0
+            .decryption_factor(&ciphertexts, &[])
+            .unwrap()
+    });
+    let verification_keys: [C::Element; T] =
+        array::from_fn(|i| dynamic_recipients[i].0.get_verification_key().clone());
+
+    let dynamic_dfactors: Vec<Vec<DynDecryptionFactor<C, W>>> = dfactors
+        .iter()
+        .map(|df| df.iter().cloned().map(DynDecryptionFactor::from).collect())
+        .collect();
+    assert_eq!(dfactors[0][0].ser(), dynamic_dfactors[0][0].ser());
+    let decrypted =
+        dynamic::combine(&params, &plain, &dynamic_dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(message, decrypted[0]);
+
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] = array::from_fn(|i| {
+        dynamic_recipients[i]
+            .0
+            .decryption_factor(&plain, &[])
+            .unwrap()
+            .iter()
+            .map(|df| DecryptionFactor::<C, P, W>::deser(&df.ser()).unwrap())
+            .collect()
+    });
+    let decrypted = combine(&ciphertexts, &dfactors, &verification_keys, &[]).unwrap();
+    assert_eq!(message, decrypted[0]);
+}
+
+fn test_dynamic_invalid<C: Context>() {
+    assert!(matches!(
+        DkgParameters::new(0, 3),
+        Err(Error::DkgParameterError(_))
+    ));
+    assert!(matches!(
+        DkgParameters::new(4, 3),
+        Err(Error::DkgParameterError(_))
+    ));
+
+    // not limited to fewer than 100 participants
+    assert!(DkgParameters::new(2, 1000).is_ok());
+
+    let params = DkgParameters::new(2, 3).unwrap();
+    assert!(DynParticipantPosition::new(0, &params).is_err());
+    assert!(DynParticipantPosition::new(4, &params).is_err());
+    assert!(DynParticipantPosition::new(3, &params).is_ok());
+
+    let (dealer_shares, recipients) = dynamic_setup::<C>(&params);
+    let position = DynParticipantPosition::new(1, &params).unwrap();
+    let shares: Vec<DynVerifiableShare<C>> = dealer_shares
+        .iter()
+        .map(|d| d.for_recipient(&position).unwrap())
+        .collect();
+
+    // wrong number of shares
+    let result = DynRecipient::from_shares(&params, position.clone(), &shares[1..]);
+    assert!(matches!(result, Err(Error::DkgParameterError(_))));
+
+    // share for a different position
+    let other = DynParticipantPosition::new(2, &params).unwrap();
+    let result = DynRecipient::from_shares(&params, other, &shares);
+    assert!(matches!(result, Err(Error::ShareVerificationFailed(_))));
+
+    // parameters for a different threshold
+    let other_params = DkgParameters::new(3, 3).unwrap();
+    let result = DynRecipient::from_shares(&other_params, position, &shares);
+    assert!(matches!(result, Err(Error::DkgParameterError(_))));
+
+    assert_eq!(recipients[0].get_parameters(), &params);
+
+    let all_checking_values: Vec<Vec<C::Element>> = dealer_shares
+        .iter()
+        .map(|d| d.checking_values.clone())
+        .collect();
+    let pk = DynRecipient::<C>::joint_public_key(&params, &all_checking_values).unwrap();
+    let ciphertexts = vec![pk.encrypt(&[C::random_element()])];
+    let dfactors: Vec<Vec<DynDecryptionFactor<C, 1>>> = recipients
+        .iter()
+        .map(|r| r.decryption_factor(&ciphertexts, &[]).unwrap())
+        .collect();
+    let verification_keys: Vec<C::Element> = recipients
+        .iter()
+        .map(|r| r.get_verification_key().clone())
+        .collect();
+
+    // too many decryption factors
+    let result = dynamic::combine(&params, &ciphertexts, &dfactors, &verification_keys, &[]);
+    assert!(matches!(result, Err(Error::DkgParameterError(_))));
+
+    // repeated source
+    let repeated = vec![dfactors[0].clone(), dfactors[0].clone()];
+    let result = dynamic::combine(
+        &params,
+        &ciphertexts,
+        &repeated,
+        &verification_keys[0..2],
+        &[],
+    );
+    assert!(matches!(result, Err(Error::DkgParameterError(_))));
+
+    // mismatched verification keys
+    let result = dynamic::combine(
+        &params,
+        &ciphertexts,
+        &dfactors[0..2],
+        &verification_keys[1..3],
+        &[],
+    );
+    assert!(matches!(result, Err(Error::DecryptProofFailed(_))));
+
+    let result = dynamic::combine(
+        &params,
+        &ciphertexts,
+        &dfactors[0..2],
+        &verification_keys[0..2],
+        &[],
+    );
+    assert!(result.is_ok());
+}
+
 fn untyped_combine<C: Context, const P: usize, const W: usize>(
     ciphertexts: &[Ciphertext<C, W>],
     dfactors: &[Vec<DecryptionFactor<C, P, W>>],
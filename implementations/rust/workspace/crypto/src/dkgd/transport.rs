@@ -0,0 +1,488 @@
+/*
+ * Joint-Feldman distributed key generation: share transport
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::cryptosystem::hybrid::{self, HybridCiphertext};
+use crate::dkgd::dealer::{Dealer, DealerShares, VerifiableShare};
+use crate::dkgd::recipient::ParticipantPosition;
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error;
+use crate::utils::serialization::{Bytes, VDeserializable, VSerializable};
+use crate::utils::signatures::{SignatureScheme, Signer, Verifier};
+use vser_derive::VSerializable;
+
+/// The signing key type of context `C`.
+pub type SigningKey<C> =
+    <<C as Context>::SignatureScheme as SignatureScheme<<C as Context>::Rng>>::Signer;
+
+/// The verification key type of context `C`.
+pub type VerifyingKey<C> =
+    <<C as Context>::SignatureScheme as SignatureScheme<<C as Context>::Rng>>::Verifier;
+
+/// The signature type of context `C`.
+pub type Signature<C> =
+    <<C as Context>::SignatureScheme as SignatureScheme<<C as Context>::Rng>>::Signature;
+
+/// Message type label of [`PublicCheckValueMessage`], included in its signature
+const PUBLIC_CHECK_VALUE_LABEL: &[u8] = b"public_check_value";
+
+/// Message type label of [`PairwiseShareMessage`], included in its signature and encryption
+const PAIRWISE_SHARE_LABEL: &[u8] = b"pairwise_share";
+
+/**
+ * A signed message carrying one dealer's public checking values.
+ *
+ * See `election-key-gen-spec.md`, Phase 1: Public Check Value.
+ *
+ * The checking values are public; they are used by recipients to verify the
+ * [pairwise shares][`PairwiseShareMessage`] they receive from this dealer, and by
+ * anyone to compute the [joint public key][`crate::dkgd::recipient::Recipient::joint_public_key`].
+ */
+#[derive(VSerializable)]
+pub struct PublicCheckValueMessage<C: Context, const T: usize> {
+    /// the hash of the election configuration
+    pub election_hash: Bytes,
+    /// the dealer's checking values
+    pub check_values: [C::Element; T],
+    /// the dealer's public signing key
+    pub public_key: VerifyingKey<C>,
+    /// the dealer's signature over the remaining fields
+    pub signature: Signature<C>,
+}
+
+impl<C: Context, const T: usize> PublicCheckValueMessage<C, T> {
+    /// Construct and sign a public check value message.
+    ///
+    /// # Parameters
+    ///
+    /// - `shares`: the dealer's shares, of which only the checking values are used
+    /// - `election_hash`: the hash of the election configuration
+    /// - `signer`: the dealer's signing key
+    ///
+    /// # Errors
+    ///
+    /// - `ShareTransportError` if signing fails
+    pub fn sign<const P: usize>(
+        shares: &DealerShares<C, T, P>,
+        election_hash: &[u8],
+        signer: &SigningKey<C>,
+    ) -> Result<Self, Error> {
+        let election_hash = Bytes(election_hash.to_vec());
+        let public_key = C::SignatureScheme::verifying_key(signer);
+        let check_values = shares.checking_values.clone();
+
+        let message = Self::signed_bytes(&election_hash, &check_values, &public_key);
+        let signature = sign::<C>(signer, &message)?;
+
+        Ok(Self {
+            election_hash,
+            check_values,
+            public_key,
+            signature,
+        })
+    }
+
+    /// Verify this message against the expected election hash and the dealer's public signing key.
+    ///
+    /// # Errors
+    ///
+    /// - `ShareTransportError` if the election hash or public key do not match, or
+    ///   the signature does not verify
+    pub fn verify(&self, election_hash: &[u8], trustee_key: &VerifyingKey<C>) -> Result<(), Error> {
+        check_header::<C>(
+            &self.election_hash,
+            election_hash,
+            &self.public_key,
+            trustee_key,
+        )?;
+
+        let message = Self::signed_bytes(&self.election_hash, &self.check_values, &self.public_key);
+        verify::<C>(&self.public_key, &message, &self.signature)
+    }
+
+    /// Returns the bytes covered by the signature.
+    fn signed_bytes(
+        election_hash: &Bytes,
+        check_values: &[C::Element; T],
+        public_key: &VerifyingKey<C>,
+    ) -> Vec<u8> {
+        let label = Bytes(PUBLIC_CHECK_VALUE_LABEL.to_vec());
+
+        (&label, election_hash, check_values, public_key).ser()
+    }
+}
+
+/**
+ * A signed message carrying one dealer's encrypted share for one recipient.
+ *
+ * See `election-key-gen-spec.md`, Phase 1: Pairwise Share Message.
+ *
+ * The share is [encrypted][`crate::cryptosystem::hybrid`] to the recipient's public
+ * encryption key. The message type, election hash, and both parties' keys are
+ * bound to the encryption as well as to the signature.
+ */
+#[derive(VSerializable)]
+pub struct PairwiseShareMessage<C: Context> {
+    /// the hash of the election configuration
+    pub election_hash: Bytes,
+    /// the dealer's share for the recipient, encrypted to `recipient_public_key`
+    pub pairwise_share: HybridCiphertext<C>,
+    /// the recipient's public encryption key
+    pub recipient_public_key: C::Element,
+    /// the dealer's public signing key
+    pub public_key: VerifyingKey<C>,
+    /// the dealer's signature over the remaining fields
+    pub signature: Signature<C>,
+}
+
+impl<C: Context> PairwiseShareMessage<C> {
+    /// Construct, encrypt and sign a pairwise share message.
+    ///
+    /// # Parameters
+    ///
+    /// - `shares`: the dealer's shares
+    /// - `recipient`: the position of the recipient
+    /// - `recipient_public_key`: the recipient's public encryption key
+    /// - `election_hash`: the hash of the election configuration
+    /// - `signer`: the dealer's signing key
+    ///
+    /// # Errors
+    ///
+    /// - `HybridError` if encryption fails
+    /// - `ShareTransportError` if signing fails
+    pub fn seal<const T: usize, const P: usize>(
+        shares: &DealerShares<C, T, P>,
+        recipient: &ParticipantPosition<P>,
+        recipient_public_key: &C::Element,
+        election_hash: &[u8],
+        signer: &SigningKey<C>,
+    ) -> Result<Self, Error> {
+        let public_key = C::SignatureScheme::verifying_key(signer);
+        let share = shares.for_recipient(recipient);
+
+        let (info, aad) = Self::hybrid_context(election_hash, recipient_public_key, &public_key);
+        let pairwise_share =
+            hybrid::seal::<C>(recipient_public_key, &info, &aad, &share.value.ser())?;
+
+        let election_hash = Bytes(election_hash.to_vec());
+        let message = Self::signed_bytes(
+            &election_hash,
+            &pairwise_share,
+            recipient_public_key,
+            &public_key,
+        );
+        let signature = sign::<C>(signer, &message)?;
+
+        Ok(Self {
+            election_hash,
+            pairwise_share,
+            recipient_public_key: recipient_public_key.clone(),
+            public_key,
+            signature,
+        })
+    }
+
+    /// Verify this message against the expected election hash and the dealer's public signing key.
+    ///
+    /// # Errors
+    ///
+    /// - `ShareTransportError` if the election hash or public key do not match, or
+    ///   the signature does not verify
+    pub fn verify(&self, election_hash: &[u8], trustee_key: &VerifyingKey<C>) -> Result<(), Error> {
+        check_header::<C>(
+            &self.election_hash,
+            election_hash,
+            &self.public_key,
+            trustee_key,
+        )?;
+
+        let message = Self::signed_bytes(
+            &self.election_hash,
+            &self.pairwise_share,
+            &self.recipient_public_key,
+            &self.public_key,
+        );
+        verify::<C>(&self.public_key, &message, &self.signature)
+    }
+
+    /// Verify and decrypt this message, returning the share value.
+    ///
+    /// The returned value must still be verified against the dealer's checking
+    /// values, see [`receive_shares`].
+    ///
+    /// # Parameters
+    ///
+    /// - `election_hash`: the expected hash of the election configuration
+    /// - `secret_key`: the recipient's secret encryption key
+    /// - `trustee_key`: the dealer's expected public signing key
+    ///
+    /// # Errors
+    ///
+    /// - `ShareTransportError` if the election hash, public key or recipient key do
+    ///   not match, or the signature does not verify
+    /// - `HybridError` if the share cannot be decrypted
+    pub fn open(
+        &self,
+        election_hash: &[u8],
+        secret_key: &C::Scalar,
+        trustee_key: &VerifyingKey<C>,
+    ) -> Result<C::Scalar, Error> {
+        if !C::generator()
+            .exp(secret_key)
+            .equals(&self.recipient_public_key)
+        {
+            return Err(Error::ShareTransportError(
+                "Pairwise share is addressed to a different recipient".to_string(),
+            ));
+        }
+        self.verify(election_hash, trustee_key)?;
+
+        let dh = self.pairwise_share.enc.exp(secret_key);
+        self.open_with_dh(election_hash, &dh)
+    }
+
+    /// Decrypt this message with the recipient's revealed Diffie-Hellman value.
+    ///
+    /// This function does not verify the message signature, or the
+    /// Diffie-Hellman value. See [`Complaint`][`crate::dkgd::complaint::Complaint`].
+    ///
+    /// # Errors
+    ///
+    /// - `HybridError` if the share cannot be decrypted
+    /// - `DeserializationError` if the plaintext is not a scalar
+    pub fn open_with_dh(&self, election_hash: &[u8], dh: &C::Element) -> Result<C::Scalar, Error> {
+        let (info, aad) =
+            Self::hybrid_context(election_hash, &self.recipient_public_key, &self.public_key);
+        let plaintext = hybrid::open_with_dh::<C>(
+            dh,
+            &self.recipient_public_key,
+            &self.pairwise_share,
+            &info,
+            &aad,
+        )?;
+
+        C::Scalar::deser(&plaintext)
+    }
+
+    /// Returns the bytes covered by the signature.
+    fn signed_bytes(
+        election_hash: &Bytes,
+        pairwise_share: &HybridCiphertext<C>,
+        recipient_public_key: &C::Element,
+        public_key: &VerifyingKey<C>,
+    ) -> Vec<u8> {
+        let label = Bytes(PAIRWISE_SHARE_LABEL.to_vec());
+
+        (
+            &label,
+            election_hash,
+            pairwise_share,
+            recipient_public_key,
+            public_key,
+        )
+            .ser()
+    }
+
+    /// Returns the `info` and `aad` inputs to the share encryption.
+    fn hybrid_context(
+        election_hash: &[u8],
+        recipient_public_key: &C::Element,
+        public_key: &VerifyingKey<C>,
+    ) -> (Vec<u8>, Vec<u8>) {
+        let info = [PAIRWISE_SHARE_LABEL, election_hash].concat();
+        let aad = (recipient_public_key, public_key).ser();
+
+        (info, aad)
+    }
+}
+
+/// Compute the signed messages that a dealer posts to the trustee board.
+///
+/// Returns the dealer's [`PublicCheckValueMessage`] and one [`PairwiseShareMessage`]
+/// for each of the `P` recipients, including the dealer itself, in position order.
+///
+/// # Parameters
+///
+/// - `dealer`: the dealer
+/// - `recipient_keys`: the recipients' public encryption keys, in position order
+/// - `election_hash`: the hash of the election configuration
+/// - `signer`: the dealer's signing key
+///
+/// # Examples
+///
+/// ```
+/// use std::array;
+/// use crypto::context::Context;
+/// use crypto::context::RistrettoCtx as RCtx;
+/// use crypto::cryptosystem::elgamal::KeyPair;
+/// use crypto::dkgd::dealer::Dealer;
+/// use crypto::dkgd::recipient::{ParticipantPosition, Recipient};
+/// use crypto::dkgd::transport::{self, VerifyingKey};
+/// use crypto::utils::signatures::SignatureScheme;
+///
+/// const P: usize = 3;
+/// const T: usize = 2;
+///
+/// let election_hash = b"election";
+/// let signing_keys = array::from_fn::<_, P, _>(|_| RCtx::gen_signing_key());
+/// let trustee_keys: [VerifyingKey<RCtx>; P] =
+///     array::from_fn(|i| <RCtx as Context>::SignatureScheme::verifying_key(&signing_keys[i]));
+/// let encryption_keys = array::from_fn::<_, P, _>(|_| KeyPair::<RCtx>::generate());
+/// let recipient_keys = array::from_fn::<_, P, _>(|i| encryption_keys[i].pkey.y.clone());
+///
+/// // each dealer posts its messages
+/// let dealers: [Dealer<RCtx, T, P>; P] = array::from_fn(|_| Dealer::generate());
+/// let messages = array::from_fn::<_, P, _>(|i| {
+///     transport::post_shares(&dealers[i], &recipient_keys, election_hash, &signing_keys[i])
+///         .unwrap()
+/// });
+///
+/// // recipient 1 collects its share messages, and verifies and decrypts them
+/// let check_values = messages.each_ref().map(|(cv, _)| cv);
+/// let pairwise_shares = messages.each_ref().map(|(_, shares)| &shares[0]);
+/// let shares = transport::receive_shares(
+///     election_hash,
+///     &encryption_keys[0].skey,
+///     &trustee_keys,
+///     &check_values,
+///     &pairwise_shares,
+/// )
+/// .unwrap();
+///
+/// let position = ParticipantPosition::from_usize(1);
+/// let (recipient, pk) = Recipient::from_shares(position, &shares).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// - `HybridError` if encryption fails
+/// - `ShareTransportError` if signing fails
+pub fn post_shares<C: Context, const T: usize, const P: usize>(
+    dealer: &Dealer<C, T, P>,
+    recipient_keys: &[C::Element; P],
+    election_hash: &[u8],
+    signer: &SigningKey<C>,
+) -> Result<(PublicCheckValueMessage<C, T>, [PairwiseShareMessage<C>; P]), Error> {
+    let shares = dealer.get_verifiable_shares();
+    let check_values = PublicCheckValueMessage::sign(&shares, election_hash, signer)?;
+
+    let pairwise_shares = recipient_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            // i + 1 cannot overflow, i < P < 100
+            #[allow(clippy::arithmetic_side_effects)]
+            let position = ParticipantPosition::from_usize(i + 1);
+            PairwiseShareMessage::seal(&shares, &position, key, election_hash, signer)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let pairwise_shares: [PairwiseShareMessage<C>; P] = pairwise_shares
+        .try_into()
+        .map_err(|_| Error::ShareTransportError("Unexpected number of shares".to_string()))?;
+
+    Ok((check_values, pairwise_shares))
+}
+
+/// Verify and decrypt the messages addressed to one recipient, returning its verifiable shares.
+///
+/// The returned shares can be passed directly to
+/// [`Recipient::from_shares`][`crate::dkgd::recipient::Recipient::from_shares`],
+/// which verifies them against the checking values.
+///
+/// # Parameters
+///
+/// - `election_hash`: the expected hash of the election configuration
+/// - `secret_key`: the recipient's secret encryption key
+/// - `trustee_keys`: the public signing keys of all `P` dealers, in position order
+/// - `check_values`: the public check value messages of all `P` dealers, in position order
+/// - `pairwise_shares`: the pairwise share messages addressed to this recipient
+///   from all `P` dealers, in position order
+///
+/// # Errors
+///
+/// - `ShareTransportError` if some message does not match the election hash, its
+///   dealer's signing key, or this recipient's key, or if some signature does not verify
+/// - `HybridError` if some share cannot be decrypted
+pub fn receive_shares<C: Context, const T: usize, const P: usize>(
+    election_hash: &[u8],
+    secret_key: &C::Scalar,
+    trustee_keys: &[VerifyingKey<C>; P],
+    check_values: &[&PublicCheckValueMessage<C, T>; P],
+    pairwise_shares: &[&PairwiseShareMessage<C>; P],
+) -> Result<[VerifiableShare<C, T>; P], Error> {
+    let shares = trustee_keys
+        .iter()
+        .zip(check_values.iter())
+        .zip(pairwise_shares.iter())
+        .map(|((trustee_key, check_values), pairwise_share)| {
+            check_values.verify(election_hash, trustee_key)?;
+            let value = pairwise_share.open(election_hash, secret_key, trustee_key)?;
+
+            Ok(VerifiableShare::new(
+                value,
+                check_values.check_values.clone(),
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    shares
+        .try_into()
+        .map_err(|_| Error::ShareTransportError("Unexpected number of shares".to_string()))
+}
+
+/// Sign the given bytes.
+///
+/// # Errors
+///
+/// - `ShareTransportError` if signing fails
+fn sign<C: Context>(signer: &SigningKey<C>, message: &[u8]) -> Result<Signature<C>, Error> {
+    signer
+        .try_sign(message)
+        .map_err(|e| Error::ShareTransportError(format!("Failed to sign message: {e}")))
+}
+
+/// Verify a signature on the given bytes.
+///
+/// # Errors
+///
+/// - `ShareTransportError` if the signature does not verify
+fn verify<C: Context>(
+    public_key: &VerifyingKey<C>,
+    message: &[u8],
+    signature: &Signature<C>,
+) -> Result<(), Error> {
+    public_key
+        .verify(message, signature)
+        .map_err(|e| Error::ShareTransportError(format!("Invalid message signature: {e}")))
+}
+
+/// Check that a message belongs to the expected election and was sent by the expected trustee.
+///
+/// # Errors
+///
+/// - `ShareTransportError` if either value does not match
+fn check_header<C: Context>(
+    election_hash: &Bytes,
+    expected_hash: &[u8],
+    public_key: &VerifyingKey<C>,
+    trustee_key: &VerifyingKey<C>,
+) -> Result<(), Error> {
+    if election_hash.0 != expected_hash {
+        return Err(Error::ShareTransportError(
+            "Message is for a different election".to_string(),
+        ));
+    }
+    if public_key.ser() != trustee_key.ser() {
+        return Err(Error::ShareTransportError(
+            "Message is signed by an unexpected trustee".to_string(),
+        ));
+    }
+
+    Ok(())
+}
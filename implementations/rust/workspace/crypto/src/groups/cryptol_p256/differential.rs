@@ -0,0 +1,293 @@
+/*
+ * Differential tests between the P-256 backends
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+//! Runs the same operations with [`P256Ctx`] and [`CryptolP256Ctx`] and checks that
+//! they give byte-identical results.
+//!
+//! Randomized operations run with [`P256Ctx`]. Their inputs and outputs are then
+//! carried over to [`CryptolP256Ctx`] through serialization, where the deterministic
+//! operations, including proof verification, run again. The seeded tests instead run
+//! the whole protocol under [`SeededCtx`] of each backend from the same seed.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+use crate::context::Context;
+use crate::context::CryptolP256Ctx as CCtx;
+use crate::context::P256Ctx as PCtx;
+use crate::context::SeededCtx;
+use crate::cryptosystem::elgamal::{Ciphertext, KeyPair};
+use crate::dkgd::dealer::{Dealer, DealerShares, VerifiableShare};
+use crate::dkgd::recipient::{
+    DecryptionFactor, DkgCiphertext, DkgPublicKey, ParticipantPosition, Recipient, combine,
+};
+use crate::groups::cryptol_p256::CryptolP256Group;
+use crate::groups::p256::P256Group;
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::utils::rng::SeededRng;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use crate::zkp::shuffle::{ShuffleProof, Shuffler};
+use rand::RngCore;
+use std::array;
+
+/// Carries a value over to the other backend through its serialization.
+fn transcode<A: VSerializable, B: VDeserializable>(value: &A) -> B {
+    B::deser(&value.ser()).unwrap()
+}
+
+/// Asserts that two values of either backend have the same serialization.
+fn assert_same<A: VSerializable, B: VSerializable>(lhs: &A, rhs: &B) {
+    assert_eq!(lhs.ser(), rhs.ser());
+}
+
+#[test]
+fn test_differential_group() {
+    let a = PCtx::random_scalar();
+    let b = PCtx::random_scalar();
+
+    let p_ga = P256Group::g_exp(&a);
+    let c_ga = CryptolP256Group::g_exp(&a);
+    assert_same(&p_ga, &c_ga);
+
+    let p_e = PCtx::random_element();
+    let c_e: <CCtx as Context>::Element = transcode(&p_e);
+    assert_same(&p_e.exp(&b), &c_e.exp(&b));
+    assert_same(&p_e.mul(&p_ga), &c_e.mul(&c_ga));
+    assert_same(&p_e.inv(), &c_e.inv());
+    assert_same(&p_e.mul(&p_e.inv()), &c_e.mul(&c_e.inv()));
+
+    let p_h = P256Group::hash_to_element(&[b"input"], &[b"tag"]).unwrap();
+    let c_h = CryptolP256Group::hash_to_element(&[b"input"], &[b"tag"]).unwrap();
+    assert_same(&p_h, &c_h);
+
+    let p_gens = P256Group::ind_generators(3, b"label").unwrap();
+    let c_gens = CryptolP256Group::ind_generators(3, b"label").unwrap();
+    assert_same(&p_gens, &c_gens);
+}
+
+#[test]
+fn test_differential_encoding() {
+    // the first valid point of [1u8; 30] has an odd y-coordinate under the Cryptol `enc`
+    let mut random = [0u8; 30];
+    PCtx::get_rng().fill_bytes(&mut random);
+    for plaintext in [[0u8; 30], [1u8; 30], [7u8; 30], [0xffu8; 30], random] {
+        let p_encoded = P256Group::encode(&plaintext).unwrap();
+        let c_encoded = CryptolP256Group::encode(&plaintext).unwrap();
+        assert_same(&p_encoded, &c_encoded);
+
+        // each backend decodes the encoding of the other
+        assert_eq!(
+            P256Group::decode(&transcode(&c_encoded)).unwrap(),
+            plaintext
+        );
+        let p_carried: <CCtx as Context>::Element = transcode(&p_encoded);
+        assert_eq!(CryptolP256Group::decode(&p_carried).unwrap(), plaintext);
+
+        // both backends reject the inverse of an encoding
+        assert!(P256Group::decode(&p_encoded.inv()).is_err());
+        assert!(CryptolP256Group::decode(&c_encoded.inv()).is_err());
+
+        // an encoding survives encryption and decryption with the other backend
+        let keypair: KeyPair<PCtx> = KeyPair::generate();
+        let ciphertext = keypair.encrypt(&[transcode(&c_encoded)]);
+        let decrypted: [<CCtx as Context>::Element; 1] = transcode(&keypair.decrypt(&ciphertext));
+        assert_same(&decrypted[0], &c_encoded);
+        assert_eq!(CryptolP256Group::decode(&decrypted[0]).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_differential_elgamal() {
+    const W: usize = 2;
+
+    let sk = PCtx::random_scalar();
+    let p_keypair: KeyPair<PCtx> = KeyPair::new(sk, P256Group::g_exp(&sk));
+    let c_keypair: KeyPair<CCtx> = KeyPair::new(sk, CryptolP256Group::g_exp(&sk));
+    assert_same(&p_keypair.pkey, &c_keypair.pkey);
+
+    let p_message: [<PCtx as Context>::Element; W] = array::from_fn(|_| PCtx::random_element());
+    let c_message: [<CCtx as Context>::Element; W] = transcode(&p_message);
+    let r: [<PCtx as Context>::Scalar; W] = array::from_fn(|_| PCtx::random_scalar());
+
+    let p_ciphertext = p_keypair.encrypt_with_r(&p_message, &r);
+    let c_ciphertext = c_keypair.encrypt_with_r(&c_message, &r);
+    assert_same(&p_ciphertext, &c_ciphertext);
+
+    let p_decrypted = p_keypair.decrypt(&p_ciphertext);
+    let c_decrypted = c_keypair.decrypt(&c_ciphertext);
+    assert_same(&p_decrypted, &c_decrypted);
+    assert_same(&p_decrypted, &c_message);
+
+    let r: [<PCtx as Context>::Scalar; W] = array::from_fn(|_| PCtx::random_scalar());
+    let p_reencrypted = p_ciphertext.re_encrypt(&r, &p_keypair.pkey.y);
+    let c_reencrypted = c_ciphertext.re_encrypt(&r, &c_keypair.pkey.y);
+    assert_same(&p_reencrypted, &c_reencrypted);
+}
+
+#[test]
+fn test_differential_dkgd() {
+    test_differential_dkgd_t::<2, 3, 2>();
+}
+
+/// Runs a DKG with `P` participants and threshold `T` with both backends.
+fn test_differential_dkgd_t<const T: usize, const P: usize, const W: usize>() {
+    let dealers: [Dealer<PCtx, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let p_shares: [DealerShares<PCtx, T, P>; P] = dealers.map(|d| d.get_verifiable_shares());
+    let c_shares: [DealerShares<CCtx, T, P>; P] = p_shares.each_ref().map(transcode);
+
+    let p_recipients: [(Recipient<PCtx, T, P>, DkgPublicKey<PCtx, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares: [VerifiableShare<PCtx, T>; P] =
+            p_shares.each_ref().map(|s| s.for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+    let c_recipients: [(Recipient<CCtx, T, P>, DkgPublicKey<CCtx, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares: [VerifiableShare<CCtx, T>; P] =
+            c_shares.each_ref().map(|s| s.for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+
+    for (p, c) in p_recipients.iter().zip(c_recipients.iter()) {
+        assert_same(&p.1, &c.1);
+        assert_same(p.0.get_verification_key(), c.0.get_verification_key());
+    }
+
+    let p_message: [<PCtx as Context>::Element; W] = array::from_fn(|_| PCtx::random_element());
+    let p_ciphertexts: Vec<DkgCiphertext<PCtx, W, T>> = vec![p_recipients[0].1.encrypt(&p_message)];
+    let c_ciphertexts: Vec<DkgCiphertext<CCtx, W, T>> = transcode(&p_ciphertexts);
+
+    let p_dfactors: [Vec<DecryptionFactor<PCtx, P, W>>; T] = array::from_fn(|i| {
+        p_recipients[i]
+            .0
+            .decryption_factor(&p_ciphertexts, &[])
+            .unwrap()
+    });
+    let c_dfactors: [Vec<DecryptionFactor<CCtx, P, W>>; T] = p_dfactors.each_ref().map(transcode);
+
+    let p_keys: [<PCtx as Context>::Element; T] =
+        array::from_fn(|i| *p_recipients[i].0.get_verification_key());
+    let c_keys: [<CCtx as Context>::Element; T] =
+        array::from_fn(|i| c_recipients[i].0.get_verification_key().clone());
+
+    // the Cryptol-derived backend verifies the decryption proofs
+    let p_decrypted = combine(&p_ciphertexts, &p_dfactors, &p_keys, &[]).unwrap();
+    let c_decrypted = combine(&c_ciphertexts, &c_dfactors, &c_keys, &[]).unwrap();
+    assert_same(&p_decrypted, &c_decrypted);
+    assert_same(&p_decrypted[0], &p_message);
+}
+
+#[test]
+fn test_differential_shuffle() {
+    const W: usize = 1;
+    let count = 3;
+
+    let p_keypair: KeyPair<PCtx> = KeyPair::generate();
+    let p_ciphertexts: Vec<Ciphertext<PCtx, W>> = (0..count)
+        .map(|_| p_keypair.encrypt(&[PCtx::random_element()]))
+        .collect();
+    let p_generators = P256Group::ind_generators(count, &[]).unwrap();
+    let p_shuffler = Shuffler::<PCtx, W>::new(p_generators, p_keypair.pkey.clone());
+    let (p_output, p_proof) = p_shuffler.shuffle(&p_ciphertexts, &[]).unwrap();
+
+    let c_ciphertexts: Vec<Ciphertext<CCtx, W>> = transcode(&p_ciphertexts);
+    let c_output: Vec<Ciphertext<CCtx, W>> = transcode(&p_output);
+    let c_proof: ShuffleProof<CCtx, W> = transcode(&p_proof);
+    let c_generators = CryptolP256Group::ind_generators(count, &[]).unwrap();
+    let c_shuffler = Shuffler::<CCtx, W>::new(c_generators, transcode(&p_keypair.pkey));
+
+    let p_ok = p_shuffler.verify(&p_ciphertexts, &p_output, &p_proof, &[]);
+    let c_ok = c_shuffler.verify(&c_ciphertexts, &c_output, &c_proof, &[]);
+    assert!(p_ok.unwrap());
+    assert!(c_ok.unwrap());
+
+    // a proof over a different context is rejected by both backends
+    let p_ok = p_shuffler.verify(&p_ciphertexts, &p_output, &p_proof, &[1u8]);
+    let c_ok = c_shuffler.verify(&c_ciphertexts, &c_output, &c_proof, &[1u8]);
+    assert!(!p_ok.unwrap());
+    assert!(!c_ok.unwrap());
+}
+
+#[test]
+fn test_differential_dkgd_seeded() {
+    let p_artifacts = seeded_dkgd::<SeededCtx<PCtx>, 2, 3, 2>([1u8; 32]);
+    let c_artifacts = seeded_dkgd::<SeededCtx<CCtx>, 2, 3, 2>([1u8; 32]);
+    assert_eq!(p_artifacts, c_artifacts);
+}
+
+/// Runs a DKG and a threshold decryption with the seeded context `S`, and returns the
+/// serialized shares, keys, ciphertexts, decryption factors and plaintexts.
+fn seeded_dkgd<S: Context, const T: usize, const P: usize, const W: usize>(
+    seed: [u8; 32],
+) -> Vec<Vec<u8>> {
+    SeededRng::seed(seed);
+    let dealers: [Dealer<S, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let shares: [DealerShares<S, T, P>; P] = dealers.map(|d| d.get_verifiable_shares());
+
+    let recipients: [(Recipient<S, T, P>, DkgPublicKey<S, T>); P] = array::from_fn(|i| {
+        let position = ParticipantPosition::from_usize(i + 1);
+        let shares: [VerifiableShare<S, T>; P] =
+            shares.each_ref().map(|s| s.for_recipient(&position));
+        Recipient::from_shares(position, &shares).unwrap()
+    });
+    let pk = &recipients[0].1;
+
+    let message: [S::Element; W] = array::from_fn(|_| S::random_element());
+    let ciphertexts: Vec<DkgCiphertext<S, W, T>> = vec![pk.encrypt(&message)];
+
+    let dfactors: [Vec<DecryptionFactor<S, P, W>>; T] = array::from_fn(|i| {
+        recipients[i]
+            .0
+            .decryption_factor(&ciphertexts, &[])
+            .unwrap()
+    });
+    let keys: [S::Element; T] = array::from_fn(|i| recipients[i].0.get_verification_key().clone());
+
+    let decrypted = combine(&ciphertexts, &dfactors, &keys, &[]).unwrap();
+    assert_eq!(decrypted[0], message);
+
+    vec![
+        shares.ser(),
+        pk.ser(),
+        keys.ser(),
+        ciphertexts.ser(),
+        dfactors.ser(),
+        decrypted.ser(),
+    ]
+}
+
+#[test]
+fn test_differential_shuffle_seeded() {
+    let p_artifacts = seeded_shuffle::<SeededCtx<PCtx>, 2>([1u8; 32]);
+    let c_artifacts = seeded_shuffle::<SeededCtx<CCtx>, 2>([1u8; 32]);
+    assert_eq!(p_artifacts, c_artifacts);
+}
+
+/// Runs a key generation, encryption and shuffle with the seeded context `S`, and returns
+/// the serialized key, ciphertexts, shuffled ciphertexts and proof.
+fn seeded_shuffle<S: Context, const W: usize>(seed: [u8; 32]) -> Vec<Vec<u8>> {
+    SeededRng::seed(seed);
+    let count = 3;
+    let keypair: KeyPair<S> = KeyPair::generate();
+    let ciphertexts: Vec<Ciphertext<S, W>> = (0..count)
+        .map(|_| keypair.encrypt(&array::from_fn(|_| S::random_element())))
+        .collect();
+
+    let generators = S::G::ind_generators(count, &[]).unwrap();
+    let shuffler = Shuffler::<S, W>::new(generators, keypair.pkey.clone());
+    let (output, proof) = shuffler.shuffle(&ciphertexts, &[]).unwrap();
+    assert!(shuffler.verify(&ciphertexts, &output, &proof, &[]).unwrap());
+
+    vec![
+        keypair.pkey.ser(),
+        ciphertexts.ser(),
+        output.ser(),
+        proof.ser(),
+    ]
+}
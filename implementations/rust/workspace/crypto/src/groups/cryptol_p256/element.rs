@@ -0,0 +1,211 @@
+/*
+ * GroupElement implementations for the Cryptol-derived P-256 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::p256::P256Scalar;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::{Debug, Formatter};
+use elgamal_p256::algebra::groups::inst::pfec_group_p256 as pfec;
+use elgamal_p256::algebra::groups::inst::pfec_group_p256::Inimportat32point as Point;
+use elgamal_p256::cry_rts::Z;
+use num::BigInt;
+use num::BigUint;
+use num::bigint::Sign;
+use p256::elliptic_curve::sec1::{Coordinates, FromEncodedPoint, ToEncodedPoint};
+use p256::{AffinePoint, EncodedPoint, ProjectivePoint};
+use std::sync::LazyLock;
+
+/// The prime `p` of the field of definition of P-256
+static FIELD_MODULUS: LazyLock<BigUint> = LazyLock::new(|| {
+    BigUint::parse_bytes(
+        b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        16,
+    )
+    .expect("constant is valid hexadecimal")
+});
+
+/// The length of a compressed SEC1 point encoding
+const ENCODED_LENGTH: usize = 33;
+
+/**
+ * A [`GroupElement`] implementation for the P-256 curve, backed by the
+ * Cryptol-derived `elgamal_p256` crate.
+ *
+ * Group operations delegate to the implementation generated from the
+ * project's Cryptol models. Serialization is byte-compatible with
+ * [`P256Element`](crate::groups::p256::P256Element).
+ */
+#[derive(Clone)]
+pub struct CryptolP256Element(pub(crate) Point);
+
+impl CryptolP256Element {
+    /// Create a new `CryptolP256Element` from a p256 [`ProjectivePoint`](https://docs.rs/p256/latest/p256/type.ProjectivePoint.html).
+    #[must_use]
+    pub fn new(point: &ProjectivePoint) -> Self {
+        match point.to_affine().to_encoded_point(false).coordinates() {
+            Coordinates::Uncompressed { x, y } => {
+                CryptolP256Element(Point::Affine(to_z(x), to_z(y)))
+            }
+            // only the identity has no uncompressed coordinates
+            _ => Self::one(),
+        }
+    }
+
+    /// Returns the x-coordinate, or `None` for the identity.
+    pub(crate) fn x(&self) -> Option<BigInt> {
+        match &self.0 {
+            Point::Affine(x, _) => Some(x.from_z()),
+            Point::Infinity() => None,
+        }
+    }
+
+    /// Returns `true` if the y-coordinate is odd, `false` if it is even or for the identity.
+    pub(crate) fn y_is_odd(&self) -> bool {
+        match &self.0 {
+            Point::Affine(_, y) => y.from_z().bit(0),
+            Point::Infinity() => false,
+        }
+    }
+
+    /// Returns the compressed SEC1 encoding, or all zeros for the identity.
+    fn to_bytes(&self) -> [u8; ENCODED_LENGTH] {
+        let mut ret = [0u8; ENCODED_LENGTH];
+        if let Point::Affine(x, _) = &self.0 {
+            ret[0] = if self.y_is_odd() { 0x03 } else { 0x02 };
+            ret[1..].copy_from_slice(&to_bytes_be(&x.from_z()));
+        }
+
+        ret
+    }
+}
+
+/// Returns the field element with the given big-endian bytes.
+fn to_z(bytes: &[u8]) -> Z {
+    Z {
+        remainder: BigInt::from_bytes_be(Sign::Plus, bytes),
+        modulus: FIELD_MODULUS.clone(),
+    }
+}
+
+/// Returns the 32 big-endian bytes of a non-negative integer smaller than `2^256`.
+// the input is a reduced field element, so that its length is at most 32
+#[allow(clippy::arithmetic_side_effects)]
+fn to_bytes_be(value: &BigInt) -> [u8; 32] {
+    let (_, bytes) = value.to_bytes_be();
+    let mut ret = [0u8; 32];
+    ret[32 - bytes.len()..].copy_from_slice(&bytes);
+
+    ret
+}
+
+/// Returns the integer value of a scalar, in the range `[0, n)`.
+pub(crate) fn to_integer(scalar: &P256Scalar) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &scalar.0.to_bytes())
+}
+
+impl GroupElement for CryptolP256Element {
+    type Scalar = P256Scalar;
+
+    fn one() -> Self {
+        CryptolP256Element(Point::Infinity())
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        CryptolP256Element(pfec::in_import_at__32_scmul(
+            &to_integer(&P256Scalar::random(rng)),
+            &pfec::g,
+        ))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        CryptolP256Element(pfec::gop(&self.0, &other.0))
+    }
+
+    fn inv(&self) -> Self {
+        CryptolP256Element(pfec::inv(&self.0))
+    }
+
+    fn exp(&self, scalar: &Self::Scalar) -> Self {
+        CryptolP256Element(pfec::in_import_at__32_scmul(&to_integer(scalar), &self.0))
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        pfec::in_import_at__32_affine_eq(&self.0, &other.0)
+    }
+}
+
+impl Debug for CryptolP256Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CryptolP256Element({})", hex::encode(self.to_bytes()))
+    }
+}
+
+impl PartialEq for CryptolP256Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+impl Eq for CryptolP256Element {}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+impl VSerializable for CryptolP256Element {
+    fn ser(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl VDeserializable for CryptolP256Element {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; ENCODED_LENGTH]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 33]".to_string())
+        })?;
+        if bytes == [0u8; ENCODED_LENGTH] {
+            return Ok(CryptolP256Element::one());
+        }
+
+        // point decompression is not part of the Cryptol models
+        let point = EncodedPoint::from_bytes(bytes).map_err(|_| {
+            CryptoError::DeserializationError("Failed to parse P256 encoded point".to_string())
+        })?;
+        let point: Option<AffinePoint> = AffinePoint::from_encoded_point(&point).into();
+        let point = point.ok_or(CryptoError::DeserializationError(
+            "Failed to parse P256 point bytes".to_string(),
+        ))?;
+        let ret = CryptolP256Element::new(&point.into());
+
+        if pfec::g_1(&ret.0) {
+            Ok(ret)
+        } else {
+            Err(CryptoError::DeserializationError(
+                "P256 point is not on the curve".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+
+impl FSerializable for CryptolP256Element {
+    fn size_bytes() -> usize {
+        ENCODED_LENGTH
+    }
+
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.to_bytes());
+    }
+}
+
+impl FDeserializable for CryptolP256Element {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * CryptoGroup implementations for the Cryptol-derived P-256 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::cryptol_p256::element::CryptolP256Element;
+use crate::groups::cryptol_p256::table::CryptolP256Table;
+use crate::groups::p256::{P256Group, P256Scalar};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+
+use elgamal_p256::algebra::groups::inst::pfec_group_p256 as pfec;
+use elgamal_p256::dword::DWord;
+use num::BigUint;
+
+use crate::utils::error::Error;
+use crate::utils::hash;
+use crate::utils::rng;
+
+/// The bit length of messages accepted by the Cryptol `enc` function
+const ENCODING_BITS: usize = 247;
+
+/// The bit length of plaintexts, which are placed in the low bits of the message
+const PLAINTEXT_BITS: u64 = 240;
+
+/// The bit length of x-coordinates that the Cryptol `dec` function handles
+const DECODING_BITS: u64 = 255;
+
+/**
+ * P-256 implementation of [`CryptoGroup`], backed by the Cryptol-derived `elgamal_p256` crate.
+ *
+ * The group operations, message encoding and decoding delegate to the
+ * Cryptol-derived implementation. Hashing to scalars and elements is not
+ * part of the Cryptol models and delegates to [`P256Group`], so that both
+ * backends compute the same values.
+ *
+ * Both backends encode a plaintext above an 8-bit counter in the x-coordinate,
+ * and choose the point with the even y-coordinate, so that encodings are also
+ * byte-identical.
+ */
+pub struct CryptolP256Group;
+
+impl CryptoGroup for CryptolP256Group {
+    type Element = CryptolP256Element;
+    type Scalar = P256Scalar;
+    type Hasher = hash::Hasher256;
+    type Plaintext = [u8; 30];
+    type Message = Self::Element;
+    type Table = CryptolP256Table;
+
+    fn generator() -> Self::Element {
+        CryptolP256Element(pfec::g.clone())
+    }
+
+    fn g_exp(scalar: &Self::Scalar) -> Self::Element {
+        Self::generator().exp(scalar)
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if [`P256Group::hash_to_scalar`] returns error
+    fn hash_to_scalar(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Scalar, Error> {
+        P256Group::hash_to_scalar(input_slices, ds_tags)
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToElementError` if [`P256Group::hash_to_element`] returns error
+    fn hash_to_element(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Element, Error> {
+        let ret = P256Group::hash_to_element(input_slices, ds_tags)?;
+
+        Ok(CryptolP256Element::new(&ret.0))
+    }
+
+    fn random_element<R: rng::CRng>(rng: &mut R) -> Self::Element {
+        Self::Element::random(rng)
+    }
+
+    fn random_scalar<R: rng::CRng>(rng: &mut R) -> Self::Scalar {
+        P256Group::random_scalar(rng)
+    }
+
+    // Koblitz encoding, with the 240 bits of input as the low bits of a
+    // 247-bit message, see `enc` in the Cryptol models
+    /// # Errors
+    ///
+    /// Infallible
+    ///
+    /// # Panics
+    ///
+    /// The Cryptol `enc` fails to find a point with negligible probability
+    /// `2^-256`, and panics.
+    fn encode(input: &Self::Plaintext) -> Result<Self::Message, Error> {
+        let message = DWord::from_uint(ENCODING_BITS, &BigUint::from_bytes_be(input));
+        let point = CryptolP256Element(pfec::enc(message.as_ref()));
+
+        // the Cryptol `enc` returns either square root, choose the one with the
+        // even y-coordinate, as `P256Group::encode` does
+        if point.y_is_odd() {
+            return Ok(point.inv());
+        }
+
+        Ok(point)
+    }
+
+    /// # Errors
+    ///
+    /// - `EncodingError` if the input point is not the [encoding][`Self::encode`] of
+    ///   any plaintext; this includes the identity
+    fn decode(message: &Self::Message) -> Result<Self::Plaintext, Error> {
+        let not_encoded =
+            || Error::EncodingError("P256 point is not an encoded plaintext".to_string());
+
+        // the Cryptol `dec` is only defined for points other than the identity, and
+        // truncates x-coordinates to 255 bits
+        let x = message.x().ok_or_else(not_encoded)?;
+        if x.bits() > DECODING_BITS {
+            return Err(not_encoded());
+        }
+
+        let bits = pfec::dec(&message.0);
+        let value = BigUint::from(bits.as_ref());
+        if value.bits() > PLAINTEXT_BITS {
+            return Err(not_encoded());
+        }
+
+        let bytes = value.to_bytes_be();
+        let mut ret = Self::Plaintext::default();
+        let start = ret.len().checked_sub(bytes.len()).ok_or_else(not_encoded)?;
+        ret[start..].copy_from_slice(&bytes);
+
+        // reject points other than the one found by encode, so that decode is
+        // the exact inverse of encode
+        if Self::encode(&ret)? != *message {
+            return Err(not_encoded());
+        }
+
+        Ok(ret)
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToElementError` if [`P256Group::ind_generators`] returns error
+    fn ind_generators(count: usize, label: &[u8]) -> Result<Vec<Self::Element>, Error> {
+        let ret = P256Group::ind_generators(count, label)?;
+
+        Ok(ret.iter().map(|e| CryptolP256Element::new(&e.0)).collect())
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * Group over curve P-256, backed by the Cryptol-derived implementation
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+pub use crate::groups::p256::P256Scalar;
+pub use element::CryptolP256Element;
+pub use group::CryptolP256Group;
+pub use table::CryptolP256Table;
+
+/// Cryptol-derived P-256 implementation of [`GroupElement`](crate::traits::groups::GroupElement)
+pub mod element;
+
+/// Cryptol-derived P-256 implementation of [`CryptoGroup`](crate::traits::groups::CryptoGroup)
+pub mod group;
+
+/// Cryptol-derived P-256 implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests;
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod differential;
@@ -0,0 +1,35 @@
+/*
+ * FixedBaseTable implementation for the Cryptol-derived P-256 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::cryptol_p256::element::CryptolP256Element;
+use crate::groups::p256::P256Scalar;
+use crate::traits::groups::FixedBaseTable;
+use crate::traits::groups::GroupElement;
+
+/**
+ * A [`FixedBaseTable`] implementation for the Cryptol-derived P-256 group.
+ *
+ * No multiples are precomputed, so that every exponentiation runs through
+ * the Cryptol-derived scalar multiplication.
+ */
+#[derive(Clone)]
+pub struct CryptolP256Table {
+    /// the base of exponentiations
+    base: CryptolP256Element,
+}
+
+impl FixedBaseTable<CryptolP256Element> for CryptolP256Table {
+    fn new(base: &CryptolP256Element) -> Self {
+        CryptolP256Table { base: base.clone() }
+    }
+
+    fn exp(&self, scalar: &P256Scalar) -> CryptolP256Element {
+        self.base.exp(scalar)
+    }
+}
@@ -0,0 +1,251 @@
+/*
+ * Cryptol-derived P-256 module tests
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use super::*;
+use crate::context::Context;
+use crate::context::CryptolP256Ctx as Ctx;
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+#[test]
+fn test_cryptol_p256_element_power_product() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+    let g = Ctx::generator();
+
+    let e1 = g.exp(&s1);
+    let e2 = g.exp(&s2);
+    let e3_sum = e1.mul(&e2);
+
+    let s_sum = s1.add(&s2);
+    let e3_expected = g.exp(&s_sum);
+
+    assert_eq!(
+        e3_sum, e3_expected,
+        "Element addition failed: e1+e2 != (s1+s2)*G"
+    );
+}
+
+#[test]
+fn test_cryptol_p256_element_inv() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e = g.exp(&s);
+
+    let e_neg = e.inv();
+    let e_plus_e_neg = e.mul(&e_neg);
+
+    assert_eq!(
+        e_plus_e_neg,
+        CryptolP256Element::one(),
+        "Element negation failed: e + (-e) != Id"
+    );
+
+    let s_neg = s.neg();
+    let e_neg_expected = g.exp(&s_neg);
+    assert_eq!(
+        e_neg, e_neg_expected,
+        "Element negation failed: (-s)*G != -(s*G)"
+    );
+}
+
+#[test]
+fn test_cryptol_p256_element_power_power() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+    let g = Ctx::generator();
+
+    let e1 = g.exp(&s1);
+    let e2 = e1.exp(&s2);
+
+    let s_prod = s1.mul(&s2);
+    let e_expected = g.exp(&s_prod);
+
+    assert_eq!(e2, e_expected, "Element scalar multiplication failed");
+}
+
+#[test]
+fn test_cryptol_p256_element_identity_properties() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e = g.exp(&s);
+    let id = CryptolP256Element::one();
+
+    assert_eq!(e.mul(&id), e, "e + Id != e");
+    assert_eq!(id.mul(&e), e, "Id + e != e");
+
+    let zero_scalar = P256Scalar::zero();
+    assert_eq!(g.exp(&zero_scalar), id, "G^0 != Id");
+}
+
+#[test]
+fn test_cryptol_p256_element_mul_commutativity() {
+    let e1 = Ctx::random_element();
+    let e2 = Ctx::random_element();
+
+    let sum1 = e1.mul(&e2);
+    let sum2 = e2.mul(&e1);
+
+    assert_eq!(sum1, sum2, "Element multiplication is not commutative");
+}
+
+#[test]
+fn test_cryptol_p256_scalar_element_addition_distributivity() {
+    let s_op = Ctx::random_scalar();
+
+    let e1 = Ctx::random_element();
+    let e2 = Ctx::random_element();
+
+    // (e1 * e2)^s
+    let sum_elements = e1.mul(&e2);
+    let lhs = sum_elements.exp(&s_op);
+
+    // (e1^s) * (e2^s)
+    let term1 = e1.exp(&s_op);
+    let term2 = e2.exp(&s_op);
+    let rhs = term1.mul(&term2);
+
+    assert_eq!(lhs, rhs, "Distributivity (e1*e2)^s = e1^s * e2^s failed");
+}
+
+#[test]
+fn test_cryptol_p256_element_serialization() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e_orig = g.exp(&s);
+
+    let serialized_e = e_orig.ser();
+    assert_eq!(serialized_e.len(), 33, "Serialized element length mismatch");
+
+    let e_deserialized = CryptolP256Element::deser(&serialized_e).unwrap();
+    assert_eq!(
+        e_orig, e_deserialized,
+        "Original and deserialized elements do not match"
+    );
+
+    let e_id = CryptolP256Element::one();
+    let ser_id = e_id.ser();
+    let des_id = CryptolP256Element::deser(&ser_id).unwrap();
+    assert_eq!(e_id, des_id);
+
+    // test wrong length
+    let bytes = [0u8; 40];
+    let result = CryptolP256Element::deser(&bytes);
+    assert!(result.is_err());
+
+    // test bad data
+    // this array of bytes does not correspond to a point
+    let bytes = [1u8; 33];
+    let result = CryptolP256Element::deser(&bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cryptol_p256_hash_to_element_different_inputs() {
+    let input1 = b"test input 1";
+    let input2 = b"test input 2";
+    let tag = b"domain_sep";
+
+    let elem1 = CryptolP256Group::hash_to_element(&[input1], &[tag]).unwrap();
+    let elem2 = CryptolP256Group::hash_to_element(&[input2], &[tag]).unwrap();
+    let elem3 = CryptolP256Group::hash_to_element(&[input1], &[tag]).unwrap();
+
+    // Different inputs should yield different elements
+    assert_ne!(elem1, elem2);
+    // Same input should yield the same element
+    assert_eq!(elem1, elem3);
+}
+
+#[test]
+fn test_cryptol_p256_encode_decode() {
+    use rand::Rng;
+
+    let mut rng = Ctx::get_rng();
+    let mut lhs = [0u8; 30];
+    rng.fill(&mut lhs[..]);
+
+    let element = CryptolP256Group::encode(&lhs).unwrap();
+    let rhs = CryptolP256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_cryptol_p256_encode_decode_boundary() {
+    for byte in [0x00u8, 0x01, 0x7f, 0x80, 0xff] {
+        let lhs = [byte; 30];
+
+        let element = CryptolP256Group::encode(&lhs).unwrap();
+        let rhs = CryptolP256Group::decode(&element).unwrap();
+
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn test_cryptol_p256_encode_injective() {
+    let mut lhs = [0u8; 30];
+    let e1 = CryptolP256Group::encode(&lhs).unwrap();
+    lhs[29] = 1;
+    let e2 = CryptolP256Group::encode(&lhs).unwrap();
+
+    assert_ne!(e1, e2);
+}
+
+#[test]
+fn test_cryptol_p256_encode_survives_serialization() {
+    let lhs = [7u8; 30];
+    let element = CryptolP256Group::encode(&lhs).unwrap();
+
+    let bytes = element.ser();
+    let element = CryptolP256Element::deser(&bytes).unwrap();
+    let rhs = CryptolP256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_cryptol_p256_decode_rejects_non_encoded() {
+    // the identity has no x-coordinate
+    let result = CryptolP256Group::decode(&CryptolP256Element::one());
+    assert!(result.is_err());
+
+    // the generator's x-coordinate is too large to hold a 240-bit plaintext
+    let result = CryptolP256Group::decode(&Ctx::generator());
+    assert!(result.is_err());
+
+    // the inverse of an encoded point is not the point chosen by the encoding
+    let element = CryptolP256Group::encode(&[3u8; 30]).unwrap();
+    let result = CryptolP256Group::decode(&element.inv());
+    assert!(result.is_err());
+
+    // a random point is an encoding with negligible probability
+    let result = CryptolP256Group::decode(&Ctx::random_element());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cryptol_p256_fixed_base_table() {
+    use crate::traits::groups::FixedBaseTable;
+
+    let base = Ctx::random_element();
+    let table = CryptolP256Table::new(&base);
+    let scalars = [
+        P256Scalar::zero(),
+        P256Scalar::one(),
+        P256Scalar::one().neg(),
+        Ctx::random_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(table.exp(s), base.exp(s));
+        assert_eq!(CryptolP256Group::g_exp(s), Ctx::generator().exp(s));
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * GroupElement implementations for the ElectionGuard group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::electionguard::group::EgGroup;
+use crate::groups::electionguard::parameters::{P, P_LIMBS, P_PARAMS, Q};
+use crate::groups::electionguard::scalar::EgScalar;
+use crate::traits::groups::{CryptoGroup, GroupElement, GroupScalar};
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use crypto_bigint::modular::runtime_mod::DynResidue;
+use crypto_bigint::{Encoding, U4096};
+
+/**
+ * A [`GroupElement`] implementation for the `ElectionGuard` group.
+ *
+ * An element of the subgroup of order [`q`][`crate::groups::electionguard::parameters::Q`]
+ * of `Z_p^*`, stored in Montgomery form. Elements can only be constructed
+ * through group operations or from integers that are checked to be in the
+ * subgroup, see [`EgElement::from_value`].
+ *
+ * Serializes as in `ElectionGuard`, as a 512 byte big-endian integer.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EgElement(U4096);
+
+impl EgElement {
+    /// Create a new `EgElement` from an integer in `[1, p)`.
+    ///
+    /// # Errors
+    ///
+    /// - `DeserializationError` if the integer is not in `[1, p)` or is not an element of the subgroup of order `q`
+    pub fn from_value(value: &U4096) -> Result<Self, CryptoError> {
+        if *value == U4096::ZERO || *value >= P {
+            return Err(CryptoError::DeserializationError(
+                "ElectionGuard element is not in [1, p)".to_string(),
+            ));
+        }
+        let element = Self::from_residue(&DynResidue::new(value, *P_PARAMS));
+        // subgroup membership, x^q = 1
+        let one = Self::one();
+        if element.residue().pow(&Q).as_montgomery() == &one.0 {
+            Ok(element)
+        } else {
+            Err(CryptoError::DeserializationError(
+                "ElectionGuard element is not in the subgroup of order q".to_string(),
+            ))
+        }
+    }
+
+    /// Returns the value of this element as an integer in `[1, p)`.
+    #[must_use]
+    pub fn value(&self) -> U4096 {
+        self.residue().retrieve()
+    }
+
+    /// Returns this element as a residue modulo `p`.
+    pub(crate) fn residue(&self) -> DynResidue<P_LIMBS> {
+        DynResidue::from_montgomery(self.0, *P_PARAMS)
+    }
+
+    /// Create a new `EgElement` from a residue modulo `p`, which must be in the subgroup.
+    pub(crate) fn from_residue(residue: &DynResidue<P_LIMBS>) -> Self {
+        EgElement(*residue.as_montgomery())
+    }
+}
+
+impl GroupElement for EgElement {
+    type Scalar = EgScalar;
+
+    fn one() -> Self {
+        Self::from_residue(&DynResidue::one(*P_PARAMS))
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        EgGroup::g_exp(&EgScalar::random(rng))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self::from_residue(&self.residue().mul(&other.residue()))
+    }
+
+    fn inv(&self) -> Self {
+        // x^-1 = x^(q - 1) in the subgroup of order q
+        self.exp(&EgScalar::one().neg())
+    }
+
+    fn exp(&self, scalar: &Self::Scalar) -> Self {
+        Self::from_residue(&self.residue().pow(scalar.value()))
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+impl VSerializable for EgElement {
+    fn ser(&self) -> Vec<u8> {
+        self.value().to_be_bytes().to_vec()
+    }
+}
+
+impl VDeserializable for EgElement {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 512]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 512]".to_string())
+        })?;
+
+        Self::from_value(&U4096::from_be_bytes(bytes))
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+
+impl FSerializable for EgElement {
+    fn size_bytes() -> usize {
+        512
+    }
+
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.value().to_be_bytes());
+    }
+}
+
+impl FDeserializable for EgElement {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
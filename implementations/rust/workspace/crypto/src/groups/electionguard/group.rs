@@ -0,0 +1,163 @@
+/*
+ * CryptoGroup implementations for the ElectionGuard group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::electionguard::element::EgElement;
+use crate::groups::electionguard::hash::{self as eg_hash, EgHasher, PARAMETER_BASE_HASH};
+use crate::groups::electionguard::parameters::{G, P, P_PARAMS, R};
+use crate::groups::electionguard::scalar::EgScalar;
+use crate::groups::electionguard::table::EgTable;
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::FixedBaseTable;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+
+use crate::utils::error::Error;
+use crate::utils::hash;
+use crate::utils::hash::Hasher;
+use crate::utils::rng;
+
+use crypto_bigint::modular::runtime_mod::DynResidue;
+use crypto_bigint::{Encoding, NonZero, U256, U4096};
+use sha3::Digest;
+use std::sync::LazyLock;
+
+/// The number of 256-bit hash blocks expanded to obtain an integer modulo `p`
+const HASH_BLOCKS: u32 = 16;
+
+/// The precomputed table of the default generator, computed on first use
+static GENERATOR_TABLE: LazyLock<EgTable> = LazyLock::new(|| EgTable::new(&EgGroup::generator()));
+
+/**
+ * `ElectionGuard` implementation of [`CryptoGroup`]
+ *
+ * The subgroup of order `q` of `Z_p^*`, with the `ElectionGuard` 2.x standard
+ * baseline [parameters][`crate::groups::electionguard::parameters`].
+ *
+ * This group has no efficient injective encoding of byte strings into elements,
+ * [`CryptoGroup::encode`] and [`CryptoGroup::decode`] always fail. Use
+ * [exponential `ElGamal`][`crate::cryptosystem::exponential`] to encrypt
+ * integers instead, as `ElectionGuard` does.
+ *
+ * Hashing uses the `ElectionGuard` [hash functions][`crate::groups::electionguard::hash`]
+ * keyed with the parameter base hash `H_P`: [`CryptoGroup::hash_to_scalar`]
+ * computes `H_q(H_P; ·)`, so that Fiat-Shamir challenges are `H_q` values.
+ * `ElectionGuard` does not hash into the group, [`CryptoGroup::hash_to_element`]
+ * and [`CryptoGroup::ind_generators`] expand `H(H_P; ·)` into an integer modulo
+ * `p` and map it into the subgroup.
+ */
+#[derive(Debug, Clone)]
+pub struct EgGroup;
+
+impl EgGroup {
+    /// Map a hash into the subgroup.
+    ///
+    /// Expands the hash into [`HASH_BLOCKS`] blocks `H(H_P; seed, block)` of
+    /// 256 bits, interprets them as an integer `x` modulo `p`, and returns `x^r`.
+    /// Nobody knows the discrete log of the result with respect to the generator.
+    fn element_from_hash(hasher: EgHasher) -> EgElement {
+        let seed = hasher.finalize();
+        let mut bytes = Vec::with_capacity(512);
+        for block in 0..HASH_BLOCKS {
+            bytes.extend_from_slice(&eg_hash::h(
+                PARAMETER_BASE_HASH.as_slice(),
+                &[&seed, &block.to_be_bytes()],
+            ));
+        }
+        let p = NonZero::<U4096>::from_uint(P);
+        let x = U4096::from_be_slice(&bytes).rem(&p);
+        let x = DynResidue::new(&x, *P_PARAMS);
+
+        EgElement::from_residue(&x.pow_bounded_exp(&R, R.bits()))
+    }
+}
+
+impl CryptoGroup for EgGroup {
+    type Element = EgElement;
+    type Scalar = EgScalar;
+    type Hasher = EgHasher;
+    type Plaintext = [u8; 30];
+    type Message = Self::Element;
+    type Table = EgTable;
+
+    fn generator() -> Self::Element {
+        EgElement::from_residue(&DynResidue::new(&G, *P_PARAMS))
+    }
+
+    fn g_exp(scalar: &Self::Scalar) -> Self::Element {
+        GENERATOR_TABLE.exp(scalar)
+    }
+
+    /// # Errors
+    ///
+    /// Infallible
+    fn hash_to_scalar(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Scalar, Error> {
+        let mut hasher = Self::Hasher::hasher();
+        hash::update_hasher(&mut hasher, input_slices, ds_tags);
+
+        // H_q(H_P; ·), 256 bits reduced modulo q = 2^256 - 189
+        let value = U256::from_be_bytes(hasher.finalize().into());
+
+        Ok(EgScalar::new(&value))
+    }
+
+    /// # Errors
+    ///
+    /// Infallible
+    fn hash_to_element(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Element, Error> {
+        let mut hasher = Self::Hasher::hasher();
+        hash::update_hasher(&mut hasher, input_slices, ds_tags);
+
+        Ok(Self::element_from_hash(hasher))
+    }
+
+    fn random_element<R: rng::CRng>(rng: &mut R) -> Self::Element {
+        Self::Element::random(rng)
+    }
+
+    fn random_scalar<R: rng::CRng>(rng: &mut R) -> Self::Scalar {
+        Self::Scalar::random(rng)
+    }
+
+    /// # Errors
+    ///
+    /// - `EncodingError` always, this group does not support encoding
+    fn encode(_p: &Self::Plaintext) -> Result<Self::Message, Error> {
+        Err(Error::EncodingError(
+            "Encoding is not supported by the ElectionGuard group".to_string(),
+        ))
+    }
+
+    /// # Errors
+    ///
+    /// - `EncodingError` always, this group does not support encoding
+    fn decode(_p: &Self::Message) -> Result<Self::Plaintext, Error> {
+        Err(Error::EncodingError(
+            "Decoding is not supported by the ElectionGuard group".to_string(),
+        ))
+    }
+
+    /// # Errors
+    ///
+    /// Infallible
+    fn ind_generators(count: usize, label: &[u8]) -> Result<Vec<Self::Element>, Error> {
+        let mut hasher = Self::Hasher::hasher();
+        hasher.update(label);
+        hasher.update(b"independent_generators_electionguard");
+
+        let ret: Vec<EgElement> = (0..count)
+            .map(|i| {
+                let mut hasher = hasher.clone();
+                hasher.update(i.to_be_bytes());
+                Self::element_from_hash(hasher)
+            })
+            .collect();
+
+        Ok(ret)
+    }
+}
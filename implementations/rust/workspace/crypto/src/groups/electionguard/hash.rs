@@ -0,0 +1,109 @@
+/*
+ * The ElectionGuard hash functions
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::electionguard::parameters::{G, P, Q};
+use crate::groups::electionguard::scalar::EgScalar;
+use crate::utils::hash::Hasher;
+use crypto_bigint::{Encoding, U256};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sha2::digest::consts::U32;
+use sha2::digest::{FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+use std::sync::LazyLock;
+
+/// The `ElectionGuard` specification version `ver`, `v2.1.0` padded with zeros to 32 bytes
+pub const VERSION: [u8; 32] = *b"v2.1.0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+/// The parameter base hash `H_P = H(ver; 0x00, p, q, g)`, computed on first use
+pub static PARAMETER_BASE_HASH: LazyLock<[u8; 32]> = LazyLock::new(|| {
+    h(
+        &VERSION,
+        &[
+            &[0x00],
+            &P.to_be_bytes(),
+            &Q.to_be_bytes(),
+            &G.to_be_bytes(),
+        ],
+    )
+});
+
+/// The `ElectionGuard` hash function `H(key; data) = HMAC-SHA-256(key, data)`.
+///
+/// # Parameters
+///
+/// - `key`: the key `B_0`, usually a previously computed hash
+/// - `data`: the slices of `B_1`, each already in `ElectionGuard` serialization
+///
+/// Returns the 32 byte hash.
+#[must_use]
+pub fn h(key: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let mut mac = keyed(key);
+    for slice in data {
+        Mac::update(&mut mac, slice);
+    }
+
+    mac.finalize().into_bytes().into()
+}
+
+/// The `ElectionGuard` hash function into scalars, `H_q(key; data) = H(key; data) mod q`.
+///
+/// # Parameters
+///
+/// - `key`: the key `B_0`, usually a previously computed hash
+/// - `data`: the slices of `B_1`, each already in `ElectionGuard` serialization
+#[must_use]
+pub fn h_q(key: &[u8], data: &[&[u8]]) -> EgScalar {
+    EgScalar::new(&U256::from_be_bytes(h(key, data)))
+}
+
+/// Returns an HMAC-SHA-256 instance with the given key.
+fn keyed(key: &[u8]) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length")
+}
+
+/**
+ * The `ElectionGuard` hash `H(H_P; ·)`, keyed with the [parameter base hash][`PARAMETER_BASE_HASH`].
+ *
+ * The hashing function of the [`ElectionGuard` group][`crate::groups::EgGroup`],
+ * and therefore of [`EgCtx`][`crate::context::EgCtx`]. Every hash computed in
+ * that context, including Fiat-Shamir challenges, is bound to the group
+ * parameters through `H_P`.
+ */
+#[derive(Debug, Clone)]
+pub struct EgHasher(Hmac<Sha256>);
+
+impl Default for EgHasher {
+    fn default() -> Self {
+        EgHasher(keyed(PARAMETER_BASE_HASH.as_slice()))
+    }
+}
+
+impl Update for EgHasher {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.0, data);
+    }
+}
+
+impl OutputSizeUser for EgHasher {
+    type OutputSize = U32;
+}
+
+impl FixedOutput for EgHasher {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        FixedOutput::finalize_into(self.0, out);
+    }
+}
+
+impl HashMarker for EgHasher {}
+
+impl Hasher for EgHasher {
+    fn hasher() -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * Group over the ElectionGuard integer group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+pub use element::EgElement;
+pub use group::EgGroup;
+pub use hash::EgHasher;
+pub use scalar::EgScalar;
+pub use table::EgTable;
+
+/// `ElectionGuard` implementation of [`GroupElement`](crate::traits::groups::GroupElement)
+pub mod element;
+
+/// `ElectionGuard` implementation of [`CryptoGroup`](crate::traits::groups::CryptoGroup)
+pub mod group;
+
+/// The `ElectionGuard` hash functions `H` and `H_q`
+pub mod hash;
+
+/// `ElectionGuard` 2.x standard baseline parameters
+pub mod parameters;
+
+/// `ElectionGuard` implementation of [`GroupScalar`](crate::traits::groups::GroupScalar)
+pub mod scalar;
+
+/// `ElectionGuard` implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests;
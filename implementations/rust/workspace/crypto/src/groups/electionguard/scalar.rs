@@ -0,0 +1,137 @@
+/*
+ * GroupScalar implementations for the ElectionGuard group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::electionguard::parameters::{Q, Q_C, Q_NONZERO};
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use crypto_bigint::{Encoding, Limb, RandomMod, U256};
+
+/**
+ * A [`GroupScalar`] implementation for the `ElectionGuard` group.
+ *
+ * An integer modulo the order [`q`][`crate::groups::electionguard::parameters::Q`]
+ * of the group, always reduced.
+ *
+ * Serializes as in `ElectionGuard`, as a 32 byte big-endian integer.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EgScalar(U256);
+
+impl EgScalar {
+    /// Create a new `EgScalar` from an integer, reducing it modulo `q`.
+    #[must_use]
+    pub fn new(value: &U256) -> Self {
+        // q > 2^255, so a single subtraction reduces any 256-bit integer
+        EgScalar(value.sub_mod(&Q, &Q))
+    }
+
+    /// Returns the value of this scalar as an integer in `[0, q)`.
+    #[must_use]
+    pub fn value(&self) -> &U256 {
+        &self.0
+    }
+}
+
+impl GroupScalar for EgScalar {
+    fn zero() -> Self {
+        EgScalar(U256::ZERO)
+    }
+
+    fn one() -> Self {
+        EgScalar(U256::ONE)
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        EgScalar(U256::random_mod(rng, &Q_NONZERO))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        EgScalar(self.0.add_mod(&other.0, &Q))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        EgScalar(self.0.sub_mod(&other.0, &Q))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        // q = 2^256 - 189
+        EgScalar(self.0.mul_mod_special(&other.0, Limb::from_u32(Q_C)))
+    }
+
+    fn neg(&self) -> Self {
+        EgScalar(self.0.neg_mod(&Q))
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if self.0 == U256::ZERO {
+            return None;
+        }
+        let (inverted, is_some) = self.0.inv_odd_mod(&Q);
+        if bool::from(is_some) {
+            Some(EgScalar(inverted))
+        } else {
+            None
+        }
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<u32> for EgScalar {
+    fn from(u: u32) -> EgScalar {
+        EgScalar(U256::from_u32(u))
+    }
+}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+impl VSerializable for EgScalar {
+    fn ser(&self) -> Vec<u8> {
+        self.0.to_be_bytes().to_vec()
+    }
+}
+
+impl VDeserializable for EgScalar {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 32]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 32]".to_string())
+        })?;
+        let value = U256::from_be_bytes(bytes);
+
+        if value < Q {
+            Ok(EgScalar(value))
+        } else {
+            Err(CryptoError::DeserializationError(
+                "ElectionGuard scalar is not reduced modulo q".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+
+impl FSerializable for EgScalar {
+    fn size_bytes() -> usize {
+        32
+    }
+
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.0.to_be_bytes());
+    }
+}
+
+impl FDeserializable for EgScalar {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
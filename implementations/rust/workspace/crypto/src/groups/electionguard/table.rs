@@ -0,0 +1,96 @@
+/*
+ * FixedBaseTable implementation for the ElectionGuard group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::electionguard::element::EgElement;
+use crate::groups::electionguard::parameters::P_PARAMS;
+use crate::groups::electionguard::scalar::EgScalar;
+use crate::traits::groups::{FixedBaseTable, GroupElement};
+use crypto_bigint::modular::runtime_mod::DynResidue;
+use crypto_bigint::subtle::{ConditionallySelectable, ConstantTimeEq};
+use crypto_bigint::{Encoding, U4096};
+
+/// The number of 4-bit windows of a 256-bit scalar
+const WINDOWS: usize = 64;
+
+/// The number of entries per window, one for each 4-bit digit
+const ENTRIES: usize = 16;
+
+/**
+ * A [`FixedBaseTable`] implementation for the `ElectionGuard` group.
+ *
+ * The scalar is split into 64 digits of 4 bits, `s = sum_i d_i 16^i`. For each
+ * window `i` the table stores the 16 powers `B^(k 16^i)` of the base `B`, so
+ * that an exponentiation takes 64 modular multiplications and no squarings.
+ * Every entry of a window is read to select the one for digit `d_i`, so that
+ * the memory access pattern does not depend on the scalar. The table takes
+ * 512 KiB.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::EgCtx;
+ * use crypto::groups::electionguard::EgTable;
+ * use crypto::traits::groups::{FixedBaseTable, GroupElement};
+ *
+ * let base = EgCtx::random_element();
+ * let table = EgTable::new(&base);
+ * let scalar = EgCtx::random_scalar();
+ *
+ * assert_eq!(table.exp(&scalar), base.exp(&scalar));
+ * ```
+ */
+#[derive(Clone)]
+pub struct EgTable {
+    /// the powers `B^(k 16^i)` for `k` in `0..16`, for each window `i`, in Montgomery form
+    windows: Vec<[U4096; ENTRIES]>,
+}
+
+impl FixedBaseTable<EgElement> for EgTable {
+    // index arithmetic, bounded by the table size
+    #[allow(clippy::arithmetic_side_effects)]
+    fn new(base: &EgElement) -> Self {
+        let one = *EgElement::one().residue().as_montgomery();
+        let mut windows = Vec::with_capacity(WINDOWS);
+        // B^(16^i)
+        let mut window_base = base.residue();
+        for _ in 0..WINDOWS {
+            let mut entries = [one; ENTRIES];
+            let mut power = window_base;
+            for entry in entries.iter_mut().skip(1) {
+                *entry = *power.as_montgomery();
+                power = power.mul(&window_base);
+            }
+            window_base = power;
+            windows.push(entries);
+        }
+
+        EgTable { windows }
+    }
+
+    // index arithmetic, bounded by the scalar length
+    #[allow(clippy::arithmetic_side_effects)]
+    fn exp(&self, scalar: &EgScalar) -> EgElement {
+        // big-endian
+        let bytes = scalar.value().to_be_bytes();
+        let mut ret = DynResidue::one(*P_PARAMS);
+        for (i, entries) in self.windows.iter().enumerate() {
+            let byte = bytes[bytes.len() - 1 - i / 2];
+            let digit = if i % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+
+            let mut selected = entries[0];
+            for (k, entry) in (0u8..).zip(entries.iter()) {
+                selected.conditional_assign(entry, k.ct_eq(&digit));
+            }
+            ret = ret.mul(&DynResidue::from_montgomery(selected, *P_PARAMS));
+        }
+
+        EgElement::from_residue(&ret)
+    }
+}
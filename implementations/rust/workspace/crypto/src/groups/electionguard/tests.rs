@@ -0,0 +1,172 @@
+/*
+ * ElectionGuard group module tests
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use super::*;
+use crate::context::Context;
+use crate::context::EgCtx as Ctx;
+use crate::groups::electionguard::parameters::{G, P, Q, R};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::FixedBaseTable;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{Encoding, U256, U4096};
+
+#[test]
+fn test_electionguard_parameters() {
+    // p = q r + 1
+    let q: U4096 = Q.resize();
+    assert_eq!(q.wrapping_mul(&R).wrapping_add(&U4096::ONE), P);
+
+    // g = 2^r mod p
+    let params = DynResidueParams::new(&P);
+    let two = DynResidue::new(&U4096::from_u32(2), params);
+    assert_eq!(two.pow(&R).retrieve(), G);
+
+    // g has order q
+    let g = DynResidue::new(&G, params);
+    let one = DynResidue::one(params);
+    assert_ne!(g, one);
+    assert_eq!(g.pow(&Q), one);
+    assert_eq!(EgElement::from_value(&G).unwrap(), Ctx::generator());
+    assert_ne!(Ctx::generator(), EgElement::one());
+}
+
+#[test]
+fn test_electionguard_scalar_arithmetic() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+
+    assert_eq!(s1.neg().add(&s1), EgScalar::zero());
+    assert_eq!(s1.sub(&s2).add(&s2), s1);
+    assert_eq!(s1.mul(&s1.inv().unwrap()), EgScalar::one());
+    assert!(EgScalar::zero().inv().is_none());
+
+    // q - 1 = -1
+    let q_minus_one = EgScalar::new(&Q.wrapping_sub(&U256::ONE));
+    assert_eq!(q_minus_one, EgScalar::one().neg());
+    assert_eq!(q_minus_one.mul(&q_minus_one), EgScalar::one());
+    assert_eq!(EgScalar::new(&Q), EgScalar::zero());
+
+    let five: EgScalar = 5u32.into();
+    let three: EgScalar = 3u32.into();
+    assert_eq!(five.mul(&three), 15u32.into());
+}
+
+#[test]
+fn test_electionguard_element_arithmetic() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+    let g = Ctx::generator();
+
+    assert_eq!(g.exp(&s1).mul(&g.exp(&s2)), g.exp(&s1.add(&s2)));
+    assert_eq!(g.exp(&s1).exp(&s2), g.exp(&s1.mul(&s2)));
+
+    let e = Ctx::random_element();
+    assert_eq!(e.mul(&e.inv()), EgElement::one());
+    assert_eq!(e.mul(&EgElement::one()), e);
+}
+
+#[test]
+fn test_electionguard_fixed_base_table() {
+    let base = Ctx::random_element();
+    let table = EgTable::new(&base);
+    let scalars = [
+        EgScalar::zero(),
+        EgScalar::one(),
+        EgScalar::one().neg(),
+        Ctx::random_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(table.exp(s), base.exp(s));
+        assert_eq!(EgGroup::g_exp(s), Ctx::generator().exp(s));
+    }
+}
+
+#[test]
+fn test_electionguard_serialization() {
+    let e = Ctx::random_element();
+    let bytes = e.ser();
+    assert_eq!(bytes.len(), 512);
+    assert_eq!(EgElement::deser(&bytes).unwrap(), e);
+
+    let s = Ctx::random_scalar();
+    let bytes = s.ser();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(EgScalar::deser(&bytes).unwrap(), s);
+
+    // out of range
+    assert!(EgElement::deser(&[0u8; 512]).is_err());
+    assert!(EgElement::deser(&P.to_be_bytes()).is_err());
+    assert!(EgScalar::deser(&Q.to_be_bytes()).is_err());
+    // not in the subgroup, p - 1 has order 2
+    assert!(EgElement::deser(&P.wrapping_sub(&U4096::ONE).to_be_bytes()).is_err());
+    assert!(EgElement::deser(&[1u8; 33]).is_err());
+
+    // ElectionGuard serialization, fixed width big-endian integers
+    assert_eq!(Ctx::generator().ser(), G.to_be_bytes().to_vec());
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert_eq!(EgScalar::one().ser(), one.to_vec());
+    assert_eq!(
+        EgScalar::one().neg().ser(),
+        Q.wrapping_sub(&U256::ONE).to_be_bytes().to_vec()
+    );
+}
+
+#[test]
+fn test_electionguard_hash_functions() {
+    // HMAC-SHA-256, RFC 4231 test case 2
+    let expected = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+    let value = hash::h(b"Jefe", &[b"what do ya ", b"want for nothing?"]);
+    assert_eq!(hex::encode(value), expected);
+
+    // H_P = H(ver; 0x00, p, q, g) for the v2.1.0 baseline parameters
+    let expected = "df88a9239d48e28e0b1ade3538b0cf907b18acb306186d8b502d09619511f183";
+    assert_eq!(hex::encode(*hash::PARAMETER_BASE_HASH), expected);
+
+    // H_q is H reduced modulo q
+    let value = hash::h(&*hash::PARAMETER_BASE_HASH, &[b"input"]);
+    let expected = EgScalar::new(&U256::from_be_bytes(value));
+    assert_eq!(
+        hash::h_q(&*hash::PARAMETER_BASE_HASH, &[b"input"]),
+        expected
+    );
+
+    // hashing to scalars is H_q(H_P; ·)
+    assert_eq!(
+        EgGroup::hash_to_scalar(&[b"input"], &[b"tag"]).unwrap(),
+        hash::h_q(&*hash::PARAMETER_BASE_HASH, &[b"input", b"tag"])
+    );
+}
+
+#[test]
+fn test_electionguard_hash() {
+    let e1 = EgGroup::hash_to_element(&[b"input"], &[b"tag"]).unwrap();
+    let e2 = EgGroup::hash_to_element(&[b"input"], &[b"other tag"]).unwrap();
+    assert_ne!(e1, e2);
+    // in the subgroup
+    assert_eq!(EgElement::deser(&e1.ser()).unwrap(), e1);
+
+    let s1 = EgGroup::hash_to_scalar(&[b"input"], &[b"tag"]).unwrap();
+    let s2 = EgGroup::hash_to_scalar(&[b"input"], &[b"tag"]).unwrap();
+    assert_eq!(s1, s2);
+
+    let generators = EgGroup::ind_generators(3, b"label").unwrap();
+    assert_eq!(generators.len(), 3);
+    assert_ne!(generators[0], generators[1]);
+    assert_eq!(generators, EgGroup::ind_generators(3, b"label").unwrap());
+}
+
+#[test]
+fn test_electionguard_encode_unsupported() {
+    assert!(EgGroup::encode(&[0u8; 30]).is_err());
+    assert!(EgGroup::decode(&Ctx::generator()).is_err());
+}
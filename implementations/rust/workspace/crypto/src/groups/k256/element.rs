@@ -0,0 +1,139 @@
+/*
+ * GroupElement implementations for secp256k1 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::k256::scalar::K256Scalar;
+use crate::groups::msm;
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use k256::elliptic_curve::Group;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::subtle::CtOption;
+use k256::{EncodedPoint, ProjectivePoint};
+
+/**
+ * A [`GroupElement`] implementation for the secp256k1 curve.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct K256Element(pub ProjectivePoint);
+
+impl K256Element {
+    /// Create a new `K256Element` from a [`ProjectivePoint`](https://docs.rs/k256/latest/k256/struct.ProjectivePoint.html).
+    #[must_use]
+    pub fn new(point: ProjectivePoint) -> Self {
+        K256Element(point)
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+impl GroupElement for K256Element {
+    type Scalar = K256Scalar;
+
+    fn one() -> Self {
+        K256Element(ProjectivePoint::IDENTITY)
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        K256Element::new(ProjectivePoint::random(rng))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        K256Element(self.0 + other.0)
+    }
+
+    fn inv(&self) -> Self {
+        K256Element(-self.0)
+    }
+
+    fn exp(&self, scalar: &Self::Scalar) -> Self {
+        K256Element(self.0 * scalar.0)
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// Variable time multi-exponentiation with Pippenger's bucket method.
+    fn multi_exp(bases: &[Self], scalars: &[Self::Scalar]) -> Self {
+        let points: Vec<ProjectivePoint> = bases.iter().map(|b| b.0).collect();
+        let scalars: Vec<k256::Scalar> = scalars.iter().map(|s| s.0).collect();
+
+        K256Element(msm::pippenger(&points, &scalars))
+    }
+}
+
+impl PartialEq for K256Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+impl Eq for K256Element {}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+impl VSerializable for K256Element {
+    fn ser(&self) -> Vec<u8> {
+        let bytes = self.0.to_affine().to_encoded_point(true).to_bytes();
+        if bytes.len() == 33 {
+            bytes.to_vec()
+        } else {
+            [0u8; 33].to_vec()
+        }
+    }
+}
+
+impl VDeserializable for K256Element {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 33]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 33]".to_string())
+        })?;
+        if bytes == [0u8; 33] {
+            return Ok(K256Element::one());
+        }
+
+        let point = EncodedPoint::from_bytes(bytes).map_err(|_| {
+            CryptoError::DeserializationError("Failed to parse K256 encoded point".to_string())
+        })?;
+        let point: CtOption<K256Element> =
+            ProjectivePoint::from_encoded_point(&point).map(K256Element);
+
+        if point.is_some().into() {
+            Ok(point.expect("point.is_some() == true"))
+        } else {
+            Err(CryptoError::DeserializationError(
+                "Failed to parse K256 point bytes".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+
+impl FSerializable for K256Element {
+    fn size_bytes() -> usize {
+        33
+    }
+
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        let point = self.0.to_affine().to_encoded_point(true);
+        let bytes = point.as_bytes();
+        if bytes.len() == 33 {
+            buffer.extend_from_slice(bytes);
+        } else {
+            buffer.extend_from_slice(&[0u8; 33]);
+        }
+    }
+}
+
+impl FDeserializable for K256Element {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * CryptoGroup implementations for the secp256k1 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::k256::element::K256Element;
+use crate::groups::k256::scalar::K256Scalar;
+use crate::groups::k256::table::K256Table;
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::FixedBaseTable;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+
+use k256::elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Secp256k1};
+
+use crate::utils::error::Error;
+use crate::utils::hash;
+use crate::utils::rng;
+
+use std::sync::OnceLock;
+
+/// The precomputed table of the default generator, computed on first use
+static GENERATOR_TABLE: OnceLock<K256Table> = OnceLock::new();
+
+/// secp256k1 implementation of [`CryptoGroup`]
+pub struct K256Group;
+
+#[allow(clippy::arithmetic_side_effects)]
+impl CryptoGroup for K256Group {
+    type Element = K256Element;
+    type Scalar = K256Scalar;
+    type Hasher = hash::Hasher256;
+    type Plaintext = [u8; 30];
+    type Message = Self::Element;
+    type Table = K256Table;
+
+    fn generator() -> Self::Element {
+        K256Element::new(ProjectivePoint::GENERATOR)
+    }
+
+    fn g_exp(scalar: &Self::Scalar) -> Self::Element {
+        GENERATOR_TABLE
+            .get_or_init(|| K256Table::new(&Self::generator()))
+            .exp(scalar)
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if `Secp256k1::hash_to_scalar` returns error
+    fn hash_to_scalar(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Scalar, Error> {
+        let ret = Secp256k1::hash_to_scalar::<ExpandMsgXmd<Self::Hasher>>(input_slices, ds_tags);
+
+        Ok(K256Scalar(ret?))
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToElementError` if `Secp256k1::hash_from_bytes` returns error
+    fn hash_to_element(input_slices: &[&[u8]], ds_tags: &[&[u8]]) -> Result<Self::Element, Error> {
+        let ret = Secp256k1::hash_from_bytes::<ExpandMsgXmd<Self::Hasher>>(input_slices, ds_tags);
+        let ret: Result<ProjectivePoint, Error> =
+            ret.map_err(|e| Error::HashToElementError(e.to_string()));
+
+        Ok(K256Element(ret?))
+    }
+
+    fn random_element<R: rng::CRng>(rng: &mut R) -> Self::Element {
+        Self::Element::random(rng)
+    }
+
+    fn random_scalar<R: rng::CRng>(rng: &mut R) -> Self::Scalar {
+        Self::Scalar::random(rng)
+    }
+
+    // Try-and-increment on the x-coordinate, see `MapToGroup` in
+    // Boneh, Lynn, Shacham: "Short signatures from the Weil pairing"
+    //
+    // The compressed point encoding is laid out as
+    //
+    // [0x02, 0x00, input (30 bytes), counter]
+    //
+    // The leading zero byte ensures that the x-coordinate is smaller than the field
+    // modulus, and the 0x02 tag selects the point with even y-coordinate. Each counter
+    // value yields a valid x-coordinate with probability ~1/2.
+    /// # Errors
+    ///
+    /// - `EncodingError` if a point was not found for the input, with negligible probability
+    fn encode(input: &Self::Plaintext) -> Result<Self::Message, Error> {
+        let mut bytes = [0u8; 33];
+        bytes[0] = ENCODING_TAG;
+        bytes[2..32].copy_from_slice(input);
+        for counter in 0..=u8::MAX {
+            bytes[32] = counter;
+            if let Some(point) = decompress(&bytes) {
+                return Ok(K256Element::new(point.into()));
+            }
+        }
+        Err(Error::EncodingError(
+            "Failed to encode into k256 point".to_string(),
+        ))
+    }
+
+    /// # Errors
+    ///
+    /// - `EncodingError` if the input point is not the [encoding][`Self::encode`] of
+    ///   any plaintext; this includes the identity and points with an odd y-coordinate
+    fn decode(message: &Self::Message) -> Result<Self::Plaintext, Error> {
+        let point = message.0.to_affine().to_encoded_point(true);
+        let bytes = point.as_bytes();
+        // the identity encodes to a single byte
+        if bytes.len() != 33 || bytes[0] != ENCODING_TAG || bytes[1] != 0 {
+            return Err(Error::EncodingError(
+                "K256 point is not an encoded plaintext".to_string(),
+            ));
+        }
+        // the 30 bytes of data are placed in the range 2-31
+        let ret: Self::Plaintext = bytes[2..32].try_into().expect("slice.len() == 30");
+
+        // reject points that use a counter other than the first valid one, so that
+        // decode is the exact inverse of encode
+        if Self::encode(&ret)? != *message {
+            return Err(Error::EncodingError(
+                "K256 point is not an encoded plaintext".to_string(),
+            ));
+        }
+
+        Ok(ret)
+    }
+
+    /// # Errors
+    ///
+    /// - `HashToElementError` if `Secp256k1::hash_from_bytes` returns error
+    fn ind_generators(count: usize, label: &[u8]) -> Result<Vec<Self::Element>, Error> {
+        let ds_tags: &[&[u8]] = &[b"context", b"independent_generators_k256_counter"];
+        let mut ret = vec![];
+
+        #[crate::warning("The following code is not optimized. Parallelize with rayon")]
+        for i in 0..count {
+            let inputs = &[label, &i.to_be_bytes()];
+            let point = Secp256k1::hash_from_bytes::<ExpandMsgXmd<Self::Hasher>>(inputs, ds_tags);
+            let point = point?;
+            ret.push(K256Element(point));
+        }
+
+        Ok(ret)
+    }
+}
+
+/// SEC1 tag for compressed points with even y-coordinate, used for [encoding][`K256Group::encode`].
+const ENCODING_TAG: u8 = 0x02;
+
+/// Decompress a SEC1 compressed point, returning `None` if the bytes do not correspond to a point.
+fn decompress(bytes: &[u8; 33]) -> Option<AffinePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).ok()?;
+    AffinePoint::from_encoded_point(&encoded).into()
+}
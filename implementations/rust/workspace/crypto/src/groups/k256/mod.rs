@@ -0,0 +1,29 @@
+/*
+ * Group over curve secp256k1
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+pub use element::K256Element;
+pub use group::K256Group;
+pub use scalar::K256Scalar;
+pub use table::K256Table;
+
+/// secp256k1 implementation of [`GroupElement`](crate::traits::groups::GroupElement)
+pub mod element;
+
+/// secp256k1 implementation of [`CryptoGroup`](crate::traits::groups::CryptoGroup)
+pub mod group;
+
+/// secp256k1 implementation of [`GroupScalar`](crate::traits::groups::GroupScalar)
+pub mod scalar;
+
+/// secp256k1 implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests;
@@ -0,0 +1,136 @@
+/*
+ * GroupScalar implementations for secp256k1 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use k256::Scalar;
+use k256::Scalar as K256CrateScalar;
+use k256::elliptic_curve::Field;
+use std::ops::Neg;
+
+/**
+ * A [`GroupScalar`] implementation for the secp256k1 group.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct K256Scalar(pub Scalar);
+
+impl K256Scalar {
+    /// Create a new `K256Scalar` from a k256 [Scalar](https://docs.rs/k256/latest/k256/struct.Scalar.html).
+    #[must_use]
+    pub fn new(scalar: Scalar) -> Self {
+        K256Scalar(scalar)
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+impl GroupScalar for K256Scalar {
+    fn zero() -> Self {
+        K256Scalar(Scalar::ZERO)
+    }
+
+    fn one() -> Self {
+        K256Scalar(Scalar::ONE)
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        let scalar = K256CrateScalar::random(rng);
+        K256Scalar::new(scalar)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        K256Scalar(self.0 + other.0)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        K256Scalar(self.0 - other.0)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        K256Scalar(self.0 * other.0)
+    }
+
+    fn neg(&self) -> Self {
+        K256Scalar(self.0.neg())
+    }
+
+    fn inv(&self) -> Option<Self> {
+        // k256::Scalar::invert returns a CtOption<Scalar>
+        let inverted = self.0.invert();
+        if inverted.is_some().unwrap_u8() == 1 {
+            Some(K256Scalar(inverted.unwrap()))
+        } else {
+            None
+        }
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<u32> for K256Scalar {
+    fn from(u: u32) -> K256Scalar {
+        let scalar: K256CrateScalar = u.into();
+
+        K256Scalar(scalar)
+    }
+}
+
+impl PartialEq for K256Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+impl Eq for K256Scalar {}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use k256::elliptic_curve::PrimeField;
+
+impl VSerializable for K256Scalar {
+    fn ser(&self) -> Vec<u8> {
+        let bytes = self.0.to_bytes();
+        bytes.to_vec()
+    }
+}
+
+impl VDeserializable for K256Scalar {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 32]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 32]".to_string())
+        })?;
+
+        let scalar = Scalar::from_repr(bytes.into()).map(K256Scalar);
+
+        if scalar.is_some().into() {
+            Ok(scalar.expect("scalar.is_some() == true"))
+        } else {
+            Err(CryptoError::DeserializationError(
+                "Failed to parse K256 scalar bytes".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+impl FSerializable for K256Scalar {
+    fn size_bytes() -> usize {
+        32
+    }
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        let bytes = self.0.to_bytes();
+        buffer.extend(bytes);
+    }
+}
+impl FDeserializable for K256Scalar {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
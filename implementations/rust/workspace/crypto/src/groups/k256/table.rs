@@ -0,0 +1,88 @@
+/*
+ * FixedBaseTable implementation for the secp256k1 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::k256::element::K256Element;
+use crate::groups::k256::scalar::K256Scalar;
+use crate::traits::groups::FixedBaseTable;
+use k256::ProjectivePoint;
+use k256::elliptic_curve::subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// The number of 4-bit windows of a 256-bit scalar
+const WINDOWS: usize = 64;
+
+/// The number of entries per window, one for each 4-bit digit
+const ENTRIES: usize = 16;
+
+/**
+ * A [`FixedBaseTable`] implementation for the secp256k1 group.
+ *
+ * The scalar is split into 64 digits of 4 bits, `s = sum_i d_i 16^i`. For each
+ * window `i` the table stores the 16 multiples `k 16^i B` of the base `B`, so
+ * that an exponentiation takes 64 point additions and no doublings. Every
+ * entry of a window is read to select the one for digit `d_i`, so that the
+ * memory access pattern does not depend on the scalar.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::K256Ctx as PCtx;
+ * use crypto::groups::k256::K256Table;
+ * use crypto::traits::groups::{FixedBaseTable, GroupElement};
+ *
+ * let base = PCtx::random_element();
+ * let table = K256Table::new(&base);
+ * let scalar = PCtx::random_scalar();
+ *
+ * assert_eq!(table.exp(&scalar), base.exp(&scalar));
+ * ```
+ */
+#[derive(Clone)]
+pub struct K256Table {
+    /// the multiples `k 16^i B` for `k` in `0..16`, for each window `i`
+    windows: Vec<[ProjectivePoint; ENTRIES]>,
+}
+
+// curve arithmetic
+#[allow(clippy::arithmetic_side_effects)]
+impl FixedBaseTable<K256Element> for K256Table {
+    fn new(base: &K256Element) -> Self {
+        let mut windows = Vec::with_capacity(WINDOWS);
+        // 16^i B
+        let mut window_base = base.0;
+        for _ in 0..WINDOWS {
+            let mut entries = [ProjectivePoint::IDENTITY; ENTRIES];
+            for k in 1..ENTRIES {
+                entries[k] = entries[k - 1] + window_base;
+            }
+            window_base = entries[ENTRIES - 1] + window_base;
+            windows.push(entries);
+        }
+
+        K256Table { windows }
+    }
+
+    fn exp(&self, scalar: &K256Scalar) -> K256Element {
+        // big-endian
+        let bytes = scalar.0.to_bytes();
+        let mut ret = ProjectivePoint::IDENTITY;
+        for (i, entries) in self.windows.iter().enumerate() {
+            let byte = bytes[bytes.len() - 1 - i / 2];
+            let digit = if i % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+
+            let mut selected = ProjectivePoint::IDENTITY;
+            for (k, entry) in (0u8..).zip(entries.iter()) {
+                selected.conditional_assign(entry, k.ct_eq(&digit));
+            }
+            ret += selected;
+        }
+
+        K256Element(ret)
+    }
+}
@@ -0,0 +1,422 @@
+/*
+ * secp256k1 module tests
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use super::*;
+use crate::context::Context;
+use crate::context::K256Ctx as Ctx;
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+#[test]
+fn test_k256_scalar_from_u32() {
+    use rand::Rng;
+    let u: u32 = Ctx::get_rng().r#gen();
+
+    let _scalar: K256Scalar = u.into();
+    let one: K256Scalar = 1u32.into();
+    assert_eq!(one, K256Scalar::one());
+}
+
+#[test]
+fn test_k256_scalar_negation() {
+    let s1 = Ctx::random_scalar();
+    let s_neg = s1.neg();
+
+    assert_eq!(
+        s_neg.add(&s1),
+        K256Scalar::zero(),
+        "Negation property s + (-s) = 0 failed"
+    );
+}
+
+#[test]
+fn test_k256_scalar_inversion() {
+    let s = Ctx::random_scalar();
+
+    if s != K256Scalar::zero() {
+        let s_inv = s.inv().unwrap();
+        let product = s.mul(&s_inv);
+        assert_eq!(product, K256Scalar::one(), "s * s_inv = 1 property failed");
+    }
+
+    let zero = K256Scalar::zero();
+    assert!(zero.inv().is_none(), "Inversion of zero must be None");
+}
+
+#[test]
+fn test_k256_element_power_product() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+    let g = Ctx::generator();
+
+    let e1 = g.exp(&s1);
+    let e2 = g.exp(&s2);
+    let e3_sum = e1.mul(&e2);
+
+    let s_sum = s1.add(&s2);
+    let e3_expected = g.exp(&s_sum);
+
+    assert_eq!(
+        e3_sum, e3_expected,
+        "Element addition failed: e1+e2 != (s1+s2)*G"
+    );
+}
+
+#[test]
+fn test_k256_element_inv() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e = g.exp(&s);
+
+    let e_neg = e.inv();
+    let e_plus_e_neg = e.mul(&e_neg);
+
+    assert_eq!(
+        e_plus_e_neg,
+        K256Element::one(),
+        "Element negation failed: e + (-e) != Id"
+    );
+
+    let s_neg = s.neg();
+    let e_neg_expected = g.exp(&s_neg);
+    assert_eq!(
+        e_neg, e_neg_expected,
+        "Element negation failed: (-s)*G != -(s*G)"
+    );
+}
+
+#[test]
+fn test_k256_element_power_power() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+    let g = Ctx::generator();
+
+    let e1 = g.exp(&s1);
+    let e2 = e1.exp(&s2);
+
+    let s_prod = s1.mul(&s2);
+    let e_expected = g.exp(&s_prod);
+
+    assert_eq!(e2, e_expected, "Element scalar multiplication failed");
+}
+
+#[test]
+fn test_k256_element_identity_properties() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e = g.exp(&s);
+    let id = K256Element::one();
+
+    assert_eq!(e.mul(&id), e, "e + Id != e");
+    assert_eq!(id.mul(&e), e, "Id + e != e");
+
+    let zero_scalar = K256Scalar::zero();
+    assert_eq!(g.exp(&zero_scalar), id, "G^0 != Id");
+}
+
+#[test]
+fn test_k256_element_mul_commutativity() {
+    let e1 = Ctx::random_element();
+    let e2 = Ctx::random_element();
+
+    let sum1 = e1.mul(&e2);
+    let sum2 = e2.mul(&e1);
+
+    assert_eq!(sum1, sum2, "Element multiplication is not commutative");
+}
+
+#[test]
+fn test_k256_element_mul_associativity() {
+    let e1 = Ctx::random_element();
+    let e2 = Ctx::random_element();
+    let e3 = Ctx::random_element();
+
+    let sum_left_assoc = (e1.mul(&e2)).mul(&e3);
+    let sum_right_assoc = e1.mul(&(e2.mul(&e3)));
+
+    assert_eq!(
+        sum_left_assoc, sum_right_assoc,
+        "Element multiplication is not associative"
+    );
+}
+
+#[test]
+fn test_k256_scalar_element_addition_distributivity() {
+    let s_op = Ctx::random_scalar();
+
+    let e1 = Ctx::random_element();
+    let e2 = Ctx::random_element();
+
+    // (e1 * e2)^s
+    let sum_elements = e1.mul(&e2);
+    let lhs = sum_elements.exp(&s_op);
+
+    // (e1^s) * (e2^s)
+    let term1 = e1.exp(&s_op);
+    let term2 = e2.exp(&s_op);
+    let rhs = term1.mul(&term2);
+
+    assert_eq!(lhs, rhs, "Distributivity (e1*e2)^s = e1^s * e2^s failed");
+}
+
+#[test]
+fn test_k256_scalar_element_mul_distributivity() {
+    let s1 = Ctx::random_scalar();
+    let s2 = Ctx::random_scalar();
+
+    let e = Ctx::random_element();
+
+    let sum_scalars = s1.add(&s2);
+    let lhs = e.exp(&sum_scalars);
+
+    let term1 = e.exp(&s1);
+    let term2 = e.exp(&s2);
+    let rhs = term1.mul(&term2);
+
+    assert_eq!(lhs, rhs, "Distributivity e^(s1+s2) = e^s1 + e^s2 failed");
+}
+
+#[test]
+fn test_k256_group_hash_to_scalar() {
+    let input1 = b"some input data";
+    let input2 = b"other input data";
+    let ds_tag = b"ds tag";
+
+    let s1 = K256Group::hash_to_scalar(&[input1], &[ds_tag]).unwrap();
+    // Same input, same output
+    let s2 = K256Group::hash_to_scalar(&[input1], &[ds_tag]).unwrap();
+    // Different input, different output
+    let s3 = K256Group::hash_to_scalar(&[input2], &[ds_tag]).unwrap();
+
+    assert_eq!(s1, s2, "Hash to scalar not equal for equal input");
+    assert_ne!(
+        s1, s3,
+        "Hash to scalar produces same output for different inputs"
+    );
+}
+
+#[test]
+fn test_k256_element_serialization() {
+    let s = Ctx::random_scalar();
+    let g = Ctx::generator();
+    let e_orig = g.exp(&s);
+
+    let serialized_e = e_orig.ser();
+    assert_eq!(serialized_e.len(), 33, "Serialized element length mismatch");
+
+    let e_deserialized = K256Element::deser(&serialized_e).unwrap();
+    assert_eq!(
+        e_orig, e_deserialized,
+        "Original and deserialized elements do not match"
+    );
+
+    let e_id = K256Element::one();
+    let ser_id = e_id.ser();
+    let des_id = K256Element::deser(&ser_id).unwrap();
+    assert_eq!(e_id, des_id);
+
+    // test wrong length
+    let bytes = [0u8; 40];
+    let result = K256Element::deser(&bytes);
+    assert!(result.is_err());
+
+    // test bad data
+    // this array of bytes does not correspond to a point
+    let bytes = [1u8; 33];
+    let result = K256Element::deser(&bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_k256_scalar_serialization() {
+    let s_orig = Ctx::random_scalar();
+
+    let serialized_s = s_orig.ser();
+    assert_eq!(serialized_s.len(), 32, "Serialized scalar length mismatch");
+
+    let s_deserialized = K256Scalar::deser(&serialized_s).unwrap();
+    assert_eq!(
+        s_orig, s_deserialized,
+        "Original and deserialized scalars do not match"
+    );
+
+    let s_zero = K256Scalar::zero();
+    let ser_zero = s_zero.ser();
+    let des_zero = K256Scalar::deser(&ser_zero).unwrap();
+    assert_eq!(s_zero, des_zero);
+
+    let s_one = K256Scalar::one();
+    let ser_one = s_one.ser();
+    let des_one = K256Scalar::deser(&ser_one).unwrap();
+    assert_eq!(s_one, des_one);
+
+    // test wrong length
+    let bytes = [0u8; 40];
+    let result = K256Scalar::deser(&bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_k256_hash_to_element_different_inputs() {
+    let input1 = b"test input 1";
+    let input2 = b"test input 2";
+    let tag = b"domain_sep";
+
+    let elem1 = K256Group::hash_to_element(&[input1], &[tag]).unwrap();
+    let elem2 = K256Group::hash_to_element(&[input2], &[tag]).unwrap();
+    let elem3 = K256Group::hash_to_element(&[input1], &[tag]).unwrap();
+
+    // Different inputs should yield different elements
+    assert_ne!(elem1, elem2);
+    // Same input should yield the same element
+    assert_eq!(elem1, elem3);
+}
+
+#[test]
+fn test_k256_hash_to_element_different_tags() {
+    let input = b"test input";
+    let tag1 = b"tag1";
+    let tag2 = b"tag2";
+
+    let elem1 = K256Group::hash_to_element(&[input], &[tag1]).unwrap();
+    let elem2 = K256Group::hash_to_element(&[input], &[tag2]).unwrap();
+
+    assert_ne!(elem1, elem2);
+}
+
+#[test]
+fn test_k256_hash_to_element_empty_input() {
+    let h = K256Group::hash_to_element(&[], &[]);
+    assert!(h.is_err());
+}
+
+#[test]
+fn test_k256_hash_to_scalar_empty_input() {
+    let h = K256Group::hash_to_scalar(&[], &[]);
+    assert!(h.is_err());
+}
+
+#[test]
+fn test_k256_encode_decode() {
+    use rand::Rng;
+
+    let mut rng = Ctx::get_rng();
+    let mut lhs = [0u8; 30];
+    rng.fill(&mut lhs[..]);
+
+    let element = K256Group::encode(&lhs).unwrap();
+    let rhs = K256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_k256_encode_decode_boundary() {
+    for byte in [0x00u8, 0x01, 0x7f, 0x80, 0xff] {
+        let lhs = [byte; 30];
+
+        let element = K256Group::encode(&lhs).unwrap();
+        let rhs = K256Group::decode(&element).unwrap();
+
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn test_k256_encode_injective() {
+    let mut lhs = [0u8; 30];
+    let e1 = K256Group::encode(&lhs).unwrap();
+    lhs[29] = 1;
+    let e2 = K256Group::encode(&lhs).unwrap();
+
+    assert_ne!(e1, e2);
+}
+
+#[test]
+fn test_k256_encode_survives_serialization() {
+    let lhs = [7u8; 30];
+    let element = K256Group::encode(&lhs).unwrap();
+
+    let bytes = element.ser();
+    let element = K256Element::deser(&bytes).unwrap();
+    let rhs = K256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_k256_decode_rejects_non_encoded() {
+    // the identity has no x-coordinate
+    let result = K256Group::decode(&K256Element::one());
+    assert!(result.is_err());
+
+    // the generator's x-coordinate does not have a leading zero byte
+    let result = K256Group::decode(&Ctx::generator());
+    assert!(result.is_err());
+
+    // the inverse of an encoded point has an odd y-coordinate
+    let element = K256Group::encode(&[3u8; 30]).unwrap();
+    let result = K256Group::decode(&element.inv());
+    assert!(result.is_err());
+
+    // a random point is an encoding with negligible probability
+    let result = K256Group::decode(&Ctx::random_element());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_k256_multi_exp() {
+    // below and above the threshold of the bucket method
+    for n in [0, 1, 5, 40, 150] {
+        let mut bases: Vec<K256Element> = (0..n).map(|_| Ctx::random_element()).collect();
+        let mut scalars: Vec<K256Scalar> = (0..n).map(|_| Ctx::random_scalar()).collect();
+        if n > 1 {
+            bases[0] = K256Element::one();
+            scalars[1] = K256Scalar::zero();
+        }
+        if n > 2 {
+            scalars[2] = K256Scalar::one().neg();
+        }
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(K256Element::one(), |acc, (b, s)| acc.mul(&b.exp(s)));
+
+        assert_eq!(K256Element::multi_exp(&bases, &scalars), expected);
+    }
+
+    // extra bases are ignored
+    let bases = [Ctx::random_element(), Ctx::random_element()];
+    let scalars = [Ctx::random_scalar()];
+    assert_eq!(
+        K256Element::multi_exp(&bases, &scalars),
+        bases[0].exp(&scalars[0])
+    );
+}
+
+#[test]
+fn test_k256_fixed_base_table() {
+    use crate::traits::groups::FixedBaseTable;
+
+    let base = Ctx::random_element();
+    let table = K256Table::new(&base);
+    let scalars = [
+        K256Scalar::zero(),
+        K256Scalar::one(),
+        K256Scalar::one().neg(),
+        Ctx::random_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(table.exp(s), base.exp(s));
+        assert_eq!(K256Group::g_exp(s), Ctx::generator().exp(s));
+    }
+}
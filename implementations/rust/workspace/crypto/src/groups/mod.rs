@@ -13,10 +13,28 @@
 //!
 //! Group over curve P-256, backed by the [p256](https://github.com/RustCrypto/elliptic-curves/tree/master/p256) crate
 //!
+//! # [`p384`]
+//!
+//! Group over curve P-384, backed by the [p384](https://github.com/RustCrypto/elliptic-curves/tree/master/p384) crate
+//!
+//! # [`k256`]
+//!
+//! Group over curve secp256k1, backed by the [k256](https://github.com/RustCrypto/elliptic-curves/tree/master/k256) crate
+//!
 //! # [`ristretto255`]
 //!
 //! Group over the Ristretto group, backed by the [curve25519-dalek](https://github.com/dalek-cryptography/curve25519-dalek) crate
 //!
+//! # [`electionguard`]
+//!
+//! The prime order subgroup of the integers modulo a prime, with the [`ElectionGuard`](https://www.electionguard.vote/) 2.x standard baseline parameters,
+//! backed by the [crypto-bigint](https://github.com/RustCrypto/crypto-bigint) crate.
+//! Hashing uses the `ElectionGuard` hash functions keyed with the parameter base hash, and serialization is that of `ElectionGuard`.
+//!
+//! # `cryptol_p256`
+//!
+//! Group over curve P-256, backed by the Cryptol-derived `elgamal_p256` crate. Requires the `cryptol` feature.
+//!
 //! # [`productgroup`]
 //!
 //! Product implementations of [`GroupElement`][`crate::traits::groups::GroupElement`] and [`GroupScalar`][`crate::traits::groups::GroupScalar`].
@@ -54,14 +72,35 @@
 //! let es_r = es.dist_exp(&r);
 //! ```
 
+/// Group over curve P-256, backed by the Cryptol-derived `elgamal_p256` crate
+#[cfg(feature = "cryptol")]
+pub mod cryptol_p256;
+
+/// Group over the `ElectionGuard` integer group, backed by the [crypto-bigint](https://github.com/RustCrypto/crypto-bigint) crate
+pub mod electionguard;
+
+/// Group over curve secp256k1, backed by the [k256](https://github.com/RustCrypto/elliptic-curves/tree/master/k256) crate
+pub mod k256;
+
+/// Multi-scalar multiplication for the short Weierstrass curve groups
+mod msm;
+
 /// Group over curve P-256, backed by the [p256](https://github.com/RustCrypto/elliptic-curves/tree/master/p256) crate
 pub mod p256;
 
+/// Group over curve P-384, backed by the [p384](https://github.com/RustCrypto/elliptic-curves/tree/master/p384) crate
+pub mod p384;
+
 /// Generic product groups for elements and scalars
 pub mod productgroup;
 
 /// Group over the Ristretto group, backed by the [curve25519-dalek](https://github.com/dalek-cryptography/curve25519-dalek) crate
 pub mod ristretto255;
 
+#[cfg(feature = "cryptol")]
+pub use cryptol_p256::CryptolP256Group;
+pub use electionguard::EgGroup;
+pub use k256::K256Group;
 pub use p256::P256Group;
+pub use p384::P384Group;
 pub use ristretto255::Ristretto255Group;
@@ -0,0 +1,101 @@
+/*
+ * Multi-scalar multiplication for the short Weierstrass curve groups
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use p256::elliptic_curve::Group;
+use p256::elliptic_curve::ff::PrimeField;
+
+/// Below this number of terms, points are multiplied individually.
+const PIPPENGER_THRESHOLD: usize = 8;
+
+/**
+ * Compute `sum_i scalars_i points_i` with Pippenger's bucket method.
+ *
+ * Runs in variable time, the inputs must be public. Extra points or scalars,
+ * if the lengths differ, are ignored. Scalars must have a big-endian
+ * [representation][`PrimeField::to_repr`], as is the case for the SEC1
+ * curves P-256, P-384 and secp256k1.
+ *
+ * The scalars are split into windows of `c` bits. For each window, every
+ * point is added to the bucket of its digit, and the buckets are summed with
+ * weights `1..2^c` with a running sum, for a total cost of about
+ * `(b / c) (n + 2^(c + 1))` additions for `n` terms and `b` bit scalars.
+ */
+// curve and index arithmetic, bounded by the scalar length
+#[allow(clippy::arithmetic_side_effects)]
+pub(crate) fn pippenger<P: Group>(points: &[P], scalars: &[P::Scalar]) -> P {
+    let len = points.len().min(scalars.len());
+    if len < PIPPENGER_THRESHOLD {
+        return points
+            .iter()
+            .zip(scalars.iter())
+            .fold(P::identity(), |acc, (p, s)| acc + *p * s);
+    }
+
+    let c = window_bits(len);
+    let digits: Vec<Vec<u8>> = scalars[..len].iter().map(little_endian).collect();
+    // the bit length of the scalar representation
+    let bits = <P::Scalar as PrimeField>::Repr::default().as_ref().len() * 8;
+
+    let mut ret = P::identity();
+    for window in (0..bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            ret = ret.double();
+        }
+
+        let mut buckets = vec![P::identity(); (1 << c) - 1];
+        for (point, scalar) in points[..len].iter().zip(digits.iter()) {
+            let digit = digit(scalar, window * c, c, bits);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+
+        // sum_k k bucket_k = sum_k (sum_{j >= k} bucket_j)
+        let mut running = P::identity();
+        let mut sum = P::identity();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            sum += running;
+        }
+        ret += sum;
+    }
+
+    ret
+}
+
+/// Returns the window size in bits for `len` terms, approximately `ln(len) + 2`.
+// len >= PIPPENGER_THRESHOLD > 0, the result is at most 15
+#[allow(clippy::arithmetic_side_effects)]
+fn window_bits(len: usize) -> usize {
+    let bits = (len.ilog2() * 2 / 3 + 2).min(15);
+
+    usize::try_from(bits).unwrap_or(15)
+}
+
+/// Returns the little-endian bytes of `scalar`.
+fn little_endian<S: PrimeField>(scalar: &S) -> Vec<u8> {
+    let mut bytes = scalar.to_repr().as_ref().to_vec();
+    bytes.reverse();
+
+    bytes
+}
+
+/// Returns the `c` bits of `scalar` starting at bit `start`, as an integer,
+/// ignoring bits at or above `bits`.
+// index arithmetic, bounded by the scalar length
+#[allow(clippy::arithmetic_side_effects)]
+fn digit(scalar: &[u8], start: usize, c: usize, bits: usize) -> usize {
+    (0..c)
+        .map(|j| start + j)
+        .filter(|bit| *bit < bits)
+        .fold(0, |acc, bit| {
+            let value = usize::from((scalar[bit / 8] >> (bit % 8)) & 1);
+            acc | (value << (bit - start))
+        })
+}
@@ -7,6 +7,7 @@
  * @version 0.1
  */
 
+use crate::groups::msm;
 use crate::groups::p256::scalar::P256Scalar;
 use crate::traits::groups::GroupElement;
 use crate::utils::error::Error as CryptoError;
@@ -58,6 +59,14 @@ impl GroupElement for P256Element {
     fn equals(&self, other: &Self) -> bool {
         self.0 == other.0
     }
+
+    /// Variable time multi-exponentiation with Pippenger's bucket method.
+    fn multi_exp(bases: &[Self], scalars: &[Self::Scalar]) -> Self {
+        let points: Vec<ProjectivePoint> = bases.iter().map(|b| b.0).collect();
+        let scalars: Vec<p256::Scalar> = scalars.iter().map(|s| s.0).collect();
+
+        P256Element(msm::pippenger(&points, &scalars))
+    }
 }
 
 impl PartialEq for P256Element {
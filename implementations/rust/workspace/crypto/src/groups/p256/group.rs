@@ -9,18 +9,25 @@
 
 use crate::groups::p256::element::P256Element;
 use crate::groups::p256::scalar::P256Scalar;
+use crate::groups::p256::table::P256Table;
 use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::FixedBaseTable;
 use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 
-use p256::NistP256;
-use p256::ProjectivePoint;
 use p256::elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use p256::{AffinePoint, EncodedPoint, NistP256, ProjectivePoint};
 
 use crate::utils::error::Error;
 use crate::utils::hash;
 use crate::utils::rng;
 
+use std::sync::OnceLock;
+
+/// The precomputed table of the default generator, computed on first use
+static GENERATOR_TABLE: OnceLock<P256Table> = OnceLock::new();
+
 /// P-256 implementation of [`CryptoGroup`]
 pub struct P256Group;
 
@@ -31,13 +38,16 @@ impl CryptoGroup for P256Group {
     type Hasher = hash::Hasher256;
     type Plaintext = [u8; 30];
     type Message = Self::Element;
+    type Table = P256Table;
 
     fn generator() -> Self::Element {
         P256Element::new(ProjectivePoint::GENERATOR)
     }
 
     fn g_exp(scalar: &Self::Scalar) -> Self::Element {
-        P256Element::new(ProjectivePoint::GENERATOR * scalar.0)
+        GENERATOR_TABLE
+            .get_or_init(|| P256Table::new(&Self::generator()))
+            .exp(scalar)
     }
 
     #[crate::warning("Panics on empty input")]
@@ -72,18 +82,59 @@ impl CryptoGroup for P256Group {
         Self::Scalar::random(rng)
     }
 
+    // Try-and-increment on the x-coordinate, see `MapToGroup` in
+    // Boneh, Lynn, Shacham: "Short signatures from the Weil pairing"
+    //
+    // The compressed point encoding is laid out as
+    //
+    // [0x02, 0x00, input (30 bytes), counter]
+    //
+    // The leading zero byte ensures that the x-coordinate is smaller than the field
+    // modulus, and the 0x02 tag selects the point with even y-coordinate. Each counter
+    // value yields a valid x-coordinate with probability ~1/2.
     /// # Errors
     ///
-    /// todo!()
-    fn encode(_p: &Self::Plaintext) -> Result<Self::Message, Error> {
-        todo!()
+    /// - `EncodingError` if a point was not found for the input, with negligible probability
+    fn encode(input: &Self::Plaintext) -> Result<Self::Message, Error> {
+        let mut bytes = [0u8; 33];
+        bytes[0] = ENCODING_TAG;
+        bytes[2..32].copy_from_slice(input);
+        for counter in 0..=u8::MAX {
+            bytes[32] = counter;
+            if let Some(point) = decompress(&bytes) {
+                return Ok(P256Element::new(point.into()));
+            }
+        }
+        Err(Error::EncodingError(
+            "Failed to encode into p256 point".to_string(),
+        ))
     }
 
     /// # Errors
     ///
-    /// todo!()
-    fn decode(_p: &Self::Message) -> Result<Self::Plaintext, Error> {
-        todo!()
+    /// - `EncodingError` if the input point is not the [encoding][`Self::encode`] of
+    ///   any plaintext; this includes the identity and points with an odd y-coordinate
+    fn decode(message: &Self::Message) -> Result<Self::Plaintext, Error> {
+        let point = message.0.to_affine().to_encoded_point(true);
+        let bytes = point.as_bytes();
+        // the identity encodes to a single byte
+        if bytes.len() != 33 || bytes[0] != ENCODING_TAG || bytes[1] != 0 {
+            return Err(Error::EncodingError(
+                "P256 point is not an encoded plaintext".to_string(),
+            ));
+        }
+        // the 30 bytes of data are placed in the range 2-31
+        let ret: Self::Plaintext = bytes[2..32].try_into().expect("slice.len() == 30");
+
+        // reject points that use a counter other than the first valid one, so that
+        // decode is the exact inverse of encode
+        if Self::encode(&ret)? != *message {
+            return Err(Error::EncodingError(
+                "P256 point is not an encoded plaintext".to_string(),
+            ));
+        }
+
+        Ok(ret)
     }
 
     /// # Errors
@@ -104,3 +155,12 @@ impl CryptoGroup for P256Group {
         Ok(ret)
     }
 }
+
+/// SEC1 tag for compressed points with even y-coordinate, used for [encoding][`P256Group::encode`].
+const ENCODING_TAG: u8 = 0x02;
+
+/// Decompress a SEC1 compressed point, returning `None` if the bytes do not correspond to a point.
+fn decompress(bytes: &[u8; 33]) -> Option<AffinePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).ok()?;
+    AffinePoint::from_encoded_point(&encoded).into()
+}
@@ -10,6 +10,7 @@
 pub use element::P256Element;
 pub use group::P256Group;
 pub use scalar::P256Scalar;
+pub use table::P256Table;
 
 /// P-256 implementation of [`GroupElement`](crate::traits::groups::GroupElement)
 pub mod element;
@@ -20,6 +21,9 @@ pub mod group;
 /// P-256 implementation of [`GroupScalar`](crate::traits::groups::GroupScalar)
 pub mod scalar;
 
+/// P-256 implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests;
@@ -295,11 +295,128 @@ fn test_p256_hash_to_element_different_tags() {
 #[test]
 fn test_p256_hash_to_element_empty_input() {
     let h = P256Group::hash_to_element(&[], &[]);
-    assert!(h.is_err())
+    assert!(h.is_err());
 }
 
 #[test]
 fn test_p256_hash_to_scalar_empty_input() {
     let h = P256Group::hash_to_scalar(&[], &[]);
-    assert!(h.is_err())
+    assert!(h.is_err());
+}
+
+#[test]
+fn test_p256_encode_decode() {
+    use rand::Rng;
+
+    let mut rng = Ctx::get_rng();
+    let mut lhs = [0u8; 30];
+    rng.fill(&mut lhs[..]);
+
+    let element = P256Group::encode(&lhs).unwrap();
+    let rhs = P256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_p256_encode_decode_boundary() {
+    for byte in [0x00u8, 0x01, 0x7f, 0x80, 0xff] {
+        let lhs = [byte; 30];
+
+        let element = P256Group::encode(&lhs).unwrap();
+        let rhs = P256Group::decode(&element).unwrap();
+
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn test_p256_encode_injective() {
+    let mut lhs = [0u8; 30];
+    let e1 = P256Group::encode(&lhs).unwrap();
+    lhs[29] = 1;
+    let e2 = P256Group::encode(&lhs).unwrap();
+
+    assert_ne!(e1, e2);
+}
+
+#[test]
+fn test_p256_encode_survives_serialization() {
+    let lhs = [7u8; 30];
+    let element = P256Group::encode(&lhs).unwrap();
+
+    let bytes = element.ser();
+    let element = P256Element::deser(&bytes).unwrap();
+    let rhs = P256Group::decode(&element).unwrap();
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_p256_decode_rejects_non_encoded() {
+    // the identity has no x-coordinate
+    let result = P256Group::decode(&P256Element::one());
+    assert!(result.is_err());
+
+    // the generator's x-coordinate does not have a leading zero byte
+    let result = P256Group::decode(&Ctx::generator());
+    assert!(result.is_err());
+
+    // the inverse of an encoded point has an odd y-coordinate
+    let element = P256Group::encode(&[3u8; 30]).unwrap();
+    let result = P256Group::decode(&element.inv());
+    assert!(result.is_err());
+
+    // a random point is an encoding with negligible probability
+    let result = P256Group::decode(&Ctx::random_element());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_p256_multi_exp() {
+    // below and above the threshold of the bucket method
+    for n in [0, 1, 5, 40, 150] {
+        let mut bases: Vec<P256Element> = (0..n).map(|_| Ctx::random_element()).collect();
+        let mut scalars: Vec<P256Scalar> = (0..n).map(|_| Ctx::random_scalar()).collect();
+        if n > 1 {
+            bases[0] = P256Element::one();
+            scalars[1] = P256Scalar::zero();
+        }
+        if n > 2 {
+            scalars[2] = P256Scalar::one().neg();
+        }
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(P256Element::one(), |acc, (b, s)| acc.mul(&b.exp(s)));
+
+        assert_eq!(P256Element::multi_exp(&bases, &scalars), expected);
+    }
+
+    // extra bases are ignored
+    let bases = [Ctx::random_element(), Ctx::random_element()];
+    let scalars = [Ctx::random_scalar()];
+    assert_eq!(
+        P256Element::multi_exp(&bases, &scalars),
+        bases[0].exp(&scalars[0])
+    );
+}
+
+#[test]
+fn test_p256_fixed_base_table() {
+    use crate::traits::groups::FixedBaseTable;
+
+    let base = Ctx::random_element();
+    let table = P256Table::new(&base);
+    let scalars = [
+        P256Scalar::zero(),
+        P256Scalar::one(),
+        P256Scalar::one().neg(),
+        Ctx::random_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(table.exp(s), base.exp(s));
+        assert_eq!(P256Group::g_exp(s), Ctx::generator().exp(s));
+    }
 }
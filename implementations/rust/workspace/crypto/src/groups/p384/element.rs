@@ -0,0 +1,139 @@
+/*
+ * GroupElement implementations for P-384 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::msm;
+use crate::groups::p384::scalar::P384Scalar;
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use p384::elliptic_curve::Group;
+use p384::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use p384::elliptic_curve::subtle::CtOption;
+use p384::{EncodedPoint, ProjectivePoint};
+
+/**
+ * A [`GroupElement`] implementation for the P-384 curve.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct P384Element(pub ProjectivePoint);
+
+impl P384Element {
+    /// Create a new `P384Element` from a [`ProjectivePoint`](https://docs.rs/p384/latest/p384/type.ProjectivePoint.html).
+    #[must_use]
+    pub fn new(point: ProjectivePoint) -> Self {
+        P384Element(point)
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+impl GroupElement for P384Element {
+    type Scalar = P384Scalar;
+
+    fn one() -> Self {
+        P384Element(ProjectivePoint::IDENTITY)
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        P384Element::new(ProjectivePoint::random(rng))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        P384Element(self.0 + other.0)
+    }
+
+    fn inv(&self) -> Self {
+        P384Element(-self.0)
+    }
+
+    fn exp(&self, scalar: &Self::Scalar) -> Self {
+        P384Element(self.0 * scalar.0)
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// Variable time multi-exponentiation with Pippenger's bucket method.
+    fn multi_exp(bases: &[Self], scalars: &[Self::Scalar]) -> Self {
+        let points: Vec<ProjectivePoint> = bases.iter().map(|b| b.0).collect();
+        let scalars: Vec<p384::Scalar> = scalars.iter().map(|s| s.0).collect();
+
+        P384Element(msm::pippenger(&points, &scalars))
+    }
+}
+
+impl PartialEq for P384Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+impl Eq for P384Element {}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+
+impl VSerializable for P384Element {
+    fn ser(&self) -> Vec<u8> {
+        let bytes = self.0.to_affine().to_encoded_point(true).to_bytes();
+        if bytes.len() == 49 {
+            bytes.to_vec()
+        } else {
+            [0u8; 49].to_vec()
+        }
+    }
+}
+
+impl VDeserializable for P384Element {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 49]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 49]".to_string())
+        })?;
+        if bytes == [0u8; 49] {
+            return Ok(P384Element::one());
+        }
+
+        let point = EncodedPoint::from_bytes(bytes).map_err(|_| {
+            CryptoError::DeserializationError("Failed to parse P384 encoded point".to_string())
+        })?;
+        let point: CtOption<P384Element> =
+            ProjectivePoint::from_encoded_point(&point).map(P384Element);
+
+        if point.is_some().into() {
+            Ok(point.expect("point.is_some() == true"))
+        } else {
+            Err(CryptoError::DeserializationError(
+                "Failed to parse P384 point bytes".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+
+impl FSerializable for P384Element {
+    fn size_bytes() -> usize {
+        49
+    }
+
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        let point = self.0.to_affine().to_encoded_point(true);
+        let bytes = point.as_bytes();
+        if bytes.len() == 49 {
+            buffer.extend_from_slice(bytes);
+        } else {
+            buffer.extend_from_slice(&[0u8; 49]);
+        }
+    }
+}
+
+impl FDeserializable for P384Element {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
@@ -0,0 +1,29 @@
+/*
+ * Group over curve P-384
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+pub use element::P384Element;
+pub use group::P384Group;
+pub use scalar::P384Scalar;
+pub use table::P384Table;
+
+/// P-384 implementation of [`GroupElement`](crate::traits::groups::GroupElement)
+pub mod element;
+
+/// P-384 implementation of [`CryptoGroup`](crate::traits::groups::CryptoGroup)
+pub mod group;
+
+/// P-384 implementation of [`GroupScalar`](crate::traits::groups::GroupScalar)
+pub mod scalar;
+
+/// P-384 implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests;
@@ -0,0 +1,136 @@
+/*
+ * GroupScalar implementations for P-384 group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error as CryptoError;
+use crate::utils::rng;
+use core::fmt::Debug;
+use p384::Scalar;
+use p384::Scalar as P384CrateScalar;
+use p384::elliptic_curve::Field;
+use std::ops::Neg;
+
+/**
+ * A [`GroupScalar`] implementation for the P-384 group.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct P384Scalar(pub Scalar);
+
+impl P384Scalar {
+    /// Create a new `P384Scalar` from a p384 [Scalar](https://docs.rs/p384/latest/p384/struct.Scalar.html).
+    #[must_use]
+    pub fn new(scalar: Scalar) -> Self {
+        P384Scalar(scalar)
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+impl GroupScalar for P384Scalar {
+    fn zero() -> Self {
+        P384Scalar(Scalar::ZERO)
+    }
+
+    fn one() -> Self {
+        P384Scalar(Scalar::ONE)
+    }
+
+    fn random<R: rng::CRng>(rng: &mut R) -> Self {
+        let scalar = P384CrateScalar::random(rng);
+        P384Scalar::new(scalar)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        P384Scalar(self.0 + other.0)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        P384Scalar(self.0 - other.0)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        P384Scalar(self.0 * other.0)
+    }
+
+    fn neg(&self) -> Self {
+        P384Scalar(self.0.neg())
+    }
+
+    fn inv(&self) -> Option<Self> {
+        // p384::Scalar::invert returns a CtOption<Scalar>
+        let inverted = self.0.invert();
+        if inverted.is_some().unwrap_u8() == 1 {
+            Some(P384Scalar(inverted.unwrap()))
+        } else {
+            None
+        }
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<u32> for P384Scalar {
+    fn from(u: u32) -> P384Scalar {
+        let scalar: P384CrateScalar = u.into();
+
+        P384Scalar(scalar)
+    }
+}
+
+impl PartialEq for P384Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+impl Eq for P384Scalar {}
+
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use p384::elliptic_curve::PrimeField;
+
+impl VSerializable for P384Scalar {
+    fn ser(&self) -> Vec<u8> {
+        let bytes = self.0.to_bytes();
+        bytes.to_vec()
+    }
+}
+
+impl VDeserializable for P384Scalar {
+    fn deser(buffer: &[u8]) -> Result<Self, CryptoError> {
+        let bytes = <[u8; 48]>::try_from(buffer).map_err(|_| {
+            CryptoError::DeserializationError("Failed to convert Vec<u8> to [u8; 48]".to_string())
+        })?;
+
+        let scalar = Scalar::from_repr(bytes.into()).map(P384Scalar);
+
+        if scalar.is_some().into() {
+            Ok(scalar.expect("scalar.is_some() == true"))
+        } else {
+            Err(CryptoError::DeserializationError(
+                "Failed to parse P384 scalar bytes".to_string(),
+            ))
+        }
+    }
+}
+
+use crate::utils::serialization::{FDeserializable, FSerializable};
+impl FSerializable for P384Scalar {
+    fn size_bytes() -> usize {
+        48
+    }
+    fn ser_into(&self, buffer: &mut Vec<u8>) {
+        let bytes = self.0.to_bytes();
+        buffer.extend(bytes);
+    }
+}
+impl FDeserializable for P384Scalar {
+    fn deser_f(buffer: &[u8]) -> Result<Self, CryptoError> {
+        Self::deser(buffer)
+    }
+}
@@ -58,7 +58,7 @@ fn test_element_mul<C: Context>() {
 
     let op_product = lhs.mul(&rhs);
     let op = lhs.iter().zip(rhs.iter());
-    let op = op.map(|(l, r)| l.mul(&r));
+    let op = op.map(|(l, r)| l.mul(r));
     let op: [C::Element; W] = op.collect::<Vec<C::Element>>().try_into().unwrap();
 
     assert_eq!(op_product, op);
@@ -304,7 +304,7 @@ fn test_scalar_ass<C: Context>() {
 
     let op_product = lhs.add(&rhs);
     let op = lhs.iter().zip(rhs.iter());
-    let op = op.map(|(l, r)| l.add(&r));
+    let op = op.map(|(l, r)| l.add(r));
     let op: [C::Scalar; W] = op.collect::<Vec<C::Scalar>>().try_into().unwrap();
 
     assert_eq!(op_product, op);
@@ -372,7 +372,7 @@ fn test_sub<C: Context>() {
 
     let op_product = lhs.sub(&rhs);
     let op = lhs.iter().zip(rhs.iter());
-    let op = op.map(|(l, r)| l.sub(&r));
+    let op = op.map(|(l, r)| l.sub(r));
     let op: [C::Scalar; W] = op.collect::<Vec<C::Scalar>>().try_into().unwrap();
 
     assert_eq!(op_product, op);
@@ -440,7 +440,7 @@ fn test_mul<C: Context>() {
 
     let op_product = lhs.mul(&rhs);
     let op = lhs.iter().zip(rhs.iter());
-    let op = op.map(|(l, r)| l.mul(&r));
+    let op = op.map(|(l, r)| l.mul(r));
     let op: [C::Scalar; W] = op.collect::<Vec<C::Scalar>>().try_into().unwrap();
 
     assert_eq!(op_product, op);
@@ -532,7 +532,7 @@ fn test_inv<C: Context>() {
     let op_inv = val.inv();
     let op: Option<[C::Scalar; W]> = val
         .iter()
-        .map(|x| x.inv())
+        .map(crate::traits::groups::GroupScalar::inv)
         .collect::<Option<Vec<C::Scalar>>>()
         .map(|v| v.try_into().unwrap());
 
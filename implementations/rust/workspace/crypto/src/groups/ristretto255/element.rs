@@ -13,7 +13,7 @@ use crate::utils::error::Error as CryptoError;
 use crate::utils::rng;
 use core::fmt::Debug;
 use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
-use curve25519_dalek::traits::Identity;
+use curve25519_dalek::traits::{Identity, VartimeMultiscalarMul};
 use sha3::digest::Digest;
 use sha3::digest::typenum::U64;
 
@@ -77,6 +77,15 @@ impl GroupElement for RistrettoElement {
     fn equals(&self, other: &Self) -> bool {
         self.0 == other.0
     }
+
+    /// Variable time multi-exponentiation with [`VartimeMultiscalarMul`](https://docs.rs/curve25519-dalek/latest/curve25519_dalek/traits/trait.VartimeMultiscalarMul.html).
+    fn multi_exp(bases: &[Self], scalars: &[Self::Scalar]) -> Self {
+        let len = bases.len().min(scalars.len());
+        let points = bases[..len].iter().map(|b| b.0);
+        let scalars = scalars[..len].iter().map(|s| s.0);
+
+        RistrettoElement(RistrettoPoint::vartime_multiscalar_mul(scalars, points))
+    }
 }
 
 impl PartialEq for RistrettoElement {
@@ -13,6 +13,7 @@ use crate::traits::groups::GroupScalar;
 
 use crate::groups::ristretto255::element::RistrettoElement;
 use crate::groups::ristretto255::scalar::RistrettoScalar;
+use crate::groups::ristretto255::table::RistrettoTable;
 
 use crate::utils::error::Error;
 use crate::utils::hash;
@@ -35,6 +36,7 @@ impl CryptoGroup for Ristretto255Group {
     type Hasher = hash::Hasher512;
     type Plaintext = [u8; 30];
     type Message = Self::Element;
+    type Table = RistrettoTable;
 
     #[inline]
     fn generator() -> Self::Element {
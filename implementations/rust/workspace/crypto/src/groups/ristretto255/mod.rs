@@ -10,6 +10,7 @@
 pub use element::RistrettoElement;
 pub use group::Ristretto255Group;
 pub use scalar::RistrettoScalar;
+pub use table::RistrettoTable;
 
 /// Ristretto implementation of [`GroupElement`](crate::traits::groups::GroupElement)
 pub mod element;
@@ -20,6 +21,9 @@ pub mod group;
 /// Ristretto implementation of [`GroupScalar`](crate::traits::groups::GroupScalar)
 pub mod scalar;
 
+/// Ristretto implementation of [`FixedBaseTable`](crate::traits::groups::FixedBaseTable)
+pub mod table;
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests;
@@ -0,0 +1,49 @@
+/*
+ * FixedBaseTable implementation for the Ristretto group
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::groups::ristretto255::element::RistrettoElement;
+use crate::groups::ristretto255::scalar::RistrettoScalar;
+use crate::traits::groups::FixedBaseTable;
+use curve25519_dalek::ristretto::RistrettoBasepointTable;
+
+/**
+ * A [`FixedBaseTable`] implementation for the Ristretto group.
+ *
+ * Wraps a [`RistrettoBasepointTable`](https://docs.rs/curve25519-dalek/latest/curve25519_dalek/ristretto/struct.RistrettoBasepointTable.html),
+ * the same kind of table that accelerates exponentiation of the default generator.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::groups::ristretto255::RistrettoTable;
+ * use crypto::traits::groups::{FixedBaseTable, GroupElement};
+ *
+ * let base = RCtx::random_element();
+ * let table = RistrettoTable::new(&base);
+ * let scalar = RCtx::random_scalar();
+ *
+ * assert_eq!(table.exp(&scalar), base.exp(&scalar));
+ * ```
+ */
+#[derive(Clone)]
+pub struct RistrettoTable(RistrettoBasepointTable);
+
+impl FixedBaseTable<RistrettoElement> for RistrettoTable {
+    fn new(base: &RistrettoElement) -> Self {
+        RistrettoTable(RistrettoBasepointTable::create(&base.0))
+    }
+
+    fn exp(&self, scalar: &RistrettoScalar) -> RistrettoElement {
+        // curve arithmetic
+        #[allow(clippy::arithmetic_side_effects)]
+        RistrettoElement(&self.0 * &scalar.0)
+    }
+}
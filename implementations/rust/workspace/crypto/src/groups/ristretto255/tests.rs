@@ -330,3 +330,52 @@ fn test_ristretto_hash_to_element_empty_input() {
 fn test_ristretto_hash_to_scalar_empty_input() {
     let _ = RGroup::hash_to_scalar(&[], &[]);
 }
+
+#[test]
+fn test_ristretto_multi_exp() {
+    // below and above the threshold of the bucket method
+    for n in [0, 1, 5, 40, 150] {
+        let mut bases: Vec<RistrettoElement> = (0..n).map(|_| Ctx::random_element()).collect();
+        let mut scalars: Vec<RistrettoScalar> = (0..n).map(|_| Ctx::random_scalar()).collect();
+        if n > 1 {
+            bases[0] = RistrettoElement::one();
+            scalars[1] = RistrettoScalar::zero();
+        }
+        if n > 2 {
+            scalars[2] = RistrettoScalar::one().neg();
+        }
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(RistrettoElement::one(), |acc, (b, s)| acc.mul(&b.exp(s)));
+
+        assert_eq!(RistrettoElement::multi_exp(&bases, &scalars), expected);
+    }
+
+    // extra bases are ignored
+    let bases = [Ctx::random_element(), Ctx::random_element()];
+    let scalars = [Ctx::random_scalar()];
+    assert_eq!(
+        RistrettoElement::multi_exp(&bases, &scalars),
+        bases[0].exp(&scalars[0])
+    );
+}
+
+#[test]
+fn test_ristretto_fixed_base_table() {
+    use crate::traits::groups::FixedBaseTable;
+
+    let base = Ctx::random_element();
+    let table = RistrettoTable::new(&base);
+    let scalars = [
+        RistrettoScalar::zero(),
+        RistrettoScalar::one(),
+        RistrettoScalar::one().neg(),
+        Ctx::random_scalar(),
+    ];
+    for s in &scalars {
+        assert_eq!(table.exp(s), base.exp(s));
+        assert_eq!(Ristretto255Group::g_exp(s), Ctx::generator().exp(s));
+    }
+}
@@ -18,12 +18,19 @@
 // final pass
 // #![warn(clippy::restriction)]
 
+/// Benaloh ballot check: revealing randomizers and recomputing ballot ciphertexts.
+pub mod ballot_check;
 /// Defines implementation choices for key cryptographic functionalities.
 pub mod context;
 pub mod cryptosystem;
 #[crate::warning("Asserts are present in this module. Not optimized.")]
 pub mod dkgd;
 pub mod groups;
+/// Verifiable homomorphic tally with published aggregation logs.
+pub mod tally;
+/// Known-answer test vectors for the protocol artifacts.
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
 pub mod traits;
 /// Utilities such as random number generation, hashing, signatures and serialization.
 pub mod utils;
@@ -0,0 +1,680 @@
+/*
+ * Verifiable homomorphic tally
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+//! Verifiable homomorphic tally
+//!
+//! Ballots are [exponential `ElGamal`][`crate::cryptosystem::exponential`]
+//! ciphertexts, one per contest, each of width `W` (one element per selection).
+//! The tally proceeds in three steps:
+//!
+//! 1) [`aggregate_contests`][`crate::tally::aggregate_contests`] folds the
+//!    ballots of each contest into an aggregate ciphertext, by homomorphic
+//!    addition (component-wise multiplication). Each folding step is recorded
+//!    in a hash-chained [`AggregationLog`][`crate::tally::AggregationLog`],
+//!    which is published so that anyone can replay the summation and check
+//!    that every ballot was included without modification.
+//!
+//! 2) Each trustee computes [decryption factors][`crate::dkgd::recipient::DecryptionFactor`]
+//!    for the aggregates, see [`aggregates`][`crate::tally::aggregates`] and
+//!    [`Recipient::decryption_factor`][`crate::dkgd::recipient::Recipient::decryption_factor`].
+//!
+//! 3) [`decrypt_tally`][`crate::tally::decrypt_tally`] combines the decryption
+//!    factors of `T` trustees with [`combine`][`crate::dkgd::recipient::combine`]
+//!    and recovers the counts with a
+//!    [`DiscreteLogTable`][`crate::cryptosystem::exponential::DiscreteLogTable`].
+//!
+//! A third party verifies the whole tally with
+//! [`verify_tally`][`crate::tally::verify_tally`], which replays the
+//! aggregation logs, checks the decryption proofs and the final counts.
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::Ciphertext;
+use crate::cryptosystem::exponential::DiscreteLogTable;
+use crate::dkgd::recipient::{DecryptionFactor, DkgCiphertext, combine, combine_with_version};
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error;
+use crate::utils::hash;
+use crate::utils::serialization::{Bytes, VSerializable};
+use crate::zkp::transcript::ChallengeVersion;
+use sha3::Digest;
+use vser_derive::VSerializable as VSer;
+
+/**
+ * One step of the homomorphic summation of a contest.
+ *
+ * Records the aggregate after folding in one ballot, together with the
+ * hash chaining this step to the previous one.
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct LogEntry<C: Context, const W: usize> {
+    /// the aggregate ciphertext after this step
+    pub aggregate: Ciphertext<C, W>,
+    /// `H(previous hash, ballot, aggregate)`
+    pub hash: Bytes,
+}
+
+/**
+ * Hash-chained log of the homomorphic summation of the ballots of one contest.
+ *
+ * Entry `i` holds the product of the first `i + 1` ballots, and the hash
+ * chaining it to entry `i - 1`, where the hash preceding the first entry is
+ * derived from the contest label. The last entry's hash thus commits to
+ * every ballot in order.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::cryptosystem::exponential::{self, DiscreteLogTable};
+ * use crypto::tally::AggregationLog;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let ballots = [[1, 0], [0, 1], [1, 0]].map(|v| exponential::encrypt(&keypair.pkey, &v));
+ *
+ * let log = AggregationLog::aggregate(&ballots, b"contest-1");
+ * assert!(log.verify(&ballots, b"contest-1"));
+ *
+ * let table = DiscreteLogTable::<RCtx>::new(100);
+ * let counts = exponential::decrypt(&keypair, &log.result(), &table).unwrap();
+ * assert_eq!(counts, [2, 1]);
+ * ```
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct AggregationLog<C: Context, const W: usize> {
+    /// one entry per folded ballot
+    pub entries: Vec<LogEntry<C, W>>,
+}
+
+impl<C: Context, const W: usize> AggregationLog<C, W> {
+    /// Construct an aggregation log from the given entries.
+    #[must_use]
+    pub fn new(entries: Vec<LogEntry<C, W>>) -> Self {
+        Self { entries }
+    }
+
+    /// Fold the given ballots into an aggregate, logging each step.
+    ///
+    /// # Parameters
+    ///
+    /// - `ballots`: the ciphertexts of this contest, in ballot box order
+    /// - `contest`: a label identifying the contest, bound into the hash chain
+    #[must_use]
+    pub fn aggregate(ballots: &[Ciphertext<C, W>], contest: &[u8]) -> Self {
+        let mut previous = Self::genesis(contest);
+        let mut aggregate = Self::identity();
+        let mut entries = Vec::with_capacity(ballots.len());
+
+        for ballot in ballots {
+            aggregate = aggregate.mul(ballot);
+            let hash = Self::link(&previous, ballot, &aggregate);
+            previous.clone_from(&hash);
+            entries.push(LogEntry {
+                aggregate: aggregate.clone(),
+                hash: Bytes(hash),
+            });
+        }
+
+        Self::new(entries)
+    }
+
+    /// Returns the final aggregate, the encryption of the tally.
+    ///
+    /// For an empty log this is the trivial encryption of zero.
+    #[must_use]
+    pub fn result(&self) -> Ciphertext<C, W> {
+        self.entries
+            .last()
+            .map_or_else(Self::identity, |e| e.aggregate.clone())
+    }
+
+    /// Returns the hash of the last entry, committing to the whole log.
+    ///
+    /// For an empty log this is the hash derived from the contest label.
+    #[must_use]
+    pub fn head(&self, contest: &[u8]) -> Vec<u8> {
+        self.entries
+            .last()
+            .map_or_else(|| Self::genesis(contest), |e| e.hash.0.clone())
+    }
+
+    /// Replay this log against the given ballots.
+    ///
+    /// Returns `true` if every entry holds the product of the ballots so far
+    /// and is correctly chained to the previous entry, `false` otherwise.
+    pub fn verify(&self, ballots: &[Ciphertext<C, W>], contest: &[u8]) -> bool {
+        if self.entries.len() != ballots.len() {
+            return false;
+        }
+
+        let mut previous = Self::genesis(contest);
+        let mut aggregate = Self::identity();
+        for (entry, ballot) in self.entries.iter().zip(ballots.iter()) {
+            aggregate = aggregate.mul(ballot);
+            if entry.aggregate != aggregate {
+                return false;
+            }
+            let hash = Self::link(&previous, ballot, &aggregate);
+            if entry.hash.0 != hash {
+                return false;
+            }
+            previous = hash;
+        }
+
+        true
+    }
+
+    /// The trivial encryption of zero, `(1, 1)`.
+    fn identity() -> Ciphertext<C, W> {
+        Ciphertext::new(<[C::Element; W]>::one(), <[C::Element; W]>::one())
+    }
+
+    /// Domain separation tags for the hash chain
+    const DS_TAGS: [&[u8]; 3] = [b"previous", b"ballot", b"aggregate"];
+
+    /// Hash preceding the first entry, derived from the contest label.
+    fn genesis(contest: &[u8]) -> Vec<u8> {
+        let mut hasher = C::get_hasher();
+        hash::update_hasher(&mut hasher, &[contest], &[b"tally_log_genesis"]);
+
+        hasher.finalize().to_vec()
+    }
+
+    /// Hash chaining an entry to the previous one.
+    fn link(previous: &[u8], ballot: &Ciphertext<C, W>, aggregate: &Ciphertext<C, W>) -> Vec<u8> {
+        let mut hasher = C::get_hasher();
+        let input = [previous, &ballot.ser(), &aggregate.ser()];
+        hash::update_hasher(&mut hasher, &input, &Self::DS_TAGS);
+
+        hasher.finalize().to_vec()
+    }
+}
+
+/**
+ * Decrypted result of a homomorphic tally.
+ *
+ * Holds the published aggregation logs, the decryption factors (with
+ * proofs) of the `T` trustees that decrypted the aggregates, and the
+ * resulting counts, one array of `W` counts per contest.
+ *
+ * See [`decrypt_tally`] and [`verify_tally`].
+ */
+#[derive(Debug, VSer, PartialEq)]
+pub struct TallyResult<C: Context, const W: usize, const T: usize, const P: usize> {
+    /// the aggregation log of each contest
+    pub logs: Vec<AggregationLog<C, W>>,
+    /// the decryption factors of each participating trustee, one per contest
+    pub dfactors: [Vec<DecryptionFactor<C, P, W>>; T],
+    /// the counts of each contest
+    pub counts: Vec<[u32; W]>,
+}
+
+/// Fold the ballots of each contest into its aggregate, logging each step.
+///
+/// # Parameters
+///
+/// - `ballots`: the ballots in ballot box order, each holding one ciphertext per contest
+/// - `contests`: a label identifying each contest
+///
+/// # Errors
+///
+/// - `TallyError` if some ballot does not hold exactly one ciphertext per contest
+///
+/// Returns one [`AggregationLog`] per contest.
+pub fn aggregate_contests<C: Context, const W: usize>(
+    ballots: &[Vec<Ciphertext<C, W>>],
+    contests: &[&[u8]],
+) -> Result<Vec<AggregationLog<C, W>>, Error> {
+    let by_contest = transpose(ballots, contests.len())?;
+
+    let ret = by_contest
+        .iter()
+        .zip(contests.iter())
+        .map(|(ballots, contest)| AggregationLog::aggregate(ballots, contest))
+        .collect();
+
+    Ok(ret)
+}
+
+/// Returns the aggregate of each contest, as ciphertexts to be decrypted by the trustees.
+///
+/// Pass the result to [`Recipient::decryption_factor`][`crate::dkgd::recipient::Recipient::decryption_factor`].
+#[must_use]
+pub fn aggregates<C: Context, const W: usize, const T: usize>(
+    logs: &[AggregationLog<C, W>],
+) -> Vec<DkgCiphertext<C, W, T>> {
+    logs.iter().map(|l| DkgCiphertext(l.result())).collect()
+}
+
+/// Threshold decrypt the aggregates and recover the counts.
+///
+/// # Parameters
+///
+/// - `logs`: the aggregation logs, see [`aggregate_contests`]
+/// - `dfactors`: the decryption factors of `T` trustees for the [aggregates][`aggregates`]
+/// - `verification_keys`: the verification keys of the `T` trustees, in the same order
+/// - `table`: table recovering counts up to the maximum possible count
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `DecryptProofFailed` if some decryption proof does not verify
+/// - `DiscreteLogError` if some count exceeds the table's maximum
+///
+/// Returns the [`TallyResult`].
+pub fn decrypt_tally<C: Context, const W: usize, const T: usize, const P: usize>(
+    logs: Vec<AggregationLog<C, W>>,
+    dfactors: [Vec<DecryptionFactor<C, P, W>>; T],
+    verification_keys: &[C::Element; T],
+    table: &DiscreteLogTable<C>,
+    proof_context: &[u8],
+) -> Result<TallyResult<C, W, T, P>, Error> {
+    let ciphertexts = aggregates::<C, W, T>(&logs);
+    let plaintexts = combine(&ciphertexts, &dfactors, verification_keys, proof_context)?;
+    let counts = plaintexts
+        .iter()
+        .map(|p| table.solve_array(p))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(TallyResult {
+        logs,
+        dfactors,
+        counts,
+    })
+}
+
+/// Verify a homomorphic tally.
+///
+/// Replays the aggregation log of each contest against the ballots, verifies
+/// the trustees' decryption proofs for the aggregates, and checks that the
+/// decrypted aggregates match the published counts.
+///
+/// # Parameters
+///
+/// - `ballots`: the ballots in ballot box order, each holding one ciphertext per contest
+/// - `contests`: a label identifying each contest
+/// - `result`: the published tally result
+/// - `verification_keys`: the verification keys of the `T` trustees, in the same order
+///   as their decryption factors
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `TallyError` if some ballot does not hold exactly one ciphertext per contest
+/// - `HashToElementError` if challenge generation for decryption proofs returns error
+///
+/// Returns `true` if the tally is valid, `false` otherwise.
+pub fn verify_tally<C: Context, const W: usize, const T: usize, const P: usize>(
+    ballots: &[Vec<Ciphertext<C, W>>],
+    contests: &[&[u8]],
+    result: &TallyResult<C, W, T, P>,
+    verification_keys: &[C::Element; T],
+    proof_context: &[u8],
+) -> Result<bool, Error> {
+    verify_tally_with_version(
+        ChallengeVersion::CURRENT,
+        ballots,
+        contests,
+        result,
+        verification_keys,
+        proof_context,
+    )
+}
+
+/// Verify a homomorphic tally, with decryption proofs computed with the given
+/// challenge version.
+///
+/// See [`verify_tally`].
+///
+/// # Parameters
+///
+/// - `version`: the challenge version the decryption proofs were computed with
+/// - `ballots`: the ballots in ballot box order, each holding one ciphertext per contest
+/// - `contests`: a label identifying each contest
+/// - `result`: the published tally result
+/// - `verification_keys`: the verification keys of the `T` trustees, in the same order
+///   as their decryption factors
+/// - `proof_context`: proof context label (ZKP CONTEXT)
+///
+/// # Errors
+///
+/// - `TallyError` if some ballot does not hold exactly one ciphertext per contest
+/// - `HashToElementError` if challenge generation for decryption proofs returns error
+///
+/// Returns `true` if the tally is valid, `false` otherwise.
+pub fn verify_tally_with_version<C: Context, const W: usize, const T: usize, const P: usize>(
+    version: ChallengeVersion,
+    ballots: &[Vec<Ciphertext<C, W>>],
+    contests: &[&[u8]],
+    result: &TallyResult<C, W, T, P>,
+    verification_keys: &[C::Element; T],
+    proof_context: &[u8],
+) -> Result<bool, Error> {
+    let by_contest = transpose(ballots, contests.len())?;
+    if result.logs.len() != contests.len()
+        || result.counts.len() != contests.len()
+        || result.dfactors.iter().any(|d| d.len() != contests.len())
+    {
+        return Ok(false);
+    }
+
+    let logs_ok = result
+        .logs
+        .iter()
+        .zip(by_contest.iter().zip(contests.iter()))
+        .all(|(log, (ballots, contest))| log.verify(ballots, contest));
+    if !logs_ok {
+        return Ok(false);
+    }
+
+    let ciphertexts = aggregates::<C, W, T>(&result.logs);
+    let plaintexts = match combine_with_version(
+        version,
+        &ciphertexts,
+        &result.dfactors,
+        verification_keys,
+        proof_context,
+    ) {
+        Ok(plaintexts) => plaintexts,
+        Err(Error::DecryptProofFailed(_)) => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    let g = C::generator();
+    let counts_ok = plaintexts
+        .iter()
+        .zip(result.counts.iter())
+        .all(|(plaintext, counts)| {
+            let expected = counts.map(|m| g.exp(&C::Scalar::from(m)));
+            *plaintext == expected
+        });
+
+    Ok(counts_ok)
+}
+
+/// Regroup ballots (one ciphertext per contest) into ciphertexts per contest.
+///
+/// # Errors
+///
+/// - `TallyError` if some ballot does not hold exactly `contests` ciphertexts
+fn transpose<C: Context, const W: usize>(
+    ballots: &[Vec<Ciphertext<C, W>>],
+    contests: usize,
+) -> Result<Vec<Vec<Ciphertext<C, W>>>, Error> {
+    let mut ret = vec![Vec::with_capacity(ballots.len()); contests];
+    for ballot in ballots {
+        if ballot.len() != contests {
+            return Err(Error::TallyError(format!(
+                "Ballot holds {} ciphertexts, expected {contests}",
+                ballot.len()
+            )));
+        }
+        for (contest, ciphertext) in ret.iter_mut().zip(ballot.iter()) {
+            contest.push(ciphertext.clone());
+        }
+    }
+
+    Ok(ret)
+}
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::exponential;
+    use crate::dkgd::dealer::{Dealer, VerifiableShare};
+    use crate::dkgd::recipient::{DkgPublicKey, ParticipantPosition, Recipient};
+    use crate::traits::groups::DistGroupOps;
+    use crate::traits::groups::GroupScalar;
+    use crate::utils::serialization::VDeserializable;
+    use crate::zkp::dlogeq::DlogEqProof;
+    use std::array;
+
+    const W: usize = 3;
+    const T: usize = 2;
+    const P: usize = 3;
+    const CONTESTS: [&[u8]; 2] = [b"contest-1", b"contest-2"];
+
+    #[test]
+    fn test_tally_ristretto() {
+        test_tally::<RCtx>();
+    }
+
+    #[test]
+    fn test_tally_p256() {
+        test_tally::<PCtx>();
+    }
+
+    #[test]
+    fn test_tally_invalid_ristretto() {
+        test_tally_invalid::<RCtx>();
+    }
+
+    #[test]
+    fn test_tally_invalid_p256() {
+        test_tally_invalid::<PCtx>();
+    }
+
+    #[test]
+    fn test_tally_legacy_ristretto() {
+        test_tally_legacy::<RCtx>();
+    }
+
+    #[test]
+    fn test_tally_legacy_p256() {
+        test_tally_legacy::<PCtx>();
+    }
+
+    #[test]
+    fn test_aggregation_log_ristretto() {
+        test_aggregation_log::<RCtx>();
+    }
+
+    #[test]
+    fn test_aggregation_log_p256() {
+        test_aggregation_log::<PCtx>();
+    }
+
+    /// Generates trustees, ballots, and returns the decrypted tally.
+    #[allow(clippy::type_complexity)]
+    fn setup<Ctx: Context>() -> (
+        Vec<Vec<Ciphertext<Ctx, W>>>,
+        TallyResult<Ctx, W, T, P>,
+        [Ctx::Element; T],
+    ) {
+        let (ballots, result, verification_keys, _) = setup_with_trustees::<Ctx>();
+
+        (ballots, result, verification_keys)
+    }
+
+    /// Generates trustees, ballots, and returns the decrypted tally together
+    /// with the `T` trustees that decrypted it.
+    #[allow(clippy::type_complexity)]
+    fn setup_with_trustees<Ctx: Context>() -> (
+        Vec<Vec<Ciphertext<Ctx, W>>>,
+        TallyResult<Ctx, W, T, P>,
+        [Ctx::Element; T],
+        [Recipient<Ctx, T, P>; T],
+    ) {
+        let dealers: [Dealer<Ctx, T, P>; P] = array::from_fn(|_| Dealer::generate());
+        let recipients: [(Recipient<Ctx, T, P>, DkgPublicKey<Ctx, T>); P] = array::from_fn(|i| {
+            let position = ParticipantPosition::from_usize(i + 1);
+            let verifiable_shares: [VerifiableShare<Ctx, T>; P] = dealers
+                .clone()
+                .map(|d| d.get_verifiable_shares().for_recipient(&position));
+
+            Recipient::from_shares(position, &verifiable_shares).unwrap()
+        });
+        let pk = &recipients[0].1.inner;
+
+        let votes = [
+            [[1, 0, 0], [0, 1, 0]],
+            [[0, 1, 0], [0, 1, 0]],
+            [[1, 0, 0], [0, 0, 1]],
+            [[0, 0, 1], [0, 1, 0]],
+        ];
+        let ballots: Vec<Vec<Ciphertext<Ctx, W>>> = votes
+            .iter()
+            .map(|b| b.iter().map(|c| exponential::encrypt(pk, c)).collect())
+            .collect();
+
+        let logs = aggregate_contests(&ballots, &CONTESTS).unwrap();
+        let ciphertexts = aggregates::<Ctx, W, T>(&logs);
+
+        let [(first, _), _, (third, _)] = recipients;
+        let trustees = [third, first];
+        let dfactors = trustees
+            .each_ref()
+            .map(|r| r.decryption_factor(&ciphertexts, b"tally").unwrap());
+        let verification_keys = trustees
+            .each_ref()
+            .map(|r| r.get_verification_key().clone());
+
+        let table = DiscreteLogTable::<Ctx>::new(100);
+        let result = decrypt_tally(logs, dfactors, &verification_keys, &table, b"tally").unwrap();
+        assert_eq!(result.counts, vec![[2, 1, 1], [0, 3, 1]]);
+
+        (ballots, result, verification_keys, trustees)
+    }
+
+    fn test_tally<Ctx: Context>() {
+        let (ballots, result, verification_keys) = setup::<Ctx>();
+
+        let ok = verify_tally(&ballots, &CONTESTS, &result, &verification_keys, b"tally");
+        assert!(ok.unwrap());
+
+        let serialized = result.ser();
+        let deserialized = TallyResult::<Ctx, W, T, P>::deser(&serialized).unwrap();
+        assert_eq!(result, deserialized);
+    }
+
+    fn test_tally_invalid<Ctx: Context>() {
+        let (ballots, result, verification_keys) = setup::<Ctx>();
+        let vks = &verification_keys;
+
+        // wrong proof context
+        let ok = verify_tally(&ballots, &CONTESTS, &result, vks, b"other");
+        assert!(!ok.unwrap());
+
+        // dropped ballot
+        let ok = verify_tally(&ballots[1..], &CONTESTS, &result, vks, b"tally");
+        assert!(!ok.unwrap());
+
+        // wrong counts
+        let mut tampered = TallyResult::<Ctx, W, T, P>::deser(&result.ser()).unwrap();
+        tampered.counts[0] = [1, 2, 1];
+        let ok = verify_tally(&ballots, &CONTESTS, &tampered, vks, b"tally");
+        assert!(!ok.unwrap());
+
+        // tampered decryption factor
+        let mut tampered = TallyResult::<Ctx, W, T, P>::deser(&result.ser()).unwrap();
+        let value = &tampered.dfactors[0][1].value;
+        tampered.dfactors[0][1].value = value.dist_exp(&Ctx::Scalar::from(2));
+        let ok = verify_tally(&ballots, &CONTESTS, &tampered, vks, b"tally");
+        assert!(!ok.unwrap());
+
+        // swapped contest labels
+        let swapped = [CONTESTS[1], CONTESTS[0]];
+        let ok = verify_tally(&ballots, &swapped, &result, vks, b"tally");
+        assert!(!ok.unwrap());
+
+        // malformed ballot
+        let mut malformed = ballots.clone();
+        malformed[0].pop();
+        let ok = verify_tally(&malformed, &CONTESTS, &result, vks, b"tally");
+        assert!(matches!(ok, Err(Error::TallyError(_))));
+    }
+
+    fn test_tally_legacy<Ctx: Context>() {
+        let (ballots, result, verification_keys, trustees) = setup_with_trustees::<Ctx>();
+        let vks = &verification_keys;
+        let v0 = ChallengeVersion::V0;
+
+        // the same decryption factors with proofs computed with the V0 challenge
+        let ciphertexts = aggregates::<Ctx, W, T>(&result.logs);
+        let g = Ctx::generator();
+        let dfactors = trustees.each_ref().map(|r| {
+            let (sk, vk) = (r.get_secret(), r.get_verification_key());
+            ciphertexts
+                .iter()
+                .map(|c| {
+                    let value = c.u().dist_exp(sk);
+                    let a = Ctx::random_scalar();
+                    let (big_a_0, big_a_1) = (g.exp(&a), c.u().dist_exp(&a));
+                    let v = DlogEqProof::<Ctx, W>::legacy_challenge(
+                        &g,
+                        c.u(),
+                        vk,
+                        &value,
+                        &big_a_0,
+                        &big_a_1,
+                        b"tally",
+                    )
+                    .unwrap();
+                    let proof = DlogEqProof::new(big_a_0, big_a_1, a.add(&v.mul(sk)));
+                    DecryptionFactor::new(value, proof, r.get_position().clone())
+                })
+                .collect()
+        });
+        let legacy = TallyResult {
+            logs: result.logs.clone(),
+            dfactors,
+            counts: result.counts.clone(),
+        };
+
+        let ok = verify_tally_with_version(v0, &ballots, &CONTESTS, &legacy, vks, b"tally");
+        assert!(ok.unwrap());
+        let ok = verify_tally(&ballots, &CONTESTS, &legacy, vks, b"tally");
+        assert!(!ok.unwrap());
+
+        // nor do current proofs verify as V0 proofs
+        let ok = verify_tally_with_version(v0, &ballots, &CONTESTS, &result, vks, b"tally");
+        assert!(!ok.unwrap());
+        let current = ChallengeVersion::CURRENT;
+        let ok = verify_tally_with_version(current, &ballots, &CONTESTS, &result, vks, b"tally");
+        assert!(ok.unwrap());
+    }
+
+    fn test_aggregation_log<Ctx: Context>() {
+        let keypair = crate::cryptosystem::elgamal::KeyPair::<Ctx>::generate();
+        let ballots: Vec<Ciphertext<Ctx, 1>> = (0..4)
+            .map(|i| exponential::encrypt(&keypair.pkey, &[i % 2]))
+            .collect();
+
+        let log = AggregationLog::aggregate(&ballots, b"contest");
+        assert!(log.verify(&ballots, b"contest"));
+        assert!(!log.verify(&ballots, b"other"));
+        assert_eq!(log.head(b"contest"), log.entries[3].hash.0);
+
+        // reordered ballots change the intermediate aggregates
+        let mut reordered = ballots.clone();
+        reordered.swap(0, 1);
+        assert!(!log.verify(&reordered, b"contest"));
+
+        // substituted intermediate aggregate
+        let mut tampered = log.clone();
+        tampered.entries[1].aggregate = ballots[3].clone();
+        assert!(!tampered.verify(&ballots, b"contest"));
+
+        // broken chain
+        let mut tampered = log.clone();
+        tampered.entries[2].hash = log.entries[1].hash.clone();
+        assert!(!tampered.verify(&ballots, b"contest"));
+
+        // empty log
+        let empty = AggregationLog::<Ctx, 1>::aggregate(&[], b"contest");
+        assert!(empty.verify(&[], b"contest"));
+        assert_ne!(empty.head(b"contest"), empty.head(b"other"));
+        let table = DiscreteLogTable::<Ctx>::new(0);
+        let counts = exponential::decrypt(&keypair, &empty.result(), &table).unwrap();
+        assert_eq!(counts, [0]);
+    }
+}
@@ -0,0 +1,940 @@
+/*
+ * Known-answer test vectors
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+//! Known-answer test vectors
+//!
+//! Generates JSON test vectors for the protocol artifacts, and checks a vector
+//! file against the current build.
+//!
+//! - [`generate`][`crate::test_vectors::generate`] runs every protocol in a
+//!   [seeded context][`crate::context::SeededCtx`], and records the inputs and
+//!   outputs of each artifact as hexadecimal strings of their
+//!   [serialization][`crate::utils::serialization::VSerializable`].
+//!
+//! - [`check`][`crate::test_vectors::check`] deserializes every value and checks
+//!   that it serializes back to the recorded bytes, recomputes the outputs that
+//!   are fully determined by the inputs, and verifies every proof.
+//!
+//! A vector file generated by an earlier build fails to check if the serialization
+//! format, the hash functions or the Fiat-Shamir challenges have changed since.
+//! Files of version `1` precede the [Fiat-Shamir transcripts][`crate::zkp::transcript`];
+//! their proofs are checked with
+//! [`ChallengeVersion::V0`][`crate::zkp::transcript::ChallengeVersion::V0`], and are
+//! not recomputed.
+//!
+//! The `vectors/v2` directory holds the vector file of every context in
+//! [`CONTEXTS`], generated from the seed `01..01`; the tests check that the
+//! current build reproduces them.
+//!
+//! The `test-vectors` binary exposes both functions on the command line:
+//!
+//! ```text
+//! test-vectors generate <context> <seed> > vectors.json
+//! test-vectors check vectors.json
+//! ```
+
+use crate::context::{Context, EgCtx, K256Ctx, P256Ctx, P384Ctx, RistrettoCtx, SeededCtx};
+use crate::cryptosystem::{elgamal, naoryung};
+use crate::dkgd::dealer::{Dealer, DealerShares, VerifiableShare};
+use crate::dkgd::recipient::{
+    DecryptionFactor, DkgCiphertext, DkgPublicKey, ParticipantPosition, Recipient, combine,
+    combine_with_version,
+};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::DistGroupOps;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::ReplGroupOps;
+use crate::utils::error::Error;
+use crate::utils::rng::SeededRng;
+use crate::utils::serialization::{VDeserializable, VSerializable};
+use crate::zkp::dlogeq::DlogEqProof;
+use crate::zkp::pleq::PlEqProof;
+use crate::zkp::schnorr::SchnorrProof;
+use crate::zkp::shuffle::{ShuffleProof, Shuffler};
+use crate::zkp::transcript::ChallengeVersion;
+use serde::{Deserialize, Serialize};
+use std::array;
+use std::collections::BTreeMap;
+
+/// The version of the vector file format
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The version of vector files with [`ChallengeVersion::V0`] proofs
+const LEGACY_VERSION: u32 = 1;
+
+/// The names of the contexts for which vectors can be generated and checked
+pub const CONTEXTS: [&str; 5] = ["ristretto255", "p256", "p384", "secp256k1", "electionguard"];
+
+/// The proof context label (ZKP CONTEXT) of all artifacts
+const PROOF_CONTEXT: &[u8] = b"test_vectors";
+
+/// The width of encrypted messages
+const W: usize = 2;
+
+/// The threshold of the distributed key generation
+const T: usize = 2;
+
+/// The number of participants of the distributed key generation
+const P: usize = 3;
+
+/// The number of ciphertexts that are decrypted and shuffled
+const COUNT: usize = 3;
+
+/**
+ * A file of known-answer test vectors for one context.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::test_vectors::{self, VectorFile};
+ *
+ * let file = test_vectors::generate("ristretto255", [1u8; 32]).unwrap();
+ * let json = file.to_json().unwrap();
+ *
+ * let parsed = VectorFile::from_json(&json).unwrap();
+ * assert!(test_vectors::check(&parsed).is_ok());
+ * ```
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorFile {
+    /// the version of the file format, see [`FORMAT_VERSION`]
+    pub version: u32,
+    /// the name of the context, one of [`CONTEXTS`]
+    pub context: String,
+    /// the hexadecimal seed of the [`SeededRng`] stream that generated the vectors
+    pub seed: String,
+    /// the test vectors, one per artifact
+    pub vectors: Vec<TestVector>,
+}
+
+impl VectorFile {
+    /// Returns this vector file as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// - `SerializationError` if JSON serialization fails
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    /// Parses a vector file from JSON.
+    ///
+    /// # Errors
+    ///
+    /// - `DeserializationError` if the input is not a valid vector file
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|e| Error::DeserializationError(e.to_string()))
+    }
+}
+
+/// The protocol artifacts covered by the test vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Artifact {
+    /// `ElGamal` encryption, see [`elgamal::KeyPair::encrypt_with_r`]
+    #[serde(rename = "elgamal_encryption")]
+    ElGamalEncryption,
+    /// Naor-Yung encryption, see [`naoryung::PublicKey::encrypt_with_randomness`]
+    NaorYungEncryption,
+    /// Schnorr proof of knowledge, see [`SchnorrProof`]
+    SchnorrProof,
+    /// Proof of equality of discrete logarithms, see [`DlogEqProof`]
+    DlogEqProof,
+    /// Proof of equality of plaintexts, see [`PlEqProof`]
+    PlEqProof,
+    /// Dealer shares, joint public key and verification keys of the distributed key generation
+    DkgShares,
+    /// Decryption factors of the distributed decryption, see [`combine`]
+    DecryptionFactors,
+    /// Terelius-Wikström proof of shuffle, see [`Shuffler`]
+    ShuffleProof,
+}
+
+/**
+ * One known-answer test vector.
+ *
+ * Inputs and outputs are keyed by name. Values are hexadecimal strings of
+ * their [serialization][`VSerializable`], except for the proof context
+ * label, which is stored as its raw bytes.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// the artifact that this vector covers
+    pub artifact: Artifact,
+    /// the inputs of the artifact
+    pub inputs: BTreeMap<String, String>,
+    /// the outputs of the artifact
+    pub outputs: BTreeMap<String, String>,
+}
+
+impl TestVector {
+    /// Constructs an empty vector for the given artifact.
+    fn new(artifact: Artifact) -> Self {
+        Self {
+            artifact,
+            inputs: BTreeMap::new(),
+            outputs: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the named input.
+    fn with_input<V: VSerializable>(mut self, name: &str, value: &V) -> Self {
+        self.inputs
+            .insert(name.to_string(), hex::encode(value.ser()));
+        self
+    }
+
+    /// Adds the named output.
+    fn with_output<V: VSerializable>(mut self, name: &str, value: &V) -> Self {
+        self.outputs
+            .insert(name.to_string(), hex::encode(value.ser()));
+        self
+    }
+
+    /// Adds the proof context label as an input.
+    fn with_context(mut self) -> Self {
+        self.inputs
+            .insert("context".to_string(), hex::encode(PROOF_CONTEXT));
+        self
+    }
+
+    /// Returns the named input.
+    fn input<V: VSerializable + VDeserializable>(&self, name: &str) -> Result<V, Error> {
+        self.value(&self.inputs, "input", name)
+    }
+
+    /// Returns the named output.
+    fn output<V: VSerializable + VDeserializable>(&self, name: &str) -> Result<V, Error> {
+        self.value(&self.outputs, "output", name)
+    }
+
+    /// Returns the proof context label.
+    fn context(&self) -> Result<Vec<u8>, Error> {
+        self.bytes(&self.inputs, "input", "context")
+    }
+
+    /// Returns the named value, which must serialize back to the recorded bytes.
+    fn value<V: VSerializable + VDeserializable>(
+        &self,
+        values: &BTreeMap<String, String>,
+        kind: &str,
+        name: &str,
+    ) -> Result<V, Error> {
+        let bytes = self.bytes(values, kind, name)?;
+        let ret = V::deser(&bytes)
+            .map_err(|e| self.error(&format!("{kind} `{name}` does not deserialize: {e}")))?;
+
+        if ret.ser() == bytes {
+            Ok(ret)
+        } else {
+            Err(self.error(&format!(
+                "{kind} `{name}` does not serialize to the recorded bytes"
+            )))
+        }
+    }
+
+    /// Returns the bytes of the named value.
+    fn bytes(
+        &self,
+        values: &BTreeMap<String, String>,
+        kind: &str,
+        name: &str,
+    ) -> Result<Vec<u8>, Error> {
+        let value = values
+            .get(name)
+            .ok_or_else(|| self.error(&format!("missing {kind} `{name}`")))?;
+
+        hex::decode(value)
+            .map_err(|e| self.error(&format!("{kind} `{name}` is not hexadecimal: {e}")))
+    }
+
+    /// Checks that the named input serializes to the same bytes as the recomputed value.
+    fn expect_input<V: VSerializable>(&self, name: &str, value: &V) -> Result<(), Error> {
+        let recorded = self.bytes(&self.inputs, "input", name)?;
+        self.expect(
+            recorded == value.ser(),
+            &format!("input `{name}` does not match the recomputed value"),
+        )
+    }
+
+    /// Checks that the named output serializes to the same bytes as the recomputed value.
+    fn expect_output<V: VSerializable>(&self, name: &str, value: &V) -> Result<(), Error> {
+        let recorded = self.bytes(&self.outputs, "output", name)?;
+        self.expect(
+            recorded == value.ser(),
+            &format!("output `{name}` does not match the recomputed value"),
+        )
+    }
+
+    /// Returns an error with the given message if the condition does not hold.
+    fn expect(&self, condition: bool, message: &str) -> Result<(), Error> {
+        if condition {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Returns a `TestVectorError` naming this vector's artifact.
+    fn error(&self, message: &str) -> Error {
+        Error::TestVectorError(format!("{:?}: {message}", self.artifact))
+    }
+}
+
+/// Generates the test vectors of the named context from the given seed.
+///
+/// The vectors are computed with [`SeededCtx`], so that the same seed always
+/// yields the same file. The context names are listed in [`CONTEXTS`].
+///
+/// # Errors
+///
+/// - `TestVectorError` if the context name is unknown
+/// - Any error returned by the protocols while computing the artifacts
+pub fn generate(context: &str, seed: [u8; 32]) -> Result<VectorFile, Error> {
+    let vectors = match context {
+        "ristretto255" => generate_ctx::<RistrettoCtx>(seed),
+        "p256" => generate_ctx::<P256Ctx>(seed),
+        "p384" => generate_ctx::<P384Ctx>(seed),
+        "secp256k1" => generate_ctx::<K256Ctx>(seed),
+        "electionguard" => generate_ctx::<EgCtx>(seed),
+        _ => Err(unknown_context(context)),
+    }?;
+
+    Ok(VectorFile {
+        version: FORMAT_VERSION,
+        context: context.to_string(),
+        seed: hex::encode(seed),
+        vectors,
+    })
+}
+
+/// Checks a vector file against the current build.
+///
+/// Every value must deserialize and serialize back to the recorded bytes.
+/// Outputs that are fully determined by the inputs are recomputed and compared,
+/// and every proof must verify. Proofs with prover randomness that is not part
+/// of the vector are only verified. Files of an earlier version are checked
+/// with the challenge version of their proofs.
+///
+/// # Errors
+///
+/// - `TestVectorError` if the file version or context is not supported, or
+///   any vector does not match the current build
+/// - Any error returned by the protocols while checking the artifacts, for
+///   example `ShareVerificationFailed` for invalid dealer shares
+pub fn check(file: &VectorFile) -> Result<(), Error> {
+    let version = match file.version {
+        FORMAT_VERSION => ChallengeVersion::CURRENT,
+        LEGACY_VERSION => ChallengeVersion::V0,
+        _ => {
+            return Err(Error::TestVectorError(format!(
+                "Unsupported vector file version {}",
+                file.version
+            )));
+        }
+    };
+
+    match file.context.as_str() {
+        "ristretto255" => check_ctx::<RistrettoCtx>(&file.vectors, version),
+        "p256" => check_ctx::<P256Ctx>(&file.vectors, version),
+        "p384" => check_ctx::<P384Ctx>(&file.vectors, version),
+        "secp256k1" => check_ctx::<K256Ctx>(&file.vectors, version),
+        "electionguard" => check_ctx::<EgCtx>(&file.vectors, version),
+        _ => Err(unknown_context(&file.context)),
+    }
+}
+
+/// Returns the error for an unknown context name.
+fn unknown_context(context: &str) -> Error {
+    Error::TestVectorError(format!(
+        "Unknown context `{context}`, expected one of {}",
+        CONTEXTS.join(", ")
+    ))
+}
+
+/// Generates the test vectors of every artifact, in the order of [`Artifact`].
+fn generate_ctx<C: Context>(seed: [u8; 32]) -> Result<Vec<TestVector>, Error> {
+    SeededRng::seed(seed);
+
+    let elgamal = generate_elgamal::<SeededCtx<C>>();
+    let naoryung = generate_naoryung::<SeededCtx<C>>()?;
+    let schnorr = generate_schnorr::<SeededCtx<C>>()?;
+    let dlogeq = generate_dlogeq::<SeededCtx<C>>()?;
+    let pleq = generate_pleq::<SeededCtx<C>>()?;
+    let [dkg, decryption] = generate_dkg::<SeededCtx<C>>()?;
+    let shuffle = generate_shuffle::<SeededCtx<C>>()?;
+
+    Ok(vec![
+        elgamal, naoryung, schnorr, dlogeq, pleq, dkg, decryption, shuffle,
+    ])
+}
+
+/// Checks every vector of the file, with proofs of the given challenge version.
+fn check_ctx<C: Context>(vectors: &[TestVector], version: ChallengeVersion) -> Result<(), Error> {
+    for vector in vectors {
+        match vector.artifact {
+            Artifact::ElGamalEncryption => check_elgamal::<C>(vector),
+            Artifact::NaorYungEncryption => check_naoryung::<C>(vector, version),
+            Artifact::SchnorrProof => check_schnorr::<C>(vector, version),
+            Artifact::DlogEqProof => check_dlogeq::<C>(vector, version),
+            Artifact::PlEqProof => check_pleq::<C>(vector, version),
+            Artifact::DkgShares => check_dkg::<C>(vector),
+            Artifact::DecryptionFactors => check_decryption::<C>(vector, version),
+            Artifact::ShuffleProof => check_shuffle::<C>(vector, version),
+        }?;
+    }
+
+    Ok(())
+}
+
+/// `ElGamal` encryption of a random message.
+fn generate_elgamal<C: Context>() -> TestVector {
+    let keypair = elgamal::KeyPair::<C>::generate();
+    let message: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let r: [C::Scalar; W] = array::from_fn(|_| C::random_scalar());
+    let ciphertext = keypair.encrypt_with_r(&message, &r);
+
+    TestVector::new(Artifact::ElGamalEncryption)
+        .with_input("secret_key", &keypair.skey)
+        .with_input("public_key", &keypair.pkey)
+        .with_input("message", &message)
+        .with_input("randomness", &r)
+        .with_output("ciphertext", &ciphertext)
+}
+
+/// Recomputes the public key and the ciphertext, and decrypts the ciphertext.
+fn check_elgamal<C: Context>(vector: &TestVector) -> Result<(), Error> {
+    let sk: C::Scalar = vector.input("secret_key")?;
+    let _: elgamal::PublicKey<C> = vector.input("public_key")?;
+    let message: [C::Element; W] = vector.input("message")?;
+    let r: [C::Scalar; W] = vector.input("randomness")?;
+    let ciphertext: elgamal::Ciphertext<C, W> = vector.output("ciphertext")?;
+
+    let keypair = elgamal::KeyPair::<C>::new(sk.clone(), C::generator().exp(&sk));
+    vector.expect_input("public_key", &keypair.pkey)?;
+    vector.expect_output("ciphertext", &keypair.encrypt_with_r(&message, &r))?;
+
+    let decrypted = keypair.decrypt(&ciphertext);
+    vector.expect(
+        decrypted.ser() == message.ser(),
+        "ciphertext does not decrypt to the message",
+    )
+}
+
+/// Naor-Yung encryption of a random message.
+fn generate_naoryung<C: Context>() -> Result<TestVector, Error> {
+    let keypair = naoryung::KeyPair::augment(&elgamal::KeyPair::<C>::generate(), PROOF_CONTEXT)?;
+    let message: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let r: [C::Scalar; W] = array::from_fn(|_| C::random_scalar());
+    let proof_r: [C::Scalar; W] = array::from_fn(|_| C::random_scalar());
+    let ciphertext = keypair
+        .pkey
+        .encrypt_with_randomness(&message, &r, &proof_r, PROOF_CONTEXT)?;
+
+    let ret = TestVector::new(Artifact::NaorYungEncryption)
+        .with_input("secret_key", &keypair.sk_b)
+        .with_input("public_key", &keypair.pkey)
+        .with_input("message", &message)
+        .with_input("randomness", &r)
+        .with_input("proof_randomness", &proof_r)
+        .with_context()
+        .with_output("ciphertext", &ciphertext);
+
+    Ok(ret)
+}
+
+/// Recomputes the public key and the ciphertext, including its proof if it is
+/// of the current version, verifies the proof and decrypts the ciphertext.
+fn check_naoryung<C: Context>(vector: &TestVector, version: ChallengeVersion) -> Result<(), Error> {
+    let sk: C::Scalar = vector.input("secret_key")?;
+    let _: naoryung::PublicKey<C> = vector.input("public_key")?;
+    let message: [C::Element; W] = vector.input("message")?;
+    let r: [C::Scalar; W] = vector.input("randomness")?;
+    let proof_r: [C::Scalar; W] = vector.input("proof_randomness")?;
+    let context = vector.context()?;
+    let ciphertext: naoryung::Ciphertext<C, W> = vector.output("ciphertext")?;
+
+    let elgamal_keypair = elgamal::KeyPair::<C>::new(sk.clone(), C::generator().exp(&sk));
+    let keypair = naoryung::KeyPair::augment(&elgamal_keypair, &context)?;
+    vector.expect_input("public_key", &keypair.pkey)?;
+
+    let recomputed = keypair
+        .pkey
+        .encrypt_with_randomness(&message, &r, &proof_r, &context)?;
+    if version == ChallengeVersion::CURRENT {
+        vector.expect_output("ciphertext", &recomputed)?;
+    } else {
+        let parts = |c: &naoryung::Ciphertext<C, W>| (c.u_b.ser(), c.v_b.ser(), c.u_a.ser());
+        vector.expect(
+            parts(&recomputed) == parts(&ciphertext),
+            "output `ciphertext` does not match",
+        )?;
+    }
+
+    // decryption verifies the proof
+    let decrypted = match keypair.decrypt_with_version(version, &ciphertext, &context) {
+        Err(Error::NaorYungStripError(_)) => return vector.expect(false, "proof does not verify"),
+        decrypted => decrypted?,
+    };
+    vector.expect(
+        decrypted.ser() == message.ser(),
+        "ciphertext does not decrypt to the message",
+    )
+}
+
+/// Schnorr proof of knowledge of a random discrete logarithm.
+fn generate_schnorr<C: Context>() -> Result<TestVector, Error> {
+    let g = C::generator();
+    let x = C::random_scalar();
+    let y = g.exp(&x);
+    let proof = SchnorrProof::<C>::prove(&g, &y, &x, PROOF_CONTEXT)?;
+
+    let ret = TestVector::new(Artifact::SchnorrProof)
+        .with_input("g", &g)
+        .with_input("y", &y)
+        .with_context()
+        .with_output("proof", &proof);
+
+    Ok(ret)
+}
+
+/// Verifies the proof.
+fn check_schnorr<C: Context>(vector: &TestVector, version: ChallengeVersion) -> Result<(), Error> {
+    let g: C::Element = vector.input("g")?;
+    let y: C::Element = vector.input("y")?;
+    let context = vector.context()?;
+    let proof: SchnorrProof<C> = vector.output("proof")?;
+
+    let ok = proof.verify_with_version(version, &g, &y, &context)?;
+    vector.expect(ok, "proof does not verify")
+}
+
+/// Proof of equality of discrete logarithms, with random second bases.
+fn generate_dlogeq<C: Context>() -> Result<TestVector, Error> {
+    let x = C::random_scalar();
+    let g0 = C::generator();
+    let y0 = g0.exp(&x);
+    let g1: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let y1 = g1.dist_exp(&x);
+    let proof = DlogEqProof::<C, W>::prove(&x, &g0, &y0, &g1, &y1, PROOF_CONTEXT)?;
+
+    let ret = TestVector::new(Artifact::DlogEqProof)
+        .with_input("g0", &g0)
+        .with_input("y0", &y0)
+        .with_input("g1", &g1)
+        .with_input("y1", &y1)
+        .with_context()
+        .with_output("proof", &proof);
+
+    Ok(ret)
+}
+
+/// Verifies the proof.
+fn check_dlogeq<C: Context>(vector: &TestVector, version: ChallengeVersion) -> Result<(), Error> {
+    let g0: C::Element = vector.input("g0")?;
+    let y0: C::Element = vector.input("y0")?;
+    let g1: [C::Element; W] = vector.input("g1")?;
+    let y1: [C::Element; W] = vector.input("y1")?;
+    let context = vector.context()?;
+    let proof: DlogEqProof<C, W> = vector.output("proof")?;
+
+    let ok = proof.verify_with_version(version, &g0, &y0, &g1, &y1, &context)?;
+    vector.expect(ok, "proof does not verify")
+}
+
+/// Proof of equality of plaintexts for a Naor-Yung encryption of a random message.
+#[allow(clippy::similar_names)]
+fn generate_pleq<C: Context>() -> Result<TestVector, Error> {
+    let keypair = naoryung::KeyPair::augment(&elgamal::KeyPair::<C>::generate(), PROOF_CONTEXT)?;
+    let (y, z) = (&keypair.pkey.pk_b, &keypair.pkey.pk_a);
+    let message: [C::Element; W] = array::from_fn(|_| C::random_element());
+    let r: [C::Scalar; W] = array::from_fn(|_| C::random_scalar());
+    let a_prime: [C::Scalar; W] = array::from_fn(|_| C::random_scalar());
+
+    let u_b = C::generator().repl_exp(&r);
+    let v_b = message.mul(&y.repl_exp(&r));
+    let u_a = z.repl_exp(&r);
+    let proof = PlEqProof::<C, W>::prove_with_randomness(
+        y,
+        z,
+        &u_b,
+        &v_b,
+        &u_a,
+        &r,
+        &a_prime,
+        PROOF_CONTEXT,
+    )?;
+
+    let ret = TestVector::new(Artifact::PlEqProof)
+        .with_input("y", y)
+        .with_input("z", z)
+        .with_input("u_b", &u_b)
+        .with_input("v_b", &v_b)
+        .with_input("u_a", &u_a)
+        .with_input("randomness", &r)
+        .with_input("proof_randomness", &a_prime)
+        .with_context()
+        .with_output("proof", &proof);
+
+    Ok(ret)
+}
+
+/// Recomputes the proof if it is of the current version, and verifies it.
+#[allow(clippy::similar_names)]
+fn check_pleq<C: Context>(vector: &TestVector, version: ChallengeVersion) -> Result<(), Error> {
+    let y: C::Element = vector.input("y")?;
+    let z: C::Element = vector.input("z")?;
+    let u_b: [C::Element; W] = vector.input("u_b")?;
+    let v_b: [C::Element; W] = vector.input("v_b")?;
+    let u_a: [C::Element; W] = vector.input("u_a")?;
+    let r: [C::Scalar; W] = vector.input("randomness")?;
+    let a_prime: [C::Scalar; W] = vector.input("proof_randomness")?;
+    let context = vector.context()?;
+    let proof: PlEqProof<C, W> = vector.output("proof")?;
+
+    if version == ChallengeVersion::CURRENT {
+        let recomputed = PlEqProof::<C, W>::prove_with_randomness(
+            &y, &z, &u_b, &v_b, &u_a, &r, &a_prime, &context,
+        )?;
+        vector.expect_output("proof", &recomputed)?;
+    }
+
+    let ok = proof.verify_with_version(version, &y, &z, &u_b, &v_b, &u_a, &context)?;
+    vector.expect(ok, "proof does not verify")
+}
+
+/// Distributed key generation with `P` dealers, followed by the distributed
+/// decryption of `COUNT` random messages by the first `T` participants.
+fn generate_dkg<C: Context>() -> Result<[TestVector; 2], Error> {
+    let dealers: [Dealer<C, T, P>; P] = array::from_fn(|_| Dealer::generate());
+    let shares: [DealerShares<C, T, P>; P] = dealers.map(|d| d.get_verifiable_shares());
+
+    let recipients = (1..=P)
+        .map(|i| {
+            let position = ParticipantPosition::from_usize(i);
+            let verifiable: [VerifiableShare<C, T>; P] =
+                shares.each_ref().map(|s| s.for_recipient(&position));
+            Recipient::from_shares(position, &verifiable)
+        })
+        .collect::<Result<Vec<(Recipient<C, T, P>, DkgPublicKey<C, T>)>, Error>>()?;
+    let joint_pk = &recipients[0].1;
+    let verification_keys: [C::Element; P] =
+        array::from_fn(|i| recipients[i].0.get_verification_key().clone());
+
+    let dkg = TestVector::new(Artifact::DkgShares)
+        .with_output("dealer_shares", &shares)
+        .with_output("joint_public_key", joint_pk)
+        .with_output("verification_keys", &verification_keys);
+
+    let ciphertexts: Vec<DkgCiphertext<C, W, T>> = (0..COUNT)
+        .map(|_| joint_pk.encrypt(&array::from_fn(|_| C::random_element())))
+        .collect();
+    let dfactors = recipients[..T]
+        .iter()
+        .map(|(recipient, _)| recipient.decryption_factor(&ciphertexts, PROOF_CONTEXT))
+        .collect::<Result<Vec<Vec<DecryptionFactor<C, P, W>>>, Error>>()?;
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] = dfactors
+        .try_into()
+        .map_err(|_| Error::TestVectorError("Expected T decryption factors".to_string()))?;
+    let present_keys: [C::Element; T] = array::from_fn(|i| verification_keys[i].clone());
+    let plaintexts = combine(&ciphertexts, &dfactors, &present_keys, PROOF_CONTEXT)?;
+
+    let decryption = TestVector::new(Artifact::DecryptionFactors)
+        .with_input("ciphertexts", &ciphertexts)
+        .with_input("verification_keys", &present_keys)
+        .with_context()
+        .with_output("decryption_factors", &dfactors)
+        .with_output("plaintexts", &plaintexts);
+
+    Ok([dkg, decryption])
+}
+
+/// Verifies the shares of every participant, and recomputes the joint public
+/// key and the verification keys.
+fn check_dkg<C: Context>(vector: &TestVector) -> Result<(), Error> {
+    let shares: [DealerShares<C, T, P>; P] = vector.output("dealer_shares")?;
+    let _: DkgPublicKey<C, T> = vector.output("joint_public_key")?;
+    let verification_keys: [C::Element; P] = vector.output("verification_keys")?;
+
+    for (i, verification_key) in (1..=P).zip(verification_keys.iter()) {
+        let position = ParticipantPosition::from_usize(i);
+        let verifiable: [VerifiableShare<C, T>; P] =
+            shares.each_ref().map(|s| s.for_recipient(&position));
+        let (recipient, joint_pk) = Recipient::from_shares(position, &verifiable)?;
+
+        vector.expect_output("joint_public_key", &joint_pk)?;
+        vector.expect(
+            recipient.get_verification_key().ser() == verification_key.ser(),
+            &format!("output `verification_keys` does not match participant {i}"),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Verifies the decryption factors and recomputes the plaintexts.
+fn check_decryption<C: Context>(
+    vector: &TestVector,
+    version: ChallengeVersion,
+) -> Result<(), Error> {
+    let ciphertexts: Vec<DkgCiphertext<C, W, T>> = vector.input("ciphertexts")?;
+    let verification_keys: [C::Element; T] = vector.input("verification_keys")?;
+    let context = vector.context()?;
+    let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] = vector.output("decryption_factors")?;
+    let _: Vec<[C::Element; W]> = vector.output("plaintexts")?;
+
+    for dfactor in &dfactors {
+        vector.expect(
+            dfactor.len() == ciphertexts.len(),
+            "output `decryption_factors` does not match the ciphertexts",
+        )?;
+    }
+
+    // combine verifies the decryption proofs
+    let plaintexts = match combine_with_version(
+        version,
+        &ciphertexts,
+        &dfactors,
+        &verification_keys,
+        &context,
+    ) {
+        Err(Error::DecryptProofFailed(_)) => {
+            return vector.expect(false, "decryption proof does not verify");
+        }
+        plaintexts => plaintexts?,
+    };
+
+    vector.expect_output("plaintexts", &plaintexts)
+}
+
+/// Shuffle of `COUNT` `ElGamal` encryptions of random messages.
+fn generate_shuffle<C: Context>() -> Result<TestVector, Error> {
+    let keypair = elgamal::KeyPair::<C>::generate();
+    let ciphertexts: Vec<elgamal::Ciphertext<C, W>> = (0..COUNT)
+        .map(|_| keypair.encrypt(&array::from_fn(|_| C::random_element())))
+        .collect();
+    let generators = C::G::ind_generators(COUNT, PROOF_CONTEXT)?;
+    let shuffler = Shuffler::<C, W>::new(generators.clone(), keypair.pkey.clone());
+    let (permuted, proof) = shuffler.shuffle(&ciphertexts, PROOF_CONTEXT)?;
+
+    let ret = TestVector::new(Artifact::ShuffleProof)
+        .with_input("public_key", &keypair.pkey)
+        .with_input("generators", &generators)
+        .with_input("ciphertexts", &ciphertexts)
+        .with_context()
+        .with_output("permuted", &permuted)
+        .with_output("proof", &proof);
+
+    Ok(ret)
+}
+
+/// Recomputes the independent generators and verifies the proof.
+fn check_shuffle<C: Context>(vector: &TestVector, version: ChallengeVersion) -> Result<(), Error> {
+    let public_key: elgamal::PublicKey<C> = vector.input("public_key")?;
+    let generators: Vec<C::Element> = vector.input("generators")?;
+    let ciphertexts: Vec<elgamal::Ciphertext<C, W>> = vector.input("ciphertexts")?;
+    let context = vector.context()?;
+    let permuted: Vec<elgamal::Ciphertext<C, W>> = vector.output("permuted")?;
+    let proof: ShuffleProof<C, W> = vector.output("proof")?;
+
+    let recomputed = C::G::ind_generators(ciphertexts.len(), &context)?;
+    vector.expect_input("generators", &recomputed)?;
+
+    let shuffler = Shuffler::<C, W>::new(generators, public_key);
+    let ok = shuffler.verify_with_version(version, &ciphertexts, &permuted, &proof, &context)?;
+    vector.expect(ok, "proof does not verify")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkgd::recipient::{combine_robust, combine_robust_with_version};
+
+    #[test]
+    fn test_vectors_ristretto() {
+        generate_and_check("ristretto255");
+    }
+
+    #[test]
+    fn test_vectors_p256() {
+        generate_and_check("p256");
+    }
+
+    #[test]
+    fn test_vectors_p384() {
+        generate_and_check("p384");
+    }
+
+    #[test]
+    fn test_vectors_secp256k1() {
+        generate_and_check("secp256k1");
+    }
+
+    #[test]
+    fn test_vectors_electionguard() {
+        generate_and_check("electionguard");
+    }
+
+    /// Generates vectors, checks them after a round trip through JSON, and
+    /// checks that the same seed gives the same vectors.
+    fn generate_and_check(context: &str) {
+        let seed = [7u8; 32];
+        let file = generate(context, seed).unwrap();
+        assert_eq!(file.vectors.len(), 8);
+
+        let parsed = VectorFile::from_json(&file.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, file);
+        check(&parsed).unwrap();
+
+        let again = generate(context, seed).unwrap();
+        assert_eq!(again, file);
+
+        let other = generate(context, [8u8; 32]).unwrap();
+        assert_ne!(other.vectors, file.vectors);
+    }
+
+    /// Checks the committed files of the current version, and that their seed
+    /// reproduces them.
+    #[test]
+    fn test_vectors_committed() {
+        let files = [
+            include_str!("../vectors/v2/ristretto255.json"),
+            include_str!("../vectors/v2/p256.json"),
+            include_str!("../vectors/v2/p384.json"),
+            include_str!("../vectors/v2/secp256k1.json"),
+            include_str!("../vectors/v2/electionguard.json"),
+        ];
+
+        for (json, context) in files.into_iter().zip(CONTEXTS) {
+            let file = VectorFile::from_json(json).unwrap();
+            assert_eq!(file.version, FORMAT_VERSION);
+            assert_eq!(file.context, context);
+            check(&file).unwrap();
+
+            let seed: [u8; 32] = hex::decode(&file.seed).unwrap().try_into().unwrap();
+            assert_eq!(generate(context, seed).unwrap(), file);
+        }
+    }
+
+    /// Checks the version 1 files, generated before the Fiat-Shamir transcripts.
+    #[test]
+    fn test_vectors_legacy() {
+        let files = [
+            include_str!("../vectors/v1/ristretto255.json"),
+            include_str!("../vectors/v1/p256.json"),
+        ];
+
+        for json in files {
+            let file = VectorFile::from_json(json).unwrap();
+            assert_eq!(file.version, LEGACY_VERSION);
+            check(&file).unwrap();
+
+            // the proofs do not verify as current proofs
+            let mut relabeled = file.clone();
+            relabeled.version = FORMAT_VERSION;
+            assert!(matches!(check(&relabeled), Err(Error::TestVectorError(_))));
+        }
+
+        // nor do current proofs as legacy proofs
+        let mut relabeled = generate("ristretto255", [7u8; 32]).unwrap();
+        relabeled.version = LEGACY_VERSION;
+        assert!(matches!(check(&relabeled), Err(Error::TestVectorError(_))));
+    }
+
+    /// Strips and combines the artifacts of a version 1 file with the
+    /// [`ChallengeVersion::V0`] variants of the protocol functions.
+    #[test]
+    fn test_vectors_legacy_artifacts() {
+        type C = RistrettoCtx;
+        let file = VectorFile::from_json(include_str!("../vectors/v1/ristretto255.json")).unwrap();
+        let v0 = ChallengeVersion::V0;
+
+        let vector = &file.vectors[1];
+        let public_key: naoryung::PublicKey<C> = vector.input("public_key").unwrap();
+        let message: [<C as Context>::Element; W] = vector.input("message").unwrap();
+        let context = vector.context().unwrap();
+        let ciphertext: naoryung::Ciphertext<C, W> = vector.output("ciphertext").unwrap();
+
+        let stripped = public_key
+            .strip_with_version(v0, ciphertext.clone(), &context)
+            .unwrap();
+        assert_eq!(stripped.u(), &ciphertext.u_b);
+        assert_eq!(stripped.v(), &ciphertext.v_b);
+        let result = public_key.strip(ciphertext, &context);
+        assert!(matches!(result, Err(Error::NaorYungStripError(_))));
+
+        let sk: <C as Context>::Scalar = vector.input("secret_key").unwrap();
+        let y = C::generator().exp(&sk);
+        let keypair = elgamal::KeyPair::<C>::new(sk, y);
+        let keypair = naoryung::KeyPair::augment(&keypair, &context).unwrap();
+        let ciphertext = vector.output("ciphertext").unwrap();
+        let decrypted = keypair.decrypt_with_version(v0, &ciphertext, &context);
+        assert_eq!(decrypted.unwrap(), message);
+
+        let all_keys: [<C as Context>::Element; P] =
+            file.vectors[5].output("verification_keys").unwrap();
+        let vector = &file.vectors[6];
+        let ciphertexts: Vec<DkgCiphertext<C, W, T>> = vector.input("ciphertexts").unwrap();
+        let verification_keys: [<C as Context>::Element; T] =
+            vector.input("verification_keys").unwrap();
+        let context = vector.context().unwrap();
+        let dfactors: [Vec<DecryptionFactor<C, P, W>>; T] =
+            vector.output("decryption_factors").unwrap();
+        let plaintexts: Vec<[<C as Context>::Element; W]> = vector.output("plaintexts").unwrap();
+
+        let combined =
+            combine_with_version(v0, &ciphertexts, &dfactors, &verification_keys, &context);
+        assert_eq!(combined.unwrap(), plaintexts);
+        let result = combine(&ciphertexts, &dfactors, &verification_keys, &context);
+        assert!(matches!(result, Err(Error::DecryptProofFailed(_))));
+
+        let (combined, faulty) =
+            combine_robust_with_version(v0, &ciphertexts, &dfactors, &all_keys, &context).unwrap();
+        assert_eq!(combined, plaintexts);
+        assert!(faulty.is_empty());
+        let result = combine_robust(&ciphertexts, &dfactors, &all_keys, &context);
+        assert!(matches!(result, Err(Error::DecryptProofFailed(_))));
+    }
+
+    #[test]
+    fn test_vectors_tampered() {
+        let file = generate("ristretto255", [7u8; 32]).unwrap();
+
+        // a proof of a different vector
+        let mut tampered = file.clone();
+        let other = file.vectors[3].outputs["proof"].clone();
+        tampered.vectors[2]
+            .outputs
+            .insert("proof".to_string(), other);
+        assert!(matches!(check(&tampered), Err(Error::TestVectorError(_))));
+
+        // a different proof context
+        let mut tampered = file.clone();
+        for vector in &mut tampered.vectors {
+            if let Some(context) = vector.inputs.get_mut("context") {
+                *context = hex::encode(b"other");
+            }
+        }
+        assert!(check(&tampered).is_err());
+
+        // a different recorded output
+        let mut tampered = file.clone();
+        let ciphertext = tampered.vectors[0].outputs.get_mut("ciphertext").unwrap();
+        *ciphertext = file.vectors[5].outputs["joint_public_key"].clone();
+        assert!(check(&tampered).is_err());
+
+        // an unknown context and version
+        let mut tampered = file.clone();
+        tampered.context = "other".to_string();
+        assert!(check(&tampered).is_err());
+        let mut tampered = file;
+        tampered.version = 0;
+        assert!(check(&tampered).is_err());
+    }
+}
@@ -51,6 +51,9 @@ pub trait CryptoGroup {
     /// The type on which encryption operates to yield ciphertexts (WIP)
     type Message;
 
+    /// The precomputed table type for exponentiation of a fixed base
+    type Table: FixedBaseTable<Self::Element> + Send + Sync;
+
     /// The default generator
     fn generator() -> Self::Element;
 
@@ -89,8 +92,7 @@ pub trait CryptoGroup {
     ///
     /// # Errors
     ///
-    /// - `EncodingError` if using `Ristretto255Group` and a point was not found for the input, with negligible probability
-    /// - undefined if using `P256Group`
+    /// - `EncodingError` if a point was not found for the input, with negligible probability
     #[crate::warning("# Errors for this function are incompletely specified")]
     fn encode(p: &Self::Plaintext) -> Result<Self::Message, Error>;
 
@@ -98,7 +100,7 @@ pub trait CryptoGroup {
     ///
     /// # Errors
     ///
-    /// - undefined if using `P256Group`
+    /// - `EncodingError` if using `P256Group` and the input is not the encoding of any plaintext
     #[crate::warning("# Errors for this function are incompletely specified")]
     fn decode(p: &Self::Message) -> Result<Self::Plaintext, Error>;
 
@@ -179,6 +181,39 @@ pub trait GroupElement: Sized + Debug + Eq {
      * seed rather than a Rust RNG of type [`rng::CRng`].
      */
     fn random<R: rng::CRng>(rng: &mut R) -> Self;
+
+    /**
+     * Multi-exponentiation `prod_i bases_i^scalars_i`
+     *
+     * Extra bases or scalars, if the lengths differ, are ignored. The product
+     * of no terms is the identity.
+     *
+     * Implementations may run in variable time, so the inputs must be
+     * public, for example when verifying proofs. The default implementation
+     * exponentiates each base individually.
+     */
+    #[must_use]
+    fn multi_exp(bases: &[Self], scalars: &[Self::Scalar]) -> Self {
+        bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(Self::one(), |acc, (b, s)| acc.mul(&b.exp(s)))
+    }
+}
+
+/**
+ * Trait for a precomputed table of powers of a fixed base
+ *
+ * Accelerates repeated exponentiation of the same base, such as the
+ * generator or a public key, at the cost of computing and storing the
+ * table once. Exponentiation with the table runs in constant time.
+ */
+pub trait FixedBaseTable<E: GroupElement>: Sized {
+    /// Compute the table for the given base
+    fn new(base: &E) -> Self;
+
+    /// Exponentiation of the fixed base, equal to `base.exp(scalar)`
+    fn exp(&self, scalar: &E::Scalar) -> E;
 }
 
 /**
@@ -40,6 +40,18 @@ pub enum Error {
     #[error("{0}")]
     EncodingError(String),
 
+    /// Occurs when a byte string does not fit in a [multi-element encoding][`crate::cryptosystem::encoding`]
+    #[error("Payload of {0} bytes exceeds encoding capacity of {1} bytes")]
+    EncodingCapacityError(usize, usize),
+
+    /// Occurs when a [bounded discrete log][`crate::cryptosystem::exponential::DiscreteLogTable`] cannot be recovered
+    #[error("{0}")]
+    DiscreteLogError(String),
+
+    /// Occurs when [hybrid encryption][`crate::cryptosystem::hybrid`] or decryption fails
+    #[error("{0}")]
+    HybridError(String),
+
     /// Occurs when [Naor-Yung][`crate::cryptosystem::naoryung::Ciphertext`] well-formedness proofs fail to verify.
     #[error("{0}")]
     NaorYungStripError(String),
@@ -48,10 +60,46 @@ pub enum Error {
     #[error("{0}")]
     ShareVerificationFailed(String),
 
+    /// Occurs when a signed [DKG share message][`crate::dkgd::transport`] fails to verify or decrypt.
+    #[error("{0}")]
+    ShareTransportError(String),
+
+    /// Occurs when a [DKG complaint][`crate::dkgd::complaint::Complaint`] cannot be made or ruled on.
+    #[error("{0}")]
+    ComplaintError(String),
+
+    /// Occurs when [resharing][`crate::dkgd::reshare`] input is inconsistent with the current key.
+    #[error("{0}")]
+    ReshareError(String),
+
+    /// Occurs when runtime [DKG parameters][`crate::dkgd::dynamic::DkgParameters`] are invalid or do not match the input.
+    #[error("{0}")]
+    DkgParameterError(String),
+
     /// Occurs when [decryption proofs][`crate::dkgd::recipient::DecryptionFactor`] fail to verify.
     #[error("{0}")]
     DecryptProofFailed(String),
 
+    /// Occurs when [plaintext equivalence test][`crate::dkgd::pet`] blinding factors are malformed or fail to verify.
+    #[error("{0}")]
+    PetError(String),
+
+    /// Occurs when a [range proof][`crate::zkp::range::RangeProof`] cannot be computed for the given witness
+    #[error("{0}")]
+    RangeProofError(String),
+
+    /// Occurs when a [Sigma protocol][`crate::zkp::sigma`] relation or witness is malformed
+    #[error("{0}")]
+    SigmaProofError(String),
+
+    /// Occurs when [tally][`crate::tally`] input is malformed
+    #[error("{0}")]
+    TallyError(String),
+
+    /// Occurs when a [ballot check][`crate::ballot_check`] fails or a ballot is cast after being spoiled
+    #[error("{0}")]
+    BallotCheckError(String),
+
     /// Occurs when a permutation is applied to a slice of mismatched length
     #[error("Mismatched permutation length")]
     MismatchedPermutationLength,
@@ -71,4 +119,8 @@ pub enum Error {
     /// Occurs when a hash to curve or hash to scalar error occurs in `p256`
     #[error("{0}")]
     HashToElementError(String),
+
+    /// Occurs when a known-answer test vector does not match the current build
+    #[error("{0}")]
+    TestVectorError(String),
 }
@@ -15,7 +15,7 @@ use sha3::{Digest, Sha3_256, Sha3_512};
  *
  * Allows retrieving a hasher instance in some [Context][`crate::context::Context`].
  */
-pub trait Hasher: Digest + FixedOutput {
+pub trait Hasher: Digest + FixedOutput + Clone {
     /// Returns a hasher instance.
     fn hasher() -> Self;
 }
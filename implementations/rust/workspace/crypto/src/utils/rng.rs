@@ -37,3 +37,93 @@ impl Rng for OsRng {
         rand::rngs::OsRng
     }
 }
+
+#[cfg(any(test, feature = "test-vectors"))]
+pub use seeded::SeededRng;
+
+/// Deterministic random number generation for test vectors.
+#[cfg(any(test, feature = "test-vectors"))]
+mod seeded {
+    use super::{CRng, Rng};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use std::cell::RefCell;
+
+    /// The seed of the stream before the first call to [`SeededRng::seed`]
+    const DEFAULT_SEED: [u8; 32] = [0u8; 32];
+
+    thread_local! {
+        /// The stream shared by all [`SeededRng`] instances on this thread
+        static STREAM: RefCell<ChaCha20Rng> = RefCell::new(ChaCha20Rng::from_seed(DEFAULT_SEED));
+    }
+
+    /**
+     * A deterministic random number generator, for reproducible test vectors.
+     *
+     * All instances on a thread draw from a single `ChaCha20` stream, so that
+     * the outputs of a sequence of protocol operations are a function of the
+     * seed passed to [`SeededRng::seed`]. Before the first call to `seed`,
+     * the stream starts from the all-zero seed.
+     *
+     * Only available with the `test-vectors` feature, and only used by
+     * [`SeededCtx`][`crate::context::SeededCtx`]; the production contexts use
+     * [`OsRng`][`rand::rngs::OsRng`].
+     *
+     * # Examples
+     *
+     * ```
+     * use crypto::utils::rng::{Rng, SeededRng};
+     * use rand::RngCore;
+     *
+     * SeededRng::seed([7u8; 32]);
+     * let first = SeededRng::rng().next_u64();
+     *
+     * SeededRng::seed([7u8; 32]);
+     * assert_eq!(first, SeededRng::rng().next_u64());
+     * ```
+     */
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct SeededRng;
+
+    impl SeededRng {
+        /// Resets the stream of the current thread to the given seed.
+        pub fn seed(seed: [u8; 32]) {
+            STREAM.with(|stream| *stream.borrow_mut() = ChaCha20Rng::from_seed(seed));
+        }
+    }
+
+    impl rand::RngCore for SeededRng {
+        fn next_u32(&mut self) -> u32 {
+            STREAM.with(|stream| stream.borrow_mut().next_u32())
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            STREAM.with(|stream| stream.borrow_mut().next_u64())
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            STREAM.with(|stream| stream.borrow_mut().fill_bytes(dest));
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            STREAM.with(|stream| stream.borrow_mut().try_fill_bytes(dest))
+        }
+    }
+
+    /**
+     * `ChaCha20` is a cryptographically secure generator; its outputs are
+     * predictable from the seed, which is the point of test vectors.
+     */
+    impl rand::CryptoRng for SeededRng {}
+
+    impl CRng for SeededRng {}
+
+    /**
+     * Implements the random number generation [context][`crate::context::Context`] dependency with [`SeededRng`].
+     */
+    impl Rng for SeededRng {
+        fn rng() -> SeededRng {
+            SeededRng
+        }
+    }
+}
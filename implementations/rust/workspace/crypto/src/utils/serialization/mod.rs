@@ -125,7 +125,7 @@
 //!
 
 pub use fixed::{FDeserializable, FSer, FSerializable};
-pub use variable::{LargeVector, TFTuple, VDeserializable, VSer, VSerializable};
+pub use variable::{Bytes, LargeVector, TFTuple, VDeserializable, VSer, VSerializable};
 
 #[deny(clippy::indexing_slicing)]
 #[crate::warning(
@@ -7,7 +7,11 @@
  * @version 0.1
  */
 
-use crate::context::{Context, P256Ctx, RistrettoCtx};
+#[cfg(feature = "cryptol")]
+use crate::context::CryptolP256Ctx;
+#[cfg(any(test, feature = "test-vectors"))]
+use crate::context::SeededCtx;
+use crate::context::{Context, EgCtx, K256Ctx, P256Ctx, P384Ctx, RistrettoCtx};
 use crate::cryptosystem::{elgamal, naoryung};
 use crate::dkgd::dealer::{DealerShares, VerifiableShare};
 use crate::dkgd::recipient::{DecryptionFactor, DkgCiphertext, DkgPublicKey};
@@ -158,12 +162,111 @@ impl serde::Serialize for P256Ctx {
     }
 }
 
+// P384Ctx
+impl<'de> serde::Deserialize<'de> for P384Ctx {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(P384Ctx)
+    }
+}
+
+impl serde::Serialize for P384Ctx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&[])
+    }
+}
+
+// K256Ctx
+impl<'de> serde::Deserialize<'de> for K256Ctx {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(K256Ctx)
+    }
+}
+
+impl serde::Serialize for K256Ctx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&[])
+    }
+}
+
+// CryptolP256Ctx
+#[cfg(feature = "cryptol")]
+impl<'de> serde::Deserialize<'de> for CryptolP256Ctx {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(CryptolP256Ctx)
+    }
+}
+
+#[cfg(feature = "cryptol")]
+impl serde::Serialize for CryptolP256Ctx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&[])
+    }
+}
+
+// SeededCtx
+#[cfg(any(test, feature = "test-vectors"))]
+impl<'de, C: Context> serde::Deserialize<'de> for SeededCtx<C> {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(SeededCtx(std::marker::PhantomData))
+    }
+}
+
+#[cfg(any(test, feature = "test-vectors"))]
+impl<C: Context> serde::Serialize for SeededCtx<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&[])
+    }
+}
+
+// EgCtx
+impl<'de> serde::Deserialize<'de> for EgCtx {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(EgCtx)
+    }
+}
+
+impl serde::Serialize for EgCtx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&[])
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::context::{Context, P256Ctx, RistrettoCtx as RCtx};
     use crate::cryptosystem::{elgamal, naoryung};
-    use crate::dkgd::{DkgCiphertext, DkgPublicKey};
+    use crate::dkgd::recipient::{DkgCiphertext, DkgPublicKey};
     use crate::traits::groups::CryptoGroup;
     use crate::traits::groups::DistGroupOps;
     use crate::traits::groups::GroupElement;
@@ -223,7 +326,7 @@ mod tests {
         let eg_kp = elgamal::KeyPair::<P256Ctx>::generate();
         let kp = naoryung::KeyPair::new(&eg_kp, P256Ctx::random_element());
         let m = [P256Ctx::random_element(), P256Ctx::random_element()];
-        let ct = kp.encrypt(&m, &vec![]);
+        let ct = kp.encrypt(&m, &[]).unwrap();
 
         let serialized = bincode::serde::encode_to_vec(&ct, bincode::config::standard()).unwrap();
         let (deserialized, _): (naoryung::Ciphertext<P256Ctx, 2>, _) =
@@ -232,6 +335,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::similar_names)]
     fn test_serde_dlogeq_proof() {
         let secret_x = P256Ctx::random_scalar();
         let g1 = P256Ctx::random_element();
@@ -241,7 +345,7 @@ mod tests {
         let public_yn = gn.dist_exp(&secret_x);
 
         let proof: dlogeq::DlogEqProof<P256Ctx, 2> =
-            dlogeq::DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &vec![]);
+            dlogeq::DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &[]).unwrap();
         let serialized =
             bincode::serde::encode_to_vec(&proof, bincode::config::standard()).unwrap();
         let (deserialized, _): (dlogeq::DlogEqProof<P256Ctx, 2>, _) =
@@ -255,7 +359,7 @@ mod tests {
         let ny_kp = naoryung::KeyPair::new(&eg_kp, RCtx::random_element());
         let m = [RCtx::random_element(), RCtx::random_element()];
         let r = [RCtx::random_scalar(), RCtx::random_scalar()];
-        let ct = ny_kp.encrypt_with_r(&m, &r, &vec![]);
+        let ct = ny_kp.encrypt_with_r(&m, &r, &[]).unwrap();
 
         let proof: pleq::PlEqProof<RCtx, 2> = pleq::PlEqProof::prove(
             &ny_kp.pkey.pk_b,
@@ -264,8 +368,9 @@ mod tests {
             &ct.v_b,
             &ct.u_a,
             &r,
-            &vec![],
-        );
+            &[],
+        )
+        .unwrap();
         let serialized =
             bincode::serde::encode_to_vec(&proof, bincode::config::standard()).unwrap();
         let (deserialized, _): (pleq::PlEqProof<RCtx, 2>, _) =
@@ -279,7 +384,7 @@ mod tests {
         let secret_x = P256Ctx::random_scalar();
         let public_y = g.exp(&secret_x);
 
-        let proof = schnorr::SchnorrProof::<P256Ctx>::prove(&g, &public_y, &secret_x);
+        let proof = schnorr::SchnorrProof::<P256Ctx>::prove(&g, &public_y, &secret_x, &[]).unwrap();
         let serialized =
             bincode::serde::encode_to_vec(&proof, bincode::config::standard()).unwrap();
         let (deserialized, _): (schnorr::SchnorrProof<P256Ctx>, _) =
@@ -300,10 +405,10 @@ mod tests {
         let ciphertexts: Vec<elgamal::Ciphertext<P256Ctx, W>> =
             messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-        let generators = <P256Ctx as Context>::G::ind_generators(count, &vec![]);
+        let generators = <P256Ctx as Context>::G::ind_generators(count, &[]).unwrap();
         let shuffler = shuffle::Shuffler::<P256Ctx, W>::new(generators, keypair.pkey);
 
-        let (_, proof) = shuffler.shuffle(&ciphertexts, &vec![]);
+        let (_, proof) = shuffler.shuffle(&ciphertexts, &[]).unwrap();
         let serialized =
             bincode::serde::encode_to_vec(&proof, bincode::config::standard()).unwrap();
         let (deserialized, _): (shuffle::ShuffleProof<P256Ctx, W>, _) =
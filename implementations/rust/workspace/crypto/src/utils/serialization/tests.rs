@@ -8,55 +8,56 @@
  */
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::context::Context;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
     use crate::cryptosystem::elgamal::{Ciphertext, KeyPair};
-    use crate::utils::serialization::LargeVector;
     use crate::utils::serialization::fixed::{FDeserializable, FSerializable};
     use crate::utils::serialization::variable::LENGTH_BYTES;
     use crate::utils::serialization::variable::{VDeserializable, VSerializable};
+    use crate::utils::serialization::{Bytes, LargeVector};
     use vser_derive::VSerializable as VSer;
 
     #[test]
     fn test_struct_vser_ristretto() {
-        test_struct_vser::<RCtx>()
+        test_struct_vser::<RCtx>();
     }
 
     #[test]
     fn test_struct_vser_p256() {
-        test_struct_vser::<PCtx>()
+        test_struct_vser::<PCtx>();
     }
 
     #[test]
     fn test_elgamal_struct_vser_ristretto() {
-        test_elgamal_struct_vser::<RCtx>()
+        test_elgamal_struct_vser::<RCtx>();
     }
 
     #[test]
     fn test_elgamal_struct_vser_p256() {
-        test_elgamal_struct_vser::<PCtx>()
+        test_elgamal_struct_vser::<PCtx>();
     }
 
     #[test]
     fn test_vector_vser_ristretto() {
-        test_vector_vser::<RCtx>()
+        test_vector_vser::<RCtx>();
     }
 
     #[test]
     fn test_vector_vser_p256() {
-        test_vector_vser::<PCtx>()
+        test_vector_vser::<PCtx>();
     }
 
     #[test]
     fn test_4_struct_vser_ristretto() {
-        test_4_struct_vser::<RCtx>()
+        test_4_struct_vser::<RCtx>();
     }
 
     #[test]
     fn test_4_struct_vser_p256() {
-        test_4_struct_vser::<PCtx>()
+        test_4_struct_vser::<PCtx>();
     }
 
     fn test_struct_vser<Ctx: Context + PartialEq>() {
@@ -90,7 +91,7 @@ mod tests {
 
         let keypair = KeyPair::<Ctx>::generate();
         let message = Ctx::random_element();
-        let ciphertext: Ciphertext<Ctx, 1> = keypair.encrypt(&[message.clone()]);
+        let ciphertext: Ciphertext<Ctx, 1> = keypair.encrypt(std::array::from_ref(&message));
 
         let eg = EG::<Ctx> {
             keypair,
@@ -107,6 +108,7 @@ mod tests {
         assert_eq!(decrypted, [message]);
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn test_vector_vser<Ctx: Context>() {
         #[derive(Debug, VSer, PartialEq)]
         struct EG<Ctx: Context> {
@@ -122,13 +124,13 @@ mod tests {
 
         let ciphertexts: Vec<Ciphertext<Ctx, 1>> = messages
             .iter()
-            .map(|m| keypair.encrypt(&[m.clone()]))
+            .map(|m| keypair.encrypt(std::array::from_ref(m)))
             .collect();
 
         let eg = EG::<Ctx> {
             keypair,
             messages: messages.clone(),
-            ciphertexts: ciphertexts,
+            ciphertexts,
         };
 
         let serialized = eg.ser();
@@ -142,6 +144,7 @@ mod tests {
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn test_4_struct_vser<Ctx: Context + PartialEq>() {
         #[derive(Debug, VSer, PartialEq)]
         struct EG<Ctx: Context> {
@@ -159,13 +162,13 @@ mod tests {
             .collect();
 
         let ciphertexts: Vec<Ciphertext<Ctx, 2>> =
-            messages.iter().map(|m| keypair.encrypt(&m)).collect();
+            messages.iter().map(|m| keypair.encrypt(m)).collect();
 
         let tag = "test".to_string();
         let eg = EG {
             keypair,
             messages: messages.clone(),
-            ciphertexts: ciphertexts,
+            ciphertexts,
             tag: tag.clone(),
         };
 
@@ -197,6 +200,7 @@ mod tests {
         test_elgamal_largevector::<PCtx>();
     }
 
+    #[allow(clippy::arithmetic_side_effects)]
     fn test_elgamal_largevector<Ctx: Context>() {
         let mut lv = LargeVector(vec![]);
         let count = 5;
@@ -232,6 +236,7 @@ mod tests {
         test_tuple_struct_vser::<PCtx>();
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn test_tuple_struct_vser<Ctx: Context + PartialEq>() {
         #[derive(Debug, VSer, PartialEq)]
         struct EG<Ctx: Context>(
@@ -251,7 +256,7 @@ mod tests {
             .collect();
 
         let ciphertexts: Vec<Ciphertext<Ctx, 2>> =
-            messages.iter().map(|m| keypair.encrypt(&m)).collect();
+            messages.iter().map(|m| keypair.encrypt(m)).collect();
 
         let tag = "test".to_string();
         let eg = EG(keypair, messages.clone(), ciphertexts, tag.clone(), 1, 1);
@@ -298,4 +303,29 @@ mod tests {
         let decrypted = back.0.decrypt(&back.2);
         assert_eq!(message, decrypted);
     }
+
+    #[test]
+    fn test_bytes_vser() {
+        #[derive(Debug, VSer, PartialEq)]
+        struct Tagged {
+            tag: u32,
+            payload: Bytes,
+            chunks: Vec<Bytes>,
+        }
+
+        let payload = Bytes(vec![1, 2, 3, 4, 5]);
+        let chunks = vec![Bytes(vec![]), Bytes(vec![0xff; 33]), Bytes(vec![7])];
+        let tagged = Tagged {
+            tag: 42,
+            payload: payload.clone(),
+            chunks,
+        };
+
+        // raw bytes are not length prefixed individually
+        assert_eq!(payload.ser(), vec![1, 2, 3, 4, 5]);
+
+        let serialized = tagged.ser();
+        let back = Tagged::deser(&serialized).unwrap();
+        assert_eq!(tagged, back);
+    }
 }
@@ -28,6 +28,7 @@
 //! - [`LargeVector`] (for performant serialization on large data)
 //! - u32, u64
 //! - String
+//! - [`Bytes`]
 //!
 //! # Derive macro implementations
 //!
@@ -630,6 +631,43 @@ impl VDeserializable for String {
     }
 }
 
+/**
+ * A byte string with compact variable length serialization.
+ *
+ * The generic implementation for `Vec<T>` length prefixes every element,
+ * which is wasteful for raw bytes such as hashes or symmetric ciphertexts.
+ * This newtype instead serializes to its bytes unchanged; the length is
+ * given by the enclosing container's length prefix.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Bytes(pub Vec<u8>);
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Implements [`VSerializable`] for [`Bytes`]
+impl VSerializable for Bytes {
+    fn ser(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+}
+
+/// Implements [`VDeserializable`] for [`Bytes`]
+impl VDeserializable for Bytes {
+    fn deser(buffer: &[u8]) -> Result<Self, Error> {
+        Ok(Bytes(buffer.to_vec()))
+    }
+}
+
 /// Helper for checked addition of usize values
 fn checked_add(a: usize, b: usize) -> Result<usize, Error> {
     a.checked_add(b)
@@ -59,6 +59,9 @@ pub trait SignatureScheme<R: CRng> {
     ///
     /// The corresponding public verification key can be obtained with `signing_key.verifying_key()`.
     fn gen_signing_key(rng: &mut R) -> Self::Signer;
+
+    /// Returns the public verification key corresponding to the given signing key.
+    fn verifying_key(signer: &Self::Signer) -> Self::Verifier;
 }
 
 /**
@@ -92,6 +95,10 @@ impl<R: CRng> SignatureScheme<R> for Ed25519<R> {
     fn gen_signing_key(rng: &mut R) -> ed25519_dalek::SigningKey {
         Self::Signer::generate(rng)
     }
+
+    fn verifying_key(signer: &ed25519_dalek::SigningKey) -> ed25519_dalek::VerifyingKey {
+        signer.verifying_key()
+    }
 }
 
 impl FSerializable for SigningKey {
@@ -0,0 +1,65 @@
+/*
+ * Batch verification of proofs.
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+
+/**
+ * Accumulates the verification equations of many proofs into one.
+ *
+ * Each verification equation is written in the form `prod_i b_i^e_i == 1`.
+ * Every equation is raised to an independent random exponent `r`, and all of
+ * them are multiplied together, so that the batch is checked with a single
+ * multi-exponentiation. If some equation does not hold, the batch holds with
+ * probability at most `1/q`, where `q` is the order of the group.
+ *
+ * Exponents of the bases that are common to all equations, such as the
+ * generator, are accumulated separately so that each such base is
+ * exponentiated once.
+ */
+pub(crate) struct BatchVerifier<C: Context> {
+    /// bases of the combined equation
+    bases: Vec<C::Element>,
+    /// exponents of the combined equation, in the same order as `bases`
+    exponents: Vec<C::Scalar>,
+}
+
+impl<C: Context> BatchVerifier<C> {
+    /// Construct an empty [`BatchVerifier`] with the given common bases.
+    ///
+    /// The common bases are referred to by index in [`BatchVerifier::add_common`].
+    pub(crate) fn new(common: &[&C::Element]) -> Self {
+        Self {
+            bases: common.iter().map(|b| (*b).clone()).collect(),
+            exponents: vec![C::Scalar::zero(); common.len()],
+        }
+    }
+
+    /// Returns a fresh random exponent for the next equation.
+    pub(crate) fn coefficient() -> C::Scalar {
+        C::random_scalar()
+    }
+
+    /// Add `base^exponent` to the combined equation.
+    pub(crate) fn add(&mut self, base: &C::Element, exponent: C::Scalar) {
+        self.bases.push(base.clone());
+        self.exponents.push(exponent);
+    }
+
+    /// Add `base^exponent` to the combined equation, where `base` is the common base at `index`.
+    pub(crate) fn add_common(&mut self, index: usize, exponent: &C::Scalar) {
+        self.exponents[index] = self.exponents[index].add(exponent);
+    }
+
+    /// Returns `true` if the combined equation holds.
+    pub(crate) fn check(&self) -> bool {
+        C::Element::multi_exp(&self.bases, &self.exponents).equals(&C::Element::one())
+    }
+}
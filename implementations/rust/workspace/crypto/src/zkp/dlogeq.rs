@@ -9,11 +9,12 @@
 
 use crate::context::Context;
 use crate::traits::groups::CryptoGroup;
-use crate::traits::groups::DistGroupOps;
-use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::utils::error::Error;
 use crate::utils::serialization::VSerializable;
+use crate::zkp::batch::BatchVerifier;
+use crate::zkp::sigma::{Homomorphism, Relation};
+use crate::zkp::transcript::{ChallengeVersion, Transcript};
 use vser_derive::VSerializable as VSer;
 
 /**
@@ -103,17 +104,46 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
         y1: &[C::Element; W],
         proof_context: &[u8],
     ) -> Result<DlogEqProof<C, W>, Error> {
-        let a = C::random_scalar();
-        let big_a_0 = g0.exp(&a);
-        let big_a_1 = g1.dist_exp(&a);
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
 
-        let (input, dsts) =
-            Self::challenge_input(g0, g1, y0, y1, &big_a_0, &big_a_1, proof_context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+        Self::prove_with_transcript(&mut transcript, secret_x, g0, y0, g1, y1)
+    }
+
+    /// Prove equality of discrete logarithms such that `y_0 = g_0^secret_x`
+    /// and `y_1 = g_1^secret_x`, deriving the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `g0`: The first group element base, public
+    /// - `y0`: The first group element `y0` = `g0^secret_x`, public
+    /// - `g1`: The second group element bases of width `W`, public
+    /// - `y1`: The second group elements `y1` = `g1^secret_x` of width `W`, public
+    /// - `secret_x`: The secret scalar
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`DlogEqProof`] instance.
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        secret_x: &C::Scalar,
+        g0: &C::Element,
+        y0: &C::Element,
+        g1: &[C::Element; W],
+        y1: &[C::Element; W],
+    ) -> Result<DlogEqProof<C, W>, Error> {
+        let relation = Self::relation(g0, y0, g1, y1)?;
+        let (a, big_a) = relation.commit();
+        let (big_a_0, big_a_1) = Self::split(big_a)?;
+
+        let v = Self::challenge(transcript, g0, g1, y0, y1, &big_a_0, &big_a_1)?;
+
+        let k = relation.respond(std::slice::from_ref(secret_x), &a, &v)?;
+        let [k] = <[C::Scalar; 1]>::try_from(k)
+            .map_err(|_| Error::SigmaProofError("Expected a single response".to_string()))?;
 
-        let vx = v.mul(secret_x);
-        let k = a.add(&vx);
         Ok(Self::new(big_a_0, big_a_1, k))
     }
 
@@ -142,29 +172,299 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
         y1: &[C::Element; W],
         proof_context: &[u8],
     ) -> Result<bool, Error> {
-        let k = &self.k;
+        self.verify_with_version(ChallengeVersion::CURRENT, g0, y0, g1, y1, proof_context)
+    }
 
-        let (input, dsts) =
-            Self::challenge_input(g0, g1, y0, y1, &self.big_a_0, &self.big_a_1, proof_context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+    /// Verify this proof of equality of discrete logarithms, computed with the
+    /// given challenge version.
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `g0`: The first group element base
+    /// - `y0`: The first group element value
+    /// - `g1`: The second group element bases of width `W`
+    /// - `y1`: The second group elements of width `W`
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        g0: &C::Element,
+        y0: &C::Element,
+        g1: &[C::Element; W],
+        y1: &[C::Element; W],
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        match version {
+            ChallengeVersion::V0 => {
+                let v = Self::legacy_challenge(
+                    g0,
+                    g1,
+                    y0,
+                    y1,
+                    &self.big_a_0,
+                    &self.big_a_1,
+                    proof_context,
+                )?;
+
+                self.check(g0, y0, g1, y1, &v)
+            }
+            ChallengeVersion::V1 => {
+                let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+                self.verify_with_transcript(&mut transcript, g0, y0, g1, y1)
+            }
+        }
+    }
+
+    /// Verify this proof of equality of discrete logarithms, deriving the
+    /// challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `g0`: The first group element base
+    /// - `y0`: The first group element value
+    /// - `g1`: The second group element bases of width `W`
+    /// - `y1`: The second group elements of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        g0: &C::Element,
+        y0: &C::Element,
+        g1: &[C::Element; W],
+        y1: &[C::Element; W],
+    ) -> Result<bool, Error> {
+        let v = Self::challenge(transcript, g0, g1, y0, y1, &self.big_a_0, &self.big_a_1)?;
+
+        self.check(g0, y0, g1, y1, &v)
+    }
+
+    /// Verify a batch of proofs of equality of discrete logarithms with a
+    /// common first base.
+    ///
+    /// Equivalent to calling [`DlogEqProof::verify`] on every proof, up to a
+    /// negligible probability of error, but computes a single
+    /// multi-exponentiation. Returns `false` if any proof is invalid, without
+    /// identifying it.
+    ///
+    /// # Parameters
+    ///
+    /// - `g0`: The first group element base, common to all proofs
+    /// - `proofs`: tuples of a proof, its first group element value `y0`, its
+    ///   second group element bases `g1` and its second group element values `y1`
+    /// - `proof_context`: proof context label (ZKP CONTEXT), common to all proofs
+    ///
+    /// See also [`combine`][`crate::dkgd::recipient::combine`]
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if all proofs are valid, `false` otherwise.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(
+        g0: &C::Element,
+        proofs: &[(&Self, &C::Element, &[C::Element; W], &[C::Element; W])],
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        Self::verify_batch_with_version(ChallengeVersion::CURRENT, g0, proofs, proof_context)
+    }
+
+    /// Verify a batch of proofs of equality of discrete logarithms with a
+    /// common first base, computed with the given challenge version.
+    ///
+    /// See [`DlogEqProof::verify_batch`].
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proofs were computed with
+    /// - `g0`: The first group element base, common to all proofs
+    /// - `proofs`: tuples of a proof, its first group element value `y0`, its
+    ///   second group element bases `g1` and its second group element values `y1`
+    /// - `proof_context`: proof context label (ZKP CONTEXT), common to all proofs
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if all proofs are valid, `false` otherwise.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch_with_version(
+        version: ChallengeVersion,
+        g0: &C::Element,
+        proofs: &[(&Self, &C::Element, &[C::Element; W], &[C::Element; W])],
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        let mut batch = BatchVerifier::<C>::new(&[g0]);
+        // every proof starts from the same transcript prefix
+        let transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        for (proof, y0, g1, y1) in proofs {
+            let (big_a_0, big_a_1) = (&proof.big_a_0, &proof.big_a_1);
+            let v = match version {
+                ChallengeVersion::V0 => {
+                    Self::legacy_challenge(g0, g1, y0, y1, big_a_0, big_a_1, proof_context)?
+                }
+                ChallengeVersion::V1 => {
+                    Self::challenge(&mut transcript.clone(), g0, g1, y0, y1, big_a_0, big_a_1)?
+                }
+            };
+
+            // g0^k == y0^v * big_a_0
+            let r = BatchVerifier::<C>::coefficient();
+            batch.add_common(0, &r.mul(&proof.k));
+            batch.add(y0, r.mul(&v).neg());
+            batch.add(&proof.big_a_0, r.neg());
+
+            // g1^k == y1^v * big_a_1, for each of the W components
+            for ((g1, y1), big_a_1) in g1.iter().zip(y1.iter()).zip(proof.big_a_1.iter()) {
+                let r = BatchVerifier::<C>::coefficient();
+                batch.add(g1, r.mul(&proof.k));
+                batch.add(y1, r.mul(&v).neg());
+                batch.add(big_a_1, r.neg());
+            }
+        }
+
+        Ok(batch.check())
+    }
+
+    /// Returns the [`Relation`] `y0 = g0^secret_x`, `y1 = g1^secret_x` of this proof.
+    ///
+    /// The image, and the commitment, are `y0` followed by the `W` elements of `y1`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    pub fn relation(
+        g0: &C::Element,
+        y0: &C::Element,
+        g1: &[C::Element; W],
+        y1: &[C::Element; W],
+    ) -> Result<Relation<C>, Error> {
+        let rows = std::iter::once(g0)
+            .chain(g1.iter())
+            .map(|g| vec![(0, g.clone())])
+            .collect();
+        let map = Homomorphism::new(1, rows)?;
+        let image = std::iter::once(y0).chain(y1.iter()).cloned().collect();
+
+        Relation::new(map, image)
+    }
 
-        let y0_v = y0.exp(&v);
-        let y0_v_big_a_0 = y0_v.mul(&self.big_a_0);
-        let g0_k = g0.exp(k);
-        let check1 = y0_v_big_a_0.equals(&g0_k);
+    /// Splits a commitment of [`DlogEqProof::relation`] into `big_a_0` and `big_a_1`.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the commitment does not have `1 + W` elements
+    fn split(commitment: Vec<C::Element>) -> Result<(C::Element, [C::Element; W]), Error> {
+        let mut commitment = commitment.into_iter();
+        let big_a_0 = commitment.next();
+        let big_a_1 = <[C::Element; W]>::try_from(commitment.collect::<Vec<C::Element>>());
+
+        match (big_a_0, big_a_1) {
+            (Some(big_a_0), Ok(big_a_1)) => Ok((big_a_0, big_a_1)),
+            _ => Err(Error::SigmaProofError(format!(
+                "Expected a commitment of 1 + {W} elements"
+            ))),
+        }
+    }
+
+    /// Returns `true` if the verification equations `g0^k == y0^v * big_a_0`
+    /// and `g1^k == y1^v * big_a_1` hold for challenge `v`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    fn check(
+        &self,
+        g0: &C::Element,
+        y0: &C::Element,
+        g1: &[C::Element; W],
+        y1: &[C::Element; W],
+        v: &C::Scalar,
+    ) -> Result<bool, Error> {
+        let relation = Self::relation(g0, y0, g1, y1)?;
+        let big_a: Vec<C::Element> = std::iter::once(&self.big_a_0)
+            .chain(self.big_a_1.iter())
+            .cloned()
+            .collect();
+
+        Ok(relation.check(&big_a, v, std::slice::from_ref(&self.k)))
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"dlogeq_proof";
+
+    /// Absorbs the statement and the commitments, and returns the challenge.
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `g0`: The first group element base
+    /// - `g1`: The second group element bases of width `W`
+    /// - `y0`: The first group element value
+    /// - `y1`: The second group elements of width `W`
+    /// - `big_a_0`: The first group element commitment
+    /// - `big_a_1`: The second group element commitments of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        g0: &C::Element,
+        g1: &[C::Element; W],
+        y0: &C::Element,
+        y1: &[C::Element; W],
+        big_a_0: &C::Element,
+        big_a_1: &[C::Element; W],
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"g0", g0);
+        transcript.absorb(b"g1", g1);
+        transcript.absorb(b"y0", y0);
+        transcript.absorb(b"y1", y1);
+        transcript.absorb(b"big_a_0", big_a_0);
+        transcript.absorb(b"big_a_1", big_a_1);
+
+        transcript.challenge(b"dlogeq_challenge")
+    }
 
-        let y1_v = y1.dist_exp(&v);
-        let y1_v_big_a_1 = y1_v.mul(&self.big_a_1);
-        let g1_k = g1.dist_exp(k);
-        let check2 = y1_v_big_a_1.equals(&g1_k);
+    /// Computes the [`ChallengeVersion::V0`] challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    pub(crate) fn legacy_challenge(
+        g0: &C::Element,
+        g1: &[C::Element; W],
+        y0: &C::Element,
+        y1: &[C::Element; W],
+        big_a_0: &C::Element,
+        big_a_1: &[C::Element; W],
+        proof_context: &[u8],
+    ) -> Result<C::Scalar, Error> {
+        let (input, dsts) =
+            Self::legacy_challenge_input(g0, g1, y0, y1, big_a_0, big_a_1, proof_context);
+        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
 
-        Ok(check1 && check2)
+        C::G::hash_to_scalar(&input, &dsts)
     }
 
-    /// Domain separation tags for the challenge input
-    #[crate::warning("Challenge inputs are incomplete.")]
-    const DS_TAGS: [&[u8]; 7] = [
+    /// Domain separation tags for the [`ChallengeVersion::V0`] challenge input
+    const LEGACY_DS_TAGS: [&[u8]; 7] = [
         b"g0",
         b"g1",
         b"y0",
@@ -174,7 +474,7 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
         b"dlogeq_proof_context",
     ];
 
-    /// Compute the challenge input for the discrete logarithm equality proof.
+    /// Compute the [`ChallengeVersion::V0`] challenge input for the discrete logarithm equality proof.
     ///
     /// # Params
     ///
@@ -189,7 +489,7 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
     /// Returns byte arrays for input values and domain separation tags.
     /// These values will be passed to the hash function to compute
     /// the challenge.
-    fn challenge_input(
+    fn legacy_challenge_input(
         g0: &C::Element,
         g1: &[C::Element; W],
         y0: &C::Element,
@@ -208,7 +508,7 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
             proof_context.to_vec(),
         ];
 
-        (a, Self::DS_TAGS)
+        (a, Self::LEGACY_DS_TAGS)
     }
 }
 
@@ -216,8 +516,11 @@ impl<C: Context, const W: usize> DlogEqProof<C, W> {
 mod tests {
     use super::*;
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
+    use crate::traits::groups::DistGroupOps;
+    use crate::traits::groups::GroupElement;
     use crate::utils::serialization::{FDeserializable, FSerializable};
 
     #[test]
@@ -235,11 +538,26 @@ mod tests {
         test_dlogeq_proof_invalid::<RCtx>();
     }
 
+    #[test]
+    fn test_dlogeq_proof_batch_ristretto() {
+        test_dlogeq_proof_batch::<RCtx>();
+    }
+
+    #[test]
+    fn test_dlogeq_proof_legacy_ristretto() {
+        test_dlogeq_proof_legacy::<RCtx>();
+    }
+
     #[test]
     fn test_dlogeq_proof_valid_p256() {
         test_dlogeq_proof_valid::<PCtx>();
     }
 
+    #[test]
+    fn test_dlogeq_proof_valid_electionguard() {
+        test_dlogeq_proof_valid::<ECtx>();
+    }
+
     #[test]
     fn dlogeq_proof_serialization_p256() {
         test_dlogeq_proof_serialization::<PCtx>();
@@ -250,6 +568,17 @@ mod tests {
         test_dlogeq_proof_invalid::<PCtx>();
     }
 
+    #[test]
+    fn test_dlogeq_proof_invalid_electionguard() {
+        test_dlogeq_proof_invalid::<ECtx>();
+    }
+
+    #[test]
+    fn test_dlogeq_proof_batch_p256() {
+        test_dlogeq_proof_batch::<PCtx>();
+    }
+
+    #[allow(clippy::similar_names)]
     fn test_dlogeq_proof_valid<Ctx: Context>() {
         let secret_x = Ctx::random_scalar();
         let g1 = Ctx::random_element();
@@ -264,16 +593,55 @@ mod tests {
         let public_yn = [public_y2, public_y3];
 
         let proof: DlogEqProof<Ctx, 2> =
-            DlogEqProof::<Ctx, 2>::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &vec![])
+            DlogEqProof::<Ctx, 2>::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &[]).unwrap();
+        assert!(
+            proof.verify(&g1, &public_y1, &gn, &public_yn, &[]).unwrap(),
+            "Verification of a valid DlogEqProof proof should succeed"
+        );
+    }
+
+    fn test_dlogeq_proof_legacy<Ctx: Context>() {
+        let secret_x = Ctx::random_scalar();
+        let g0 = Ctx::generator();
+        let g1 = [Ctx::random_element(), Ctx::random_element()];
+        let y0 = g0.exp(&secret_x);
+        let y1 = g1.dist_exp(&secret_x);
+
+        // a proof computed with the V0 challenge
+        let a = Ctx::random_scalar();
+        let big_a_0 = g0.exp(&a);
+        let big_a_1 = g1.dist_exp(&a);
+        let v =
+            DlogEqProof::<Ctx, 2>::legacy_challenge(&g0, &g1, &y0, &y1, &big_a_0, &big_a_1, &[])
                 .unwrap();
+        let legacy = DlogEqProof::<Ctx, 2>::new(big_a_0, big_a_1, a.add(&v.mul(&secret_x)));
+
+        assert!(
+            legacy
+                .verify_with_version(ChallengeVersion::V0, &g0, &y0, &g1, &y1, &[])
+                .unwrap()
+        );
+        assert!(!legacy.verify(&g0, &y0, &g1, &y1, &[]).unwrap());
+        let batch = [(&legacy, &y0, &g1, &y1)];
+        assert!(
+            DlogEqProof::verify_batch_with_version(ChallengeVersion::V0, &g0, &batch, &[]).unwrap()
+        );
+        assert!(!DlogEqProof::verify_batch(&g0, &batch, &[]).unwrap());
+
+        let proof = DlogEqProof::<Ctx, 2>::prove(&secret_x, &g0, &y0, &g1, &y1, &[]).unwrap();
         assert!(
             proof
-                .verify(&g1, &public_y1, &gn, &public_yn, &vec![])
-                .unwrap(),
-            "Verification of a valid DlogEqProof proof should succeed"
+                .verify_with_version(ChallengeVersion::CURRENT, &g0, &y0, &g1, &y1, &[])
+                .unwrap()
+        );
+        assert!(
+            !proof
+                .verify_with_version(ChallengeVersion::V0, &g0, &y0, &g1, &y1, &[])
+                .unwrap()
         );
     }
 
+    #[allow(clippy::similar_names)]
     fn test_dlogeq_proof_serialization<Ctx: Context>() {
         let secret_x = Ctx::random_scalar();
         let g1 = Ctx::random_element();
@@ -283,14 +651,14 @@ mod tests {
         let public_yn = gn.dist_exp(&secret_x);
 
         let proof: DlogEqProof<Ctx, 2> =
-            DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &vec![]).unwrap();
+            DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &[]).unwrap();
         let proof_bytes = proof.ser_f();
         assert_eq!(proof_bytes.len(), DlogEqProof::<Ctx, 2>::size_bytes());
 
         let parsed_proof = DlogEqProof::<Ctx, 2>::deser_f(&proof_bytes).unwrap();
         assert!(
             parsed_proof
-                .verify(&g1, &public_y1, &gn, &public_yn, &vec![])
+                .verify(&g1, &public_y1, &gn, &public_yn, &[])
                 .unwrap(),
             "Verification of a parsed valid Chaum-Pedersen proof should succeed"
         );
@@ -300,6 +668,7 @@ mod tests {
         assert_eq!(proof.k, parsed_proof.k, "s should match");
     }
 
+    #[allow(clippy::similar_names)]
     fn test_dlogeq_proof_invalid<Ctx: Context>() {
         let secret_x = Ctx::random_scalar();
         let g1 = Ctx::random_element();
@@ -309,16 +678,55 @@ mod tests {
         let public_yn = gn.dist_exp(&secret_x);
 
         let proof: DlogEqProof<Ctx, 2> =
-            DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &vec![]).unwrap();
+            DlogEqProof::prove(&secret_x, &g1, &public_y1, &gn, &public_yn, &[]).unwrap();
 
         let original_s = proof.k;
         let tampered_k = original_s.add(&Ctx::Scalar::one());
         let tampered_proof = DlogEqProof::<Ctx, 2>::new(proof.big_a_0, proof.big_a_1, tampered_k);
         assert!(
             !tampered_proof
-                .verify(&g1, &public_y1, &gn, &public_yn, &vec![])
+                .verify(&g1, &public_y1, &gn, &public_yn, &[])
                 .unwrap(),
             "Verification of a DlogEq proof with a tampered response 's' should fail"
         );
     }
+
+    #[allow(clippy::similar_names)]
+    fn test_dlogeq_proof_batch<Ctx: Context>() {
+        let g0 = Ctx::generator();
+        let statements: Vec<_> = (0..4)
+            .map(|_| {
+                let secret_x = Ctx::random_scalar();
+                let gn = [Ctx::random_element(), Ctx::random_element()];
+                let public_y0 = g0.exp(&secret_x);
+                let public_yn = gn.dist_exp(&secret_x);
+                let proof: DlogEqProof<Ctx, 2> =
+                    DlogEqProof::prove(&secret_x, &g0, &public_y0, &gn, &public_yn, &[]).unwrap();
+                (proof, public_y0, gn, public_yn)
+            })
+            .collect();
+
+        let batch: Vec<_> = statements
+            .iter()
+            .map(|(p, y0, g1, y1)| (p, y0, g1, y1))
+            .collect();
+        assert!(DlogEqProof::verify_batch(&g0, &batch, &[]).unwrap());
+        assert!(DlogEqProof::<Ctx, 2>::verify_batch(&g0, &[], &[]).unwrap());
+
+        // an error in one component of the second equation
+        let mut tampered = statements[1].0.clone();
+        tampered.big_a_1[1] = tampered.big_a_1[1].mul(&g0);
+        let mut batch = batch;
+        batch[1].0 = &tampered;
+        assert!(!DlogEqProof::verify_batch(&g0, &batch, &[]).unwrap());
+
+        // errors in the first and second equations that would cancel out
+        // if both were raised to the same exponent
+        let mut tampered = statements[2].0.clone();
+        tampered.big_a_0 = tampered.big_a_0.mul(&g0);
+        tampered.big_a_1[0] = tampered.big_a_1[0].mul(&g0.inv());
+        batch[1].0 = &statements[1].0;
+        batch[2].0 = &tampered;
+        assert!(!DlogEqProof::verify_batch(&g0, &batch, &[]).unwrap());
+    }
 }
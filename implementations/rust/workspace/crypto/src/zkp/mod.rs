@@ -9,6 +9,32 @@
 
 //! Zero knowledge proofs
 //!
+//! # [Transcripts][`crate::zkp::transcript`]
+//!
+//! All proofs derive their challenges from a Fiat-Shamir
+//! [`Transcript`][`crate::zkp::transcript::Transcript`], which absorbs the
+//! protocol label, the group, the proof context and the full statement before
+//! any commitment. Proofs computed before transcripts were introduced can be
+//! verified with the `verify_with_version` functions and
+//! [`ChallengeVersion::V0`][`crate::zkp::transcript::ChallengeVersion::V0`].
+//!
+//! # Batch verification
+//!
+//! Schnorr, discrete log equality and plaintext equality proofs can be
+//! verified in batches with random linear combinations, see
+//! [`DlogEqProof::verify_batch`][`crate::zkp::dlogeq::DlogEqProof::verify_batch`].
+//! Batches are used in [distributed decryption][`crate::dkgd::recipient::combine`]
+//! and to [strip][`crate::cryptosystem::naoryung::PublicKey::strip_batch`]
+//! Naor-Yung ciphertexts.
+//!
+//! # [Re-encryption][`crate::zkp::reencryption`]
+//!
+//! Proves that an `ElGamal` ciphertext is a re-encryption of another, either
+//! publicly or to a designated verifier only.
+//!
+//! These proofs are used to prove re-randomization of ciphertexts, see
+//! [`re_encrypt`][`crate::cryptosystem::elgamal::Ciphertext::re_encrypt`].
+//!
 //! # [Schnorr][`crate::zkp::schnorr`]
 //!
 //! Proves knowledge of a discrete logarithm.
@@ -25,6 +51,8 @@
 //!
 //! This proof is used to verify partial decryption correctness.
 //! See [`decryption_factor`][`crate::dkgd::recipient::Recipient::decryption_factor`]
+//! It also proves single-key decryption, see
+//! [`decrypt_with_proof`][`crate::cryptosystem::elgamal::KeyPair::decrypt_with_proof`].
 //!
 //! # [Plaintext equality][`crate::zkp::pleq`]
 //!
@@ -35,6 +63,25 @@
 //! This proof is used to construct the [`Naor-Yung`][`crate::cryptosystem::naoryung`]
 //! cryptosystem and in the validation of its ciphertexts.
 //!
+//! # [Range][`crate::zkp::range`]
+//!
+//! Disjunctive Chaum-Pedersen proofs that an exponential `ElGamal` ciphertext
+//! encrypts a value in `0..=max`, and that a contest's selections add up to at
+//! most its selection limit.
+//!
+//! These proofs are used to validate encrypted selections for homomorphic
+//! tallying, see [`exponential`][`crate::cryptosystem::exponential`].
+//!
+//! # [Sigma protocols][`crate::zkp::sigma`]
+//!
+//! Generic proofs of knowledge of a preimage of a group homomorphism, with
+//! conjunctive and disjunctive composition and simulation.
+//!
+//! The [Schnorr][`crate::zkp::schnorr`], [discrete log equality][`crate::zkp::dlogeq`]
+//! and [plaintext equality][`crate::zkp::pleq`] proofs are instances of
+//! [`Relation`][`crate::zkp::sigma::Relation`], with their own serialization
+//! and challenges.
+//!
 //! # [Shuffle][`crate::zkp::shuffle`]
 //!
 //! Terelius-Wikstrom proof of shuffle.
@@ -45,15 +92,30 @@
 //! their inputs, or equivalently that their set of corresponding plaintexts
 //! are equal.
 
+/// Batch verification of proofs.
+pub(crate) mod batch;
+
 /// Discrete logarithm equality proofs.
 pub mod dlogeq;
 
 /// Plaintext equality proofs.
 pub mod pleq;
 
+/// Disjunctive range proofs.
+pub mod range;
+
+/// Re-encryption proofs.
+pub mod reencryption;
+
 /// Schnorr knowledge of discrete logarithm proofs.
 pub mod schnorr;
 
 #[crate::warning("Asserts are present in this module")]
 /// Terelius-Wikstrom proof of shuffle.
 pub mod shuffle;
+
+/// Generic Sigma protocols.
+pub mod sigma;
+
+/// Fiat-Shamir transcripts.
+pub mod transcript;
@@ -9,13 +9,12 @@
 
 use crate::context::Context;
 use crate::traits::groups::CryptoGroup;
-use crate::traits::groups::DistGroupOps;
-use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
-use crate::traits::groups::ReplGroupOps;
-use crate::traits::groups::ReplScalarOps;
 use crate::utils::error::Error;
 use crate::utils::serialization::VSerializable;
+use crate::zkp::batch::BatchVerifier;
+use crate::zkp::sigma::{Homomorphism, Relation};
+use crate::zkp::transcript::{ChallengeVersion, Transcript};
 use vser_derive::VSerializable as VSer;
 
 /**
@@ -117,26 +116,116 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
         r: &[C::Scalar; W],
         proof_context: &[u8],
     ) -> Result<PlEqProof<C, W>, Error> {
-        let g = C::generator();
         let mut rng = C::get_rng();
         let a_prime = <[C::Scalar; W]>::random(&mut rng);
-        let a = a_prime.mul(r);
-        let big_a_g = g.repl_exp(&a);
-        let big_a_z = z.repl_exp(&a);
 
-        let big_a = [big_a_g, big_a_z];
+        Self::prove_with_randomness(y, z, u_b, v_b, u_a, r, &a_prime, proof_context)
+    }
 
-        let (input, dsts) = Self::challenge_input(&g, y, z, u_b, v_b, u_a, &big_a, proof_context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+    /// Prove equality of plaintexts for a Naor-Yung ciphertext, using the given
+    /// prover randomness.
+    ///
+    /// This function is deterministic in its inputs, which allows a party
+    /// that learns `r` and `a_prime` to recompute the proof, as required by the
+    /// [ballot check][`crate::ballot_check`]. Use [`PlEqProof::prove`] otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `u_b`: The Naor-Yung ciphertext component `u_b`, of width `W`
+    /// - `v_b`: The Naor-Yung ciphertext component `v_b`, of width `W`
+    /// - `u_a`: The Naor-Yung ciphertext component `u_a`, of width `W`
+    /// - `r`: The random scalar used in the encryption, of width `W`
+    /// - `a_prime`: The prover randomness, of width `W`
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`PlEqProof`] instance.
+    #[allow(clippy::too_many_arguments, clippy::many_single_char_names)]
+    pub fn prove_with_randomness(
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        r: &[C::Scalar; W],
+        a_prime: &[C::Scalar; W],
+        proof_context: &[u8],
+    ) -> Result<PlEqProof<C, W>, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        Self::prove_with_transcript_randomness(&mut transcript, y, z, u_b, v_b, u_a, r, a_prime)
+    }
 
-        let vr = v.repl_mul(r);
-        let k = vr.add(&a);
+    /// Prove equality of plaintexts for a Naor-Yung ciphertext, deriving the
+    /// challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `u_b`: The Naor-Yung ciphertext component `u_b`, of width `W`
+    /// - `v_b`: The Naor-Yung ciphertext component `v_b`, of width `W`
+    /// - `u_a`: The Naor-Yung ciphertext component `u_a`, of width `W`
+    /// - `r`: The random scalar used in the encryption, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`PlEqProof`] instance.
+    #[allow(clippy::many_single_char_names)]
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        r: &[C::Scalar; W],
+    ) -> Result<PlEqProof<C, W>, Error> {
+        let mut rng = C::get_rng();
+        let a_prime = <[C::Scalar; W]>::random(&mut rng);
+
+        Self::prove_with_transcript_randomness(transcript, y, z, u_b, v_b, u_a, r, &a_prime)
+    }
+
+    /// Prove equality of plaintexts for a Naor-Yung ciphertext, deriving the
+    /// challenge from the given transcript and using the given prover randomness.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    #[allow(clippy::too_many_arguments, clippy::many_single_char_names)]
+    fn prove_with_transcript_randomness(
+        transcript: &mut Transcript<C>,
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        r: &[C::Scalar; W],
+        a_prime: &[C::Scalar; W],
+    ) -> Result<PlEqProof<C, W>, Error> {
+        let relation = Self::relation(z, u_b, u_a)?;
+        let a = a_prime.mul(r);
+        let big_a = Self::split(relation.commit_with_nonce(&a)?)?;
+
+        let v = Self::challenge(transcript, y, z, u_b, v_b, u_a, &big_a)?;
+
+        let k = relation.respond(r, &a, &v)?;
+        let k = <[C::Scalar; W]>::try_from(k)
+            .map_err(|_| Error::SigmaProofError(format!("Expected {W} responses")))?;
 
         Ok(PlEqProof::new(big_a, k))
     }
 
-    /// Verify this Schnorr proof of knowledge.
+    /// Verify this proof of equality of plaintexts.
     ///
     /// - `y`: The Naor-Yung public key `y`
     /// - `z`: The Naor-Yung public key `z`
@@ -158,24 +247,259 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
         v_b: &[C::Element; W],
         u_a: &[C::Element; W],
         context: &[u8],
+    ) -> Result<bool, Error> {
+        self.verify_with_version(ChallengeVersion::CURRENT, y, z, u_b, v_b, u_a, context)
+    }
+
+    /// Verify this proof of equality of plaintexts, computed with the given
+    /// challenge version.
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `u_b`: The Naor-Yung ciphertext component `u_b`, of width `W`
+    /// - `v_b`: The Naor-Yung ciphertext component `v_b`, of width `W`
+    /// - `u_a`: The Naor-Yung ciphertext component `u_a`, of width `W`
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        match version {
+            ChallengeVersion::V0 => {
+                let v = Self::legacy_challenge(y, z, u_b, v_b, u_a, &self.big_a, proof_context)?;
+
+                self.check(z, u_b, u_a, &v)
+            }
+            ChallengeVersion::V1 => {
+                let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+                self.verify_with_transcript(&mut transcript, y, z, u_b, v_b, u_a)
+            }
+        }
+    }
+
+    /// Verify this proof of equality of plaintexts, deriving the challenge
+    /// from the given transcript.
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `u_b`: The Naor-Yung ciphertext component `u_b`, of width `W`
+    /// - `v_b`: The Naor-Yung ciphertext component `v_b`, of width `W`
+    /// - `u_a`: The Naor-Yung ciphertext component `u_a`, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+    ) -> Result<bool, Error> {
+        let v = Self::challenge(transcript, y, z, u_b, v_b, u_a, &self.big_a)?;
+
+        self.check(z, u_b, u_a, &v)
+    }
+
+    /// Verify a batch of proofs of equality of plaintexts under the same
+    /// Naor-Yung public key.
+    ///
+    /// Equivalent to calling [`PlEqProof::verify`] on every proof, up to a
+    /// negligible probability of error, but computes a single
+    /// multi-exponentiation. Returns `false` if any proof is invalid, without
+    /// identifying it.
+    ///
+    /// # Parameters
+    ///
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `proofs`: tuples of a proof and its Naor-Yung ciphertext components `u_b`, `v_b` and `u_a`
+    /// - `proof_context`: proof context label (ZKP CONTEXT), common to all proofs
+    ///
+    /// See also [`PublicKey::strip_batch`][`crate::cryptosystem::naoryung::PublicKey::strip_batch`]
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if all proofs are valid, `false` otherwise.
+    #[allow(clippy::type_complexity, clippy::many_single_char_names)]
+    pub fn verify_batch(
+        y: &C::Element,
+        z: &C::Element,
+        proofs: &[(&Self, &[C::Element; W], &[C::Element; W], &[C::Element; W])],
+        proof_context: &[u8],
     ) -> Result<bool, Error> {
         let g = C::generator();
-        let (input, dsts) = Self::challenge_input(&g, y, z, u_b, v_b, u_a, &self.big_a, context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+        let mut batch = BatchVerifier::<C>::new(&[&g, z]);
+        // every proof starts from the same transcript prefix
+        let transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        for (proof, u_b, v_b, u_a) in proofs {
+            let v = Self::challenge(&mut transcript.clone(), y, z, u_b, v_b, u_a, &proof.big_a)?;
+
+            // g^k == u_b^v * big_a[0] and z^k == u_a^v * big_a[1], component-wise
+            for (common, (u, big_a)) in [(u_b, &proof.big_a[0]), (u_a, &proof.big_a[1])]
+                .into_iter()
+                .enumerate()
+            {
+                for ((u, big_a), k) in u.iter().zip(big_a.iter()).zip(proof.k.iter()) {
+                    let r = BatchVerifier::<C>::coefficient();
+                    batch.add_common(common, &r.mul(k));
+                    batch.add(u, r.mul(&v).neg());
+                    batch.add(big_a, r.neg());
+                }
+            }
+        }
+
+        Ok(batch.check())
+    }
 
-        let f_k = [g, z.clone()].map(|e| e.repl_exp(&self.k));
+    /// Returns the [`Relation`] `u_b = g^r`, `u_a = z^r` of this proof, for
+    /// the generator `g` and the randomness `r` of width `W`.
+    ///
+    /// The image, and the commitment, are the `W` elements of `u_b` followed
+    /// by the `W` elements of `u_a`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    pub fn relation(
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+    ) -> Result<Relation<C>, Error> {
+        let g = C::generator();
+        let rows = [g, z.clone()]
+            .iter()
+            .flat_map(|base| (0..W).map(|i| vec![(i, base.clone())]))
+            .collect();
+        let map = Homomorphism::new(W, rows)?;
+        let image = u_b.iter().chain(u_a.iter()).cloned().collect();
+
+        Relation::new(map, image)
+    }
+
+    /// Splits a commitment of [`PlEqProof::relation`] into `big_a`.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the commitment does not have `2 * W` elements
+    fn split(commitment: Vec<C::Element>) -> Result<[[C::Element; W]; 2], Error> {
+        let mut commitment = commitment.into_iter();
+        let big_a_g = <[C::Element; W]>::try_from(commitment.by_ref().take(W).collect::<Vec<_>>());
+        let big_a_z = <[C::Element; W]>::try_from(commitment.collect::<Vec<_>>());
+
+        match (big_a_g, big_a_z) {
+            (Ok(big_a_g), Ok(big_a_z)) => Ok([big_a_g, big_a_z]),
+            _ => Err(Error::SigmaProofError(format!(
+                "Expected a commitment of 2 * {W} elements"
+            ))),
+        }
+    }
 
-        let u_v = [u_b, u_a].map(|e| e.dist_exp(&v));
+    /// Returns `true` if the verification equations `g^k == u_b^v * big_a[0]`
+    /// and `z^k == u_a^v * big_a[1]` hold component-wise for challenge `v`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    fn check(
+        &self,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        v: &C::Scalar,
+    ) -> Result<bool, Error> {
+        let relation = Self::relation(z, u_b, u_a)?;
+        let big_a: Vec<C::Element> = self.big_a.iter().flatten().cloned().collect();
+
+        Ok(relation.check(&big_a, v, &self.k))
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"pleq_proof";
+
+    /// Absorbs the statement and the commitments, and returns the challenge.
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `y`: The Naor-Yung public key `y`
+    /// - `z`: The Naor-Yung public key `z`
+    /// - `u_b`: The Naor-Yung ciphertext component `u_b`, of width `W`
+    /// - `v_b`: The Naor-Yung ciphertext component `v_b`, of width `W`
+    /// - `u_a`: The Naor-Yung ciphertext component `u_a`, of width `W`
+    /// - `big_a`: The prover commitments, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    #[allow(clippy::many_single_char_names)]
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        big_a: &[[C::Element; W]; 2],
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"y", y);
+        transcript.absorb(b"z", z);
+        transcript.absorb(b"u_b", u_b);
+        transcript.absorb(b"v_b", v_b);
+        transcript.absorb(b"u_a", u_a);
+        transcript.absorb(b"big_a", big_a);
+
+        transcript.challenge(b"pleq_challenge")
+    }
 
-        let u_v_big_a = u_v.mul(&self.big_a);
+    /// Computes the [`ChallengeVersion::V0`] challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    #[allow(clippy::many_single_char_names)]
+    fn legacy_challenge(
+        y: &C::Element,
+        z: &C::Element,
+        u_b: &[C::Element; W],
+        v_b: &[C::Element; W],
+        u_a: &[C::Element; W],
+        big_a: &[[C::Element; W]; 2],
+        proof_context: &[u8],
+    ) -> Result<C::Scalar, Error> {
+        let g = C::generator();
+        let (input, dsts) =
+            Self::legacy_challenge_input(&g, y, z, u_b, v_b, u_a, big_a, proof_context);
+        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
 
-        Ok(u_v_big_a.equals(&f_k))
+        C::G::hash_to_scalar(&input, &dsts)
     }
 
-    /// Domain separation tags for the challenge input
-    #[crate::warning("Challenge inputs are incomplete.")]
-    const DS_TAGS: [&[u8]; 8] = [
+    /// Domain separation tags for the [`ChallengeVersion::V0`] challenge input
+    const LEGACY_DS_TAGS: [&[u8]; 8] = [
         b"g",
         b"y",
         b"z",
@@ -186,7 +510,7 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
         b"pleq_context",
     ];
 
-    /// Computes the challenge input for the plaintext equality proof.
+    /// Computes the [`ChallengeVersion::V0`] challenge input for the plaintext equality proof.
     ///
     /// # Params
     ///
@@ -203,7 +527,7 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
     /// These values will be passed to the hash function to compute
     /// the challenge.
     #[allow(clippy::too_many_arguments)]
-    fn challenge_input(
+    fn legacy_challenge_input(
         g: &C::Element,
         y: &C::Element,
         z: &C::Element,
@@ -224,7 +548,7 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
             context.to_vec(),
         ];
 
-        (a, Self::DS_TAGS)
+        (a, Self::LEGACY_DS_TAGS)
     }
 }
 
@@ -232,10 +556,13 @@ impl<C: Context, const W: usize> PlEqProof<C, W> {
 mod tests {
     use super::*;
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
     use crate::cryptosystem::naoryung::KeyPair;
     use crate::traits::groups::DistScalarOps;
+    use crate::traits::groups::ReplGroupOps;
+    use crate::traits::groups::ReplScalarOps;
     use crate::utils::serialization::{FDeserializable, FSerializable};
 
     #[test]
@@ -248,6 +575,26 @@ mod tests {
         test_pleq_proof_valid::<PCtx>();
     }
 
+    #[test]
+    fn test_pleq_proof_valid_electionguard() {
+        test_pleq_proof_valid::<ECtx>();
+    }
+
+    #[test]
+    fn test_pleq_proof_batch_ristretto() {
+        test_pleq_proof_batch::<RCtx>();
+    }
+
+    #[test]
+    fn test_pleq_proof_batch_p256() {
+        test_pleq_proof_batch::<PCtx>();
+    }
+
+    #[test]
+    fn test_pleq_proof_legacy_ristretto() {
+        test_pleq_proof_legacy::<RCtx>();
+    }
+
     #[test]
     fn test_pleq_proof_serialization_ristretto() {
         test_pleq_proof_serialization::<RCtx>();
@@ -259,12 +606,12 @@ mod tests {
     }
 
     fn test_pleq_proof_valid<Ctx: Context>() {
-        let ny: KeyPair<Ctx> = KeyPair::generate(&vec![]).unwrap();
+        let ny: KeyPair<Ctx> = KeyPair::generate(&[]).unwrap();
 
         let msg = [Ctx::random_element(), Ctx::random_element()];
         let mut rng = Ctx::get_rng();
         let r = <[Ctx::Scalar; 2]>::random(&mut rng);
-        let ciphertext = ny.encrypt_with_r(&msg, &r, &vec![]).unwrap();
+        let ciphertext = ny.encrypt_with_r(&msg, &r, &[]).unwrap();
 
         let proof = PlEqProof::<Ctx, 2>::prove(
             &ny.pkey.pk_b,
@@ -273,7 +620,7 @@ mod tests {
             &ciphertext.v_b,
             &ciphertext.u_a,
             &r,
-            &vec![],
+            &[],
         )
         .unwrap();
 
@@ -284,7 +631,7 @@ mod tests {
                 &ciphertext.u_b,
                 &ciphertext.v_b,
                 &ciphertext.u_a,
-                &vec![],
+                &[],
             )
             .unwrap();
 
@@ -302,20 +649,53 @@ mod tests {
                 &ciphertext.u_b,
                 &ciphertext.v_b,
                 &ciphertext.u_a,
-                &vec![],
+                &[],
             )
             .unwrap();
 
         assert!(!not_ok);
     }
 
+    #[allow(clippy::many_single_char_names)]
+    fn test_pleq_proof_legacy<Ctx: Context>() {
+        let ny: KeyPair<Ctx> = KeyPair::generate(&[]).unwrap();
+        let (y, z) = (&ny.pkey.pk_b, &ny.pkey.pk_a);
+
+        let msg = [Ctx::random_element(), Ctx::random_element()];
+        let mut rng = Ctx::get_rng();
+        let r = <[Ctx::Scalar; 2]>::random(&mut rng);
+        let c = ny.encrypt_with_r(&msg, &r, &[]).unwrap();
+
+        // a proof computed with the V0 challenge
+        let a = <[Ctx::Scalar; 2]>::random(&mut rng);
+        let big_a = [Ctx::generator().repl_exp(&a), z.repl_exp(&a)];
+        let v = PlEqProof::<Ctx, 2>::legacy_challenge(y, z, &c.u_b, &c.v_b, &c.u_a, &big_a, &[])
+            .unwrap();
+        let legacy = PlEqProof::<Ctx, 2>::new(big_a, v.repl_mul(&r).add(&a));
+
+        let v0 = ChallengeVersion::V0;
+        assert!(
+            legacy
+                .verify_with_version(v0, y, z, &c.u_b, &c.v_b, &c.u_a, &[])
+                .unwrap()
+        );
+        assert!(!legacy.verify(y, z, &c.u_b, &c.v_b, &c.u_a, &[]).unwrap());
+
+        let proof = PlEqProof::<Ctx, 2>::prove(y, z, &c.u_b, &c.v_b, &c.u_a, &r, &[]).unwrap();
+        assert!(
+            !proof
+                .verify_with_version(v0, y, z, &c.u_b, &c.v_b, &c.u_a, &[])
+                .unwrap()
+        );
+    }
+
     fn test_pleq_proof_serialization<Ctx: Context>() {
-        let ny: KeyPair<Ctx> = KeyPair::generate(&vec![]).unwrap();
+        let ny: KeyPair<Ctx> = KeyPair::generate(&[]).unwrap();
 
         let msg = [Ctx::random_element(), Ctx::random_element()];
         let mut rng = Ctx::get_rng();
         let r = <[Ctx::Scalar; 2]>::random(&mut rng);
-        let ciphertext = ny.encrypt_with_r(&msg, &r, &vec![]).unwrap();
+        let ciphertext = ny.encrypt_with_r(&msg, &r, &[]).unwrap();
 
         let proof = PlEqProof::<Ctx, 2>::prove(
             &ny.pkey.pk_b,
@@ -324,7 +704,7 @@ mod tests {
             &ciphertext.v_b,
             &ciphertext.u_a,
             &r,
-            &vec![],
+            &[],
         )
         .unwrap();
         let bytes = proof.ser_f();
@@ -337,10 +717,34 @@ mod tests {
                 &ciphertext.u_b,
                 &ciphertext.v_b,
                 &ciphertext.u_a,
-                &vec![],
+                &[],
             )
             .unwrap();
 
         assert!(ok);
     }
+
+    fn test_pleq_proof_batch<Ctx: Context>() {
+        let ny: KeyPair<Ctx> = KeyPair::generate(&[]).unwrap();
+        let (y, z) = (&ny.pkey.pk_b, &ny.pkey.pk_a);
+
+        let ciphertexts: Vec<_> = (0..3)
+            .map(|_| {
+                let msg = [Ctx::random_element(), Ctx::random_element()];
+                ny.encrypt(&msg, &[]).unwrap()
+            })
+            .collect();
+        let batch: Vec<_> = ciphertexts
+            .iter()
+            .map(|c| (&c.proof, &c.u_b, &c.v_b, &c.u_a))
+            .collect();
+        assert!(PlEqProof::verify_batch(y, z, &batch, &[]).unwrap());
+        assert!(!PlEqProof::verify_batch(y, z, &batch, b"other context").unwrap());
+
+        let tampered_k = ciphertexts[1].proof.k.dist_add(&Ctx::Scalar::one());
+        let tampered = PlEqProof::<Ctx, 2>::new(ciphertexts[1].proof.big_a.clone(), tampered_k);
+        let mut batch = batch;
+        batch[1].0 = &tampered;
+        assert!(!PlEqProof::verify_batch(y, z, &batch, &[]).unwrap());
+    }
 }
@@ -0,0 +1,780 @@
+/*
+ * Disjunctive proofs that exponential ElGamal ciphertexts encrypt values in a range.
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::{Ciphertext, PublicKey};
+use crate::traits::groups::CryptoGroup;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use crate::utils::serialization::VSerializable;
+use crate::zkp::transcript::{ChallengeVersion, Transcript};
+use vser_derive::VSerializable as VSer;
+
+/**
+ * Disjunctive Chaum-Pedersen proof that a ciphertext encrypts `g^m` for `0 <= m <= max`.
+ *
+ * Given an [exponential `ElGamal`][`crate::cryptosystem::exponential`] ciphertext
+ * `(u, v) = (g^r, y^r * g^m)` this proof shows, without revealing `m`, that
+ * for some `j` in `0..=max` the tuple `(g, y, u, v / g^j)` is a Diffie-Hellman
+ * tuple. The proof is the Cramer-Damgard-Schoenmakers OR composition of
+ * `max + 1` discrete log equality proofs, where all but the true branch are
+ * simulated and the branch challenges must sum to the Fiat-Shamir challenge.
+ *
+ * With `max = 1` this is the standard proof that an encrypted selection is
+ * either `0` or `1`.
+ *
+ * See also [`SelectionLimitProof`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::cryptosystem::exponential;
+ * use crypto::zkp::range::RangeProof;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let r = RCtx::random_scalar();
+ * let ciphertext = exponential::encrypt_with_r(&keypair.pkey, &[1], &[r.clone()]);
+ *
+ * // Set to some relevant context value
+ * let proof_context = &[];
+ * let proof = RangeProof::<RCtx>::prove(&keypair.pkey, &ciphertext, 1, &r, 1, proof_context).unwrap();
+ *
+ * let ok = proof.verify(&keypair.pkey, &ciphertext, 1, proof_context).unwrap();
+ * assert!(ok);
+ * ```
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct RangeProof<C: Context> {
+    /// Commitments `g^a_j`, one per branch
+    pub big_a: Vec<C::Element>,
+    /// Commitments `y^a_j`, one per branch
+    pub big_b: Vec<C::Element>,
+    /// Branch challenges, summing to the Fiat-Shamir challenge
+    pub challenges: Vec<C::Scalar>,
+    /// Branch responses
+    pub responses: Vec<C::Scalar>,
+}
+
+impl<C: Context> RangeProof<C> {
+    /// Construct a range proof from the given values.
+    #[must_use]
+    pub fn new(
+        big_a: Vec<C::Element>,
+        big_b: Vec<C::Element>,
+        challenges: Vec<C::Scalar>,
+        responses: Vec<C::Scalar>,
+    ) -> Self {
+        RangeProof {
+            big_a,
+            big_b,
+            challenges,
+            responses,
+        }
+    }
+
+    /// Prove that `ciphertext` encrypts `g^m` with `0 <= m <= max`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext `(g^r, y^r * g^m)`
+    /// - `m`: The encrypted integer, secret
+    /// - `r`: The encryption randomness, secret
+    /// - `max`: The inclusive upper bound of the range
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `RangeProofError` if `m > max`
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`RangeProof`] instance.
+    pub fn prove(
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        m: u32,
+        r: &C::Scalar,
+        max: u32,
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        Self::prove_with_transcript(&mut transcript, pk, ciphertext, m, r, max)
+    }
+
+    /// Prove that `ciphertext` encrypts `g^m` with `0 <= m <= max`, deriving
+    /// the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext `(g^r, y^r * g^m)`
+    /// - `m`: The encrypted integer, secret
+    /// - `r`: The encryption randomness, secret
+    /// - `max`: The inclusive upper bound of the range
+    ///
+    /// # Errors
+    ///
+    /// - `RangeProofError` if `m > max`
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`RangeProof`] instance.
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        m: u32,
+        r: &C::Scalar,
+        max: u32,
+    ) -> Result<Self, Error> {
+        if m > max {
+            return Err(Error::RangeProofError(format!(
+                "Plaintext {m} is outside the range 0..={max}"
+            )));
+        }
+        let g = C::generator();
+        let [u] = ciphertext.u();
+        let shifted = Self::shifted(ciphertext, max);
+
+        let mut big_a = vec![];
+        let mut big_b = vec![];
+        let mut challenges = vec![];
+        let mut responses = vec![];
+        let alpha = C::random_scalar();
+
+        for (branch, v_j) in (0..=max).zip(shifted.iter()) {
+            if branch == m {
+                big_a.push(g.exp(&alpha));
+                big_b.push(pk.y.exp(&alpha));
+                challenges.push(C::Scalar::zero());
+                responses.push(C::Scalar::zero());
+            } else {
+                // simulated branch: A = g^z / u^c, B = y^z / (v / g^j)^c
+                let c_j = C::random_scalar();
+                let z_j = C::random_scalar();
+                big_a.push(g.exp(&z_j).mul(&u.exp(&c_j).inv()));
+                big_b.push(pk.y.exp(&z_j).mul(&v_j.exp(&c_j).inv()));
+                challenges.push(c_j);
+                responses.push(z_j);
+            }
+        }
+
+        let challenge = Self::challenge(transcript, pk, ciphertext, max, &big_a, &big_b)?;
+        let simulated = challenges
+            .iter()
+            .fold(C::Scalar::zero(), |acc, next| acc.add(next));
+        let c_m = challenge.sub(&simulated);
+        let z_m = alpha.add(&c_m.mul(r));
+
+        // m <= max, so the index is in bounds
+        let index = m as usize;
+        challenges[index] = c_m;
+        responses[index] = z_m;
+
+        Ok(Self::new(big_a, big_b, challenges, responses))
+    }
+
+    /// Verify this proof that `ciphertext` encrypts `g^m` with `0 <= m <= max`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext
+    /// - `max`: The inclusive upper bound of the range
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify(
+        &self,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        max: u32,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        self.verify_with_version(
+            ChallengeVersion::CURRENT,
+            pk,
+            ciphertext,
+            max,
+            proof_context,
+        )
+    }
+
+    /// Verify this proof that `ciphertext` encrypts `g^m` with `0 <= m <= max`,
+    /// computed with the given challenge version.
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext
+    /// - `max`: The inclusive upper bound of the range
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        max: u32,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        match version {
+            ChallengeVersion::V0 => {
+                let challenge = Self::legacy_challenge(
+                    pk,
+                    ciphertext,
+                    &self.big_a,
+                    &self.big_b,
+                    proof_context,
+                )?;
+
+                Ok(self.check(pk, ciphertext, max, &challenge))
+            }
+            ChallengeVersion::V1 => {
+                let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+                self.verify_with_transcript(&mut transcript, pk, ciphertext, max)
+            }
+        }
+    }
+
+    /// Verify this proof that `ciphertext` encrypts `g^m` with `0 <= m <= max`,
+    /// deriving the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext
+    /// - `max`: The inclusive upper bound of the range
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        max: u32,
+    ) -> Result<bool, Error> {
+        let challenge = Self::challenge(transcript, pk, ciphertext, max, &self.big_a, &self.big_b)?;
+
+        Ok(self.check(pk, ciphertext, max, &challenge))
+    }
+
+    /// Returns `true` if the branch challenges sum to `challenge` and the
+    /// verification equations of every branch hold.
+    fn check(
+        &self,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        max: u32,
+        challenge: &C::Scalar,
+    ) -> bool {
+        let branches = (max as usize).saturating_add(1);
+        if self.big_a.len() != branches
+            || self.big_b.len() != branches
+            || self.challenges.len() != branches
+            || self.responses.len() != branches
+        {
+            return false;
+        }
+
+        let g = C::generator();
+        let [u] = ciphertext.u();
+        let shifted = Self::shifted(ciphertext, max);
+
+        let sum = self
+            .challenges
+            .iter()
+            .fold(C::Scalar::zero(), |acc, next| acc.add(next));
+        if !sum.equals(challenge) {
+            return false;
+        }
+
+        let branches = self
+            .big_a
+            .iter()
+            .zip(self.big_b.iter())
+            .zip(self.challenges.iter().zip(self.responses.iter()))
+            .zip(shifted.iter());
+
+        for (((big_a, big_b), (c_j, z_j)), v_j) in branches {
+            // g^z = A * u^c
+            let check1 = g.exp(z_j).equals(&big_a.mul(&u.exp(c_j)));
+            // y^z = B * (v / g^j)^c
+            let check2 = pk.y.exp(z_j).equals(&big_b.mul(&v_j.exp(c_j)));
+            if !(check1 && check2) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Compute `v / g^j` for every `j` in `0..=max`.
+    fn shifted(ciphertext: &Ciphertext<C, 1>, max: u32) -> Vec<C::Element> {
+        let [v] = ciphertext.v();
+        let g_inv = C::generator().inv();
+
+        let mut ret = Vec::with_capacity((max as usize).saturating_add(1));
+        let mut current = v.clone();
+        for _ in 0..=max {
+            let next = current.mul(&g_inv);
+            ret.push(current);
+            current = next;
+        }
+
+        ret
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"range_proof";
+
+    /// Absorbs the statement and the commitments, and returns the challenge.
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext
+    /// - `max`: The inclusive upper bound of the range
+    /// - `big_a`: The commitments `g^a_j`
+    /// - `big_b`: The commitments `y^a_j`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        max: u32,
+        big_a: &Vec<C::Element>,
+        big_b: &Vec<C::Element>,
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"y", &pk.y);
+        transcript.absorb(b"ciphertext", ciphertext);
+        transcript.absorb_bytes(b"max", &max.to_be_bytes());
+        transcript.absorb(b"big_a", big_a);
+        transcript.absorb(b"big_b", big_b);
+
+        transcript.challenge(b"range_challenge")
+    }
+
+    /// Computes the [`ChallengeVersion::V0`] challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    fn legacy_challenge(
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        big_a: &Vec<C::Element>,
+        big_b: &Vec<C::Element>,
+        proof_context: &[u8],
+    ) -> Result<C::Scalar, Error> {
+        let (input, dsts) =
+            Self::legacy_challenge_input(pk, ciphertext, big_a, big_b, proof_context);
+        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
+
+        C::G::hash_to_scalar(&input, &dsts)
+    }
+
+    /// Domain separation tags for the [`ChallengeVersion::V0`] challenge input
+    const LEGACY_DS_TAGS: [&[u8]; 6] = [
+        b"g",
+        b"y",
+        b"ciphertext",
+        b"big_a",
+        b"big_b",
+        b"range_proof_context",
+    ];
+
+    /// Compute the [`ChallengeVersion::V0`] challenge input for the range proof.
+    ///
+    /// # Params
+    ///
+    /// - `pk`: The public key the ciphertext was encrypted with
+    /// - `ciphertext`: The exponential `ElGamal` ciphertext
+    /// - `big_a`: The commitments `g^a_j`
+    /// - `big_b`: The commitments `y^a_j`
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// Returns byte arrays for input values and domain separation tags.
+    /// These values will be passed to the hash function to compute
+    /// the challenge. The range bound is bound implicitly by the number of
+    /// commitments.
+    fn legacy_challenge_input(
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C, 1>,
+        big_a: &Vec<C::Element>,
+        big_b: &Vec<C::Element>,
+        proof_context: &[u8],
+    ) -> ([Vec<u8>; 6], [&'static [u8]; 6]) {
+        let a = [
+            C::generator().ser(),
+            pk.y.ser(),
+            ciphertext.ser(),
+            big_a.ser(),
+            big_b.ser(),
+            proof_context.to_vec(),
+        ];
+
+        (a, Self::LEGACY_DS_TAGS)
+    }
+}
+
+/**
+ * Proof that the selections of a contest add up to at most its selection limit.
+ *
+ * Given the exponential `ElGamal` ciphertexts of a contest's selections, their
+ * component-wise product encrypts the sum of the selections. This proof is a
+ * [`RangeProof`] on that product ciphertext with bound `limit`, using the sum
+ * of the selections' encryption randomness as witness.
+ *
+ * Together with a [`RangeProof`] with bound `1` for each selection, this shows
+ * that a contest is a valid vote.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::cryptosystem::exponential;
+ * use crypto::zkp::range::SelectionLimitProof;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let votes = [1, 0, 1];
+ * let r = votes.map(|_| RCtx::random_scalar());
+ * let selections: Vec<_> = votes
+ *     .iter()
+ *     .zip(r.iter())
+ *     .map(|(m, r)| exponential::encrypt_with_r(&keypair.pkey, &[*m], &[r.clone()]))
+ *     .collect();
+ *
+ * let proof = SelectionLimitProof::<RCtx>::prove(&keypair.pkey, &selections, &votes, &r, 2, &[]).unwrap();
+ * assert!(proof.verify(&keypair.pkey, &selections, 2, &[]).unwrap());
+ * ```
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct SelectionLimitProof<C: Context> {
+    /// Range proof on the product of the selections
+    pub proof: RangeProof<C>,
+}
+
+impl<C: Context> SelectionLimitProof<C> {
+    /// Construct a selection limit proof from the given range proof.
+    #[must_use]
+    pub fn new(proof: RangeProof<C>) -> Self {
+        SelectionLimitProof { proof }
+    }
+
+    /// Prove that the given selections add up to at most `limit`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The public key the selections were encrypted with
+    /// - `selections`: The exponential `ElGamal` ciphertexts of each selection
+    /// - `votes`: The encrypted integer of each selection, secret
+    /// - `r`: The encryption randomness of each selection, secret
+    /// - `limit`: The contest's selection limit
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `RangeProofError` if the input lengths do not match, or if the votes
+    ///   add up to more than `limit`
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`SelectionLimitProof`] instance.
+    pub fn prove(
+        pk: &PublicKey<C>,
+        selections: &[Ciphertext<C, 1>],
+        votes: &[u32],
+        r: &[C::Scalar],
+        limit: u32,
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        if selections.len() != votes.len() || selections.len() != r.len() {
+            return Err(Error::RangeProofError(
+                "Mismatched selection, vote and randomness lengths".to_string(),
+            ));
+        }
+        let total = votes
+            .iter()
+            .try_fold(0u32, |acc, next| acc.checked_add(*next))
+            .ok_or(Error::RangeProofError(
+                "Selection total overflows".to_string(),
+            ))?;
+        let r_total = r.iter().fold(C::Scalar::zero(), |acc, next| acc.add(next));
+        let product = Self::product(selections);
+
+        let proof = RangeProof::prove(pk, &product, total, &r_total, limit, proof_context)?;
+
+        Ok(Self::new(proof))
+    }
+
+    /// Verify this proof that the given selections add up to at most `limit`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The public key the selections were encrypted with
+    /// - `selections`: The exponential `ElGamal` ciphertexts of each selection
+    /// - `limit`: The contest's selection limit
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify(
+        &self,
+        pk: &PublicKey<C>,
+        selections: &[Ciphertext<C, 1>],
+        limit: u32,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        self.verify_with_version(
+            ChallengeVersion::CURRENT,
+            pk,
+            selections,
+            limit,
+            proof_context,
+        )
+    }
+
+    /// Verify this proof that the given selections add up to at most `limit`,
+    /// computed with the given challenge version.
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `pk`: The public key the selections were encrypted with
+    /// - `selections`: The exponential `ElGamal` ciphertexts of each selection
+    /// - `limit`: The contest's selection limit
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        pk: &PublicKey<C>,
+        selections: &[Ciphertext<C, 1>],
+        limit: u32,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        let product = Self::product(selections);
+
+        self.proof
+            .verify_with_version(version, pk, &product, limit, proof_context)
+    }
+
+    /// Compute the homomorphic sum (component-wise product) of the selections.
+    fn product(selections: &[Ciphertext<C, 1>]) -> Ciphertext<C, 1> {
+        let one = Ciphertext::new([C::Element::one()], [C::Element::one()]);
+
+        selections.iter().fold(one, |acc, next| acc.mul(next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::elgamal::KeyPair;
+    use crate::cryptosystem::exponential;
+    use crate::utils::serialization::VDeserializable;
+
+    #[test]
+    fn test_range_proof_valid_ristretto() {
+        test_range_proof_valid::<RCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_valid_p256() {
+        test_range_proof_valid::<PCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_invalid_ristretto() {
+        test_range_proof_invalid::<RCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_invalid_p256() {
+        test_range_proof_invalid::<PCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_legacy_ristretto() {
+        test_range_proof_legacy::<RCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_serialization_ristretto() {
+        test_range_proof_serialization::<RCtx>();
+    }
+
+    #[test]
+    fn test_range_proof_serialization_p256() {
+        test_range_proof_serialization::<PCtx>();
+    }
+
+    #[test]
+    fn test_selection_limit_proof_ristretto() {
+        test_selection_limit_proof::<RCtx>();
+    }
+
+    #[test]
+    fn test_selection_limit_proof_p256() {
+        test_selection_limit_proof::<PCtx>();
+    }
+
+    fn encrypt<Ctx: Context>(pk: &PublicKey<Ctx>, m: u32) -> (Ciphertext<Ctx, 1>, Ctx::Scalar) {
+        let r = Ctx::random_scalar();
+        let c = exponential::encrypt_with_r(pk, &[m], std::array::from_ref(&r));
+
+        (c, r)
+    }
+
+    fn test_range_proof_valid<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let pk = &keypair.pkey;
+
+        for max in [0, 1, 2, 5] {
+            for m in 0..=max {
+                let (c, r) = encrypt(pk, m);
+                let proof = RangeProof::<Ctx>::prove(pk, &c, m, &r, max, &[]).unwrap();
+                assert!(proof.verify(pk, &c, max, &[]).unwrap());
+            }
+        }
+    }
+
+    fn test_range_proof_invalid<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let pk = &keypair.pkey;
+
+        let (c, r) = encrypt(pk, 1);
+        let proof = RangeProof::<Ctx>::prove(pk, &c, 1, &r, 1, b"context").unwrap();
+        assert!(proof.verify(pk, &c, 1, b"context").unwrap());
+
+        // wrong proof context
+        assert!(!proof.verify(pk, &c, 1, b"other").unwrap());
+        // wrong range bound
+        assert!(!proof.verify(pk, &c, 2, b"context").unwrap());
+        // wrong ciphertext
+        let (other, _) = encrypt(pk, 1);
+        assert!(!proof.verify(pk, &other, 1, b"context").unwrap());
+        // wrong public key
+        let other_pk = KeyPair::<Ctx>::generate().pkey;
+        assert!(!proof.verify(&other_pk, &c, 1, b"context").unwrap());
+
+        // witness out of range
+        let (c, r) = encrypt(pk, 2);
+        assert!(RangeProof::<Ctx>::prove(pk, &c, 2, &r, 1, &[]).is_err());
+
+        // a proof computed with a false witness does not verify
+        let proof = RangeProof::<Ctx>::prove(pk, &c, 1, &r, 1, &[]).unwrap();
+        assert!(!proof.verify(pk, &c, 1, &[]).unwrap());
+
+        // tampered challenges
+        let (c, r) = encrypt(pk, 0);
+        let mut proof = RangeProof::<Ctx>::prove(pk, &c, 0, &r, 1, &[]).unwrap();
+        proof.challenges.swap(0, 1);
+        assert!(!proof.verify(pk, &c, 1, &[]).unwrap());
+    }
+
+    fn test_range_proof_legacy<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let pk = &keypair.pkey;
+        let (c, r) = encrypt(pk, 0);
+
+        // a single branch proof computed with the V0 challenge
+        let alpha = Ctx::random_scalar();
+        let big_a = vec![Ctx::generator().exp(&alpha)];
+        let big_b = vec![pk.y.exp(&alpha)];
+        let challenge = RangeProof::legacy_challenge(pk, &c, &big_a, &big_b, &[]).unwrap();
+        let response = alpha.add(&challenge.mul(&r));
+        let legacy = RangeProof::<Ctx>::new(big_a, big_b, vec![challenge], vec![response]);
+
+        let v0 = ChallengeVersion::V0;
+        assert!(legacy.verify_with_version(v0, pk, &c, 0, &[]).unwrap());
+        assert!(!legacy.verify(pk, &c, 0, &[]).unwrap());
+
+        let proof = RangeProof::<Ctx>::prove(pk, &c, 0, &r, 0, &[]).unwrap();
+        assert!(!proof.verify_with_version(v0, pk, &c, 0, &[]).unwrap());
+    }
+
+    fn test_range_proof_serialization<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let pk = &keypair.pkey;
+
+        let (c, r) = encrypt(pk, 2);
+        let proof = RangeProof::<Ctx>::prove(pk, &c, 2, &r, 3, &[]).unwrap();
+
+        let serialized = proof.ser();
+        let deserialized = RangeProof::<Ctx>::deser(&serialized).unwrap();
+        assert_eq!(proof, deserialized);
+        assert!(deserialized.verify(pk, &c, 3, &[]).unwrap());
+    }
+
+    fn test_selection_limit_proof<Ctx: Context>() {
+        let keypair = KeyPair::<Ctx>::generate();
+        let pk = &keypair.pkey;
+
+        let votes = [1, 0, 1, 0];
+        let (selections, r): (Vec<_>, Vec<_>) = votes.iter().map(|m| encrypt(pk, *m)).unzip();
+
+        let proof = SelectionLimitProof::<Ctx>::prove(pk, &selections, &votes, &r, 2, &[]).unwrap();
+        assert!(proof.verify(pk, &selections, 2, &[]).unwrap());
+        assert!(!proof.verify(pk, &selections, 3, &[]).unwrap());
+        assert!(!proof.verify(pk, &selections[1..], 2, &[]).unwrap());
+
+        let proof = SelectionLimitProof::<Ctx>::prove(pk, &selections, &votes, &r, 3, &[]).unwrap();
+        assert!(proof.verify(pk, &selections, 3, &[]).unwrap());
+
+        // over the limit
+        assert!(SelectionLimitProof::<Ctx>::prove(pk, &selections, &votes, &r, 1, &[]).is_err());
+        // mismatched lengths
+        assert!(
+            SelectionLimitProof::<Ctx>::prove(pk, &selections, &votes[1..], &r, 2, &[]).is_err()
+        );
+
+        let serialized = proof.ser();
+        let deserialized = SelectionLimitProof::<Ctx>::deser(&serialized).unwrap();
+        assert_eq!(proof, deserialized);
+    }
+}
@@ -0,0 +1,672 @@
+/*
+ * Proof of correct re-encryption.
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::cryptosystem::elgamal::Ciphertext;
+use crate::traits::groups::GroupElement;
+use crate::utils::error::Error;
+use crate::zkp::sigma::{Homomorphism, OrProof, Relation};
+use crate::zkp::transcript::Transcript;
+use vser_derive::VSerializable as VSer;
+
+/**
+ * Proof of correct re-encryption.
+ *
+ * Given public values `pk`, `ciphertext` and `re_encrypted`, and a secret
+ * `r_n` of width `W`, proves that `re_encrypted` is
+ * [`ciphertext.re_encrypt(r_n, pk)`][`Ciphertext::re_encrypt`], that is
+ * `re_encrypted / ciphertext = (g^r_n, pk^r_n)` component-wise, without
+ * revealing `r_n`.
+ *
+ * This proof is an instance of a [Sigma protocol][`crate::zkp::sigma`], see
+ * [`ReEncryptionProof::relation`].
+ *
+ * # Examples
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::zkp::reencryption::ReEncryptionProof;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let ciphertext = keypair.encrypt(&[RCtx::random_element(); 2]);
+ * let r_n = [RCtx::random_scalar(), RCtx::random_scalar()];
+ * let re_encrypted = ciphertext.re_encrypt(&r_n, &keypair.pkey.y);
+ *
+ * // Set to some relevant context value
+ * let proof_context = &[];
+ * let proof = ReEncryptionProof::<RCtx, 2>::prove(
+ *     &keypair.pkey.y,
+ *     &ciphertext,
+ *     &re_encrypted,
+ *     &r_n,
+ *     proof_context,
+ * ).unwrap();
+ *
+ * let ok = proof.verify(&keypair.pkey.y, &ciphertext, &re_encrypted, proof_context).unwrap();
+ * assert!(ok);
+ * ```
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct ReEncryptionProof<C: Context, const W: usize> {
+    /// Prover commitments, for `g` and `pk`
+    pub big_a: [[C::Element; W]; 2],
+    /// Challenge responses
+    pub k: [C::Scalar; W],
+}
+
+impl<C: Context, const W: usize> ReEncryptionProof<C, W> {
+    /// Construct a re-encryption proof from the given values.
+    #[must_use]
+    pub fn new(big_a: [[C::Element; W]; 2], k: [C::Scalar; W]) -> Self {
+        Self { big_a, k }
+    }
+
+    /// Prove that `re_encrypted` is a re-encryption of `ciphertext` under `pk`
+    /// with randomness `r_n`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The `ElGamal` public key, public
+    /// - `ciphertext`: The original ciphertext, public
+    /// - `re_encrypted`: The re-encrypted ciphertext, public
+    /// - `r_n`: The secret re-encryption randomness, of width `W`
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`ReEncryptionProof`] instance.
+    pub fn prove(
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        r_n: &[C::Scalar; W],
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        Self::prove_with_transcript(&mut transcript, pk, ciphertext, re_encrypted, r_n)
+    }
+
+    /// Prove that `re_encrypted` is a re-encryption of `ciphertext` under `pk`
+    /// with randomness `r_n`, deriving the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `pk`: The `ElGamal` public key, public
+    /// - `ciphertext`: The original ciphertext, public
+    /// - `re_encrypted`: The re-encrypted ciphertext, public
+    /// - `r_n`: The secret re-encryption randomness, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`ReEncryptionProof`] instance.
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        r_n: &[C::Scalar; W],
+    ) -> Result<Self, Error> {
+        let relation = Self::relation(pk, ciphertext, re_encrypted)?;
+        let (a, big_a) = relation.commit();
+        let big_a = Self::split(big_a)?;
+
+        let v = Self::challenge(transcript, pk, ciphertext, re_encrypted, &big_a)?;
+
+        let k = relation.respond(r_n, &a, &v)?;
+        let k = <[C::Scalar; W]>::try_from(k)
+            .map_err(|_| Error::SigmaProofError(format!("Expected {W} responses")))?;
+
+        Ok(Self::new(big_a, k))
+    }
+
+    /// Verify this proof of correct re-encryption.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The `ElGamal` public key
+    /// - `ciphertext`: The original ciphertext
+    /// - `re_encrypted`: The re-encrypted ciphertext
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify(
+        &self,
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        self.verify_with_transcript(&mut transcript, pk, ciphertext, re_encrypted)
+    }
+
+    /// Verify this proof of correct re-encryption, deriving the challenge from the given transcript
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `pk`: The `ElGamal` public key
+    /// - `ciphertext`: The original ciphertext
+    /// - `re_encrypted`: The re-encrypted ciphertext
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+    ) -> Result<bool, Error> {
+        let v = Self::challenge(transcript, pk, ciphertext, re_encrypted, &self.big_a)?;
+
+        let relation = Self::relation(pk, ciphertext, re_encrypted)?;
+        let big_a: Vec<C::Element> = self.big_a.iter().flatten().cloned().collect();
+
+        Ok(relation.check(&big_a, &v, &self.k))
+    }
+
+    /// Returns the [`Relation`] `re_encrypted / ciphertext = (g^r_n, pk^r_n)`
+    /// of this proof, for the generator `g` and the randomness `r_n` of width `W`.
+    ///
+    /// The image, and the commitment, are the `W` elements of the quotient
+    /// of the `u` components followed by the `W` elements of the quotient of
+    /// the `v` components.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    pub fn relation(
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+    ) -> Result<Relation<C>, Error> {
+        let g = C::generator();
+        let rows = [g, pk.clone()]
+            .iter()
+            .flat_map(|base| (0..W).map(|i| vec![(i, base.clone())]))
+            .collect();
+        let map = Homomorphism::new(W, rows)?;
+        let quotient = re_encrypted.0.mul(&ciphertext.0.inv());
+        let image = quotient.into_iter().flatten().collect();
+
+        Relation::new(map, image)
+    }
+
+    /// Splits a commitment of [`ReEncryptionProof::relation`] into `big_a`.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the commitment does not have `2 * W` elements
+    fn split(commitment: Vec<C::Element>) -> Result<[[C::Element; W]; 2], Error> {
+        let mut commitment = commitment.into_iter();
+        let big_a_g = <[C::Element; W]>::try_from(commitment.by_ref().take(W).collect::<Vec<_>>());
+        let big_a_y = <[C::Element; W]>::try_from(commitment.collect::<Vec<_>>());
+
+        match (big_a_g, big_a_y) {
+            (Ok(big_a_g), Ok(big_a_y)) => Ok([big_a_g, big_a_y]),
+            _ => Err(Error::SigmaProofError(format!(
+                "Expected a commitment of 2 * {W} elements"
+            ))),
+        }
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"reencryption_proof";
+
+    /// Absorbs the statement and the commitments, and returns the challenge.
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `pk`: The `ElGamal` public key
+    /// - `ciphertext`: The original ciphertext
+    /// - `re_encrypted`: The re-encrypted ciphertext
+    /// - `big_a`: The prover commitments, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        big_a: &[[C::Element; W]; 2],
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"public_y", pk);
+        transcript.absorb(b"ciphertext", ciphertext);
+        transcript.absorb(b"re_encrypted", re_encrypted);
+        transcript.absorb(b"big_a", big_a);
+
+        transcript.challenge(b"reencryption_challenge")
+    }
+}
+
+/**
+ * Designated-verifier proof of correct re-encryption.
+ *
+ * Proves that `re_encrypted` is a re-encryption of `ciphertext` under `pk`
+ * _or_ knowledge of the secret key of the designated verifier, whose public
+ * key is `verifier_y = g^verifier_x`. The designated verifier, who knows it
+ * did not produce the proof, is convinced of the re-encryption, while
+ * nobody else is: the verifier can [forge][`DesignatedReEncryptionProof::forge`]
+ * an indistinguishable proof for any pair of ciphertexts. This
+ * non-transferability is required for receipt-freeness, for example when
+ * re-randomizing ballots or blinding return codes.
+ *
+ * Jakobsson, Sako, Impagliazzo: Designated Verifier Proofs and Their
+ * Applications. This proof is a [disjunction][`crate::zkp::sigma::OrProof`]
+ * of [`ReEncryptionProof::relation`] and knowledge of a discrete logarithm.
+ *
+ * # Examples
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::cryptosystem::elgamal::KeyPair;
+ * use crypto::zkp::reencryption::DesignatedReEncryptionProof;
+ *
+ * let keypair: KeyPair<RCtx> = KeyPair::generate();
+ * let verifier: KeyPair<RCtx> = KeyPair::generate();
+ * let ciphertext = keypair.encrypt(&[RCtx::random_element(); 2]);
+ * let r_n = [RCtx::random_scalar(), RCtx::random_scalar()];
+ * let re_encrypted = ciphertext.re_encrypt(&r_n, &keypair.pkey.y);
+ *
+ * let proof = DesignatedReEncryptionProof::<RCtx, 2>::prove(
+ *     &keypair.pkey.y,
+ *     &ciphertext,
+ *     &re_encrypted,
+ *     &r_n,
+ *     &verifier.pkey.y,
+ *     &[],
+ * ).unwrap();
+ * let ok = proof.verify(&keypair.pkey.y, &ciphertext, &re_encrypted, &verifier.pkey.y, &[]).unwrap();
+ * assert!(ok);
+ *
+ * // the verifier can produce a valid proof for an unrelated ciphertext
+ * let other = keypair.encrypt(&[RCtx::random_element(); 2]);
+ * let forged = DesignatedReEncryptionProof::<RCtx, 2>::forge(
+ *     &keypair.pkey.y,
+ *     &ciphertext,
+ *     &other,
+ *     &verifier.skey,
+ *     &verifier.pkey.y,
+ *     &[],
+ * ).unwrap();
+ * let ok = forged.verify(&keypair.pkey.y, &ciphertext, &other, &verifier.pkey.y, &[]).unwrap();
+ * assert!(ok);
+ * ```
+ */
+#[derive(Debug, Clone, VSer, PartialEq)]
+pub struct DesignatedReEncryptionProof<C: Context, const W: usize> {
+    /// The disjunction of re-encryption and knowledge of the verifier secret key
+    pub proof: OrProof<C>,
+}
+
+impl<C: Context, const W: usize> DesignatedReEncryptionProof<C, W> {
+    /// Construct a designated-verifier re-encryption proof from the given disjunctive proof.
+    #[must_use]
+    pub fn new(proof: OrProof<C>) -> Self {
+        Self { proof }
+    }
+
+    /// Prove, to the holder of the secret key of `verifier_y`, that
+    /// `re_encrypted` is a re-encryption of `ciphertext` under `pk` with
+    /// randomness `r_n`.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The `ElGamal` public key, public
+    /// - `ciphertext`: The original ciphertext, public
+    /// - `re_encrypted`: The re-encrypted ciphertext, public
+    /// - `r_n`: The secret re-encryption randomness, of width `W`
+    /// - `verifier_y`: The public key of the designated verifier
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`DesignatedReEncryptionProof`] instance.
+    pub fn prove(
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        r_n: &[C::Scalar; W],
+        verifier_y: &C::Element,
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let relations = Self::relations(pk, ciphertext, re_encrypted, verifier_y)?;
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        let proof = OrProof::prove_with_transcript(&mut transcript, &relations, 0, r_n)?;
+
+        Ok(Self::new(proof))
+    }
+
+    /// Forge a proof for any pair of ciphertexts, with the secret key of the
+    /// designated verifier.
+    ///
+    /// Forged proofs are indistinguishable from proofs computed with
+    /// [`DesignatedReEncryptionProof::prove`], which is why they do not
+    /// convince anyone but the designated verifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The `ElGamal` public key, public
+    /// - `ciphertext`: The first ciphertext, public
+    /// - `re_encrypted`: The second ciphertext, public
+    /// - `verifier_x`: The secret key of the designated verifier
+    /// - `verifier_y`: The public key of the designated verifier
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`DesignatedReEncryptionProof`] instance.
+    pub fn forge(
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        verifier_x: &C::Scalar,
+        verifier_y: &C::Element,
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let relations = Self::relations(pk, ciphertext, re_encrypted, verifier_y)?;
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        let proof = OrProof::prove_with_transcript(
+            &mut transcript,
+            &relations,
+            1,
+            std::slice::from_ref(verifier_x),
+        )?;
+
+        Ok(Self::new(proof))
+    }
+
+    /// Verify this designated-verifier proof of correct re-encryption.
+    ///
+    /// # Parameters
+    ///
+    /// - `pk`: The `ElGamal` public key
+    /// - `ciphertext`: The original ciphertext
+    /// - `re_encrypted`: The re-encrypted ciphertext
+    /// - `verifier_y`: The public key of the designated verifier
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise. A valid proof
+    /// only convinces the designated verifier, and only if it did not forge it.
+    pub fn verify(
+        &self,
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        verifier_y: &C::Element,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        let relations = Self::relations(pk, ciphertext, re_encrypted, verifier_y)?;
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        self.proof
+            .verify_with_transcript(&mut transcript, &relations)
+    }
+
+    /// Returns the relations of the disjunction: re-encryption, and knowledge
+    /// of the discrete logarithm of `verifier_y`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for these relations; returns `SigmaProofError` like [`Relation::new`].
+    fn relations(
+        pk: &C::Element,
+        ciphertext: &Ciphertext<C, W>,
+        re_encrypted: &Ciphertext<C, W>,
+        verifier_y: &C::Element,
+    ) -> Result<[Relation<C>; 2], Error> {
+        let re_encryption = ReEncryptionProof::relation(pk, ciphertext, re_encrypted)?;
+        let map = Homomorphism::new(1, vec![vec![(0, C::generator())]])?;
+        let verifier_key = Relation::new(map, vec![verifier_y.clone()])?;
+
+        Ok([re_encryption, verifier_key])
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"designated_reencryption_proof";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::cryptosystem::elgamal::KeyPair;
+    use crate::traits::groups::GroupScalar;
+    use crate::utils::serialization::{
+        FDeserializable, FSerializable, VDeserializable, VSerializable,
+    };
+
+    #[test]
+    fn test_reencryption_proof_ristretto() {
+        test_reencryption_proof::<RCtx>();
+    }
+
+    #[test]
+    fn test_reencryption_proof_invalid_ristretto() {
+        test_reencryption_proof_invalid::<RCtx>();
+    }
+
+    #[test]
+    fn test_designated_reencryption_proof_ristretto() {
+        test_designated_reencryption_proof::<RCtx>();
+    }
+
+    #[test]
+    fn test_reencryption_proof_p256() {
+        test_reencryption_proof::<PCtx>();
+    }
+
+    #[test]
+    fn test_reencryption_proof_invalid_p256() {
+        test_reencryption_proof_invalid::<PCtx>();
+    }
+
+    #[test]
+    fn test_designated_reencryption_proof_p256() {
+        test_designated_reencryption_proof::<PCtx>();
+    }
+
+    fn test_reencryption_proof<Ctx: Context>() {
+        let keypair: KeyPair<Ctx> = KeyPair::generate();
+        let pk = &keypair.pkey.y;
+        let message = [
+            Ctx::random_element(),
+            Ctx::random_element(),
+            Ctx::random_element(),
+        ];
+        let ciphertext = keypair.encrypt(&message);
+        let r_n = [
+            Ctx::random_scalar(),
+            Ctx::random_scalar(),
+            Ctx::random_scalar(),
+        ];
+        let re_encrypted = ciphertext.re_encrypt(&r_n, pk);
+
+        let proof =
+            ReEncryptionProof::<Ctx, 3>::prove(pk, &ciphertext, &re_encrypted, &r_n, b"context")
+                .unwrap();
+        assert!(
+            proof
+                .verify(pk, &ciphertext, &re_encrypted, b"context")
+                .unwrap()
+        );
+        assert!(
+            !proof
+                .verify(pk, &ciphertext, &re_encrypted, b"other context")
+                .unwrap()
+        );
+
+        let bytes = proof.ser_f();
+        assert_eq!(bytes.len(), ReEncryptionProof::<Ctx, 3>::size_bytes());
+        let parsed = ReEncryptionProof::<Ctx, 3>::deser_f(&bytes).unwrap();
+        assert_eq!(proof, parsed);
+        assert!(
+            parsed
+                .verify(pk, &ciphertext, &re_encrypted, b"context")
+                .unwrap()
+        );
+    }
+
+    fn test_reencryption_proof_invalid<Ctx: Context>() {
+        let keypair: KeyPair<Ctx> = KeyPair::generate();
+        let pk = &keypair.pkey.y;
+        let ciphertext = keypair.encrypt(&[Ctx::random_element(), Ctx::random_element()]);
+        let r_n = [Ctx::random_scalar(), Ctx::random_scalar()];
+        let re_encrypted = ciphertext.re_encrypt(&r_n, pk);
+
+        // a fresh encryption of a different message is not a re-encryption
+        let other = keypair.encrypt(&[Ctx::random_element(), Ctx::random_element()]);
+        let proof = ReEncryptionProof::<Ctx, 2>::prove(pk, &ciphertext, &other, &r_n, &[]).unwrap();
+        assert!(!proof.verify(pk, &ciphertext, &other, &[]).unwrap());
+
+        // a valid proof for different ciphertexts or key
+        let proof =
+            ReEncryptionProof::<Ctx, 2>::prove(pk, &ciphertext, &re_encrypted, &r_n, &[]).unwrap();
+        assert!(!proof.verify(pk, &ciphertext, &other, &[]).unwrap());
+        assert!(!proof.verify(pk, &re_encrypted, &ciphertext, &[]).unwrap());
+        let other_pk = Ctx::random_element();
+        assert!(
+            !proof
+                .verify(&other_pk, &ciphertext, &re_encrypted, &[])
+                .unwrap()
+        );
+
+        let mut tampered = proof.clone();
+        tampered.k[1] = tampered.k[1].add(&Ctx::Scalar::one());
+        assert!(
+            !tampered
+                .verify(pk, &ciphertext, &re_encrypted, &[])
+                .unwrap()
+        );
+    }
+
+    fn test_designated_reencryption_proof<Ctx: Context>() {
+        let keypair: KeyPair<Ctx> = KeyPair::generate();
+        let verifier: KeyPair<Ctx> = KeyPair::generate();
+        let pk = &keypair.pkey.y;
+        let verifier_y = &verifier.pkey.y;
+        let ciphertext = keypair.encrypt(&[Ctx::random_element(), Ctx::random_element()]);
+        let r_n = [Ctx::random_scalar(), Ctx::random_scalar()];
+        let re_encrypted = ciphertext.re_encrypt(&r_n, pk);
+
+        let proof = DesignatedReEncryptionProof::<Ctx, 2>::prove(
+            pk,
+            &ciphertext,
+            &re_encrypted,
+            &r_n,
+            verifier_y,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            proof
+                .verify(pk, &ciphertext, &re_encrypted, verifier_y, &[])
+                .unwrap()
+        );
+        assert!(
+            !proof
+                .verify(pk, &ciphertext, &re_encrypted, verifier_y, b"other")
+                .unwrap()
+        );
+
+        // the proof is bound to the designated verifier
+        let other_verifier: KeyPair<Ctx> = KeyPair::generate();
+        assert!(
+            !proof
+                .verify(pk, &ciphertext, &re_encrypted, &other_verifier.pkey.y, &[])
+                .unwrap()
+        );
+
+        let bytes = proof.ser();
+        let parsed = DesignatedReEncryptionProof::<Ctx, 2>::deser(&bytes).unwrap();
+        assert!(
+            parsed
+                .verify(pk, &ciphertext, &re_encrypted, verifier_y, &[])
+                .unwrap()
+        );
+
+        // without a witness for either branch the proof does not verify
+        let other = keypair.encrypt(&[Ctx::random_element(), Ctx::random_element()]);
+        let proof = DesignatedReEncryptionProof::<Ctx, 2>::prove(
+            pk,
+            &ciphertext,
+            &other,
+            &r_n,
+            verifier_y,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            !proof
+                .verify(pk, &ciphertext, &other, verifier_y, &[])
+                .unwrap()
+        );
+
+        // the designated verifier can forge a proof for unrelated ciphertexts
+        let forged = DesignatedReEncryptionProof::<Ctx, 2>::forge(
+            pk,
+            &ciphertext,
+            &other,
+            &verifier.skey,
+            verifier_y,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            forged
+                .verify(pk, &ciphertext, &other, verifier_y, &[])
+                .unwrap()
+        );
+        let forged = DesignatedReEncryptionProof::<Ctx, 2>::forge(
+            pk,
+            &ciphertext,
+            &other,
+            &other_verifier.skey,
+            verifier_y,
+            &[],
+        )
+        .unwrap();
+        assert!(
+            !forged
+                .verify(pk, &ciphertext, &other, verifier_y, &[])
+                .unwrap()
+        );
+    }
+}
@@ -9,10 +9,12 @@
 
 use crate::context::Context;
 use crate::traits::groups::CryptoGroup;
-use crate::traits::groups::GroupElement;
 use crate::traits::groups::GroupScalar;
 use crate::utils::error::Error;
 use crate::utils::serialization::VSerializable;
+use crate::zkp::batch::BatchVerifier;
+use crate::zkp::sigma::{Homomorphism, Relation};
+use crate::zkp::transcript::{ChallengeVersion, Transcript};
 use vser_derive::VSerializable;
 
 /**
@@ -71,21 +73,47 @@ impl<C: Context> SchnorrProof<C> {
     /// - `HashToElementError` if challenge generation returns error
     ///
     /// Returns a [`SchnorrProof`] instance.
-    #[allow(clippy::many_single_char_names)]
     pub fn prove(
         g: &C::Element,
         y: &C::Element,
         secret_x: &C::Scalar,
         proof_context: &[u8],
     ) -> Result<SchnorrProof<C>, Error> {
-        let a = C::random_scalar();
-        let big_a = g.exp(&a);
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
 
-        let (input, dsts) = Self::challenge_input(g, y, &big_a, proof_context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+        Self::prove_with_transcript(&mut transcript, g, y, secret_x)
+    }
+
+    /// Prove knowledge of the discrete logarithm `secret_x` for `y = g^secret_x`,
+    /// deriving the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `g`: The group element base, public
+    /// - `y`: The group element `y` = `g^secret_x`, public
+    /// - `secret_x`: The secret scalar
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns a [`SchnorrProof`] instance.
+    #[allow(clippy::many_single_char_names)]
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        g: &C::Element,
+        y: &C::Element,
+        secret_x: &C::Scalar,
+    ) -> Result<SchnorrProof<C>, Error> {
+        let relation = Self::relation(g, y)?;
+        let (a, big_a) = relation.commit();
+        let big_a = Self::single(big_a)?;
+
+        let v = Self::challenge(transcript, g, y, &big_a)?;
 
-        let k = a.add(&v.mul(secret_x));
+        let k = relation.respond(std::slice::from_ref(secret_x), &a, &v)?;
+        let k = Self::single(k)?;
 
         Ok(Self::new(big_a, k))
     }
@@ -109,24 +137,190 @@ impl<C: Context> SchnorrProof<C> {
         y: &C::Element,
         proof_context: &[u8],
     ) -> Result<bool, Error> {
-        let big_a = &self.big_a;
-        let k = &self.k;
+        self.verify_with_version(ChallengeVersion::CURRENT, g, y, proof_context)
+    }
 
-        let (input, dsts) = Self::challenge_input(g, y, big_a, proof_context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+    /// Verify this Schnorr proof of knowledge, computed with the given challenge version
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `g`: The group element base
+    /// - `y`: The group element value
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        g: &C::Element,
+        y: &C::Element,
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        match version {
+            ChallengeVersion::V0 => {
+                let v = Self::legacy_challenge(g, y, &self.big_a, proof_context)?;
+
+                self.check(g, y, &v)
+            }
+            ChallengeVersion::V1 => {
+                let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+                self.verify_with_transcript(&mut transcript, g, y)
+            }
+        }
+    }
+
+    /// Verify this Schnorr proof of knowledge, deriving the challenge from the given transcript
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `g`: The group element base
+    /// - `y`: The group element value
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        g: &C::Element,
+        y: &C::Element,
+    ) -> Result<bool, Error> {
+        let v = Self::challenge(transcript, g, y, &self.big_a)?;
+
+        self.check(g, y, &v)
+    }
+
+    /// Verify a batch of Schnorr proofs of knowledge with a common base.
+    ///
+    /// Equivalent to calling [`SchnorrProof::verify`] on every proof, up to a
+    /// negligible probability of error, but computes a single
+    /// multi-exponentiation. Returns `false` if any proof is invalid, without
+    /// identifying it.
+    ///
+    /// # Parameters
+    ///
+    /// - `g`: The group element base, common to all proofs
+    /// - `proofs`: pairs of a proof and its group element value `y`
+    /// - `proof_context`: proof context label (ZKP CONTEXT), common to all proofs
+    ///
+    /// # Errors
+    ///
+    /// - `HashToElementError` if challenge generation returns error
+    ///
+    /// Returns `true` if all proofs are valid, `false` otherwise.
+    pub fn verify_batch(
+        g: &C::Element,
+        proofs: &[(&Self, &C::Element)],
+        proof_context: &[u8],
+    ) -> Result<bool, Error> {
+        let mut batch = BatchVerifier::<C>::new(&[g]);
+        // every proof starts from the same transcript prefix
+        let transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        for (proof, y) in proofs {
+            let v = Self::challenge(&mut transcript.clone(), g, y, &proof.big_a)?;
+
+            // g^k == y^v * big_a
+            let r = BatchVerifier::<C>::coefficient();
+            batch.add_common(0, &r.mul(&proof.k));
+            batch.add(y, r.mul(&v).neg());
+            batch.add(&proof.big_a, r.neg());
+        }
+
+        Ok(batch.check())
+    }
+
+    /// Returns the [`Relation`] `y = g^secret_x` of this proof.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    pub fn relation(g: &C::Element, y: &C::Element) -> Result<Relation<C>, Error> {
+        let map = Homomorphism::new(1, vec![vec![(0, g.clone())]])?;
+
+        Relation::new(map, vec![y.clone()])
+    }
 
-        let g_k = C::G::generator().exp(k);
-        let y_v = y.exp(&v);
-        let y_v_big_a = y_v.mul(big_a);
+    /// Returns the only value of a commitment or response of [`SchnorrProof::relation`].
+    fn single<T>(values: Vec<T>) -> Result<T, Error> {
+        let [value] = <[T; 1]>::try_from(values)
+            .map_err(|_| Error::SigmaProofError("Expected a single value".to_string()))?;
 
-        Ok(y_v_big_a.equals(&g_k))
+        Ok(value)
     }
 
-    /// Domain separation tags for the challenge input
-    #[crate::warning("Challenge inputs are incomplete.")]
-    const DS_TAGS: [&[u8]; 4] = [b"g", b"public_y", b"big_a", b"schnorr_context"];
-    /// Computes the challenge input for the Schnorr proof
+    /// Returns `true` if the verification equation `g^k == y^v * big_a` holds for challenge `v`.
+    ///
+    /// # Errors
+    ///
+    /// Infallible for this relation; returns `SigmaProofError` like [`Relation::new`].
+    fn check(&self, g: &C::Element, y: &C::Element, v: &C::Scalar) -> Result<bool, Error> {
+        let relation = Self::relation(g, y)?;
+
+        Ok(relation.check(
+            std::slice::from_ref(&self.big_a),
+            v,
+            std::slice::from_ref(&self.k),
+        ))
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"schnorr_proof";
+
+    /// Absorbs the statement and the commitment, and returns the challenge.
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `g`: The group element base
+    /// - `y`: The group element value
+    /// - `big_a`: The group element commitment
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        g: &C::Element,
+        y: &C::Element,
+        big_a: &C::Element,
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"g", g);
+        transcript.absorb(b"public_y", y);
+        transcript.absorb(b"big_a", big_a);
+
+        transcript.challenge(b"schnorr_challenge")
+    }
+
+    /// Computes the [`ChallengeVersion::V0`] challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn legacy_challenge(
+        g: &C::Element,
+        y: &C::Element,
+        big_a: &C::Element,
+        context: &[u8],
+    ) -> Result<C::Scalar, Error> {
+        let (input, dsts) = Self::legacy_challenge_input(g, y, big_a, context);
+        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
+
+        C::G::hash_to_scalar(&input, &dsts)
+    }
+
+    /// Domain separation tags for the [`ChallengeVersion::V0`] challenge input
+    const LEGACY_DS_TAGS: [&[u8]; 4] = [b"g", b"public_y", b"big_a", b"schnorr_context"];
+    /// Computes the [`ChallengeVersion::V0`] challenge input for the Schnorr proof
     ///
     /// # Params
     ///
@@ -138,7 +332,7 @@ impl<C: Context> SchnorrProof<C> {
     /// Returns byte arrays for input values and domain separation tags.
     /// These values will be passed to the hash function to compute
     /// the challenge.
-    fn challenge_input(
+    fn legacy_challenge_input(
         g: &C::Element,
         y: &C::Element,
         big_a: &C::Element,
@@ -146,7 +340,7 @@ impl<C: Context> SchnorrProof<C> {
     ) -> ([Vec<u8>; 4], [&'static [u8]; 4]) {
         let a = [g.ser(), y.ser(), big_a.ser(), context.to_vec()];
 
-        (a, Self::DS_TAGS)
+        (a, Self::LEGACY_DS_TAGS)
     }
 }
 
@@ -154,8 +348,10 @@ impl<C: Context> SchnorrProof<C> {
 mod tests {
     use super::*;
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
+    use crate::traits::groups::GroupElement;
     use crate::utils::serialization::{FDeserializable, FSerializable};
 
     #[test]
@@ -173,11 +369,31 @@ mod tests {
         test_schnorr_proof_serialization::<RCtx>();
     }
 
+    #[test]
+    fn test_schnorr_proof_base_ristretto() {
+        test_schnorr_proof_base::<RCtx>();
+    }
+
+    #[test]
+    fn test_schnorr_proof_batch_ristretto() {
+        test_schnorr_proof_batch::<RCtx>();
+    }
+
+    #[test]
+    fn test_schnorr_proof_legacy_ristretto() {
+        test_schnorr_proof_legacy::<RCtx>();
+    }
+
     #[test]
     fn test_schnorr_proof_valid_p256() {
         test_schnorr_proof_valid::<PCtx>();
     }
 
+    #[test]
+    fn test_schnorr_proof_valid_electionguard() {
+        test_schnorr_proof_valid::<ECtx>();
+    }
+
     #[test]
     fn test_schnorr_proof_serialization_p256() {
         test_schnorr_proof_serialization::<PCtx>();
@@ -188,6 +404,21 @@ mod tests {
         test_schnorr_proof_invalid::<PCtx>();
     }
 
+    #[test]
+    fn test_schnorr_proof_base_p256() {
+        test_schnorr_proof_base::<PCtx>();
+    }
+
+    #[test]
+    fn test_schnorr_proof_batch_p256() {
+        test_schnorr_proof_batch::<PCtx>();
+    }
+
+    #[test]
+    fn test_schnorr_proof_batch_electionguard() {
+        test_schnorr_proof_batch::<ECtx>();
+    }
+
     fn test_schnorr_proof_valid<Ctx: Context>() {
         let g = Ctx::generator();
         let secret_x = Ctx::random_scalar();
@@ -200,6 +431,62 @@ mod tests {
         );
     }
 
+    fn test_schnorr_proof_base<Ctx: Context>() {
+        let g = Ctx::random_element();
+        let secret_x = Ctx::random_scalar();
+        let public_y = g.exp(&secret_x);
+
+        let proof = SchnorrProof::<Ctx>::prove(&g, &public_y, &secret_x, &[]).unwrap();
+        assert!(
+            proof.verify(&g, &public_y, &[]).unwrap(),
+            "Verification of a valid proof with a base other than the generator should succeed"
+        );
+        assert!(
+            !proof.verify(&Ctx::generator(), &public_y, &[]).unwrap(),
+            "Verification of a proof against a different base should fail"
+        );
+    }
+
+    fn test_schnorr_proof_legacy<Ctx: Context>() {
+        let g = Ctx::generator();
+        let secret_x = Ctx::random_scalar();
+        let public_y = g.exp(&secret_x);
+
+        // a proof computed with the V0 challenge
+        let a = Ctx::random_scalar();
+        let big_a = g.exp(&a);
+        let v = SchnorrProof::<Ctx>::legacy_challenge(&g, &public_y, &big_a, &[]).unwrap();
+        let legacy = SchnorrProof::<Ctx>::new(big_a, a.add(&v.mul(&secret_x)));
+
+        assert!(
+            legacy
+                .verify_with_version(ChallengeVersion::V0, &g, &public_y, &[])
+                .unwrap()
+        );
+        assert!(!legacy.verify(&g, &public_y, &[]).unwrap());
+
+        let proof = SchnorrProof::<Ctx>::prove(&g, &public_y, &secret_x, &[]).unwrap();
+        assert!(
+            !proof
+                .verify_with_version(ChallengeVersion::V0, &g, &public_y, &[])
+                .unwrap()
+        );
+
+        // a proof in a shared transcript only verifies in the same transcript state
+        let mut transcript = Transcript::new(b"test", &[]);
+        transcript.absorb_bytes(b"prefix", b"value");
+        let proof =
+            SchnorrProof::<Ctx>::prove_with_transcript(&mut transcript, &g, &public_y, &secret_x)
+                .unwrap();
+        let mut transcript = Transcript::new(b"test", &[]);
+        assert!(
+            !proof
+                .verify_with_transcript(&mut transcript, &g, &public_y)
+                .unwrap()
+        );
+        assert!(!proof.verify(&g, &public_y, &[]).unwrap());
+    }
+
     fn test_schnorr_proof_serialization<Ctx: Context>() {
         let g = Ctx::generator();
         let secret_x = Ctx::random_scalar();
@@ -241,4 +528,26 @@ mod tests {
             "Verification of a proof with tampered 's' should fail"
         );
     }
+
+    fn test_schnorr_proof_batch<Ctx: Context>() {
+        let g = Ctx::random_element();
+        let secrets: Vec<Ctx::Scalar> = (0..4).map(|_| Ctx::random_scalar()).collect();
+        let ys: Vec<Ctx::Element> = secrets.iter().map(|x| g.exp(x)).collect();
+        let mut proofs: Vec<SchnorrProof<Ctx>> = secrets
+            .iter()
+            .zip(ys.iter())
+            .map(|(x, y)| SchnorrProof::<Ctx>::prove(&g, y, x, &[]).unwrap())
+            .collect();
+
+        let batch: Vec<(&SchnorrProof<Ctx>, &Ctx::Element)> =
+            proofs.iter().zip(ys.iter()).collect();
+        assert!(SchnorrProof::verify_batch(&g, &batch, &[]).unwrap());
+        assert!(SchnorrProof::<Ctx>::verify_batch(&g, &[], &[]).unwrap());
+        assert!(!SchnorrProof::verify_batch(&g, &batch, b"other context").unwrap());
+
+        proofs[2].k = proofs[2].k.add(&Ctx::Scalar::one());
+        let batch: Vec<(&SchnorrProof<Ctx>, &Ctx::Element)> =
+            proofs.iter().zip(ys.iter()).collect();
+        assert!(!SchnorrProof::verify_batch(&g, &batch, &[]).unwrap());
+    }
 }
@@ -19,12 +19,14 @@ use crate::traits::groups::ReplScalarOps;
 use crate::utils::error::Error;
 use crate::utils::hash;
 use crate::utils::serialization::VSerializable;
+use crate::zkp::transcript::{ChallengeVersion, Transcript};
 
 use rand::Rng;
 use sha3::Digest;
 use vser_derive::VSerializable as VSer;
 
 use rayon::prelude::*;
+use std::array;
 
 /**
  * Shuffler for the Terelius-Wikstrom proof of shuffle
@@ -101,11 +103,10 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
     ///
     /// Returns a tuple of form (commitment exponents, re-encryption exponents)
     pub(crate) fn gen_private_exponents(size: usize) -> (Vec<C::Scalar>, Vec<[C::Scalar; W]>) {
-        #[crate::warning("The following code is not optimized. Parallelize with rayon")]
+        // drawn sequentially, so that seeded rngs give reproducible outputs
+        let mut rng = C::get_rng();
         (0..size)
-            .into_par_iter()
             .map(|_| {
-                let mut rng = C::get_rng();
                 (
                     C::Scalar::random(&mut rng),
                     <[C::Scalar; W]>::random(&mut rng),
@@ -135,16 +136,45 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
     /// not match the ciphertexts length, which should be impossible.
     ///
     /// Returns the shuffled ciphertexts of width `W` and the proof of shuffle.
+    pub fn shuffle(
+        &self,
+        ciphertexts: &Vec<Ciphertext<C, W>>,
+        context: &[u8],
+    ) -> Result<(Vec<Ciphertext<C, W>>, ShuffleProof<C, W>), Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, context);
+
+        self.shuffle_with_transcript(&mut transcript, ciphertexts)
+    }
+
+    /// Shuffle the input ciphertexts and computes a corresponding proof, deriving
+    /// the challenges from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the statement and the proof
+    /// - `ciphertexts`: The input ciphertexts to be shuffled, of width `W`
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyShuffle` if the input ciphertexts are zero length
+    /// - `MismatchedShuffleLength` if there is a length mismatch between ciphertexts and generators
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of the generated permutation does
+    /// not match the ciphertexts length, which should be impossible.
+    ///
+    /// Returns the shuffled ciphertexts of width `W` and the proof of shuffle.
     #[crate::warning(
         "The following function is not optimized. Parallelize with rayon. Error handling wrt generators length is suboptimal"
     )]
     #[allow(clippy::many_single_char_names)]
     #[allow(clippy::similar_names)]
     #[allow(clippy::too_many_lines)]
-    pub fn shuffle(
+    pub fn shuffle_with_transcript(
         &self,
+        transcript: &mut Transcript<C>,
         ciphertexts: &Vec<Ciphertext<C, W>>,
-        context: &[u8],
     ) -> Result<(Vec<Ciphertext<C, W>>, ShuffleProof<C, W>), Error> {
         if ciphertexts.is_empty() {
             return Err(Error::EmptyShuffle);
@@ -167,7 +197,12 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         ///////////////// Step 1 /////////////////
 
         // Challenge e
-        let e_n = self.challenge_e_n(ciphertexts, &permuted_ciphertexts, context)?;
+        let e_n = self.challenge_e_n(
+            transcript,
+            ciphertexts,
+            &permuted_ciphertexts,
+            &pedersen_commitments,
+        )?;
         // the calculation of A and F is moved to Step 5
 
         ///////////////// Step 2 /////////////////
@@ -191,7 +226,6 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         // b) Proof commitments
         let alpha = C::random_scalar();
         let (beta_n, epsilon_n): (Vec<C::Scalar>, Vec<C::Scalar>) = (0..big_n)
-            .into_par_iter()
             .map(|_| (C::random_scalar(), C::random_scalar()))
             .collect();
         let gamma = C::random_scalar();
@@ -270,9 +304,7 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         ///////////////// Step 3 /////////////////
 
         // Challenge v
-        let (input, dsts) = self.challenge_input_v(&commitments, context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
+        let v = Self::challenge_v(transcript, &commitments)?;
 
         ///////////////// Step 4 /////////////////
 
@@ -362,10 +394,6 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
     /// - `MismatchedShuffleLength` if there is a length mismatch between proof commitments and ciphertexts
     ///
     /// Returns `true` if the proof is valid, `false` otherwise.
-    #[crate::warning(
-        "The following function is not optimized. Parallelize with rayon. Error handling wrt generators length is suboptimal"
-    )]
-    #[allow(clippy::similar_names)]
     pub fn verify(
         &self,
         ciphertexts: &Vec<Ciphertext<C, W>>,
@@ -373,6 +401,115 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         proof: &ShuffleProof<C, W>,
         context: &[u8],
     ) -> Result<bool, Error> {
+        self.verify_with_version(
+            ChallengeVersion::CURRENT,
+            ciphertexts,
+            permuted_ciphertexts,
+            proof,
+            context,
+        )
+    }
+
+    /// Verify the given proof of shuffle with respect to the original and shuffled
+    /// ciphertexts, computed with the given challenge version.
+    ///
+    /// # Parameters
+    ///
+    /// - `version`: The challenge version the proof was computed with
+    /// - `ciphertexts`: The original ciphertexts, of width `W`
+    /// - `permuted_ciphertexts`: The shuffled ciphertexts, of width `W`
+    /// - `proof`: The proof of shuffle
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyShuffle` if the input ciphertexts are zero length
+    /// - `MismatchedShuffleLength` if there is a length mismatch between ciphertexts
+    /// - `MismatchedShuffleLength` if there is a length mismatch between ciphertexts and generators
+    /// - `MismatchedShuffleLength` if there is a length mismatch between proof commitments and ciphertexts
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_version(
+        &self,
+        version: ChallengeVersion,
+        ciphertexts: &Vec<Ciphertext<C, W>>,
+        permuted_ciphertexts: &Vec<Ciphertext<C, W>>,
+        proof: &ShuffleProof<C, W>,
+        context: &[u8],
+    ) -> Result<bool, Error> {
+        match version {
+            ChallengeVersion::V0 => {
+                self.check_lengths(ciphertexts, permuted_ciphertexts, proof)?;
+                let e_n = self.legacy_challenge_e_n(ciphertexts, permuted_ciphertexts, context)?;
+                let v = self.legacy_challenge_v(&proof.commitments, context)?;
+
+                Ok(self.check(ciphertexts, permuted_ciphertexts, proof, e_n, &v))
+            }
+            ChallengeVersion::V1 => {
+                let mut transcript = Transcript::new(Self::PROTOCOL, context);
+
+                self.verify_with_transcript(
+                    &mut transcript,
+                    ciphertexts,
+                    permuted_ciphertexts,
+                    proof,
+                )
+            }
+        }
+    }
+
+    /// Verify the given proof of shuffle with respect to the original and shuffled
+    /// ciphertexts, deriving the challenges from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `ciphertexts`: The original ciphertexts, of width `W`
+    /// - `permuted_ciphertexts`: The shuffled ciphertexts, of width `W`
+    /// - `proof`: The proof of shuffle
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyShuffle` if the input ciphertexts are zero length
+    /// - `MismatchedShuffleLength` if there is a length mismatch between ciphertexts
+    /// - `MismatchedShuffleLength` if there is a length mismatch between ciphertexts and generators
+    /// - `MismatchedShuffleLength` if there is a length mismatch between proof commitments and ciphertexts
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        ciphertexts: &Vec<Ciphertext<C, W>>,
+        permuted_ciphertexts: &Vec<Ciphertext<C, W>>,
+        proof: &ShuffleProof<C, W>,
+    ) -> Result<bool, Error> {
+        self.check_lengths(ciphertexts, permuted_ciphertexts, proof)?;
+        let commitments = &proof.commitments;
+
+        let e_n = self.challenge_e_n(
+            transcript,
+            ciphertexts,
+            permuted_ciphertexts,
+            &commitments.u_n,
+        )?;
+        let v = Self::challenge_v(transcript, commitments)?;
+
+        Ok(self.check(ciphertexts, permuted_ciphertexts, proof, e_n, &v))
+    }
+
+    /// Returns an error if the lengths of the ciphertexts, the generators and
+    /// the proof commitments do not match.
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyShuffle` if the input ciphertexts are zero length
+    /// - `MismatchedShuffleLength` if any of the lengths do not match
+    fn check_lengths(
+        &self,
+        ciphertexts: &[Ciphertext<C, W>],
+        permuted_ciphertexts: &[Ciphertext<C, W>],
+        proof: &ShuffleProof<C, W>,
+    ) -> Result<(), Error> {
         if ciphertexts.is_empty() {
             return Err(Error::EmptyShuffle);
         }
@@ -392,29 +529,34 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
             return Err(Error::MismatchedShuffleLength);
         }
 
+        Ok(())
+    }
+
+    /// Returns `true` if the verification equations hold for challenges `e_n` and `v`.
+    ///
+    /// The lengths must have been checked with [`Self::check_lengths`].
+    #[crate::warning(
+        "The following function is not optimized. Parallelize with rayon. Error handling wrt generators length is suboptimal"
+    )]
+    #[allow(clippy::similar_names)]
+    fn check(
+        &self,
+        ciphertexts: &[Ciphertext<C, W>],
+        permuted_ciphertexts: &[Ciphertext<C, W>],
+        proof: &ShuffleProof<C, W>,
+        e_n: Vec<C::Scalar>,
+        v: &C::Scalar,
+    ) -> bool {
         let commitments = &proof.commitments;
         let responses = &proof.responses;
-        let g = C::generator();
-
-        let e_n = self.challenge_e_n(ciphertexts, permuted_ciphertexts, context)?;
-        let (input, dsts) = self.challenge_input_v(commitments, context);
-        let input: Vec<&[u8]> = input.iter().map(Vec::as_slice).collect();
-        let v = C::G::hash_to_scalar(&input, &dsts)?;
 
         ///////////////// Step 5 /////////////////
 
         // A (comes from Step 1 in evs)
-        let e_n_u_n = e_n.par_iter().zip(commitments.u_n.par_iter());
-        let big_a_n = e_n_u_n.map(|(e, u)| u.exp(e));
-        let big_a: C::Element = big_a_n.reduce(C::Element::one, |acc, next| acc.mul(&next));
+        let big_a = par_multi_exp::<C>(&commitments.u_n, &e_n);
 
         // F (comes from Step 1 in evs)
-        let e_n_w_n = e_n.par_iter().zip(ciphertexts.par_iter());
-        let big_f_n = e_n_w_n.map(|(e, w)| w.map_ref(|uv| uv.dist_exp(e)));
-        // let big_f_n = e_n_w_n.map(|(e, w)| array::from_fn(|i| w.0[i].dist_exp(&e)));
-        let identity = <[[C::Element; W]; 2]>::one();
-        let big_f: [[C::Element; W]; 2] =
-            big_f_n.reduce(|| identity.clone(), |acc, next| acc.mul(&next));
+        let big_f = ciphertexts_multi_exp(ciphertexts, &e_n);
 
         // C
         let u_n_fold = commitments
@@ -443,11 +585,9 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
 
         ////// Verification 1 //////
 
-        let h_n_k_e_n = self.h_generators.par_iter().zip(responses.k_e_n.par_iter());
-        let h_n_k_e_n = h_n_k_e_n.map(|(h, k)| h.exp(k));
-        let h_n_k_e_n_fold = h_n_k_e_n.reduce(C::Element::one, |acc, next| acc.mul(&next));
-        let g_k_a = g.exp(&responses.k_a);
-        let lhs_1 = big_a.exp(&v).mul(&commitments.big_a_prime);
+        let h_n_k_e_n_fold = par_multi_exp::<C>(&self.h_generators, &responses.k_e_n);
+        let g_k_a = C::G::g_exp(&responses.k_a);
+        let lhs_1 = big_a.exp(v).mul(&commitments.big_a_prime);
         let rhs_1 = g_k_a.mul(&h_n_k_e_n_fold);
 
         ////// Verification 2 //////
@@ -467,7 +607,7 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         let rhs_2: Vec<C::Element> = big_b_0_n_minus_1_k_e_n_k_b_n
             .map(|((b, k_e), k_b)| {
                 let b_k_e = b.exp(k_e);
-                let g_k_b = g.exp(k_b);
+                let g_k_b = C::G::g_exp(k_b);
 
                 g_k_b.mul(&b_k_e)
             })
@@ -477,42 +617,40 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         let big_b_n_big_b_prime_n = big_b_n.par_iter().zip(big_b_prime_n.par_iter());
         let lhs_2: Vec<C::Element> = big_b_n_big_b_prime_n
             .map(|(big_b, big_b_prime)| {
-                let big_b_v = big_b.exp(&v);
+                let big_b_v = big_b.exp(v);
                 big_b_v.mul(big_b_prime)
             })
             .collect();
 
         ////// Verification 3 //////
 
-        let big_c_v = big_c.exp(&v);
+        let big_c_v = big_c.exp(v);
         let lhs_3 = big_c_v.mul(&commitments.big_c_prime);
-        let rhs_3 = g.exp(&responses.k_c);
+        let rhs_3 = C::G::g_exp(&responses.k_c);
 
         ////// Verification 4 //////
 
-        let big_d_v = big_d.exp(&v);
+        let big_d_v = big_d.exp(v);
         let lhs_4 = big_d_v.mul(&commitments.big_d_prime);
-        let rhs_4 = g.exp(&responses.k_d);
+        let rhs_4 = C::G::g_exp(&responses.k_d);
 
         ////// Verification 5 //////
 
         let big_f_prime = &commitments.big_f_prime;
-        let big_f_v = big_f.map(|uv| uv.dist_exp(&v));
+        let big_f_v = big_f.map(|uv| uv.dist_exp(v));
         let lhs_5 = big_f_v.mul(&big_f_prime.0);
 
         let w_prime_n = permuted_ciphertexts;
-        let w_prime_n_k_e_n = w_prime_n.par_iter().zip(responses.k_e_n.par_iter());
-        let w_prime_n_k_e_n = w_prime_n_k_e_n.map(|(w, k)| w.map_ref(|uv| uv.dist_exp(k)));
-        let w_prime_n_k_e_n_fold =
-            w_prime_n_k_e_n.reduce(|| identity.clone(), |acc, next| acc.mul(&next));
+        let w_prime_n_k_e_n_fold = ciphertexts_multi_exp(w_prime_n, &responses.k_e_n);
 
-        let one = [g, self.pk.y.clone()].map(|gy| gy.repl_exp(&responses.k_f.neg()));
+        let k_f_neg = responses.k_f.neg();
+        let one = [
+            k_f_neg.each_ref().map(C::G::g_exp),
+            self.pk.y.repl_exp(&k_f_neg),
+        ];
         let rhs_5 = one.mul(&w_prime_n_k_e_n_fold);
 
-        let ret =
-            lhs_1 == rhs_1 && lhs_2 == rhs_2 && lhs_3 == rhs_3 && lhs_4 == rhs_4 && lhs_5 == rhs_5;
-
-        Ok(ret)
+        lhs_1 == rhs_1 && lhs_2 == rhs_2 && lhs_3 == rhs_3 && lhs_4 == rhs_4 && lhs_5 == rhs_5
     }
 
     /// Re-encrypt and permute the input ciphertexts with the given permutation data.
@@ -560,18 +698,74 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         Ok(ret)
     }
 
-    /// Domain separation tags for the e-challenge input
-    #[crate::warning(
-        "Challenge inputs are incomplete. Also add generators and pedersen commitments"
-    )]
-    const DS_TAGS_CHALLENGE_E: [&[u8]; 4] = [
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"shuffle_proof";
+
+    /// Absorbs the statement and the pedersen commitments, and returns the e-challenges.
+    ///
+    /// See `EVS`: Protocol 12.3, Step 1
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript
+    /// - `w_n`: The original ciphertexts, of width `W`
+    /// - `w_prime_n`: The shuffled ciphertexts, of width `W`
+    /// - `u_n`: The pedersen commitments
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge_e_n(
+        &self,
+        transcript: &mut Transcript<C>,
+        w_n: &Vec<Ciphertext<C, W>>,
+        w_prime_n: &Vec<Ciphertext<C, W>>,
+        u_n: &Vec<C::Element>,
+    ) -> Result<Vec<C::Scalar>, Error> {
+        transcript.absorb(b"pk", &self.pk);
+        transcript.absorb(b"h_n", &self.h_generators);
+        transcript.absorb(b"w_n", w_n);
+        transcript.absorb(b"w_prime_n", w_prime_n);
+        transcript.absorb(b"u_n", u_n);
+
+        transcript.challenges(b"shuffle_challenge_e", w_n.len())
+    }
+
+    /// Absorbs the proof commitments, and returns the v-challenge.
+    ///
+    /// See `EVS`: Protocol 12.3, Step 3
+    ///
+    /// # Params
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which has absorbed the e-challenges
+    /// - `commitments`: The bridging and proof commitments
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge_v(
+        transcript: &mut Transcript<C>,
+        commitments: &ShuffleCommitments<C, W>,
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"big_b_n", &commitments.big_b_n);
+        transcript.absorb(b"big_a_prime", &commitments.big_a_prime);
+        transcript.absorb(b"big_b_prime_n", &commitments.big_b_prime_n);
+        transcript.absorb(b"big_c_prime", &commitments.big_c_prime);
+        transcript.absorb(b"big_d_prime", &commitments.big_d_prime);
+        transcript.absorb(b"big_f_prime", &commitments.big_f_prime);
+
+        transcript.challenge(b"shuffle_challenge_v")
+    }
+
+    /// Domain separation tags for the [`ChallengeVersion::V0`] e-challenge input
+    const LEGACY_DS_TAGS_CHALLENGE_E: [&[u8]; 4] = [
         b"pk",
         b"w_n",
         b"w_prime_n",
         b"shuffle_proof_challenge_e_context",
     ];
 
-    /// Compute the e-challenge input for the proof of shuffle.
+    /// Compute the [`ChallengeVersion::V0`] e-challenges for the proof of shuffle.
     ///
     /// See `EVS`: Protocol 12.3, Step 1
     ///
@@ -581,10 +775,10 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
     /// - `w_prime_n`: The shuffled ciphertexts, of width `W`
     /// - `proof_context`: proof context label (ZKP CONTEXT)
     ///
-    /// Returns byte arrays for input values and domain separation tags.
-    /// These values will be passed to the hash function to compute
-    /// the challenge.
-    fn challenge_e_n(
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn legacy_challenge_e_n(
         &self,
         w_n: &Vec<Ciphertext<C, W>>,
         w_prime_n: &Vec<Ciphertext<C, W>>,
@@ -595,7 +789,7 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         let input: Vec<&[u8]> = a.iter().map(Vec::as_slice).collect();
 
         let mut hasher = C::get_hasher();
-        hash::update_hasher(&mut hasher, &input, &Self::DS_TAGS_CHALLENGE_E);
+        hash::update_hasher(&mut hasher, &input, &Self::LEGACY_DS_TAGS_CHALLENGE_E);
         #[crate::warning("Verify that this double hashing set up is ok")]
         let bytes = hasher.finalize();
         let mut ret = vec![];
@@ -603,7 +797,7 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         #[crate::warning("The following code is not optimized. Parallelize with rayon")]
         for i in 0..w_n.len() {
             let prefix = bytes.clone();
-            let inputs: &[&[u8]] = &[prefix.as_slice(), &i.to_be_bytes()];
+            let inputs: &[&[u8]] = &[&prefix[..], &i.to_be_bytes()];
             let ds_tags: &[&[u8]; 2] = &[b"prefix", b"shuffle_proof_challenge_e_counter"];
             let scalar = C::G::hash_to_scalar(inputs, ds_tags)?;
             ret.push(scalar);
@@ -612,11 +806,8 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         Ok(ret)
     }
 
-    /// Domain separation tags for the v-challenge input
-    #[crate::warning(
-        "Challenge inputs are incomplete. Also add generators, pedersen commitments, pk, and ciphertexts"
-    )]
-    const DS_TAGS_CHALLENGE_V: [&[u8]; 8] = [
+    /// Domain separation tags for the [`ChallengeVersion::V0`] v-challenge input
+    const LEGACY_DS_TAGS_CHALLENGE_V: [&[u8]; 8] = [
         b"pk",
         b"big_b_n",
         b"big_a_prime",
@@ -627,28 +818,23 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
         b"shuffle_challenge_input_v_context",
     ];
 
-    /// Compute the v-challenge input for the proof of shuffle.
+    /// Compute the [`ChallengeVersion::V0`] v-challenge for the proof of shuffle.
     ///
     /// See `EVS`: Protocol 12.3, Step 3
     ///
     /// # Params
     ///
-    /// - `big_b_n`: Bridging commitments
-    /// - `big_a_prime`: Proof commitment
-    /// - `big_b_prime_n`: Proof commitment
-    /// - `big_c_prime`: Proof commitment
-    /// - `big_d_prime`: Proof commitment
-    /// - `big_f_prime`: Proof commitment
+    /// - `commitments`: The bridging and proof commitments
     /// - `proof_context`: proof context label (ZKP CONTEXT)
     ///
-    /// Returns byte arrays for input values and domain separation tags.
-    /// These values will be passed to the hash function to compute
-    /// the challenge.
-    fn challenge_input_v(
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn legacy_challenge_v(
         &self,
         commitments: &ShuffleCommitments<C, W>,
         context: &[u8],
-    ) -> ([Vec<u8>; 8], [&'static [u8]; 8]) {
+    ) -> Result<C::Scalar, Error> {
         #[crate::warning("Serialization of vectors is serial")]
         let a = [
             self.pk.ser(),
@@ -660,10 +846,42 @@ impl<C: Context, const W: usize> Shuffler<C, W> {
             commitments.big_f_prime.ser(),
             context.to_vec(),
         ];
-        (a, Self::DS_TAGS_CHALLENGE_V)
+        let input: Vec<&[u8]> = a.iter().map(Vec::as_slice).collect();
+
+        C::G::hash_to_scalar(&input, &Self::LEGACY_DS_TAGS_CHALLENGE_V)
     }
 }
 
+/// The minimum number of terms of each parallel multi-exponentiation in [`par_multi_exp`].
+const MULTI_EXP_CHUNK: usize = 64;
+
+/// Compute `prod_i bases_i^scalars_i`, splitting the [multi-exponentiation][`GroupElement::multi_exp`] across threads.
+fn par_multi_exp<C: Context>(bases: &[C::Element], scalars: &[C::Scalar]) -> C::Element {
+    let chunk = bases
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(MULTI_EXP_CHUNK);
+
+    bases
+        .par_chunks(chunk)
+        .zip(scalars.par_chunks(chunk))
+        .map(|(b, s)| C::Element::multi_exp(b, s))
+        .reduce(C::Element::one, |acc, next| acc.mul(&next))
+}
+
+/// Compute `prod_i ciphertexts_i^scalars_i` component-wise, with one multi-exponentiation per component.
+fn ciphertexts_multi_exp<C: Context, const W: usize>(
+    ciphertexts: &[Ciphertext<C, W>],
+    scalars: &[C::Scalar],
+) -> [[C::Element; W]; 2] {
+    array::from_fn(|i| {
+        array::from_fn(|j| {
+            let bases: Vec<C::Element> = ciphertexts.iter().map(|c| c.0[i][j].clone()).collect();
+            par_multi_exp::<C>(&bases, scalars)
+        })
+    })
+}
+
 /// Convenience structure to hold re-encryption and permutation data
 pub(crate) struct PermutationData<C: Context, const W: usize> {
     /// Commitment exponents, private
@@ -969,15 +1187,19 @@ mod tests {
     use std::array;
 
     use crate::context::Context;
+    use crate::context::EgCtx as ECtx;
     use crate::context::P256Ctx as PCtx;
     use crate::context::RistrettoCtx as RCtx;
+    use crate::context::SeededCtx;
     use crate::cryptosystem::elgamal::Ciphertext;
     use crate::cryptosystem::elgamal::KeyPair;
     use crate::traits::groups::CryptoGroup;
+    use crate::utils::rng::SeededRng;
     use crate::utils::serialization::{VDeserializable, VSerializable};
     use crate::zkp::shuffle::Permutation;
     use crate::zkp::shuffle::ShuffleProof;
     use crate::zkp::shuffle::Shuffler;
+    use crate::zkp::transcript::{ChallengeVersion, Transcript};
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -999,6 +1221,13 @@ mod tests {
         test_shuffle::<PCtx, 5>();
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[crate::warning("Miri test fails (Stacked Borrows)")]
+    fn test_shuffle_electionguard() {
+        test_shuffle::<ECtx, 1>();
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[crate::warning("Miri test fails (Stacked Borrows)")]
@@ -1013,6 +1242,13 @@ mod tests {
         test_shuffle_label::<PCtx>();
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[crate::warning("Miri test fails (Stacked Borrows)")]
+    fn test_shuffle_version_ristretto() {
+        test_shuffle_version::<RCtx>();
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[crate::warning("Miri test fails (Stacked Borrows)")]
@@ -1038,15 +1274,60 @@ mod tests {
         let ciphertexts: Vec<Ciphertext<C, W>> =
             messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-        let generators = C::G::ind_generators(count, &vec![]).unwrap();
+        let generators = C::G::ind_generators(count, &[]).unwrap();
         let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
 
-        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &vec![]).unwrap();
-        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &vec![]);
+        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &[]).unwrap();
+        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &[]);
 
         assert!(ok.unwrap());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[crate::warning("Miri test fails (Stacked Borrows)")]
+    fn test_shuffle_seeded_ristretto() {
+        test_shuffle_seeded::<RCtx>();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[crate::warning("Miri test fails (Stacked Borrows)")]
+    fn test_shuffle_seeded_p256() {
+        test_shuffle_seeded::<PCtx>();
+    }
+
+    /// Runs a key generation, encryption and shuffle with a seeded context.
+    fn seeded_shuffle<C: Context>(
+        seed: [u8; 32],
+    ) -> (
+        Vec<Ciphertext<SeededCtx<C>, 2>>,
+        ShuffleProof<SeededCtx<C>, 2>,
+    ) {
+        SeededRng::seed(seed);
+        let count = 5;
+        let keypair: KeyPair<SeededCtx<C>> = KeyPair::generate();
+        let ciphertexts: Vec<Ciphertext<SeededCtx<C>, 2>> = (0..count)
+            .map(|_| keypair.encrypt(&array::from_fn(|_| SeededCtx::<C>::random_element())))
+            .collect();
+
+        let generators = C::G::ind_generators(count, &[]).unwrap();
+        let shuffler = Shuffler::<SeededCtx<C>, 2>::new(generators, keypair.pkey);
+
+        shuffler.shuffle(&ciphertexts, &[]).unwrap()
+    }
+
+    fn test_shuffle_seeded<C: Context>() {
+        let (lhs, lhs_proof) = seeded_shuffle::<C>([1u8; 32]);
+        let (rhs, rhs_proof) = seeded_shuffle::<C>([1u8; 32]);
+        assert_eq!(lhs.ser(), rhs.ser());
+        assert_eq!(lhs_proof.ser(), rhs_proof.ser());
+
+        let (other, other_proof) = seeded_shuffle::<C>([2u8; 32]);
+        assert_ne!(lhs.ser(), other.ser());
+        assert_ne!(lhs_proof.ser(), other_proof.ser());
+    }
+
     fn test_shuffle_label<C: Context>() {
         const W: usize = 3;
         let count = 10;
@@ -1059,15 +1340,57 @@ mod tests {
         let ciphertexts: Vec<Ciphertext<C, W>> =
             messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-        let generators = C::G::ind_generators(count, &vec![]).unwrap();
+        let generators = C::G::ind_generators(count, &[]).unwrap();
+        let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
+
+        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &[1u8]).unwrap();
+        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &[2u8]);
+
+        assert!(!ok.unwrap());
+    }
+
+    fn test_shuffle_version<C: Context>() {
+        const W: usize = 2;
+        let count = 4;
+        let keypair: KeyPair<C> = KeyPair::generate();
+        let ciphertexts: Vec<Ciphertext<C, W>> = (0..count)
+            .map(|_| keypair.encrypt(&array::from_fn(|_| C::random_element())))
+            .collect();
+
+        let generators = C::G::ind_generators(count, &[]).unwrap();
         let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
+        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &[]).unwrap();
 
-        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &vec![1u8]).unwrap();
-        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &vec![2u8]);
+        let current = ChallengeVersion::CURRENT;
+        let ok = shuffler.verify_with_version(current, &ciphertexts, &pciphertexts, &proof, &[]);
+        assert!(ok.unwrap());
+        let ok = shuffler.verify_with_version(
+            ChallengeVersion::V0,
+            &ciphertexts,
+            &pciphertexts,
+            &proof,
+            &[],
+        );
+        assert!(!ok.unwrap());
 
+        // a shuffle on a transcript with a prefix only verifies with the same prefix
+        let mut transcript = Transcript::<C>::new(b"mix", &[]);
+        transcript.absorb_bytes(b"round", &[1]);
+        let (pciphertexts, proof) = shuffler
+            .shuffle_with_transcript(&mut transcript.clone(), &ciphertexts)
+            .unwrap();
+        let ok = shuffler.verify_with_transcript(
+            &mut transcript.clone(),
+            &ciphertexts,
+            &pciphertexts,
+            &proof,
+        );
+        assert!(ok.unwrap());
+        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &[]);
         assert!(!ok.unwrap());
     }
 
+    #[allow(clippy::similar_names)]
     fn test_shuffle_serialization<C: Context>() {
         const W: usize = 3;
         let count = 10;
@@ -1080,10 +1403,10 @@ mod tests {
         let ciphertexts: Vec<Ciphertext<C, W>> =
             messages.iter().map(|m| keypair.encrypt(m)).collect();
 
-        let generators = C::G::ind_generators(count, &vec![]).unwrap();
+        let generators = C::G::ind_generators(count, &[]).unwrap();
         let shuffler = Shuffler::<C, W>::new(generators, keypair.pkey);
 
-        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &vec![1u8]).unwrap();
+        let (pciphertexts, proof) = shuffler.shuffle(&ciphertexts, &[1u8]).unwrap();
         let s_proof = proof.ser();
         let s_pciphertexts = pciphertexts.ser();
         let s_ciphertexts = ciphertexts.ser();
@@ -1092,7 +1415,7 @@ mod tests {
         let ciphertexts = Vec::<Ciphertext<C, W>>::deser(&s_ciphertexts).unwrap();
         let pciphertexts = Vec::<Ciphertext<C, W>>::deser(&s_pciphertexts).unwrap();
 
-        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &vec![2u8]);
+        let ok = shuffler.verify(&ciphertexts, &pciphertexts, &proof, &[2u8]);
 
         assert!(!ok.unwrap());
     }
@@ -1137,8 +1460,7 @@ mod tests {
         for i in 0..size {
             assert_eq!(
                 perm.permutation[perm.inverse[i]], i,
-                "Inverse property failed at index {}",
-                i
+                "Inverse property failed at index {i}"
             );
         }
 
@@ -1146,8 +1468,7 @@ mod tests {
         for i in 0..size {
             assert_eq!(
                 perm.inverse[perm.permutation[i]], i,
-                "Inverse property failed at index {}",
-                i
+                "Inverse property failed at index {i}"
             );
         }
     }
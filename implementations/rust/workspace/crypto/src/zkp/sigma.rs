@@ -0,0 +1,988 @@
+/*
+ * Generic Sigma protocols for preimages of group homomorphisms
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::traits::groups::GroupElement;
+use crate::traits::groups::GroupScalar;
+use crate::utils::error::Error;
+use crate::zkp::transcript::Transcript;
+use vser_derive::VSerializable;
+
+/**
+ * A group homomorphism from vectors of scalars to vectors of group elements.
+ *
+ * The homomorphism maps a preimage `x` of `scalars` scalars to one group
+ * element per row, where each row is a list of terms `(j, b)` and
+ * `f(x)_i = prod b^x_j` over the terms of row `i`.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::traits::groups::GroupElement;
+ * use crypto::zkp::sigma::Homomorphism;
+ *
+ * let g = RCtx::generator();
+ * let h = RCtx::random_element();
+ * let x = [RCtx::random_scalar(), RCtx::random_scalar()];
+ *
+ * // f(x_0, x_1) = (g^x_0, g^x_1 * h^x_0)
+ * let f = Homomorphism::<RCtx>::new(2, vec![
+ *     vec![(0, g.clone())],
+ *     vec![(1, g.clone()), (0, h.clone())],
+ * ]).unwrap();
+ *
+ * let image = f.apply(&x).unwrap();
+ * assert_eq!(image[0], g.exp(&x[0]));
+ * assert_eq!(image[1], g.exp(&x[1]).mul(&h.exp(&x[0])));
+ * ```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Homomorphism<C: Context> {
+    /// The number of scalars of the preimage
+    scalars: usize,
+    /// The terms `(j, b)` of each group element of the image
+    rows: Vec<Vec<(usize, C::Element)>>,
+}
+
+impl<C: Context> Homomorphism<C> {
+    /// Construct a homomorphism with the given preimage size and rows.
+    ///
+    /// # Parameters
+    ///
+    /// - `scalars`: The number of scalars of the preimage
+    /// - `rows`: The terms `(j, b)` of each group element of the image
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if a term refers to a scalar outside the preimage
+    ///
+    /// Returns a [`Homomorphism`] instance.
+    pub fn new(scalars: usize, rows: Vec<Vec<(usize, C::Element)>>) -> Result<Self, Error> {
+        if rows.iter().flatten().any(|(j, _)| *j >= scalars) {
+            return Err(Error::SigmaProofError(format!(
+                "Homomorphism term refers to a scalar outside the preimage of size {scalars}"
+            )));
+        }
+
+        Ok(Self { scalars, rows })
+    }
+
+    /// Returns the number of scalars of the preimage.
+    #[must_use]
+    pub fn scalars(&self) -> usize {
+        self.scalars
+    }
+
+    /// Returns the number of group elements of the image.
+    #[must_use]
+    pub fn elements(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Applies this homomorphism to the given preimage.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the preimage does not have [`Homomorphism::scalars`] scalars
+    ///
+    /// Returns the image, one group element per row.
+    pub fn apply(&self, preimage: &[C::Scalar]) -> Result<Vec<C::Element>, Error> {
+        if preimage.len() != self.scalars {
+            return Err(Error::SigmaProofError(format!(
+                "Expected a preimage of {} scalars, got {}",
+                self.scalars,
+                preimage.len()
+            )));
+        }
+
+        Ok(self.eval(preimage))
+    }
+
+    /// Applies this homomorphism to a preimage of [`Homomorphism::scalars`] scalars.
+    fn eval(&self, preimage: &[C::Scalar]) -> Vec<C::Element> {
+        self.rows
+            .iter()
+            .map(|row| match row.as_slice() {
+                [] => C::Element::one(),
+                [(j, base)] => base.exp(&preimage[*j]),
+                _ => {
+                    let (bases, scalars): (Vec<C::Element>, Vec<C::Scalar>) = row
+                        .iter()
+                        .map(|(j, base)| (base.clone(), preimage[*j].clone()))
+                        .unzip();
+                    C::Element::multi_exp(&bases, &scalars)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the block diagonal homomorphism of the given homomorphisms.
+    ///
+    /// The preimage and the image are the concatenations of the preimages
+    /// and the images of `maps`, in order.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn and(maps: &[&Self]) -> Self {
+        let mut scalars = 0;
+        let mut rows = vec![];
+        for map in maps {
+            let offset = scalars;
+            rows.extend(map.rows.iter().map(|row| {
+                row.iter()
+                    .map(|(j, base)| (offset + j, base.clone()))
+                    .collect::<Vec<(usize, C::Element)>>()
+            }));
+            // the sum of lengths of existing vectors cannot overflow
+            scalars += map.scalars;
+        }
+
+        Self { scalars, rows }
+    }
+}
+
+/**
+ * A relation `f(x) = image` for a [`Homomorphism`] `f` and a public image.
+ *
+ * A witness of the relation is a preimage `x`. The three moves of the
+ * Sigma protocol for the relation are:
+ *
+ * - [`Relation::commit`]: the prover samples a nonce `a` and commits to `f(a)`
+ * - the verifier sends a challenge `c`
+ * - [`Relation::respond`]: the prover responds with `k = a + c * x`
+ *
+ * The verifier accepts if `f(k) = image^c * commitment`, see
+ * [`Relation::check`]. Relations compose conjunctively with
+ * [`Relation::and`], and disjunctively with [`OrProof`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::traits::groups::GroupElement;
+ * use crypto::zkp::sigma::{Homomorphism, Relation, SigmaProof};
+ *
+ * let g = RCtx::generator();
+ * let h = RCtx::random_element();
+ * let x = RCtx::random_scalar();
+ *
+ * // knowledge of x such that y_0 = g^x and y_1 = h^x
+ * let f = Homomorphism::<RCtx>::new(1, vec![vec![(0, g.clone())], vec![(0, h.clone())]]).unwrap();
+ * let relation = Relation::new(f, vec![g.exp(&x), h.exp(&x)]).unwrap();
+ *
+ * // Set to some relevant context value
+ * let proof_context = &[];
+ * let proof = SigmaProof::prove(&relation, &[x], proof_context).unwrap();
+ *
+ * let ok = proof.verify(&relation, proof_context).unwrap();
+ * assert!(ok);
+ * ```
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation<C: Context> {
+    /// The homomorphism
+    map: Homomorphism<C>,
+    /// The public image
+    image: Vec<C::Element>,
+}
+
+impl<C: Context> Relation<C> {
+    /// Construct a relation from the given homomorphism and image.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the image does not have [`Homomorphism::elements`] group elements
+    ///
+    /// Returns a [`Relation`] instance.
+    pub fn new(map: Homomorphism<C>, image: Vec<C::Element>) -> Result<Self, Error> {
+        if image.len() != map.elements() {
+            return Err(Error::SigmaProofError(format!(
+                "Expected an image of {} elements, got {}",
+                map.elements(),
+                image.len()
+            )));
+        }
+
+        Ok(Self { map, image })
+    }
+
+    /// Returns the homomorphism of this relation.
+    #[must_use]
+    pub fn map(&self) -> &Homomorphism<C> {
+        &self.map
+    }
+
+    /// Returns the public image of this relation.
+    #[must_use]
+    pub fn image(&self) -> &[C::Element] {
+        &self.image
+    }
+
+    /// Returns the conjunction of the given relations.
+    ///
+    /// A witness of the conjunction is the concatenation of witnesses of
+    /// `relations`, in order.
+    #[must_use]
+    pub fn and(relations: &[Self]) -> Self {
+        let maps: Vec<&Homomorphism<C>> = relations.iter().map(|r| &r.map).collect();
+        let image = relations.iter().flat_map(|r| r.image.clone()).collect();
+
+        Self {
+            map: Homomorphism::and(&maps),
+            image,
+        }
+    }
+
+    /// Samples a random nonce and commits to it.
+    ///
+    /// Returns the pair (nonce, commitment).
+    #[must_use]
+    pub fn commit(&self) -> (Vec<C::Scalar>, Vec<C::Element>) {
+        let mut rng = C::get_rng();
+        let nonce: Vec<C::Scalar> = (0..self.map.scalars)
+            .map(|_| C::Scalar::random(&mut rng))
+            .collect();
+        let commitment = self.map.eval(&nonce);
+
+        (nonce, commitment)
+    }
+
+    /// Commits to the given nonce.
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the nonce does not have [`Homomorphism::scalars`] scalars
+    ///
+    /// Returns the commitment `f(nonce)`.
+    pub fn commit_with_nonce(&self, nonce: &[C::Scalar]) -> Result<Vec<C::Element>, Error> {
+        self.map.apply(nonce)
+    }
+
+    /// Computes the response to a challenge.
+    ///
+    /// # Parameters
+    ///
+    /// - `witness`: The preimage of the image
+    /// - `nonce`: The committed nonce
+    /// - `challenge`: The challenge
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the witness or the nonce do not have [`Homomorphism::scalars`] scalars
+    ///
+    /// Returns the response `nonce + challenge * witness`.
+    pub fn respond(
+        &self,
+        witness: &[C::Scalar],
+        nonce: &[C::Scalar],
+        challenge: &C::Scalar,
+    ) -> Result<Vec<C::Scalar>, Error> {
+        if witness.len() != self.map.scalars || nonce.len() != self.map.scalars {
+            return Err(Error::SigmaProofError(format!(
+                "Expected a witness and a nonce of {} scalars, got {} and {}",
+                self.map.scalars,
+                witness.len(),
+                nonce.len()
+            )));
+        }
+
+        let response = nonce
+            .iter()
+            .zip(witness.iter())
+            .map(|(a, x)| a.add(&challenge.mul(x)))
+            .collect();
+
+        Ok(response)
+    }
+
+    /// Returns `true` if the verification equation
+    /// `f(response) == image^challenge * commitment` holds, `false` if it does
+    /// not hold or the lengths of `commitment` or `response` do not match.
+    #[must_use]
+    pub fn check(
+        &self,
+        commitment: &[C::Element],
+        challenge: &C::Scalar,
+        response: &[C::Scalar],
+    ) -> bool {
+        if commitment.len() != self.image.len() {
+            return false;
+        }
+        let Ok(f_k) = self.map.apply(response) else {
+            return false;
+        };
+
+        f_k.iter()
+            .zip(self.image.iter().zip(commitment.iter()))
+            .all(|(f_k, (y, big_a))| y.exp(challenge).mul(big_a).equals(f_k))
+    }
+
+    /// Simulates an accepting conversation for the given challenge.
+    ///
+    /// Returns the pair (commitment, response), distributed as in an honest
+    /// conversation with the same challenge.
+    #[must_use]
+    pub fn simulate(&self, challenge: &C::Scalar) -> (Vec<C::Element>, Vec<C::Scalar>) {
+        let mut rng = C::get_rng();
+        let response: Vec<C::Scalar> = (0..self.map.scalars)
+            .map(|_| C::Scalar::random(&mut rng))
+            .collect();
+        let minus_c = challenge.neg();
+        let commitment = self
+            .map
+            .eval(&response)
+            .iter()
+            .zip(self.image.iter())
+            .map(|(f_k, y)| f_k.mul(&y.exp(&minus_c)))
+            .collect();
+
+        (commitment, response)
+    }
+
+    /// Absorbs this relation, that is the homomorphism and the image, into the transcript.
+    pub fn absorb(&self, transcript: &mut Transcript<C>) {
+        let rows: Vec<Vec<(u64, C::Element)>> = self
+            .map
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|(j, base)| (*j as u64, base.clone()))
+                    .collect()
+            })
+            .collect();
+
+        transcript.absorb(b"scalars", &(self.map.scalars as u64));
+        transcript.absorb(b"rows", &rows);
+        transcript.absorb(b"image", &self.image);
+    }
+}
+
+/**
+ * Non-interactive Sigma proof of knowledge of a witness of a [`Relation`].
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::traits::groups::GroupElement;
+ * use crypto::zkp::sigma::{Homomorphism, Relation, SigmaProof};
+ *
+ * // Okamoto: knowledge of x_0, x_1 such that y = g^x_0 * h^x_1
+ * let g = RCtx::generator();
+ * let h = RCtx::random_element();
+ * let x = [RCtx::random_scalar(), RCtx::random_scalar()];
+ * let f = Homomorphism::<RCtx>::new(2, vec![vec![(0, g.clone()), (1, h.clone())]]).unwrap();
+ * let y = f.apply(&x).unwrap();
+ * let relation = Relation::new(f, y).unwrap();
+ *
+ * let proof = SigmaProof::prove(&relation, &x, &[]).unwrap();
+ * assert!(proof.verify(&relation, &[]).unwrap());
+ * ```
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct SigmaProof<C: Context> {
+    /// Prover commitment
+    pub commitment: Vec<C::Element>,
+    /// Challenge response
+    pub response: Vec<C::Scalar>,
+}
+
+impl<C: Context> SigmaProof<C> {
+    /// Construct a Sigma proof from the given values.
+    #[must_use]
+    pub fn new(commitment: Vec<C::Element>, response: Vec<C::Scalar>) -> Self {
+        Self {
+            commitment,
+            response,
+        }
+    }
+
+    /// Prove knowledge of a witness of the given relation.
+    ///
+    /// # Parameters
+    ///
+    /// - `relation`: The relation, public
+    /// - `witness`: The secret preimage
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the witness does not have the size of the preimage
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`SigmaProof`] instance.
+    pub fn prove(
+        relation: &Relation<C>,
+        witness: &[C::Scalar],
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        Self::prove_with_transcript(&mut transcript, relation, witness)
+    }
+
+    /// Prove knowledge of a witness of the given relation, deriving the
+    /// challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the relation and the proof
+    /// - `relation`: The relation, public
+    /// - `witness`: The secret preimage
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if the witness does not have the size of the preimage
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns a [`SigmaProof`] instance.
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        relation: &Relation<C>,
+        witness: &[C::Scalar],
+    ) -> Result<Self, Error> {
+        let (nonce, commitment) = relation.commit();
+
+        let v = Self::challenge(transcript, relation, &commitment)?;
+
+        let response = relation.respond(witness, &nonce, &v)?;
+
+        Ok(Self::new(commitment, response))
+    }
+
+    /// Simulates a proof for the given relation and challenge, without a witness.
+    ///
+    /// The result passes [`Relation::check`] with `challenge`, but does not
+    /// verify unless `challenge` is the Fiat-Shamir challenge of the proof.
+    #[must_use]
+    pub fn simulate(relation: &Relation<C>, challenge: &C::Scalar) -> Self {
+        let (commitment, response) = relation.simulate(challenge);
+
+        Self::new(commitment, response)
+    }
+
+    /// Verify this Sigma proof of knowledge
+    ///
+    /// # Parameters
+    ///
+    /// - `relation`: The relation
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify(&self, relation: &Relation<C>, proof_context: &[u8]) -> Result<bool, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        self.verify_with_transcript(&mut transcript, relation)
+    }
+
+    /// Verify this Sigma proof of knowledge, deriving the challenge from the given transcript
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `relation`: The relation
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        relation: &Relation<C>,
+    ) -> Result<bool, Error> {
+        let v = Self::challenge(transcript, relation, &self.commitment)?;
+
+        Ok(relation.check(&self.commitment, &v, &self.response))
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"sigma_proof";
+
+    /// Absorbs the relation and the commitment, and returns the challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        relation: &Relation<C>,
+        commitment: &Vec<C::Element>,
+    ) -> Result<C::Scalar, Error> {
+        relation.absorb(transcript);
+        transcript.absorb(b"commitment", commitment);
+
+        transcript.challenge(b"sigma_challenge")
+    }
+}
+
+/**
+ * Non-interactive proof of knowledge of a witness of one of several [`Relation`]s.
+ *
+ * Cramer-Damgard-Schoenmakers composition: the prover simulates every
+ * branch except the one it knows a witness for, and answers the remaining
+ * challenge, such that the challenges of all branches add up to the
+ * Fiat-Shamir challenge. The proof does not reveal the known branch.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::traits::groups::GroupElement;
+ * use crypto::zkp::sigma::{Homomorphism, OrProof, Relation};
+ *
+ * // knowledge of the discrete logarithm of y_0 or of y_1
+ * let g = RCtx::generator();
+ * let x = RCtx::random_scalar();
+ * let dlog = |y| {
+ *     let f = Homomorphism::<RCtx>::new(1, vec![vec![(0, g.clone())]]).unwrap();
+ *     Relation::new(f, vec![y]).unwrap()
+ * };
+ * let relations = [dlog(RCtx::random_element()), dlog(g.exp(&x))];
+ *
+ * let proof = OrProof::prove(&relations, 1, &[x], &[]).unwrap();
+ * assert!(proof.verify(&relations, &[]).unwrap());
+ * ```
+ */
+#[derive(Debug, Clone, VSerializable, PartialEq)]
+pub struct OrProof<C: Context> {
+    /// Prover commitment of each branch
+    pub commitments: Vec<Vec<C::Element>>,
+    /// Challenge of each branch
+    pub challenges: Vec<C::Scalar>,
+    /// Challenge response of each branch
+    pub responses: Vec<Vec<C::Scalar>>,
+}
+
+impl<C: Context> OrProof<C> {
+    /// Construct a disjunctive proof from the given values.
+    #[must_use]
+    pub fn new(
+        commitments: Vec<Vec<C::Element>>,
+        challenges: Vec<C::Scalar>,
+        responses: Vec<Vec<C::Scalar>>,
+    ) -> Self {
+        Self {
+            commitments,
+            challenges,
+            responses,
+        }
+    }
+
+    /// Prove knowledge of a witness of one of the given relations.
+    ///
+    /// # Parameters
+    ///
+    /// - `relations`: The relations, public
+    /// - `index`: The index of the relation the witness belongs to, secret
+    /// - `witness`: The secret preimage
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if `index` is out of bounds or the witness does not have the size of the preimage
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns an [`OrProof`] instance.
+    pub fn prove(
+        relations: &[Relation<C>],
+        index: usize,
+        witness: &[C::Scalar],
+        proof_context: &[u8],
+    ) -> Result<Self, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        Self::prove_with_transcript(&mut transcript, relations, index, witness)
+    }
+
+    /// Prove knowledge of a witness of one of the given relations, deriving
+    /// the challenge from the given transcript.
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, which absorbs the relations and the proof
+    /// - `relations`: The relations, public
+    /// - `index`: The index of the relation the witness belongs to, secret
+    /// - `witness`: The secret preimage
+    ///
+    /// # Errors
+    ///
+    /// - `SigmaProofError` if `index` is out of bounds or the witness does not have the size of the preimage
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns an [`OrProof`] instance.
+    pub fn prove_with_transcript(
+        transcript: &mut Transcript<C>,
+        relations: &[Relation<C>],
+        index: usize,
+        witness: &[C::Scalar],
+    ) -> Result<Self, Error> {
+        let Some(known) = relations.get(index) else {
+            return Err(Error::SigmaProofError(format!(
+                "Branch {index} is out of bounds for {} relations",
+                relations.len()
+            )));
+        };
+
+        let mut rng = C::get_rng();
+        let mut nonce = vec![];
+        let mut commitments = Vec::with_capacity(relations.len());
+        let mut challenges = Vec::with_capacity(relations.len());
+        let mut responses = Vec::with_capacity(relations.len());
+        for (j, relation) in relations.iter().enumerate() {
+            if j == index {
+                let (a, big_a) = relation.commit();
+                nonce = a;
+                commitments.push(big_a);
+                // replaced below
+                challenges.push(C::Scalar::zero());
+                responses.push(vec![]);
+            } else {
+                let c_j = C::Scalar::random(&mut rng);
+                let (big_a, k) = relation.simulate(&c_j);
+                commitments.push(big_a);
+                challenges.push(c_j);
+                responses.push(k);
+            }
+        }
+
+        let v = Self::challenge(transcript, relations, &commitments)?;
+
+        let simulated = challenges
+            .iter()
+            .fold(C::Scalar::zero(), |sum, c_j| sum.add(c_j));
+        let c = v.sub(&simulated);
+        let k = known.respond(witness, &nonce, &c)?;
+        challenges[index] = c;
+        responses[index] = k;
+
+        Ok(Self::new(commitments, challenges, responses))
+    }
+
+    /// Verify this disjunctive proof of knowledge
+    ///
+    /// # Parameters
+    ///
+    /// - `relations`: The relations
+    /// - `proof_context`: proof context label (ZKP CONTEXT)
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify(&self, relations: &[Relation<C>], proof_context: &[u8]) -> Result<bool, Error> {
+        let mut transcript = Transcript::new(Self::PROTOCOL, proof_context);
+
+        self.verify_with_transcript(&mut transcript, relations)
+    }
+
+    /// Verify this disjunctive proof of knowledge, deriving the challenge from the given transcript
+    ///
+    /// # Parameters
+    ///
+    /// - `transcript`: The Fiat-Shamir transcript, in the same state as when proving
+    /// - `relations`: The relations
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    ///
+    /// Returns `true` if the proof is valid, `false` otherwise.
+    pub fn verify_with_transcript(
+        &self,
+        transcript: &mut Transcript<C>,
+        relations: &[Relation<C>],
+    ) -> Result<bool, Error> {
+        let n = relations.len();
+        if self.commitments.len() != n || self.challenges.len() != n || self.responses.len() != n {
+            return Ok(false);
+        }
+
+        let v = Self::challenge(transcript, relations, &self.commitments)?;
+
+        let sum = self
+            .challenges
+            .iter()
+            .fold(C::Scalar::zero(), |sum, c_j| sum.add(c_j));
+        if !sum.equals(&v) {
+            return Ok(false);
+        }
+
+        let ok = relations
+            .iter()
+            .zip(self.commitments.iter())
+            .zip(self.challenges.iter().zip(self.responses.iter()))
+            .all(|((relation, big_a), (c_j, k))| relation.check(big_a, c_j, k));
+
+        Ok(ok)
+    }
+
+    /// Protocol label of the transcript
+    const PROTOCOL: &'static [u8] = b"sigma_or_proof";
+
+    /// Absorbs the relations and the commitments, and returns the challenge.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if challenge generation returns error
+    fn challenge(
+        transcript: &mut Transcript<C>,
+        relations: &[Relation<C>],
+        commitments: &Vec<Vec<C::Element>>,
+    ) -> Result<C::Scalar, Error> {
+        transcript.absorb(b"branches", &(relations.len() as u64));
+        for relation in relations {
+            relation.absorb(transcript);
+        }
+        transcript.absorb(b"commitments", commitments);
+
+        transcript.challenge(b"sigma_or_challenge")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::utils::serialization::{VDeserializable, VSerializable};
+
+    #[test]
+    fn test_sigma_proof_ristretto() {
+        test_sigma_proof::<RCtx>();
+    }
+
+    #[test]
+    fn test_sigma_simulate_ristretto() {
+        test_sigma_simulate::<RCtx>();
+    }
+
+    #[test]
+    fn test_sigma_and_ristretto() {
+        test_sigma_and::<RCtx>();
+    }
+
+    #[test]
+    fn test_sigma_or_ristretto() {
+        test_sigma_or::<RCtx>();
+    }
+
+    #[test]
+    fn test_sigma_malformed_ristretto() {
+        test_sigma_malformed::<RCtx>();
+    }
+
+    #[test]
+    fn test_sigma_proof_p256() {
+        test_sigma_proof::<PCtx>();
+    }
+
+    #[test]
+    fn test_sigma_simulate_p256() {
+        test_sigma_simulate::<PCtx>();
+    }
+
+    #[test]
+    fn test_sigma_and_p256() {
+        test_sigma_and::<PCtx>();
+    }
+
+    #[test]
+    fn test_sigma_or_p256() {
+        test_sigma_or::<PCtx>();
+    }
+
+    /// Returns the relation `y = g^x`.
+    fn dlog<Ctx: Context>(g: &Ctx::Element, y: &Ctx::Element) -> Relation<Ctx> {
+        let f = Homomorphism::new(1, vec![vec![(0, g.clone())]]).unwrap();
+        Relation::new(f, vec![y.clone()]).unwrap()
+    }
+
+    /// Returns the relation `(u, v) = (g^r, m * h^r)` for a known `m`, with witness `r`.
+    fn encryption<Ctx: Context>(
+        g: &Ctx::Element,
+        h: &Ctx::Element,
+        u: &Ctx::Element,
+        v_over_m: &Ctx::Element,
+    ) -> Relation<Ctx> {
+        let f = Homomorphism::new(1, vec![vec![(0, g.clone())], vec![(0, h.clone())]]).unwrap();
+        Relation::new(f, vec![u.clone(), v_over_m.clone()]).unwrap()
+    }
+
+    fn test_sigma_proof<Ctx: Context>() {
+        let g = Ctx::generator();
+        let h = Ctx::random_element();
+        let x = [Ctx::random_scalar(), Ctx::random_scalar()];
+        let f = Homomorphism::<Ctx>::new(
+            2,
+            vec![
+                vec![(0, g.clone()), (1, h.clone())],
+                vec![(1, g.clone())],
+                vec![],
+            ],
+        )
+        .unwrap();
+        let image = f.apply(&x).unwrap();
+        assert!(image[2].equals(&Ctx::Element::one()));
+        let relation = Relation::new(f, image).unwrap();
+
+        let proof = SigmaProof::prove(&relation, &x, b"context").unwrap();
+        assert!(proof.verify(&relation, b"context").unwrap());
+        assert!(!proof.verify(&relation, b"other context").unwrap());
+
+        let bytes = proof.ser();
+        let parsed = SigmaProof::<Ctx>::deser(&bytes).unwrap();
+        assert_eq!(proof, parsed);
+        assert!(parsed.verify(&relation, b"context").unwrap());
+
+        let mut tampered = proof.clone();
+        tampered.response[1] = tampered.response[1].add(&Ctx::Scalar::one());
+        assert!(!tampered.verify(&relation, b"context").unwrap());
+
+        let mut truncated = proof.clone();
+        truncated.response.pop();
+        assert!(!truncated.verify(&relation, b"context").unwrap());
+
+        // a different statement with the same proof
+        let other = Relation::new(
+            relation.map().clone(),
+            vec![
+                Ctx::random_element(),
+                relation.image()[1].clone(),
+                relation.image()[2].clone(),
+            ],
+        )
+        .unwrap();
+        assert!(!proof.verify(&other, b"context").unwrap());
+
+        // a wrong witness
+        let wrong = [x[0].add(&Ctx::Scalar::one()), x[1].clone()];
+        let proof = SigmaProof::prove(&relation, &wrong, b"context").unwrap();
+        assert!(!proof.verify(&relation, b"context").unwrap());
+    }
+
+    fn test_sigma_simulate<Ctx: Context>() {
+        let g = Ctx::generator();
+        let relation = dlog::<Ctx>(&g, &Ctx::random_element());
+        let c = Ctx::random_scalar();
+
+        let simulated = SigmaProof::simulate(&relation, &c);
+        assert!(relation.check(&simulated.commitment, &c, &simulated.response));
+        assert!(!relation.check(
+            &simulated.commitment,
+            &c.add(&Ctx::Scalar::one()),
+            &simulated.response
+        ));
+        // without the witness, the Fiat-Shamir challenge is not the simulated one
+        assert!(!simulated.verify(&relation, &[]).unwrap());
+    }
+
+    fn test_sigma_and<Ctx: Context>() {
+        let g = Ctx::generator();
+        let h = Ctx::random_element();
+        let x = Ctx::random_scalar();
+        let r = Ctx::random_scalar();
+
+        let relations = [
+            dlog::<Ctx>(&g, &g.exp(&x)),
+            encryption::<Ctx>(&g, &h, &g.exp(&r), &h.exp(&r)),
+        ];
+        let relation = Relation::and(&relations);
+        assert_eq!(relation.map().scalars(), 2);
+        assert_eq!(relation.map().elements(), 3);
+
+        let proof = SigmaProof::prove(&relation, &[x.clone(), r.clone()], &[]).unwrap();
+        assert!(proof.verify(&relation, &[]).unwrap());
+
+        // the witnesses are in the order of the relations
+        let proof = SigmaProof::prove(&relation, &[r, x], &[]).unwrap();
+        assert!(!proof.verify(&relation, &[]).unwrap());
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn test_sigma_or<Ctx: Context>() {
+        let g = Ctx::generator();
+        let h = Ctx::random_element();
+        let r = Ctx::random_scalar();
+        let u = g.exp(&r);
+        let hr = h.exp(&r);
+        let v = g.mul(&hr);
+
+        // (u, v) encrypts one of g^0, g^1, g^2, as in a disjunctive range proof
+        let relations: Vec<Relation<Ctx>> = (0..3u32)
+            .map(|m| {
+                let g_m = g.exp(&Ctx::Scalar::from(m));
+                encryption::<Ctx>(&g, &h, &u, &v.mul(&g_m.inv()))
+            })
+            .collect();
+
+        let proof = OrProof::prove(&relations, 1, std::slice::from_ref(&r), &[]).unwrap();
+        assert!(proof.verify(&relations, &[]).unwrap());
+        assert!(!proof.verify(&relations, b"other context").unwrap());
+        assert!(!proof.verify(&relations[..2], &[]).unwrap());
+
+        let bytes = proof.ser();
+        let parsed = OrProof::<Ctx>::deser(&bytes).unwrap();
+        assert!(parsed.verify(&relations, &[]).unwrap());
+
+        let mut tampered = proof.clone();
+        tampered.challenges.swap(0, 2);
+        assert!(!tampered.verify(&relations, &[]).unwrap());
+
+        // the witness does not belong to the claimed branch
+        let proof = OrProof::prove(&relations, 2, std::slice::from_ref(&r), &[]).unwrap();
+        assert!(!proof.verify(&relations, &[]).unwrap());
+
+        // branches are composable, for example with conjunctions
+        let x = Ctx::random_scalar();
+        let both = [
+            Relation::and(&[relations[0].clone(), dlog::<Ctx>(&g, &g.exp(&x))]),
+            Relation::and(&[relations[1].clone(), dlog::<Ctx>(&g, &g.exp(&x))]),
+        ];
+        let proof = OrProof::prove(&both, 1, &[r, x], &[]).unwrap();
+        assert!(proof.verify(&both, &[]).unwrap());
+    }
+
+    fn test_sigma_malformed<Ctx: Context>() {
+        let g = Ctx::generator();
+
+        let f = Homomorphism::<Ctx>::new(1, vec![vec![(1, g.clone())]]);
+        assert!(matches!(f, Err(Error::SigmaProofError(_))));
+
+        let f = Homomorphism::<Ctx>::new(1, vec![vec![(0, g.clone())]]).unwrap();
+        assert!(f.apply(&[]).is_err());
+        assert!(Relation::new(f, vec![]).is_err());
+
+        let relation = dlog::<Ctx>(&g, &g);
+        let one = Ctx::Scalar::one();
+        assert!(SigmaProof::prove(&relation, &[], &[]).is_err());
+        assert!(SigmaProof::prove(&relation, &[one.clone(), one.clone()], &[]).is_err());
+        assert!(
+            OrProof::prove(
+                std::slice::from_ref(&relation),
+                1,
+                std::slice::from_ref(&one),
+                &[]
+            )
+            .is_err()
+        );
+        assert!(OrProof::<Ctx>::prove(&[], 0, &[one], &[]).is_err());
+    }
+}
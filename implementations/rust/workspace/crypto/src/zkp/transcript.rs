@@ -0,0 +1,244 @@
+/*
+ * Fiat-Shamir transcripts
+ *
+ * @author David Ruescas (david@sequentech.io)\
+ * @author Frank Zeyda (frank.zeyda@freeandfair.us)\
+ * @copyright Free & Fair. 2025\
+ * @version 0.1
+ */
+
+use crate::context::Context;
+use crate::traits::groups::CryptoGroup;
+use crate::utils::error::Error;
+use crate::utils::serialization::VSerializable;
+use sha3::Digest;
+
+/**
+ * The version of the Fiat-Shamir challenge computation of a proof.
+ *
+ * Proofs do not record their version. New proofs are always computed with
+ * [`ChallengeVersion::CURRENT`], which is also the version checked by the
+ * `verify` functions. Proofs of an earlier version are checked with the
+ * `verify_with_version` functions, for example
+ * [`SchnorrProof::verify_with_version`][`crate::zkp::schnorr::SchnorrProof::verify_with_version`],
+ * and the `_with_version` variants of the protocol functions that verify proofs,
+ * for example [`combine_with_version`][`crate::dkgd::recipient::combine_with_version`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChallengeVersion {
+    /// Challenges hashed from a fixed list of inputs per proof, which does
+    /// not include the group and, for some proofs, the full statement.
+    V0,
+    /// Challenges derived from a [`Transcript`].
+    V1,
+}
+
+impl ChallengeVersion {
+    /// The version of newly computed proofs
+    pub const CURRENT: Self = Self::V1;
+}
+
+/**
+ * A labelled Fiat-Shamir transcript.
+ *
+ * A transcript follows the strong Fiat-Shamir rule: on construction it absorbs
+ * its version, the protocol label, the group and the proof context, and proofs
+ * absorb their full statement before their commitments. Every value is
+ * absorbed with its label and the lengths of both, so that different
+ * sequences of values never hash to the same input.
+ *
+ * Each challenge is absorbed back into the transcript, so that every later
+ * challenge depends on it. This allows several rounds of one proof, or several
+ * composed proofs, to share one transcript. A transcript can be cloned after
+ * absorbing common values, for example to verify a batch of proofs with the
+ * same prefix.
+ *
+ * # Examples
+ *
+ * ```
+ * use crypto::context::Context;
+ * use crypto::context::RistrettoCtx as RCtx;
+ * use crypto::traits::groups::GroupElement;
+ * use crypto::zkp::schnorr::SchnorrProof;
+ * use crypto::zkp::transcript::Transcript;
+ *
+ * let g = RCtx::generator();
+ * let secrets = [RCtx::random_scalar(), RCtx::random_scalar()];
+ * let ys = secrets.each_ref().map(|x| g.exp(x));
+ *
+ * // two proofs sharing one transcript: the second challenge binds the first proof
+ * let mut transcript = Transcript::<RCtx>::new(b"two_keys", &[]);
+ * let first = SchnorrProof::prove_with_transcript(&mut transcript, &g, &ys[0], &secrets[0]).unwrap();
+ * let second = SchnorrProof::prove_with_transcript(&mut transcript, &g, &ys[1], &secrets[1]).unwrap();
+ *
+ * let mut transcript = Transcript::<RCtx>::new(b"two_keys", &[]);
+ * assert!(first.verify_with_transcript(&mut transcript, &g, &ys[0]).unwrap());
+ * assert!(second.verify_with_transcript(&mut transcript, &g, &ys[1]).unwrap());
+ * ```
+ */
+pub struct Transcript<C: Context> {
+    /// The running hash of the absorbed values
+    hasher: C::Hasher,
+}
+
+impl<C: Context> Transcript<C> {
+    /// Domain separator of every transcript
+    const DOMAIN: &'static [u8] = b"fiat_shamir_transcript";
+
+    /// Domain separation tag of every challenge
+    const CHALLENGE_TAG: &'static [u8] = b"fiat_shamir_transcript_challenge";
+
+    /// Construct a transcript for the given protocol and proof context.
+    ///
+    /// # Parameters
+    ///
+    /// - `protocol`: the label of the protocol, unique among proofs
+    /// - `context`: proof context label (ZKP CONTEXT)
+    ///
+    /// The group is identified by its generator.
+    #[must_use]
+    pub fn new(protocol: &[u8], context: &[u8]) -> Self {
+        let mut ret = Self {
+            hasher: C::get_hasher(),
+        };
+        ret.absorb_bytes(b"domain", Self::DOMAIN);
+        ret.absorb_bytes(b"version", &[1]);
+        ret.absorb_bytes(b"protocol", protocol);
+        ret.absorb(b"group", &C::generator());
+        ret.absorb_bytes(b"context", context);
+
+        ret
+    }
+
+    /// Absorb the serialization of the given value.
+    pub fn absorb<V: VSerializable>(&mut self, label: &[u8], value: &V) {
+        self.absorb_bytes(label, &value.ser());
+    }
+
+    /// Absorb the given bytes.
+    pub fn absorb_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        update_framed(&mut self.hasher, label);
+        update_framed(&mut self.hasher, bytes);
+    }
+
+    /// Derive a challenge from the values absorbed so far and the given label.
+    ///
+    /// The label is hashed together with the absorbed values, and the challenge
+    /// is then absorbed into this transcript under that label.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if hashing to a scalar returns error
+    pub fn challenge(&mut self, label: &[u8]) -> Result<C::Scalar, Error> {
+        let digest = self.digest(label);
+        let ret = C::G::hash_to_scalar(&[&digest], &[Self::CHALLENGE_TAG])?;
+        self.absorb(label, &ret);
+
+        Ok(ret)
+    }
+
+    /// Derive `count` challenges from the values absorbed so far and the given label.
+    ///
+    /// The label is hashed together with the absorbed values, and the challenges
+    /// are then absorbed into this transcript under that label.
+    ///
+    /// # Errors
+    ///
+    /// - `HashToScalarError` if hashing to a scalar returns error
+    pub fn challenges(&mut self, label: &[u8], count: usize) -> Result<Vec<C::Scalar>, Error> {
+        let digest = self.digest(label);
+        let ret = (0..count as u64)
+            .map(|i| C::G::hash_to_scalar(&[&digest, &i.to_be_bytes()], &[Self::CHALLENGE_TAG]))
+            .collect::<Result<Vec<C::Scalar>, Error>>()?;
+        self.absorb(label, &ret);
+
+        Ok(ret)
+    }
+
+    /// Returns the hash of the values absorbed so far and the challenge label.
+    fn digest(&self, label: &[u8]) -> Vec<u8> {
+        let mut hasher = self.hasher.clone();
+        update_framed(&mut hasher, b"challenge");
+        update_framed(&mut hasher, label);
+
+        hasher.finalize().to_vec()
+    }
+}
+
+impl<C: Context> Clone for Transcript<C> {
+    fn clone(&self) -> Self {
+        Self {
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+/// Update the hasher with the given bytes, prefixed with their length.
+fn update_framed(hasher: &mut impl Digest, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_be_bytes());
+    hasher.update(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::P256Ctx as PCtx;
+    use crate::context::RistrettoCtx as RCtx;
+    use crate::traits::groups::GroupScalar;
+
+    #[test]
+    fn test_transcript_ristretto() {
+        test_transcript::<RCtx>();
+    }
+
+    #[test]
+    fn test_transcript_p256() {
+        test_transcript::<PCtx>();
+    }
+
+    fn test_transcript<Ctx: Context>() {
+        let challenge = |protocol: &[u8], context: &[u8], values: &[(&[u8], &[u8])]| {
+            let mut transcript = Transcript::<Ctx>::new(protocol, context);
+            for (label, bytes) in values {
+                transcript.absorb_bytes(label, bytes);
+            }
+            transcript.challenge(b"c").unwrap()
+        };
+
+        let base = challenge(b"p", b"ctx", &[(b"a", b"xy")]);
+        assert_eq!(base, challenge(b"p", b"ctx", &[(b"a", b"xy")]));
+
+        // the challenge label is bound
+        let mut transcript = Transcript::<Ctx>::new(b"p", b"ctx");
+        transcript.absorb_bytes(b"a", b"xy");
+        assert_ne!(base, transcript.clone().challenge(b"d").unwrap());
+        assert_ne!(
+            transcript.clone().challenges(b"c", 1).unwrap(),
+            transcript.challenges(b"d", 1).unwrap()
+        );
+
+        // protocol, context, labels and values are all bound
+        assert_ne!(base, challenge(b"q", b"ctx", &[(b"a", b"xy")]));
+        assert_ne!(base, challenge(b"p", b"other", &[(b"a", b"xy")]));
+        assert_ne!(base, challenge(b"p", b"ctx", &[(b"b", b"xy")]));
+        assert_ne!(base, challenge(b"p", b"ctx", &[(b"a", b"xz")]));
+
+        // values are framed, moving bytes across boundaries changes the challenge
+        let split = challenge(b"p", b"ctx", &[(b"a", b"x"), (b"a", b"y")]);
+        let joined = challenge(b"p", b"ctx", &[(b"a", b"xy"), (b"a", b"")]);
+        assert_ne!(split, joined);
+
+        // challenges are absorbed, and cloned transcripts evolve independently
+        let mut transcript = Transcript::<Ctx>::new(b"p", b"ctx");
+        let fork = transcript.clone();
+        let first = transcript.challenge(b"c").unwrap();
+        let second = transcript.challenge(b"c").unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first, fork.clone().challenge(b"c").unwrap());
+
+        let many = fork.clone().challenges(b"c", 3).unwrap();
+        assert_eq!(many.len(), 3);
+        assert_ne!(many[0], many[1]);
+        assert_ne!(many[0], Ctx::Scalar::zero());
+    }
+}